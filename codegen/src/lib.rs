@@ -0,0 +1,1116 @@
+//! Code generator for the product impls of `gual`.
+//!
+//! An [`Algebra`] is described by its signature (the square of each basis vector) and by the
+//! basis blades each k-vector type stores, in field order. The name of a blade gives its
+//! orientation, so `zyx` is `-xyz`. From that [`generate`] emits the wedge, antiwedge, geometric,
+//! antigeometric, dot, dual, contraction and expansion impls for every pair of k-vector types, and
+//! the antigeometric ones for the composite types too.
+//!
+//! All "anti" operations are built from the right and left complements of the unit volume (the
+//! only blade of the highest grade) the same way `gual::canonical` does:
+//! `a antiop b = left_complement(right_complement(a) op right_complement(b))`.
+//!
+//! The algebras whose types have a metric parameter are split in several files instead:
+//! [`generate_metric`] emits the products that depend on the metric, the geometric product for every
+//! pair of k-vector and composite types plus the dot, dual, contraction and expansion, once per
+//! metric. [`generate_exterior`] emits the wedge and antiwedge products for every pair of k-vector
+//! and composite types, generic over the metric. The `*` operator comes with the geometric products
+//! of [`generate`], and from [`generate_mul`] for the algebras with a metric parameter.
+//!
+//! The impls on the normalized types of `gual::homogeneous3d` (points, lines, planes and their
+//! ideal counterparts) are not products between k-vectors of one algebra and stay hand-written.
+//!
+//! Run `cargo run -p gual-codegen` to regenerate the checked-in files.
+
+use std::fmt::Write;
+
+/// A k-vector type of the algebra
+pub struct KVectorType {
+    /// Name of the rust type
+    pub name: &'static str,
+    /// Blades stored in the type, in field order. The field is named like the blade.
+    ///
+    /// The grade 0 type is built with `new` and must have an empty blade name.
+    pub blades: &'static [&'static str],
+}
+
+/// A type that stores several k-vectors, each one in a field
+#[derive(Clone, Copy)]
+pub struct CompositeType {
+    /// Name of the rust type
+    pub name: &'static str,
+    /// Grade and field name of each k-vector stored
+    pub fields: &'static [(usize, &'static str)],
+}
+
+/// Geometric algebra to generate code for
+#[derive(Clone, Copy)]
+pub struct Algebra {
+    /// Each basis vector with its square
+    pub signature: &'static [(char, i32)],
+    /// Types for each grade, from the scalar to the antiscalar
+    pub kvectors: &'static [KVectorType],
+    /// Type used for results with several even grades
+    pub even: CompositeType,
+    /// Type used for any other result with several grades
+    pub full: CompositeType,
+    /// Metric marker the types are generic over, they then store it in a `_metric: PhantomData`
+    /// field
+    pub metric: Option<&'static str>,
+    /// Whether scalars are a bare `T` instead of the grade 0 type
+    pub bare_scalar: bool,
+}
+
+/// Projective geometric algebra of 3D space, used by `gual::geometry4d`
+pub const PROJECTIVE_3D: Algebra = Algebra {
+    signature: &[('x', 1), ('y', 1), ('z', 1), ('w', 0)],
+    kvectors: &[
+        KVectorType {
+            name: "Scalar",
+            blades: &[""],
+        },
+        KVectorType {
+            name: "Vector",
+            blades: &["x", "y", "z", "w"],
+        },
+        KVectorType {
+            name: "Bivector",
+            blades: &["wx", "wy", "wz", "yz", "zx", "xy"],
+        },
+        KVectorType {
+            name: "Trivector",
+            blades: &["wyz", "wzx", "wxy", "zyx"],
+        },
+        KVectorType {
+            name: "Quadvector",
+            blades: &["xyzw"],
+        },
+    ],
+    even: CompositeType {
+        name: "Evenvector",
+        fields: &[(0, "s"), (2, "b"), (4, "a")],
+    },
+    full: CompositeType {
+        name: "Multivector",
+        fields: &[(0, "s"), (1, "v"), (2, "b"), (3, "t"), (4, "a")],
+    },
+    metric: None,
+    bare_scalar: false,
+};
+
+/// Types of `gual::geometry3d`, shared by its euclidean and projective metrics
+const KVECTORS_3D: &[KVectorType] = &[
+    KVectorType {
+        name: "Scalar",
+        blades: &[""],
+    },
+    KVectorType {
+        name: "Vector",
+        blades: &["x", "y", "z"],
+    },
+    KVectorType {
+        name: "Bivector",
+        blades: &["yz", "zx", "xy"],
+    },
+    KVectorType {
+        name: "Trivector",
+        blades: &["xyz"],
+    },
+];
+
+/// Euclidean geometric algebra of 3D space, used by `gual::geometry3d`
+pub const EUCLIDEAN_3D: Algebra = Algebra {
+    signature: &[('x', 1), ('y', 1), ('z', 1)],
+    kvectors: KVECTORS_3D,
+    even: CompositeType {
+        name: "Evenvector",
+        fields: &[(0, "s"), (2, "b")],
+    },
+    full: CompositeType {
+        name: "Multivector",
+        fields: &[(0, "s"), (1, "v"), (2, "b"), (3, "t")],
+    },
+    metric: Some("Euclidean"),
+    bare_scalar: true,
+};
+
+/// Projective geometric algebra of the plane, on the types of `gual::geometry3d`
+pub const PROJECTIVE_2D: Algebra = Algebra {
+    signature: &[('x', 1), ('y', 1), ('z', 0)],
+    metric: Some("Projective"),
+    ..EUCLIDEAN_3D
+};
+
+/// Function that generates the source of a file for an algebra
+pub type Generator = fn(&Algebra) -> String;
+
+/// Files generated by this crate, relative to the root of the workspace, with the algebra and the
+/// function that generates them
+pub const OUTPUTS: &[(&str, &Algebra, Generator)] = &[
+    ("src/geometry4d/products4d.rs", &PROJECTIVE_3D, generate),
+    (
+        "src/geometry3d/products3d.rs",
+        &EUCLIDEAN_3D,
+        generate_metric,
+    ),
+    (
+        "src/geometry3d/projective_products3d.rs",
+        &PROJECTIVE_2D,
+        generate_metric,
+    ),
+    (
+        "src/geometry3d/exterior3d.rs",
+        &EUCLIDEAN_3D,
+        generate_exterior,
+    ),
+    (
+        "src/geometry3d/geometric_mul3d.rs",
+        &EUCLIDEAN_3D,
+        generate_mul,
+    ),
+];
+
+/// A signed basis element: `sign * e_mask`, where `e_mask` has its vectors in signature order
+type Element = Option<(i32, u32)>;
+
+impl Algebra {
+    fn dimension(&self) -> usize {
+        self.signature.len()
+    }
+
+    fn volume_mask(&self) -> u32 {
+        (1 << self.dimension()) - 1
+    }
+
+    /// Mask and orientation of a blade name
+    fn parse(&self, blade: &str) -> (i32, u32) {
+        let indices: Vec<usize> = blade
+            .chars()
+            .map(|c| {
+                self.signature
+                    .iter()
+                    .position(|(v, _)| *v == c)
+                    .unwrap_or_else(|| panic!("unknown basis vector {c} in blade {blade}"))
+            })
+            .collect();
+        let mut mask = 0;
+        for i in &indices {
+            assert!(
+                mask & (1 << i) == 0,
+                "repeated basis vector in blade {blade}"
+            );
+            mask |= 1 << i;
+        }
+        let inversions = indices
+            .iter()
+            .enumerate()
+            .map(|(n, i)| indices[n + 1..].iter().filter(|j| *j < i).count())
+            .sum::<usize>();
+        (if inversions % 2 == 0 { 1 } else { -1 }, mask)
+    }
+
+    /// Grade and index of the field that stores a mask
+    fn locate(&self, mask: u32) -> (usize, usize, i32) {
+        let grade = mask.count_ones() as usize;
+        self.kvectors[grade]
+            .blades
+            .iter()
+            .enumerate()
+            .find_map(|(index, blade)| {
+                let (sign, m) = self.parse(blade);
+                (m == mask).then_some((grade, index, sign))
+            })
+            .unwrap_or_else(|| panic!("no field stores the blade with mask {mask:b}"))
+    }
+
+    fn square(&self, mask: u32) -> i32 {
+        (0..self.dimension())
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| self.signature[i].1)
+            .product()
+    }
+
+    /// Sign of reordering `e_a e_b` into `e_(a | b)`, ignoring repeated vectors
+    fn reorder(&self, a: u32, b: u32) -> i32 {
+        let swaps = (0..self.dimension())
+            .filter(|i| b & (1 << i) != 0)
+            .map(|i| (a >> (i + 1)).count_ones())
+            .sum::<u32>();
+        if swaps % 2 == 0 { 1 } else { -1 }
+    }
+
+    fn geometric(&self, a: Element, b: Element) -> Element {
+        let ((sa, a), (sb, b)) = (a?, b?);
+        let sign = sa * sb * self.reorder(a, b) * self.square(a & b);
+        (sign != 0).then_some((sign, a ^ b))
+    }
+
+    fn wedge(&self, a: Element, b: Element) -> Element {
+        let ((_, ma), (_, mb)) = (a?, b?);
+        if ma & mb != 0 {
+            None
+        } else {
+            self.geometric(a, b)
+        }
+    }
+
+    fn volume_sign(&self) -> i32 {
+        let volume = self.kvectors[self.dimension()].blades[0];
+        self.parse(volume).0
+    }
+
+    /// `a ^ right_complement(a)` is the unit volume
+    fn right_complement(&self, a: Element) -> Element {
+        let (sa, a) = a?;
+        let c = self.volume_mask() ^ a;
+        Some((sa * self.volume_sign() * self.reorder(a, c), c))
+    }
+
+    /// `left_complement(a) ^ a` is the unit volume
+    fn left_complement(&self, a: Element) -> Element {
+        let (sa, a) = a?;
+        let c = self.volume_mask() ^ a;
+        Some((sa * self.volume_sign() * self.reorder(c, a), c))
+    }
+
+    fn bulk(&self, a: Element) -> Element {
+        let (sa, a) = a?;
+        let sign = sa * self.square(a);
+        (sign != 0).then_some((sign, a))
+    }
+
+    fn weight(&self, a: Element) -> Element {
+        let (sa, a) = a?;
+        let sign = sa * self.square(self.volume_mask() ^ a);
+        (sign != 0).then_some((sign, a))
+    }
+
+    fn antiwedge(&self, a: Element, b: Element) -> Element {
+        let product = self.wedge(self.right_complement(a), self.right_complement(b));
+        self.left_complement(product)
+    }
+
+    fn antigeometric(&self, a: Element, b: Element) -> Element {
+        let product = self.geometric(self.right_complement(a), self.right_complement(b));
+        self.left_complement(product)
+    }
+
+    fn kvector(&self, grade: usize) -> &KVectorType {
+        &self.kvectors[grade]
+    }
+
+    /// Name of a type of the algebra with its generic parameters
+    fn type_name(&self, name: &str) -> String {
+        match self.metric {
+            Some(metric) => format!("{name}<T, {metric}>"),
+            None => format!("{name}<T>"),
+        }
+    }
+
+    /// Generic parameters of the impls, the metric is one of them when it is the parameter `M`
+    fn generics(&self) -> &'static str {
+        match self.metric {
+            Some("M") => "T, M",
+            _ => "T",
+        }
+    }
+
+    /// Name of the type of the k-vectors of `grade`
+    fn kvector_type(&self, grade: usize) -> String {
+        if grade == 0 && self.bare_scalar {
+            "T".to_string()
+        } else {
+            self.type_name(self.kvector(grade).name)
+        }
+    }
+}
+
+/// An operand of a product
+#[derive(Clone, Copy)]
+enum Operand<'a> {
+    KVector(usize),
+    Composite(&'a CompositeType),
+}
+
+impl Operand<'_> {
+    /// The k-vectors of `algebra` from grade 1 up, followed by its composites
+    fn all(algebra: &Algebra) -> Vec<Operand<'_>> {
+        let mut operands: Vec<Operand> = (1..=algebra.dimension()).map(Operand::KVector).collect();
+        operands.push(Operand::Composite(&algebra.even));
+        operands.push(Operand::Composite(&algebra.full));
+        operands
+    }
+
+    fn name<'a>(&'a self, algebra: &'a Algebra) -> &'a str {
+        match self {
+            Operand::KVector(grade) => algebra.kvector(*grade).name,
+            Operand::Composite(composite) => composite.name,
+        }
+    }
+
+    /// Grade, index and expression of every field of `operand`
+    fn fields(&self, algebra: &Algebra, operand: &str) -> Vec<(usize, usize, String)> {
+        let kvectors = match self {
+            Operand::KVector(grade) => vec![(*grade, operand.to_string())],
+            Operand::Composite(composite) => composite
+                .fields
+                .iter()
+                .map(|(grade, name)| (*grade, format!("{operand}.{name}")))
+                .collect(),
+        };
+        let mut fields = Vec::new();
+        for (grade, kvector) in kvectors {
+            for index in 0..algebra.kvector(grade).blades.len() {
+                let field = if grade == 0 && algebra.bare_scalar {
+                    kvector.clone()
+                } else {
+                    field(algebra, &kvector, grade, index)
+                };
+                fields.push((grade, index, field));
+            }
+        }
+        fields
+    }
+}
+
+#[derive(Default)]
+struct Bounds {
+    zero: bool,
+    add: bool,
+    sub: bool,
+    neg: bool,
+    mul: bool,
+}
+
+impl Bounds {
+    fn write(&self, out: &mut String) {
+        out.push_str("where\n    T: Copy,\n");
+        if self.zero {
+            out.push_str("    T: ConstZero,\n");
+        }
+        if self.add {
+            out.push_str("    T: Add<T, Output = T>,\n");
+        }
+        if self.sub {
+            out.push_str("    T: Sub<T, Output = T>,\n");
+        }
+        if self.neg {
+            out.push_str("    T: Neg<Output = T>,\n");
+        }
+        if self.mul {
+            out.push_str("    T: Mul<T, Output = T>,\n");
+        }
+    }
+}
+
+/// Coefficient of an output blade: sum of `sign * lhs * rhs` (or `sign * lhs` for unary operations)
+type Terms = Vec<(i32, String)>;
+
+/// Result of an operation, indexed by grade and field
+struct Value {
+    grades: Vec<Vec<Terms>>,
+}
+
+impl Value {
+    fn new(algebra: &Algebra) -> Self {
+        Value {
+            grades: algebra
+                .kvectors
+                .iter()
+                .map(|k| vec![Vec::new(); k.blades.len()])
+                .collect(),
+        }
+    }
+
+    fn non_zero_grades(&self) -> Vec<usize> {
+        (0..self.grades.len())
+            .filter(|g| self.grades[*g].iter().any(|terms| !terms.is_empty()))
+            .collect()
+    }
+}
+
+fn field(algebra: &Algebra, operand: &str, grade: usize, index: usize) -> String {
+    if grade == 0 {
+        format!("{operand}.0")
+    } else {
+        format!("{operand}.{}", algebra.kvector(grade).blades[index])
+    }
+}
+
+fn sum(terms: &Terms, bounds: &mut Bounds) -> String {
+    if terms.is_empty() {
+        bounds.zero = true;
+        return "T::ZERO".to_string();
+    }
+    if terms.iter().all(|(sign, _)| *sign < 0) {
+        bounds.neg = true;
+        if terms.len() == 1 {
+            return format!("-{}", terms[0].1);
+        }
+        bounds.add = true;
+        let inner: Vec<&str> = terms.iter().map(|(_, term)| term.as_str()).collect();
+        return format!("-({})", inner.join(" + "));
+    }
+    let mut out = String::new();
+    for (_, term) in terms.iter().filter(|(sign, _)| *sign > 0) {
+        if !out.is_empty() {
+            bounds.add = true;
+            out.push_str(" + ");
+        }
+        out.push_str(term);
+    }
+    for (_, term) in terms.iter().filter(|(sign, _)| *sign < 0) {
+        bounds.sub = true;
+        out.push_str(" - ");
+        out.push_str(term);
+    }
+    out
+}
+
+/// Lines longer than this are wrapped the way rustfmt does
+const MAX_WIDTH: usize = 100;
+
+/// Breaks a sum before each operator, continuing the lines with `indent`
+///
+/// The sum starts after `used` columns. Like rustfmt, the additions before the first subtraction
+/// stay on the first line if they fit there.
+fn wrap(sum: &str, used: usize, indent: &str) -> String {
+    let (additions, subtractions) = match sum.split_once(" - ") {
+        Some((additions, subtractions)) => (additions, Some(subtractions)),
+        None => (sum, None),
+    };
+    let mut out = match subtractions {
+        Some(_) if used + additions.len() < MAX_WIDTH => additions.to_string(),
+        _ => additions.replace(" + ", &format!("\n{indent}+ ")),
+    };
+    if let Some(subtractions) = subtractions {
+        out.push_str(&format!("\n{indent}- "));
+        out.push_str(&subtractions.replace(" - ", &format!("\n{indent}- ")));
+    }
+    out
+}
+
+/// Writes the k-vector of `grade` stored in `value` as an expression
+///
+/// The expression starts on a line indented with `indent`, `used` columns of it are already taken.
+fn kvector_expression(
+    algebra: &Algebra,
+    value: &Value,
+    grade: usize,
+    (indent, used): (&str, usize),
+    bounds: &mut Bounds,
+) -> String {
+    let kvector = algebra.kvector(grade);
+    let terms = &value.grades[grade];
+    if grade == 0 && algebra.bare_scalar {
+        let sum = sum(&terms[0], bounds);
+        if used + sum.len() + 1 > MAX_WIDTH {
+            return wrap(&sum, used, &format!("{indent}    "));
+        }
+        return sum;
+    }
+    if terms.iter().all(|t| t.is_empty()) {
+        bounds.zero = true;
+        return format!("{}::ZERO", kvector.name);
+    }
+    if grade == 0 {
+        let sum = sum(&terms[0], bounds);
+        if used + kvector.name.len() + sum.len() + 7 <= MAX_WIDTH {
+            return format!("{}::new({sum})", kvector.name);
+        }
+        let inner = format!("{indent}    ");
+        let sum = if inner.len() + sum.len() + 1 > MAX_WIDTH {
+            wrap(&sum, inner.len(), &format!("{inner}    "))
+        } else {
+            sum
+        };
+        return format!("{}::new(\n{inner}{sum},\n{indent})", kvector.name);
+    }
+    let mut out = format!("{} {{\n", kvector.name);
+    for (blade, terms) in kvector.blades.iter().zip(terms) {
+        let mut line = format!("{indent}    {blade}: {},", sum(terms, bounds));
+        if line.len() > MAX_WIDTH {
+            line = wrap(&line, 0, &format!("{indent}        "));
+        }
+        writeln!(out, "{line}").unwrap();
+    }
+    if algebra.metric.is_some() {
+        writeln!(out, "{indent}    _metric: PhantomData,").unwrap();
+    }
+    write!(out, "{indent}}}").unwrap();
+    out
+}
+
+/// Output type of a binary operation with a result of `grades`
+fn output_type(algebra: &Algebra, grades: &[usize]) -> String {
+    match grades {
+        [] => "()".to_string(),
+        [grade] => algebra.kvector_type(*grade),
+        _ if grades.iter().all(|g| g % 2 == 0) => algebra.type_name(algebra.even.name),
+        _ => algebra.type_name(algebra.full.name),
+    }
+}
+
+/// Output type and body of a binary operation with a result of `grades`, `None` if there are none
+fn expression(
+    algebra: &Algebra,
+    value: &Value,
+    grades: &[usize],
+    bounds: &mut Bounds,
+) -> Option<(String, String)> {
+    let indent = "        ";
+    match grades {
+        [] => None,
+        [grade] => Some((
+            output_type(algebra, grades),
+            kvector_expression(algebra, value, *grade, (indent, indent.len()), bounds),
+        )),
+        _ => {
+            let composite = if grades.iter().all(|g| g % 2 == 0) {
+                &algebra.even
+            } else {
+                &algebra.full
+            };
+            let mut out = format!("{} {{\n", composite.name);
+            for (grade, name) in composite.fields {
+                let inner = format!("{indent}    ");
+                let used = inner.len() + name.len() + 2;
+                let expression = kvector_expression(algebra, value, *grade, (&inner, used), bounds);
+                writeln!(out, "{inner}{name}: {expression},").unwrap();
+            }
+            write!(out, "{indent}}}").unwrap();
+            Some((output_type(algebra, grades), out))
+        }
+    }
+}
+
+/// Evaluates `op` over every pair of fields of two k-vectors
+fn binary(
+    algebra: &Algebra,
+    lhs: usize,
+    rhs: usize,
+    op: impl Fn(Element, Element) -> Element,
+) -> Value {
+    let (lhs, rhs) = (Operand::KVector(lhs), Operand::KVector(rhs));
+    operands_binary(algebra, (&lhs, &rhs), op)
+}
+
+/// Evaluates `op` over every pair of fields of two operands
+fn operands_binary(
+    algebra: &Algebra,
+    (lhs, rhs): (&Operand, &Operand),
+    op: impl Fn(Element, Element) -> Element,
+) -> Value {
+    let mut value = Value::new(algebra);
+    for (lhs_grade, i, lhs_field) in lhs.fields(algebra, "self") {
+        for (rhs_grade, j, rhs_field) in rhs.fields(algebra, "rhs") {
+            let a = algebra.parse(algebra.kvector(lhs_grade).blades[i]);
+            let b = algebra.parse(algebra.kvector(rhs_grade).blades[j]);
+            if let Some((sign, mask)) = op(Some(a), Some(b)) {
+                let (grade, index, orientation) = algebra.locate(mask);
+                let term = format!("{lhs_field} * {rhs_field}");
+                value.grades[grade][index].push((sign * orientation, term));
+            }
+        }
+    }
+    value
+}
+
+/// Grades of the geometric product of two operands when no basis vector squares to zero
+///
+/// The output type keeps all of them, even the ones a degenerate metric cancels.
+fn geometric_grades(algebra: &Algebra, (lhs, rhs): (&Operand, &Operand)) -> Vec<usize> {
+    let mut grades: Vec<usize> = Vec::new();
+    for (a, i, _) in lhs.fields(algebra, "self") {
+        for (b, j, _) in rhs.fields(algebra, "rhs") {
+            let (_, a) = algebra.parse(algebra.kvector(a).blades[i]);
+            let (_, b) = algebra.parse(algebra.kvector(b).blades[j]);
+            grades.push((a ^ b).count_ones() as usize);
+        }
+    }
+    grades.sort();
+    grades.dedup();
+    grades
+}
+
+/// Evaluates `op` over every field of a k-vector, the result must be a k-vector of `grade`
+fn unary(algebra: &Algebra, grade: usize, op: impl Fn(Element) -> Element) -> Value {
+    let mut value = Value::new(algebra);
+    for (i, a) in algebra.kvector(grade).blades.iter().enumerate() {
+        if let Some((sign, mask)) = op(Some(algebra.parse(a))) {
+            let (target, index, orientation) = algebra.locate(mask);
+            let term = field(algebra, "self", grade, i);
+            value.grades[target][index].push((sign * orientation, term));
+        }
+    }
+    value
+}
+
+fn banner(out: &mut String, title: &str) {
+    let line = "-".repeat(100);
+    writeln!(out, "// {line}\n// {title}\n// {line}\n").unwrap();
+}
+
+fn uses_rhs(body: &str) -> &'static str {
+    if body.contains("rhs.") { "rhs" } else { "_rhs" }
+}
+
+/// Emits the impl of a trait with a single method and an `Output` type
+fn product(
+    out: &mut String,
+    algebra: &Algebra,
+    (trait_name, method): (&str, &str),
+    (lhs, rhs): (usize, usize),
+    value: &Value,
+) {
+    let (lhs, rhs) = (Operand::KVector(lhs), Operand::KVector(rhs));
+    let grades = value.non_zero_grades();
+    let names = (trait_name, method);
+    operands_product(out, algebra, names, (&lhs, &rhs), value, &grades);
+}
+
+/// Emits the impl of a trait with a single method and an `Output` of `grades`
+fn operands_product(
+    out: &mut String,
+    algebra: &Algebra,
+    (trait_name, method): (&str, &str),
+    (lhs, rhs): (&Operand, &Operand),
+    value: &Value,
+    grades: &[usize],
+) {
+    let mut bounds = Bounds::default();
+    let lhs = algebra.type_name(lhs.name(algebra));
+    let rhs = algebra.type_name(rhs.name(algebra));
+    let (output, body) = match expression(algebra, value, grades, &mut bounds) {
+        Some((output, body)) => (output, format!("\n        {body}\n    ")),
+        None => ("()".to_string(), String::new()),
+    };
+    bounds.mul |= body.contains(" * ");
+    let generics = algebra.generics();
+    writeln!(out, "impl<{generics}> {trait_name}<{rhs}> for {lhs}").unwrap();
+    bounds.write(out);
+    let rhs_name = uses_rhs(&body);
+    writeln!(
+        out,
+        "{{\n    type Output = {output};\n\n    fn {method}(&self, {rhs_name}: &{rhs}) -> Self::Output {{{body}}}\n}}\n"
+    )
+    .unwrap();
+}
+
+/// Emits the impls of a product between every pair of operands where one of them is a composite
+fn composite_products(
+    out: &mut String,
+    algebra: &Algebra,
+    names: (&str, &str),
+    op: impl Fn(Element, Element) -> Element,
+) {
+    let operands = Operand::all(algebra);
+    for lhs in &operands {
+        for rhs in &operands {
+            if let (Operand::KVector(_), Operand::KVector(_)) = (lhs, rhs) {
+                continue;
+            }
+            let value = operands_binary(algebra, (lhs, rhs), &op);
+            let grades = value.non_zero_grades();
+            operands_product(out, algebra, names, (lhs, rhs), &value, &grades);
+        }
+    }
+}
+
+/// Emits `*` as the geometric product of every pair of operands, whose output has `grades`, for
+/// types with the generic parameters `generics`
+///
+/// The composites have always been multiplied with `*`, on the k-vectors it is operator sugar and
+/// `kvector_attribute` precedes it. The composites of an algebra with a metric parameter implement
+/// it by hand for every metric.
+fn geometric_mul(
+    out: &mut String,
+    algebra: &Algebra,
+    (generics, kvector_attribute): (&str, &str),
+    grades: impl Fn(&Operand, &Operand) -> Vec<usize>,
+) {
+    banner(out, "Geometric product operator");
+    let operands = Operand::all(algebra);
+    for lhs in &operands {
+        if matches!(lhs, Operand::Composite(_)) && algebra.metric.is_some() {
+            continue;
+        }
+        for rhs in &operands {
+            if let Operand::KVector(_) = lhs {
+                out.push_str(kvector_attribute);
+            }
+            let args = format!(
+                "{}, {} => {}, {generics}",
+                algebra.type_name(lhs.name(algebra)),
+                algebra.type_name(rhs.name(algebra)),
+                output_type(algebra, &grades(lhs, rhs)),
+            );
+            if "geometric_mul!();".len() + args.len() <= MAX_WIDTH {
+                writeln!(out, "geometric_mul!({args});").unwrap();
+            } else {
+                writeln!(out, "geometric_mul!(\n    {args}\n);").unwrap();
+            }
+        }
+    }
+    out.push('\n');
+}
+
+/// Emits the impl of `Contraction` or `Expansion`, both have a bulk and a weight method
+fn metric_product(
+    out: &mut String,
+    algebra: &Algebra,
+    (trait_name, method): (&str, &str),
+    (lhs, rhs): (usize, usize),
+    (bulk, weight): (&Value, &Value),
+) {
+    let mut bounds = Bounds::default();
+    let mut types = Vec::new();
+    let mut methods = String::new();
+    let rhs_type = algebra.kvector_type(rhs);
+    for (kind, value) in [("Bulk", bulk), ("Weight", weight)] {
+        let grades = value.non_zero_grades();
+        let (output, body) = match expression(algebra, value, &grades, &mut bounds) {
+            Some((output, body)) => (output, format!("\n        {body}\n    ")),
+            None => ("()".to_string(), String::new()),
+        };
+        bounds.mul |= body.contains(" * ");
+        types.push(format!("    type {kind}Output = {output};\n"));
+        write!(
+            methods,
+            "\n    fn {}_{method}(&self, {}: &{rhs_type}) -> Self::{kind}Output {{{body}}}\n",
+            kind.to_lowercase(),
+            uses_rhs(&body),
+        )
+        .unwrap();
+    }
+    let lhs = algebra.kvector_type(lhs);
+    let generics = algebra.generics();
+    writeln!(out, "impl<{generics}> {trait_name}<{rhs_type}> for {lhs}").unwrap();
+    bounds.write(out);
+    writeln!(out, "{{\n{}{methods}}}\n", types.concat()).unwrap();
+}
+
+fn dot(out: &mut String, algebra: &Algebra, grade: usize) {
+    let n = algebra.dimension();
+    let mut bounds = Bounds::default();
+    let mut methods = String::new();
+    // the antimetric of a blade is the metric of its complement
+    let metrics = [
+        ("dot", "Scalar", 0, 0),
+        ("antidot", "Antiscalar", n, algebra.volume_mask()),
+    ];
+    for (method, output, target, complement) in metrics {
+        let mut value = Value::new(algebra);
+        for (i, blade) in algebra.kvector(grade).blades.iter().enumerate() {
+            let sign = algebra.square(complement ^ algebra.parse(blade).1);
+            if sign != 0 {
+                let term = format!(
+                    "{} * {}",
+                    field(algebra, "self", grade, i),
+                    field(algebra, "rhs", grade, i)
+                );
+                value.grades[target][0].push((sign, term));
+            }
+        }
+        let body = kvector_expression(algebra, &value, target, ("        ", 8), &mut bounds);
+        write!(
+            methods,
+            "\n    fn {method}(&self, {}: &Self) -> Self::{output} {{\n        {body}\n    }}\n",
+            uses_rhs(&body),
+        )
+        .unwrap();
+    }
+    bounds.mul |= methods.contains(" * ");
+    let generics = algebra.generics();
+    writeln!(
+        out,
+        "impl<{generics}> Dot for {}",
+        algebra.kvector_type(grade)
+    )
+    .unwrap();
+    bounds.write(out);
+    writeln!(
+        out,
+        "{{\n    type Scalar = {};\n    type Antiscalar = {};\n{methods}}}\n",
+        algebra.kvector_type(0),
+        algebra.kvector_type(n),
+    )
+    .unwrap();
+}
+
+fn dual(out: &mut String, algebra: &Algebra, grade: usize) {
+    let anti = algebra.kvector_type(algebra.dimension() - grade);
+    let mut bounds = Bounds::default();
+    let mut methods = String::new();
+    let duals: [(&str, &dyn Fn(Element) -> Element); 4] = [
+        ("right_bulk_dual", &|a| {
+            algebra.right_complement(algebra.bulk(a))
+        }),
+        ("left_bulk_dual", &|a| {
+            algebra.left_complement(algebra.bulk(a))
+        }),
+        ("right_weight_dual", &|a| {
+            algebra.right_complement(algebra.weight(a))
+        }),
+        ("left_weight_dual", &|a| {
+            algebra.left_complement(algebra.weight(a))
+        }),
+    ];
+    for (method, op) in duals {
+        let value = unary(algebra, grade, op);
+        let target = algebra.dimension() - grade;
+        let body = kvector_expression(algebra, &value, target, ("        ", 8), &mut bounds);
+        write!(
+            methods,
+            "\n    fn {method}(&self) -> Self::AntiKVector {{\n        {body}\n    }}\n"
+        )
+        .unwrap();
+    }
+    let generics = algebra.generics();
+    writeln!(
+        out,
+        "impl<{generics}> Dual for {}",
+        algebra.kvector_type(grade)
+    )
+    .unwrap();
+    bounds.write(out);
+    writeln!(out, "{{\n    type AntiKVector = {anti};\n{methods}}}\n").unwrap();
+}
+
+/// Emits the dot, dual, contraction and expansion impls of every k-vector type of grade 1 and up
+///
+/// The contraction and expansion of two k-vectors of the same grade are left out unless
+/// `same_grade`: `gual` implements them from the dot product when the complement of the antiscalar
+/// is the scalar type.
+fn metric_products(out: &mut String, algebra: &Algebra, same_grade: bool) {
+    let n = algebra.dimension();
+    let grades = || 1..=n;
+    let pairs = || grades().flat_map(move |a| grades().map(move |b| (a, b)));
+
+    banner(out, "Dot");
+    for grade in grades() {
+        dot(out, algebra, grade);
+    }
+
+    banner(out, "Dual");
+    for grade in grades() {
+        dual(out, algebra, grade);
+    }
+
+    banner(out, "Contraction");
+    for (a, b) in pairs().filter(|(a, b)| a > b || same_grade && a == b) {
+        let bulk = binary(algebra, a, b, |a, b| {
+            algebra.antiwedge(a, algebra.right_complement(algebra.bulk(b)))
+        });
+        let weight = binary(algebra, a, b, |a, b| {
+            algebra.antiwedge(a, algebra.right_complement(algebra.weight(b)))
+        });
+        let names = ("Contraction", "contraction");
+        metric_product(out, algebra, names, (a, b), (&bulk, &weight));
+    }
+
+    banner(out, "Expansion");
+    for (a, b) in pairs().filter(|(a, b)| a < b || same_grade && a == b) {
+        let bulk = binary(algebra, a, b, |a, b| {
+            algebra.wedge(a, algebra.right_complement(algebra.bulk(b)))
+        });
+        let weight = binary(algebra, a, b, |a, b| {
+            algebra.wedge(a, algebra.right_complement(algebra.weight(b)))
+        });
+        let names = ("Expansion", "expansion");
+        metric_product(out, algebra, names, (a, b), (&bulk, &weight));
+    }
+}
+
+/// Comment at the top of the generated files, followed by an empty line
+fn header(algebra: &Algebra) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// This file is generated by `cargo run -p gual-codegen`, do not edit it by hand.\n",
+    );
+    let signature: Vec<String> = algebra
+        .signature
+        .iter()
+        .map(|(vector, square)| format!("{vector}² = {square}"))
+        .collect();
+    writeln!(out, "//\n// Signature: {}\n", signature.join(", ")).unwrap();
+    out
+}
+
+/// Generates the source of the product impls of an algebra
+pub fn generate(algebra: &Algebra) -> String {
+    let n = algebra.dimension();
+    let grades = || 1..=n;
+    let pairs = || grades().flat_map(move |a| grades().map(move |b| (a, b)));
+
+    let mut out = header(algebra);
+    out.push_str("use std::ops::{Add, Mul, Neg, Sub};\n\nuse num::traits::ConstZero;\n\n");
+    out.push_str("use crate::{\n    AntiwedgeProduct, Contraction, Dot, Dual, Expansion, GeometricAntiproduct, GeometricProduct,\n    WedgeProduct, geometric_mul,\n};\n\n");
+    let mut types: Vec<&str> = algebra.kvectors.iter().map(|k| k.name).collect();
+    types.push(algebra.even.name);
+    types.push(algebra.full.name);
+    types.sort();
+    writeln!(out, "use super::{{{}}};\n", types.join(", ")).unwrap();
+
+    banner(&mut out, "Wedge product");
+    for (a, b) in pairs().filter(|(a, b)| a + b <= n) {
+        let value = binary(algebra, a, b, |a, b| algebra.wedge(a, b));
+        product(&mut out, algebra, ("WedgeProduct", "wedge"), (a, b), &value);
+    }
+
+    banner(&mut out, "Antiwedge product");
+    for (a, b) in pairs().filter(|(a, b)| a + b >= n) {
+        let value = binary(algebra, a, b, |a, b| algebra.antiwedge(a, b));
+        product(
+            &mut out,
+            algebra,
+            ("AntiwedgeProduct", "antiwedge"),
+            (a, b),
+            &value,
+        );
+    }
+
+    banner(&mut out, "Geometric product");
+    for (a, b) in pairs() {
+        let value = binary(algebra, a, b, |a, b| algebra.geometric(a, b));
+        product(
+            &mut out,
+            algebra,
+            ("GeometricProduct", "geometric_product"),
+            (a, b),
+            &value,
+        );
+    }
+    let names = ("GeometricProduct", "geometric_product");
+    composite_products(&mut out, algebra, names, |a, b| algebra.geometric(a, b));
+    let sugar = ("T", "#[cfg(feature = \"operators\")]\n");
+    geometric_mul(&mut out, algebra, sugar, |lhs, rhs| {
+        operands_binary(algebra, (lhs, rhs), |a, b| algebra.geometric(a, b)).non_zero_grades()
+    });
+
+    banner(&mut out, "Geometric antiproduct");
+    for (a, b) in pairs() {
+        let value = binary(algebra, a, b, |a, b| algebra.antigeometric(a, b));
+        let names = ("GeometricAntiproduct", "geometric_antiproduct");
+        product(&mut out, algebra, names, (a, b), &value);
+    }
+    // motors are even composites, so they are composed and applied with these
+    let names = ("GeometricAntiproduct", "geometric_antiproduct");
+    composite_products(&mut out, algebra, names, |a, b| algebra.antigeometric(a, b));
+
+    metric_products(&mut out, algebra, true);
+
+    out.pop();
+    out
+}
+
+/// Generates the source of the impls that depend on the metric of an algebra with a metric
+/// parameter: the geometric product between every pair of its k-vector and composite types, and the
+/// dot, dual, contraction and expansion of its k-vectors
+pub fn generate_metric(algebra: &Algebra) -> String {
+    let operands = Operand::all(algebra);
+
+    let mut out = header(algebra);
+    out.push_str("use std::marker::PhantomData;\n");
+    out.push_str("use std::ops::{Add, Mul, Neg, Sub};\n\nuse num::traits::ConstZero;\n\n");
+    let mut uses = vec![
+        "Contraction",
+        "Dot",
+        "Dual",
+        "Expansion",
+        "GeometricProduct",
+    ];
+    uses.extend(algebra.metric);
+    uses.sort();
+    writeln!(out, "use crate::{{{}}};\n", uses.join(", ")).unwrap();
+    let mut types: Vec<&str> = operands.iter().map(|o| o.name(algebra)).collect();
+    types.sort();
+    writeln!(out, "use super::{{{}}};\n", types.join(", ")).unwrap();
+
+    for lhs in &operands {
+        banner(&mut out, lhs.name(algebra));
+        for rhs in &operands {
+            let value = operands_binary(algebra, (lhs, rhs), |a, b| algebra.geometric(a, b));
+            let grades = geometric_grades(algebra, (lhs, rhs));
+            let names = ("GeometricProduct", "geometric_product");
+            operands_product(&mut out, algebra, names, (lhs, rhs), &value, &grades);
+        }
+    }
+    metric_products(&mut out, algebra, false);
+
+    out.pop();
+    out
+}
+
+/// Generates the wedge and antiwedge products of an algebra with a metric parameter, between every
+/// pair of its k-vector and composite types, generic over the metric since they don't depend on it
+///
+/// The products with a bare scalar are written by hand.
+pub fn generate_exterior(algebra: &Algebra) -> String {
+    let generic = Algebra {
+        metric: Some("M"),
+        ..*algebra
+    };
+    let operands = Operand::all(algebra);
+    let n = algebra.dimension();
+    let grades = || 1..=n;
+    let pairs = || grades().flat_map(move |a| grades().map(move |b| (a, b)));
+
+    let mut body = String::new();
+    banner(&mut body, "Wedge product");
+    let names = ("WedgeProduct", "wedge");
+    for (a, b) in pairs() {
+        let value = binary(&generic, a, b, |a, b| generic.wedge(a, b));
+        product(&mut body, &generic, names, (a, b), &value);
+    }
+    composite_products(&mut body, &generic, names, |a, b| generic.wedge(a, b));
+
+    banner(&mut body, "Antiwedge product");
+    let names = ("AntiwedgeProduct", "antiwedge");
+    for (a, b) in pairs() {
+        let value = binary(&generic, a, b, |a, b| generic.antiwedge(a, b));
+        product(&mut body, &generic, names, (a, b), &value);
+    }
+    composite_products(&mut body, &generic, names, |a, b| generic.antiwedge(a, b));
+    body.pop();
+
+    let mut out = header(algebra);
+    out.push_str("use std::marker::PhantomData;\n");
+    let ops: Vec<&str> = ["Add", "Mul", "Neg", "Sub"]
+        .into_iter()
+        .filter(|op| body.contains(&format!("T: {op}<")))
+        .collect();
+    writeln!(out, "use std::ops::{{{}}};\n", ops.join(", ")).unwrap();
+    out.push_str("use num::traits::ConstZero;\n\nuse crate::{AntiwedgeProduct, WedgeProduct};\n\n");
+    let mut types: Vec<&str> = operands.iter().map(|o| o.name(algebra)).collect();
+    types.sort();
+    writeln!(out, "use super::{{{}}};\n", types.join(", ")).unwrap();
+    out.push_str(&body);
+    out
+}
+
+/// Generates `*` as the geometric product of the k-vectors of an algebra with a metric parameter,
+/// generic over the metric, for a module behind the `operators` feature
+///
+/// It isn't generated with the products of each metric: with one impl per metric the type
+/// inference could no longer pick the product of an unknown metric.
+pub fn generate_mul(algebra: &Algebra) -> String {
+    let generic = Algebra {
+        metric: Some("M"),
+        ..*algebra
+    };
+    let operands = Operand::all(algebra);
+
+    let mut out = header(algebra);
+    out.push_str("use crate::geometric_mul;\n\n");
+    let mut types: Vec<&str> = operands.iter().map(|o| o.name(algebra)).collect();
+    types.sort();
+    writeln!(out, "use super::{{{}}};\n", types.join(", ")).unwrap();
+
+    geometric_mul(&mut out, &generic, ("T, M", ""), |lhs, rhs| {
+        geometric_grades(algebra, (lhs, rhs))
+    });
+
+    out.pop();
+    out
+}
@@ -0,0 +1,12 @@
+//! Regenerates the checked-in product impls of `gual`.
+
+use std::{fs, path::Path};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for (path, algebra, generate) in gual_codegen::OUTPUTS {
+        fs::write(root.join(path), generate(algebra))
+            .unwrap_or_else(|e| panic!("could not write {path}: {e}"));
+        println!("generated {path}");
+    }
+}
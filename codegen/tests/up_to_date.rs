@@ -0,0 +1,13 @@
+use std::{fs, path::Path};
+
+#[test]
+fn checked_in_code_matches_the_generator() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for (path, algebra, generate) in gual_codegen::OUTPUTS {
+        let checked_in = fs::read_to_string(root.join(path)).expect("generated file should exist");
+        assert!(
+            checked_in == generate(algebra),
+            "{path} diverges from the generator, run `cargo run -p gual-codegen` to update it"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! [proptest](https://docs.rs/proptest) strategies for the types of this crate.
+//!
+//! Enabled with the `proptest` feature. Every public type implements [`Arbitrary`], so they can be
+//! generated with `any::<T>()`. Unit-constrained types (like [`crate::geometry3d::UnitVector`] or
+//! [`crate::homogeneous3d::Plane`]) are always generated already normalized.
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use num::Float;
+use proptest::prelude::*;
+
+use crate::{Scalar, interval::Interval};
+
+/// Finite values spanning several orders of magnitude (from `1e-6` to `1e6`), mixed with
+/// small integers so that exact cancellations also get exercised.
+pub fn field<T>() -> BoxedStrategy<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    prop_oneof![
+        1 => (-10i32..=10).prop_map(|i| T::from(i).expect("T should hold small integers")),
+        3 => (-1.0f64..1.0, -6i32..=6)
+            .prop_map(|(m, e)| T::from(m * 10f64.powi(e)).expect("T should hold f64 values")),
+    ]
+    .boxed()
+}
+
+impl<const D: u32, T, M> Arbitrary for Scalar<D, T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        field().prop_map(|s| Scalar(s, PhantomData)).boxed()
+    }
+}
+
+impl<T> Arbitrary for Interval<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field())
+            .prop_map(|(a, b)| Interval::new(a, b))
+            .boxed()
+    }
+}
@@ -0,0 +1,341 @@
+//! Geometric algebra calculator behind the `gual` binary.
+//!
+//! Values are written in basis-blade notation (`1 + 2x - 3yz`) and every operation is
+//! evaluated by splitting its operands in k-vectors and calling the trait of `gual` that
+//! implements it for those k-vectors, so the calculator shows exactly what the library computes.
+
+mod algebras;
+mod elements;
+mod parser;
+mod value;
+
+use std::collections::HashMap;
+
+pub use algebras::{Algebra, algebra};
+pub use value::Value;
+
+use parser::{Expression, Statement};
+
+/// Operations taking two values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binary {
+    Wedge,
+    Antiwedge,
+    GeometricProduct,
+    Dot,
+    Antidot,
+    BulkContraction,
+    WeightContraction,
+    BulkExpansion,
+    WeightExpansion,
+    Join,
+    Meet,
+    Distance,
+    Cosine,
+}
+
+/// Operations taking a single value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unary {
+    RightComplement,
+    LeftComplement,
+    RightBulkDual,
+    LeftBulkDual,
+    RightWeightDual,
+    LeftWeightDual,
+}
+
+/// Functions that can be called in an expression, they are named after the methods of `gual`
+const BINARY_FUNCTIONS: &[(&str, Binary)] = &[
+    ("wedge", Binary::Wedge),
+    ("antiwedge", Binary::Antiwedge),
+    ("geometric_product", Binary::GeometricProduct),
+    ("dot", Binary::Dot),
+    ("antidot", Binary::Antidot),
+    ("contraction", Binary::BulkContraction),
+    ("bulk_contraction", Binary::BulkContraction),
+    ("weight_contraction", Binary::WeightContraction),
+    ("expansion", Binary::BulkExpansion),
+    ("bulk_expansion", Binary::BulkExpansion),
+    ("weight_expansion", Binary::WeightExpansion),
+    ("join", Binary::Join),
+    ("meet", Binary::Meet),
+    ("distance", Binary::Distance),
+    ("cosine", Binary::Cosine),
+];
+
+const UNARY_FUNCTIONS: &[(&str, Unary)] = &[
+    ("complement", Unary::RightComplement),
+    ("right_complement", Unary::RightComplement),
+    ("left_complement", Unary::LeftComplement),
+    ("dual", Unary::RightBulkDual),
+    ("bulk_dual", Unary::RightBulkDual),
+    ("right_bulk_dual", Unary::RightBulkDual),
+    ("left_bulk_dual", Unary::LeftBulkDual),
+    ("weight_dual", Unary::RightWeightDual),
+    ("right_weight_dual", Unary::RightWeightDual),
+    ("left_weight_dual", Unary::LeftWeightDual),
+];
+
+impl Binary {
+    /// Whether the operation is linear in both operands, so it can be evaluated k-vector by k-vector
+    fn is_bilinear(self) -> bool {
+        !matches!(self, Binary::Distance | Binary::Cosine)
+    }
+
+    /// Whether the product of a k-vector of grade `a` and one of grade `b` is zero because of their
+    /// grades alone, in an algebra of `dimension` basis vectors
+    fn vanishes(self, a: usize, b: usize, dimension: usize) -> bool {
+        match self {
+            Binary::Wedge | Binary::Join => a + b > dimension,
+            Binary::Antiwedge | Binary::Meet => a + b < dimension,
+            Binary::Dot | Binary::Antidot => a != b,
+            Binary::BulkContraction | Binary::WeightContraction => a < b,
+            Binary::BulkExpansion | Binary::WeightExpansion => a > b,
+            Binary::GeometricProduct | Binary::Distance | Binary::Cosine => false,
+        }
+    }
+}
+
+/// Calculator state: the algebra it works on and the variables assigned so far
+pub struct Calculator {
+    algebra: Box<dyn Algebra>,
+    variables: HashMap<String, Value>,
+}
+
+impl Calculator {
+    pub fn new(algebra: Box<dyn Algebra>) -> Self {
+        Calculator {
+            algebra,
+            variables: HashMap::new(),
+        }
+    }
+
+    /// Runs a line of input, returning what should be printed, if anything
+    pub fn execute(&mut self, line: &str) -> Result<Option<String>, String> {
+        match parser::parse(line)? {
+            None => Ok(None),
+            Some(Statement::Evaluate(expression)) => {
+                Ok(Some(self.evaluate(&expression)?.to_string()))
+            }
+            Some(Statement::Assign(name, expression)) => {
+                if Value::blade(self.algebra.blades(), &name).is_some() {
+                    return Err(format!("`{name}` is a blade and can not be assigned"));
+                }
+                let value = self.evaluate(&expression)?;
+                let output = format!("{name} = {value}");
+                self.variables.insert(name, value);
+                Ok(Some(output))
+            }
+        }
+    }
+
+    fn evaluate(&self, expression: &Expression) -> Result<Value, String> {
+        let blades = self.algebra.blades();
+        match expression {
+            Expression::Number(number) => Ok(Value::scalar(blades, *number)),
+            Expression::Name(name) => self
+                .variables
+                .get(name)
+                .cloned()
+                .or_else(|| Value::blade(blades, name))
+                .ok_or_else(|| format!("`{name}` is neither a variable nor a blade")),
+            Expression::Negate(value) => Ok(-self.evaluate(value)?),
+            Expression::Binary(operator, lhs, rhs) => {
+                let lhs = self.evaluate(lhs)?;
+                let rhs = self.evaluate(rhs)?;
+                match operator {
+                    '+' => Ok(lhs + rhs),
+                    '-' => Ok(lhs - rhs),
+                    // scaling by a number works in every algebra, even without a geometric product
+                    '*' => match (lhs.as_scalar(), rhs.as_scalar()) {
+                        (Some(s), _) => Ok(rhs * s),
+                        (_, Some(s)) => Ok(lhs * s),
+                        _ if !self.implements(Binary::GeometricProduct) => Err(
+                            "the geometric product is not available in this algebra, `*` only \
+                             scales by a number"
+                                .to_string(),
+                        ),
+                        _ => self.binary(Binary::GeometricProduct, &lhs, &rhs),
+                    },
+                    '^' => self.binary(Binary::Wedge, &lhs, &rhs),
+                    '&' => self.binary(Binary::Antiwedge, &lhs, &rhs),
+                    '/' => match rhs.as_scalar() {
+                        Some(0.0) => Err("division by zero".to_string()),
+                        Some(s) => Ok(lhs * s.recip()),
+                        None => Err("only division by scalars is supported".to_string()),
+                    },
+                    _ => unreachable!("the parser only produces the operators above"),
+                }
+            }
+            Expression::Call(name, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.evaluate(argument))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.call(name, &arguments)
+            }
+        }
+    }
+
+    /// Help text, listing only the operators and functions this algebra implements
+    pub fn help(&self) -> String {
+        let product = if self.implements(Binary::GeometricProduct) {
+            "geometric product"
+        } else {
+            "by a scalar"
+        };
+        let mut functions: Vec<&str> = Vec::new();
+        for (name, operation) in BINARY_FUNCTIONS {
+            if self.implements(*operation) {
+                functions.push(name);
+            }
+        }
+        if self.implements(Binary::Cosine) {
+            functions.push("angle");
+        }
+        for (name, operation) in UNARY_FUNCTIONS {
+            if self.implements_unary(*operation) {
+                functions.push(name);
+            }
+        }
+        let mut lines = vec![String::new()];
+        for function in functions {
+            let line = lines.last_mut().unwrap();
+            if !line.is_empty() && line.len() + function.len() >= 60 {
+                lines.push(function.to_string());
+            } else {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(function);
+            }
+        }
+        format!(
+            "values:    blades like 2 + 3x - yz, written in any order (zy is -yz)\n\
+             operators: + - * ({product}) ^ (wedge) & (antiwedge) / (by a scalar)\n\
+             functions: {}\n\
+             variables: name = expression\n\
+             commands:  help, quit",
+            lines.join("\n           ")
+        )
+    }
+
+    /// Unit blades of every grade, the scalar first
+    fn units(&self) -> Vec<Value> {
+        let blades = self.algebra.blades();
+        let blades = blades
+            .iter()
+            .filter_map(|blade| Value::blade(blades, blade));
+        std::iter::once(Value::scalar(self.algebra.blades(), 1.0))
+            .chain(blades)
+            .collect()
+    }
+
+    /// Whether the algebra implements the operation for at least one pair of grades
+    fn implements(&self, operation: Binary) -> bool {
+        let units = self.units();
+        units.iter().any(|a| {
+            units
+                .iter()
+                .any(|b| self.algebra.binary(operation, a, b).is_some())
+        })
+    }
+
+    /// Whether the algebra implements the operation for at least one grade
+    fn implements_unary(&self, operation: Unary) -> bool {
+        let units = self.units();
+        units
+            .iter()
+            .any(|a| self.algebra.unary(operation, a).is_some())
+    }
+
+    fn call(&self, name: &str, arguments: &[Value]) -> Result<Value, String> {
+        if name == "angle" {
+            let [a, b] = arguments else {
+                return Err("`angle` takes 2 arguments".to_string());
+            };
+            if !self.implements(Binary::Cosine) {
+                return Err(not_available(name));
+            }
+            let cosine = self.binary(Binary::Cosine, a, b)?;
+            let cosine = cosine.as_scalar().expect("the cosine is a scalar");
+            return Ok(Value::scalar(
+                self.algebra.blades(),
+                cosine.clamp(-1.0, 1.0).acos(),
+            ));
+        }
+        if let Some((_, operation)) = BINARY_FUNCTIONS.iter().find(|(f, _)| *f == name) {
+            let [a, b] = arguments else {
+                return Err(format!("`{name}` takes 2 arguments"));
+            };
+            if !self.implements(*operation) {
+                return Err(not_available(name));
+            }
+            return self.binary(*operation, a, b);
+        }
+        if let Some((_, operation)) = UNARY_FUNCTIONS.iter().find(|(f, _)| *f == name) {
+            let [a] = arguments else {
+                return Err(format!("`{name}` takes 1 argument"));
+            };
+            if !self.implements_unary(*operation) {
+                return Err(not_available(name));
+            }
+            return self.unary(*operation, a);
+        }
+        Err(format!("unknown function `{name}`"))
+    }
+
+    fn binary(&self, operation: Binary, a: &Value, b: &Value) -> Result<Value, String> {
+        let blades = self.algebra.blades();
+        let dimension = blades[blades.len() - 1].len();
+        if !operation.is_bilinear() {
+            return match (a.grade(), b.grade()) {
+                (Some(ga), Some(gb)) => self
+                    .algebra
+                    .binary(operation, a, b)
+                    .unwrap_or_else(|| Err(not_implemented(operation, ga, gb))),
+                _ => Err(format!(
+                    "{operation:?} needs two non-zero values of a single grade"
+                )),
+            };
+        }
+
+        let mut result = Value::zero(blades);
+        for a in a.parts() {
+            for b in b.parts() {
+                let (ga, gb) = (a.grade().unwrap(), b.grade().unwrap());
+                match self.algebra.binary(operation, &a, &b) {
+                    Some(value) => result = result + value?,
+                    None if operation.vanishes(ga, gb, dimension) => {}
+                    None => return Err(not_implemented(operation, ga, gb)),
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn unary(&self, operation: Unary, a: &Value) -> Result<Value, String> {
+        let mut result = Value::zero(self.algebra.blades());
+        for a in a.parts() {
+            match self.algebra.unary(operation, &a) {
+                Some(value) => result = result + value,
+                None => {
+                    return Err(format!(
+                        "{operation:?} is not implemented for grade {} in this algebra",
+                        a.grade().unwrap()
+                    ));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn not_implemented(operation: Binary, a: usize, b: usize) -> String {
+    format!("{operation:?} is not implemented between grades {a} and {b} in this algebra")
+}
+
+fn not_available(name: &str) -> String {
+    format!("`{name}` is not available in this algebra, see `help`")
+}
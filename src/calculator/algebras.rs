@@ -0,0 +1,537 @@
+use gual::{
+    Angle, AntiwedgeProduct, Complement, Contraction, Distance, Dot, Dual, Euclidean, Expansion,
+    GeometricProduct, Join, KVector, Meet, Projective, WedgeProduct,
+};
+
+use gual::geometry2d as d2;
+use gual::geometry3d as d3;
+use gual::geometry4d as d4;
+
+use super::value::{Element, Value};
+use super::{Binary, Unary};
+
+/// One of the algebras of `gual`, seen through [`Value`]s
+pub trait Algebra {
+    /// Blades of the algebra, as named by the fields of its types
+    fn blades(&self) -> &'static [&'static str];
+
+    /// Applies the operation to two values, each of them a single k-vector.
+    ///
+    /// Returns `None` if `gual` does not implement it for those k-vectors, and an error if the
+    /// result is not defined for those operands.
+    fn binary(&self, operation: Binary, a: &Value, b: &Value) -> Option<Result<Value, String>>;
+
+    /// Applies the operation to a single k-vector, `None` if `gual` does not implement it.
+    fn unary(&self, operation: Unary, a: &Value) -> Option<Value>;
+}
+
+/// Algebra of `dimension` basis vectors with the given metric, if `gual` has it
+pub fn algebra(dimension: usize, metric: &str) -> Result<Box<dyn Algebra>, String> {
+    match (dimension, metric) {
+        (2, "euclidean") => Ok(Box::new(Euclidean2)),
+        (2, "projective") => Ok(Box::new(Projective2)),
+        (3, "euclidean") => Ok(Box::new(Euclidean3)),
+        (3, "projective") => Ok(Box::new(Projective3)),
+        (4, "projective") => Ok(Box::new(Projective4)),
+        (4, "euclidean") => Err("4D is only available with the projective metric".to_string()),
+        (_, "euclidean" | "projective") => Err(format!("unsupported dimension {dimension}")),
+        _ => Err(format!("unknown metric `{metric}`")),
+    }
+}
+
+const BLADES_2D: &[&str] = &["", "x", "y", "xy"];
+const BLADES_3D: &[&str] = &["", "x", "y", "z", "yz", "zx", "xy", "xyz"];
+const BLADES_4D: &[&str] = &[
+    "", "x", "y", "z", "w", "wx", "wy", "wz", "yz", "zx", "xy", "wyz", "wzx", "wxy", "zyx", "xyzw",
+];
+
+const UNDEFINED_ANGLE: &str = "the angle is undefined, one of the operands has no weight";
+
+/// Whether the value can be read as an `E` without losing anything
+fn fits<E: Element>(value: &Value) -> bool {
+    E::GRADE.is_none() || E::GRADE == value.grade()
+}
+
+/// Returns `$body` evaluated on the first pair of types that fits the operands
+macro_rules! pairs {
+    ($a:ident, $b:ident, |$l:ident, $r:ident| $body:expr, $(($lt:ty, $rt:ty)),+ $(,)?) => {{
+        $(
+            if fits::<$lt>($a) && fits::<$rt>($b) {
+                let $l: $lt = $a.to();
+                let $r: $rt = $b.to();
+                return Some(Ok(Value::from_components($a.blades(), &$body)));
+            }
+        )+
+    }};
+}
+
+/// Returns `$body` evaluated on the first type that fits the operand
+macro_rules! single {
+    ($a:ident, |$x:ident| $body:expr, $($t:ty),+ $(,)?) => {{
+        $(
+            if fits::<$t>($a) {
+                let $x: $t = $a.to();
+                return Some(Value::from_components($a.blades(), &$body));
+            }
+        )+
+    }};
+}
+
+/// Cosine of the angle between `$l` and `$r`, returning an error from the enclosing function if
+/// it is undefined
+macro_rules! cosine {
+    ($l:ident, $r:ident) => {
+        match $l.cosine(&$r) {
+            Some(cosine) => cosine,
+            None => return Some(Err(UNDEFINED_ANGLE.to_string())),
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------------------------------
+// 2D
+// ----------------------------------------------------------------------------------------------------
+
+struct Euclidean2;
+struct Projective2;
+
+impl Algebra for Euclidean2 {
+    fn blades(&self) -> &'static [&'static str] {
+        BLADES_2D
+    }
+
+    fn binary(&self, operation: Binary, a: &Value, b: &Value) -> Option<Result<Value, String>> {
+        type Vector = d2::Vector<f64>;
+        type Bivector = d2::Bivector<f64>;
+        type Multivector = d2::Multivector<f64>;
+
+        match operation {
+            Binary::Wedge => pairs!(a, b, |l, r| l.wedge(&r), (Multivector, Multivector)),
+            Binary::Antiwedge => pairs!(a, b, |l, r| l.antiwedge(&r), (Multivector, Multivector)),
+            Binary::GeometricProduct => pairs!(
+                a,
+                b,
+                |l, r| l.geometric_product(&r),
+                (Multivector, Multivector)
+            ),
+            Binary::Dot => pairs!(
+                a,
+                b,
+                |l, r| l.dot(&r),
+                (Vector, Vector),
+                (Bivector, Bivector)
+            ),
+            Binary::Antidot => pairs!(
+                a,
+                b,
+                |l, r| l.antidot(&r),
+                (Vector, Vector),
+                (Bivector, Bivector)
+            ),
+            Binary::BulkContraction => {
+                pairs!(a, b, |l, r| l.bulk_contraction(&r), (Bivector, Vector))
+            }
+            Binary::WeightContraction => {
+                pairs!(a, b, |l, r| l.weight_contraction(&r), (Bivector, Vector))
+            }
+            Binary::Cosine => pairs!(a, b, |l, r| cosine!(l, r), (Vector, Vector)),
+            _ => {}
+        }
+        None
+    }
+
+    fn unary(&self, operation: Unary, a: &Value) -> Option<Value> {
+        type Vector = d2::Vector<f64>;
+        type Bivector = d2::Bivector<f64>;
+        type Multivector = d2::Multivector<f64>;
+
+        match operation {
+            Unary::RightComplement => single!(a, |x| Complement::right_complement(&x), Multivector),
+            Unary::LeftComplement => single!(a, |x| Complement::left_complement(&x), Multivector),
+            Unary::RightBulkDual => single!(a, |x| x.right_bulk_dual(), Vector, Bivector),
+            Unary::LeftBulkDual => single!(a, |x| x.left_bulk_dual(), Vector, Bivector),
+            Unary::RightWeightDual => single!(a, |x| x.right_weight_dual(), Vector, Bivector),
+            Unary::LeftWeightDual => single!(a, |x| x.left_weight_dual(), Vector, Bivector),
+        }
+        None
+    }
+}
+
+impl Algebra for Projective2 {
+    fn blades(&self) -> &'static [&'static str] {
+        BLADES_2D
+    }
+
+    fn binary(&self, operation: Binary, a: &Value, b: &Value) -> Option<Result<Value, String>> {
+        type Multivector = d2::Multivector<f64, Projective>;
+
+        match operation {
+            Binary::Wedge => pairs!(a, b, |l, r| l.wedge(&r), (Multivector, Multivector)),
+            Binary::Antiwedge => pairs!(a, b, |l, r| l.antiwedge(&r), (Multivector, Multivector)),
+            _ => {}
+        }
+        None
+    }
+
+    fn unary(&self, operation: Unary, a: &Value) -> Option<Value> {
+        type Multivector = d2::Multivector<f64, Projective>;
+
+        match operation {
+            Unary::RightComplement => single!(a, |x| Complement::right_complement(&x), Multivector),
+            Unary::LeftComplement => single!(a, |x| Complement::left_complement(&x), Multivector),
+            _ => {}
+        }
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// 3D
+// ----------------------------------------------------------------------------------------------------
+
+struct Euclidean3;
+struct Projective3;
+
+/// Both metrics of geometry3d implement the same operations, only the types change
+macro_rules! geometry3d {
+    ($algebra:ident, $metric:ty) => {
+        impl Algebra for $algebra {
+            fn blades(&self) -> &'static [&'static str] {
+                BLADES_3D
+            }
+
+            fn binary(
+                &self,
+                operation: Binary,
+                a: &Value,
+                b: &Value,
+            ) -> Option<Result<Value, String>> {
+                type Vector = d3::Vector<f64, $metric>;
+                type Bivector = d3::Bivector<f64, $metric>;
+                type Trivector = d3::Trivector<f64, $metric>;
+                type Multivector = d3::Multivector<f64, $metric>;
+
+                match operation {
+                    Binary::Wedge => pairs!(a, b, |l, r| l.wedge(&r), (Multivector, Multivector)),
+                    Binary::Antiwedge => {
+                        pairs!(a, b, |l, r| l.antiwedge(&r), (Multivector, Multivector))
+                    }
+                    Binary::GeometricProduct => pairs!(
+                        a,
+                        b,
+                        |l, r| l.geometric_product(&r),
+                        (Multivector, Multivector)
+                    ),
+                    Binary::Dot => pairs!(
+                        a,
+                        b,
+                        |l, r| l.dot(&r),
+                        (Vector, Vector),
+                        (Bivector, Bivector),
+                        (Trivector, Trivector)
+                    ),
+                    Binary::Antidot => pairs!(
+                        a,
+                        b,
+                        |l, r| l.antidot(&r),
+                        (Vector, Vector),
+                        (Bivector, Bivector),
+                        (Trivector, Trivector)
+                    ),
+                    Binary::BulkContraction => pairs!(
+                        a,
+                        b,
+                        |l, r| l.bulk_contraction(&r),
+                        (Bivector, Vector),
+                        (Trivector, Vector),
+                        (Trivector, Bivector)
+                    ),
+                    Binary::WeightContraction => pairs!(
+                        a,
+                        b,
+                        |l, r| l.weight_contraction(&r),
+                        (Bivector, Vector),
+                        (Trivector, Vector),
+                        (Trivector, Bivector)
+                    ),
+                    Binary::BulkExpansion => {
+                        pairs!(a, b, |l, r| l.bulk_expansion(&r), (Vector, Bivector))
+                    }
+                    Binary::WeightExpansion => {
+                        pairs!(a, b, |l, r| l.weight_expansion(&r), (Vector, Bivector))
+                    }
+                    Binary::Cosine => pairs!(
+                        a,
+                        b,
+                        |l, r| cosine!(l, r),
+                        (Vector, Vector),
+                        (Bivector, Vector),
+                        (Bivector, Bivector)
+                    ),
+                    _ => {}
+                }
+                None
+            }
+
+            fn unary(&self, operation: Unary, a: &Value) -> Option<Value> {
+                type Vector = d3::Vector<f64, $metric>;
+                type Bivector = d3::Bivector<f64, $metric>;
+                type Trivector = d3::Trivector<f64, $metric>;
+                type Multivector = d3::Multivector<f64, $metric>;
+
+                match operation {
+                    Unary::RightComplement => {
+                        single!(a, |x| Complement::right_complement(&x), Multivector)
+                    }
+                    Unary::LeftComplement => {
+                        single!(a, |x| Complement::left_complement(&x), Multivector)
+                    }
+                    Unary::RightBulkDual => {
+                        single!(a, |x| x.right_bulk_dual(), Vector, Bivector, Trivector)
+                    }
+                    Unary::LeftBulkDual => {
+                        single!(a, |x| x.left_bulk_dual(), Vector, Bivector, Trivector)
+                    }
+                    Unary::RightWeightDual => {
+                        single!(a, |x| x.right_weight_dual(), Vector, Bivector, Trivector)
+                    }
+                    Unary::LeftWeightDual => {
+                        single!(a, |x| x.left_weight_dual(), Vector, Bivector, Trivector)
+                    }
+                }
+                None
+            }
+        }
+    };
+}
+
+geometry3d!(Euclidean3, Euclidean);
+geometry3d!(Projective3, Projective);
+
+// ----------------------------------------------------------------------------------------------------
+// 4D
+// ----------------------------------------------------------------------------------------------------
+
+struct Projective4;
+
+impl Algebra for Projective4 {
+    fn blades(&self) -> &'static [&'static str] {
+        BLADES_4D
+    }
+
+    fn binary(&self, operation: Binary, a: &Value, b: &Value) -> Option<Result<Value, String>> {
+        use d4::{Bivector, Quadvector, Scalar, Trivector, Vector};
+
+        type S = Scalar<f64>;
+        type V = Vector<f64>;
+        type B = Bivector<f64>;
+        type T = Trivector<f64>;
+        type Q = Quadvector<f64>;
+
+        match operation {
+            Binary::Wedge => pairs!(
+                a,
+                b,
+                |l, r| l.wedge(&r),
+                (S, S),
+                (S, V),
+                (S, B),
+                (S, T),
+                (S, Q),
+                (V, S),
+                (B, S),
+                (T, S),
+                (Q, S),
+                (V, V),
+                (V, B),
+                (V, T),
+                (B, V),
+                (B, B),
+                (T, V),
+            ),
+            Binary::Antiwedge => pairs!(
+                a,
+                b,
+                |l, r| l.antiwedge(&r),
+                (S, Q),
+                (Q, S),
+                (V, T),
+                (V, Q),
+                (B, B),
+                (B, T),
+                (B, Q),
+                (T, V),
+                (T, B),
+                (T, T),
+                (T, Q),
+                (Q, V),
+                (Q, B),
+                (Q, T),
+                (Q, Q),
+            ),
+            Binary::GeometricProduct => pairs!(
+                a,
+                b,
+                |l, r| l.geometric_product(&r),
+                (S, S),
+                (S, V),
+                (S, B),
+                (S, T),
+                (S, Q),
+                (V, S),
+                (B, S),
+                (V, V),
+                (V, B),
+                (V, T),
+                (V, Q),
+                (B, V),
+                (B, B),
+                (B, T),
+                (B, Q),
+                (T, V),
+                (T, B),
+                (T, T),
+                (T, Q),
+                (Q, V),
+                (Q, B),
+                (Q, T),
+                (Q, Q),
+            ),
+            Binary::Dot => pairs!(
+                a,
+                b,
+                |l, r| l.dot(&r),
+                (S, S),
+                (V, V),
+                (B, B),
+                (T, T),
+                (Q, Q)
+            ),
+            Binary::Antidot => pairs!(
+                a,
+                b,
+                |l, r| l.antidot(&r),
+                (S, S),
+                (V, V),
+                (B, B),
+                (T, T),
+                (Q, Q)
+            ),
+            Binary::BulkContraction => pairs!(
+                a,
+                b,
+                |l, r| l.bulk_contraction(&r),
+                (V, V),
+                (B, V),
+                (B, B),
+                (T, V),
+                (T, B),
+                (T, T),
+                (Q, V),
+                (Q, B),
+                (Q, T),
+                (Q, Q),
+            ),
+            Binary::WeightContraction => pairs!(
+                a,
+                b,
+                |l, r| l.weight_contraction(&r),
+                (V, V),
+                (B, V),
+                (B, B),
+                (T, V),
+                (T, B),
+                (T, T),
+                (Q, V),
+                (Q, B),
+                (Q, T),
+                (Q, Q),
+            ),
+            Binary::BulkExpansion => pairs!(
+                a,
+                b,
+                |l, r| l.bulk_expansion(&r),
+                (V, V),
+                (V, B),
+                (V, T),
+                (V, Q),
+                (B, B),
+                (B, T),
+                (B, Q),
+                (T, T),
+                (T, Q),
+                (Q, Q),
+            ),
+            Binary::WeightExpansion => pairs!(
+                a,
+                b,
+                |l, r| l.weight_expansion(&r),
+                (V, V),
+                (V, B),
+                (V, T),
+                (V, Q),
+                (B, B),
+                (B, T),
+                (B, Q),
+                (T, T),
+                (T, Q),
+                (Q, Q),
+            ),
+            Binary::Join => pairs!(a, b, |l, r| l.join(&r), (V, V), (V, B), (B, V)),
+            Binary::Meet => pairs!(a, b, |l, r| l.meet(&r), (T, T), (T, B), (B, T)),
+            Binary::Distance => pairs!(
+                a,
+                b,
+                |l, r| l.distance(&r),
+                (V, V),
+                (V, B),
+                (V, T),
+                (B, V),
+                (B, B),
+                (B, T),
+                (T, V),
+                (T, B),
+                (T, T),
+            ),
+            Binary::Cosine => pairs!(
+                a,
+                b,
+                |l, r| cosine!(l, r),
+                (V, V),
+                (V, B),
+                (V, T),
+                (B, V),
+                (B, B),
+                (B, T),
+                (T, V),
+                (T, B),
+                (T, T),
+            ),
+        }
+        None
+    }
+
+    fn unary(&self, operation: Unary, a: &Value) -> Option<Value> {
+        use d4::{Bivector, Quadvector, Scalar, Trivector, Vector};
+
+        type S = Scalar<f64>;
+        type V = Vector<f64>;
+        type B = Bivector<f64>;
+        type T = Trivector<f64>;
+        type Q = Quadvector<f64>;
+
+        match operation {
+            Unary::RightComplement => {
+                single!(a, |x| KVector::right_complement(&x), S, V, B, T, Q)
+            }
+            Unary::LeftComplement => {
+                single!(a, |x| KVector::left_complement(&x), S, V, B, T, Q)
+            }
+            Unary::RightBulkDual => single!(a, |x| x.right_bulk_dual(), S, V, B, T, Q),
+            Unary::LeftBulkDual => single!(a, |x| x.left_bulk_dual(), S, V, B, T, Q),
+            Unary::RightWeightDual => single!(a, |x| x.right_weight_dual(), S, V, B, T, Q),
+            Unary::LeftWeightDual => single!(a, |x| x.left_weight_dual(), S, V, B, T, Q),
+        }
+        None
+    }
+}
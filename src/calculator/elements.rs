@@ -0,0 +1,119 @@
+use gual::geometry2d as d2;
+use gual::geometry3d as d3;
+use gual::geometry4d as d4;
+
+use super::value::{Components, Element, Value};
+
+/// Implements [`Components`] and [`Element`] for a k-vector, naming its fields after its blades
+macro_rules! kvector {
+    ($grade:literal, $t:ty, new($($blade:ident),+) $(, $metric:ident)?) => {
+        impl$(<$metric>)? Components for $t {
+            fn components(&self) -> Vec<(&'static str, f64)> {
+                vec![$((stringify!($blade), self.$blade)),+]
+            }
+        }
+
+        impl$(<$metric>)? Element for $t {
+            const GRADE: Option<usize> = Some($grade);
+
+            fn from_value(value: &Value) -> Self {
+                <$t>::new($(value.coefficient(stringify!($blade))),+)
+            }
+        }
+    };
+    ($grade:literal, $t:ty, {$($blade:ident),+}) => {
+        impl Components for $t {
+            fn components(&self) -> Vec<(&'static str, f64)> {
+                vec![$((stringify!($blade), self.$blade)),+]
+            }
+        }
+
+        impl Element for $t {
+            const GRADE: Option<usize> = Some($grade);
+
+            fn from_value(value: &Value) -> Self {
+                Self {
+                    $($blade: value.coefficient(stringify!($blade))),+
+                }
+            }
+        }
+    };
+}
+
+/// Implements [`Components`] for a type made of other types, and [`Element`] if all of them are
+macro_rules! composite {
+    ($t:ty, {$($field:ident),+}, $metric:ident, from_value) => {
+        composite!($t, {$($field),+}, $metric);
+
+        impl<$metric> Element for $t {
+            const GRADE: Option<usize> = None;
+
+            fn from_value(value: &Value) -> Self {
+                Self {
+                    $($field: value.to()),+
+                }
+            }
+        }
+    };
+    ($t:ty, {$($field:ident),+} $(, $metric:ident)?) => {
+        impl$(<$metric>)? Components for $t {
+            fn components(&self) -> Vec<(&'static str, f64)> {
+                let mut components = Vec::new();
+                $(components.extend(self.$field.components());)+
+                components
+            }
+        }
+    };
+}
+
+impl Components for () {
+    fn components(&self) -> Vec<(&'static str, f64)> {
+        Vec::new()
+    }
+}
+
+impl Components for f64 {
+    fn components(&self) -> Vec<(&'static str, f64)> {
+        vec![("", *self)]
+    }
+}
+
+impl Element for f64 {
+    const GRADE: Option<usize> = Some(0);
+
+    fn from_value(value: &Value) -> Self {
+        value.coefficient("")
+    }
+}
+
+impl<const D: u32, M> Components for gual::Scalar<D, f64, M> {
+    fn components(&self) -> Vec<(&'static str, f64)> {
+        vec![("", self.0)]
+    }
+}
+
+impl Element for d4::Scalar<f64> {
+    const GRADE: Option<usize> = Some(0);
+
+    fn from_value(value: &Value) -> Self {
+        d4::Scalar::new(value.coefficient(""))
+    }
+}
+
+kvector!(1, d2::Vector<f64, M>, new(x, y), M);
+kvector!(2, d2::Bivector<f64, M>, new(xy), M);
+composite!(d2::Evenvector<f64, M>, { s, b }, M);
+composite!(d2::Multivector<f64, M>, { s, v, b }, M, from_value);
+
+kvector!(1, d3::Vector<f64, M>, new(x, y, z), M);
+kvector!(2, d3::Bivector<f64, M>, new(yz, zx, xy), M);
+kvector!(3, d3::Trivector<f64, M>, new(xyz), M);
+composite!(d3::Evenvector<f64, M>, { s, b }, M);
+composite!(d3::Multivector<f64, M>, { s, v, b, t }, M, from_value);
+
+kvector!(1, d4::Vector<f64>, { x, y, z, w });
+kvector!(2, d4::Bivector<f64>, { wx, wy, wz, yz, zx, xy });
+kvector!(3, d4::Trivector<f64>, { wyz, wzx, wxy, zyx });
+kvector!(4, d4::Quadvector<f64>, { xyzw });
+composite!(d4::Evenvector<f64>, { s, b, a });
+composite!(d4::Multivector<f64>, { s, v, b, t, a });
@@ -0,0 +1,211 @@
+/// Parsed expression, see [`parse`] for the grammar
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Number(f64),
+    /// A variable or a basis blade
+    Name(String),
+    Negate(Box<Expression>),
+    Binary(char, Box<Expression>, Box<Expression>),
+    Call(String, Vec<Expression>),
+}
+
+/// A line of input: an assignment or a bare expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Assign(String, Expression),
+    Evaluate(Expression),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Symbol(char),
+}
+
+/// Parses a line of input. `#` starts a comment, and an empty line is `None`.
+///
+/// ```text
+/// statement  := name '=' expression | expression
+/// expression := term (('+' | '-') term)*
+/// term       := unary (('*' | '^' | '&' | '/') unary)*
+/// unary      := '-' unary | number [primary] | primary
+/// primary    := name | name '(' arguments ')' | '(' expression ')'
+/// ```
+///
+/// `*`, `^` and `&` all bind equally tight, so `a ^ b * c` is `(a ^ b) * c`.
+/// A number followed by a name or a parenthesis multiplies it, so `2xy` is `2 * xy`.
+pub fn parse(line: &str) -> Result<Option<Statement>, String> {
+    let line = line.split('#').next().unwrap_or_default();
+    let tokens = tokenize(line)?;
+    if tokens.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let statement = match parser.tokens.as_slice() {
+        [Token::Name(name), Token::Symbol('='), ..] => {
+            let name = name.clone();
+            parser.position = 2;
+            Statement::Assign(name, parser.expression()?)
+        }
+        _ => Statement::Evaluate(parser.expression()?),
+    };
+    match parser.peek() {
+        None => Ok(Some(statement)),
+        Some(token) => Err(format!("unexpected {}", describe(token))),
+    }
+}
+
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if c.is_ascii_digit() || c == '.' {
+            let mut end = start + c.len_utf8();
+            while let Some((index, c)) = chars.peek().copied() {
+                if !(c.is_ascii_digit() || c == '.') {
+                    break;
+                }
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            let number = &line[start..end];
+            let number = number
+                .parse()
+                .map_err(|_| format!("invalid number `{number}`"))?;
+            tokens.push(Token::Number(number));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some((index, c)) = chars.peek().copied() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Name(line[start..end].to_string()));
+        } else if "+-*/^&(),=".contains(c) {
+            tokens.push(Token::Symbol(c));
+        } else {
+            return Err(format!("unexpected character `{c}`"));
+        }
+    }
+    Ok(tokens)
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Number(number) => format!("number `{number}`"),
+        Token::Name(name) => format!("`{name}`"),
+        Token::Symbol(symbol) => format!("`{symbol}`"),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), String> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            match self.peek() {
+                Some(token) => Err(format!("expected `{symbol}`, found {}", describe(token))),
+                None => Err(format!("expected `{symbol}`")),
+            }
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expression, String> {
+        let mut lhs = self.term()?;
+        while let Some(Token::Symbol(operator @ ('+' | '-'))) = self.peek().cloned() {
+            self.position += 1;
+            lhs = Expression::Binary(operator, Box::new(lhs), Box::new(self.term()?));
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Expression, String> {
+        let mut lhs = self.unary()?;
+        while let Some(Token::Symbol(operator @ ('*' | '^' | '&' | '/'))) = self.peek().cloned() {
+            self.position += 1;
+            lhs = Expression::Binary(operator, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expression, String> {
+        if self.eat('-') {
+            return Ok(Expression::Negate(Box::new(self.unary()?)));
+        }
+        if let Some(Token::Number(number)) = self.peek().cloned() {
+            self.position += 1;
+            let number = Expression::Number(number);
+            return match self.peek() {
+                Some(Token::Name(_) | Token::Symbol('(')) => Ok(Expression::Binary(
+                    '*',
+                    Box::new(number),
+                    Box::new(self.primary()?),
+                )),
+                _ => Ok(number),
+            };
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expression, String> {
+        match self.next() {
+            Some(Token::Name(name)) => {
+                if !self.eat('(') {
+                    return Ok(Expression::Name(name));
+                }
+                let mut arguments = Vec::new();
+                if !self.eat(')') {
+                    loop {
+                        arguments.push(self.expression()?);
+                        if self.eat(')') {
+                            break;
+                        }
+                        self.expect(',')?;
+                    }
+                }
+                Ok(Expression::Call(name, arguments))
+            }
+            Some(Token::Symbol('(')) => {
+                let expression = self.expression()?;
+                self.expect(')')?;
+                Ok(expression)
+            }
+            Some(token) => Err(format!("unexpected {}", describe(&token))),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Element of one of the algebras, stored as one coefficient per basis blade
+#[derive(Debug, Clone, PartialEq)]
+pub struct Value {
+    blades: &'static [&'static str],
+    coefficients: Vec<f64>,
+}
+
+/// Types of `gual` that can be turned into a [`Value`]
+pub trait Components {
+    /// Coefficient of each blade, blades are named like the fields of the types (`""` is the scalar)
+    fn components(&self) -> Vec<(&'static str, f64)>;
+}
+
+/// Types of `gual` that can be read from a [`Value`]
+pub trait Element: Components {
+    /// Grade of the type, `None` for types that hold every grade
+    const GRADE: Option<usize>;
+
+    /// Reads the blades of the type, ignoring the rest
+    fn from_value(value: &Value) -> Self;
+}
+
+impl Value {
+    /// Zero element of the algebra with the given blades
+    pub fn zero(blades: &'static [&'static str]) -> Self {
+        Value {
+            blades,
+            coefficients: vec![0.0; blades.len()],
+        }
+    }
+
+    pub fn scalar(blades: &'static [&'static str], s: f64) -> Self {
+        Value::zero(blades).with_coefficient(0, s)
+    }
+
+    /// Unit blade written with the basis vectors in any order, so `zy` is `-yz`.
+    ///
+    /// Returns `None` if the name is not a blade of the algebra.
+    pub fn blade(blades: &'static [&'static str], name: &str) -> Option<Self> {
+        let mut sorted: Vec<char> = name.chars().collect();
+        sorted.sort_unstable();
+        if sorted.is_empty() || sorted.windows(2).any(|w| w[0] == w[1]) {
+            return None;
+        }
+        let index = blades.iter().position(|blade| {
+            let mut chars: Vec<char> = blade.chars().collect();
+            chars.sort_unstable();
+            chars == sorted
+        })?;
+        let sign = if permutation_is_odd(name, blades[index]) {
+            -1.0
+        } else {
+            1.0
+        };
+        Some(Value::zero(blades).with_coefficient(index, sign))
+    }
+
+    /// Builds a value from the result of an operation of `gual`
+    pub fn from_components(blades: &'static [&'static str], value: &impl Components) -> Self {
+        let mut result = Value::zero(blades);
+        for (name, coefficient) in value.components() {
+            let index = blades
+                .iter()
+                .position(|blade| *blade == name)
+                .unwrap_or_else(|| panic!("{name} is not a blade of the algebra"));
+            result.coefficients[index] += coefficient;
+        }
+        result
+    }
+
+    /// Reads the blades of type `E`, ignoring all the others
+    pub fn to<E: Element>(&self) -> E {
+        E::from_value(self)
+    }
+
+    pub fn blades(&self) -> &'static [&'static str] {
+        self.blades
+    }
+
+    /// Coefficient of a blade, named exactly as it is stored
+    pub fn coefficient(&self, blade: &str) -> f64 {
+        self.blades
+            .iter()
+            .position(|b| *b == blade)
+            .map(|index| self.coefficients[index])
+            .unwrap_or_else(|| panic!("{blade} is not a blade of the algebra"))
+    }
+
+    /// Grade of the value if all of its non-zero blades have the same one
+    ///
+    /// Zero has no grade.
+    pub fn grade(&self) -> Option<usize> {
+        let mut grades = self.non_zero().map(|(blade, _)| blade.len());
+        let grade = grades.next()?;
+        grades.all(|g| g == grade).then_some(grade)
+    }
+
+    /// Splits the value in its non-zero k-vectors
+    pub fn parts(&self) -> Vec<Value> {
+        let mut grades: Vec<usize> = self.non_zero().map(|(blade, _)| blade.len()).collect();
+        grades.sort_unstable();
+        grades.dedup();
+        grades
+            .into_iter()
+            .map(|grade| {
+                let mut part = Value::zero(self.blades);
+                for (index, blade) in self.blades.iter().enumerate() {
+                    if blade.len() == grade {
+                        part.coefficients[index] = self.coefficients[index];
+                    }
+                }
+                part
+            })
+            .collect()
+    }
+
+    /// The scalar this value is, if it has no other grades
+    pub fn as_scalar(&self) -> Option<f64> {
+        // zero has no grade, and neither does a value with several grades
+        if self.non_zero().all(|(blade, _)| blade.is_empty()) {
+            Some(self.coefficients[0])
+        } else {
+            None
+        }
+    }
+
+    fn with_coefficient(mut self, index: usize, coefficient: f64) -> Self {
+        self.coefficients[index] = coefficient;
+        self
+    }
+
+    fn non_zero(&self) -> impl Iterator<Item = (&'static str, f64)> + '_ {
+        self.blades
+            .iter()
+            .zip(&self.coefficients)
+            .filter(|(_, c)| **c != 0.0)
+            .map(|(blade, c)| (*blade, *c))
+    }
+
+    fn zip(self, rhs: Value, f: impl Fn(f64, f64) -> f64) -> Value {
+        assert_eq!(self.blades, rhs.blades, "values of different algebras");
+        Value {
+            blades: self.blades,
+            coefficients: self
+                .coefficients
+                .iter()
+                .zip(&rhs.coefficients)
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+        }
+    }
+}
+
+/// Whether reordering the letters of `from` into `to` takes an odd number of swaps
+fn permutation_is_odd(from: &str, to: &str) -> bool {
+    let positions: Vec<usize> = from
+        .chars()
+        .map(|c| to.chars().position(|t| t == c).expect("same letters"))
+        .collect();
+    let inversions = positions
+        .iter()
+        .enumerate()
+        .map(|(n, p)| positions[n + 1..].iter().filter(|q| *q < p).count())
+        .sum::<usize>();
+    inversions % 2 == 1
+}
+
+impl Add for Value {
+    type Output = Value;
+    fn add(self, rhs: Value) -> Value {
+        self.zip(rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for Value {
+    type Output = Value;
+    fn sub(self, rhs: Value) -> Value {
+        self.zip(rhs, |a, b| a - b)
+    }
+}
+
+impl Neg for Value {
+    type Output = Value;
+    fn neg(self) -> Value {
+        self * -1.0
+    }
+}
+
+impl Mul<f64> for Value {
+    type Output = Value;
+    fn mul(mut self, rhs: f64) -> Value {
+        for c in &mut self.coefficients {
+            *c *= rhs;
+        }
+        self
+    }
+}
+
+/// Canonical form: `1 + 2x - yz`, blades in the order of the fields of the types
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (blade, coefficient) in self.non_zero() {
+            let magnitude = coefficient.abs();
+            match (first, coefficient < 0.0) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            if blade.is_empty() {
+                write!(f, "{magnitude}")?;
+            } else if magnitude == 1.0 {
+                write!(f, "{blade}")?;
+            } else {
+                write!(f, "{magnitude}{blade}")?;
+            }
+            first = false;
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
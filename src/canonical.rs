@@ -4,7 +4,7 @@
 
 use std::ops::{Div, Mul};
 
-use crate::{Antiscalar, AntiwedgeProduct, Complement, Dual, Epsilon, Norm, WedgeProduct};
+use crate::{Antiscalar, AntiwedgeProduct, Complement, Dual, Epsilon, KVector, Norm, WedgeProduct};
 
 use crate::Expansion as CrateExpansion;
 use crate::Metric as CrateMetric;
@@ -31,6 +31,28 @@ where
     }
 }
 
+/// Same as [`Antiwedge`], for the types that implement [`KVector`] instead of [`Complement`]
+pub trait KVectorAntiwedge<Rht> {
+    type Output;
+    fn canonical_kvector_antiwedge(&self, rhs: &Rht) -> Self::Output;
+}
+
+impl<Lht, Rht> KVectorAntiwedge<Rht> for Lht
+where
+    Lht: KVector,
+    Rht: KVector,
+    <Lht as KVector>::AntiKVector: WedgeProduct<<Rht as KVector>::AntiKVector>,
+    <<Lht as KVector>::AntiKVector as WedgeProduct<<Rht as KVector>::AntiKVector>>::Output: KVector,
+{
+    type Output = <<<Lht as KVector>::AntiKVector as WedgeProduct<<Rht as KVector>::AntiKVector>>::Output as KVector>::AntiKVector;
+
+    fn canonical_kvector_antiwedge(&self, rhs: &Rht) -> Self::Output {
+        self.left_complement()
+            .wedge(&rhs.left_complement())
+            .right_complement()
+    }
+}
+
 pub trait Metric {
     type DualOutput;
 
@@ -148,7 +170,7 @@ where
 
     fn canonical_geometric_cosine(&self, rhs: &Rht) -> (Self::Scalar, Self::Antiscalar) {
         (
-            self.weight_expansion(&rhs).bulk_norm(),
+            self.weight_expansion(rhs).bulk_norm(),
             <<Lht as Norm>::Antiscalar as Antiscalar>::from_volume(
                 self.weight_norm().volume() * rhs.weight_norm().volume(),
             ),
@@ -156,7 +178,7 @@ where
     }
 
     fn canonical_cosine(&self, rhs: &Rht) -> Option<Self::Scalar> {
-        let geometric_cosine = self.canonical_geometric_cosine(&rhs);
+        let geometric_cosine = self.canonical_geometric_cosine(rhs);
 
         if geometric_cosine.1.volume().is_near_zero() {
             None
@@ -188,7 +210,7 @@ where
 
     fn canonical_geometric_cosine_symetric(&self, rhs: &Self) -> (Self::Scalar, Self::Antiscalar) {
         (
-            self.weight_expansion(&rhs).volume(),
+            self.weight_expansion(rhs).volume(),
             <<T as Norm>::Antiscalar as Antiscalar>::from_volume(
                 self.weight_norm().volume() * rhs.weight_norm().volume(),
             ),
@@ -196,7 +218,7 @@ where
     }
 
     fn canonical_cosine_symetric(&self, rhs: &Self) -> Option<Self::Scalar> {
-        let geometric_cosine = self.canonical_geometric_cosine_symetric(&rhs);
+        let geometric_cosine = self.canonical_geometric_cosine_symetric(rhs);
 
         if geometric_cosine.1.volume().is_near_zero() {
             None
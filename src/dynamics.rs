@@ -0,0 +1,250 @@
+//! Rigid-body dynamics
+//!
+//! A [`Body`] is a motor that moves the body frame to its place in the world, and a
+//! [`Twist`] with its velocity in the body frame. The body frame has its origin at the center of
+//! mass and its axes along the principal axes of inertia, so the [`Inertia`] map from twists to
+//! momentum wrenches is diagonal. Forces are given as [`Wrench`]es in the world frame.
+
+use num::Float;
+use num::traits::{ConstOne, ConstZero};
+
+use crate::geometry3d as d3;
+use crate::homogeneous3d::{Motor, Twist, Wrench};
+use crate::{Complement, Epsilon, GeometricAntiproduct, Unitizable, WedgeProduct};
+
+/// Mass and principal moments of inertia of a rigid body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inertia<T> {
+    pub mass: T,
+    /// Moments of inertia around the `x`, `y` and `z` axes of the body frame
+    pub moments: d3::Vector<T>,
+}
+
+/// State of a rigid body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Body<T> {
+    /// Motion from the body frame to the world frame
+    pub motor: Motor<T>,
+    /// Velocity in the body frame
+    pub velocity: Twist<T>,
+}
+
+/// Wrench of `force` applied at `point`
+pub fn point_force<T>(point: &d3::Point<T>, force: &d3::Vector<T>) -> Wrench<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: ConstZero,
+    T: ConstOne,
+{
+    Wrench::new(force, &point.0.wedge(force).right_complement())
+}
+
+/// Weight of a body of `mass` with its center at `center`, under the gravitational `acceleration`
+pub fn gravity<T>(center: &d3::Point<T>, mass: T, acceleration: &d3::Vector<T>) -> Wrench<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: ConstZero,
+    T: ConstOne,
+{
+    point_force(center, &(*acceleration * mass))
+}
+
+impl<T> Inertia<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: ConstZero,
+    T: ConstOne,
+{
+    pub fn new(mass: T, moments: &d3::Vector<T>) -> Self {
+        Inertia {
+            mass,
+            moments: *moments,
+        }
+    }
+
+    /// Momentum of a body moving with `twist`, both in the body frame
+    pub fn momentum(&self, twist: &Twist<T>) -> Wrench<T> {
+        let w = twist.angular();
+        Wrench::new(
+            &(twist.linear() * self.mass),
+            &d3::Vector::new(
+                w.x * self.moments.x,
+                w.y * self.moments.y,
+                w.z * self.moments.z,
+            ),
+        )
+    }
+
+    /// Inverse of [`Inertia::momentum`]
+    pub fn velocity(&self, momentum: &Wrench<T>) -> Twist<T> {
+        let l = momentum.torque();
+        Twist::new(
+            &d3::Vector::new(
+                l.x / self.moments.x,
+                l.y / self.moments.y,
+                l.z / self.moments.z,
+            ),
+            &(momentum.force() * self.mass.recip()),
+        )
+    }
+
+    /// Acceleration in the body frame from the Newton-Euler equations, `wrench` is in the body
+    /// frame too
+    fn acceleration(&self, twist: &Twist<T>, wrench: &Wrench<T>) -> Twist<T> {
+        let w = twist.angular();
+        let gyroscopic = w.wedge(&self.momentum(twist).torque()).right_complement();
+        let transport = w.wedge(&twist.linear()).right_complement();
+        self.velocity(&Wrench::new(
+            &(wrench.force() - transport * self.mass),
+            &(wrench.torque() - gyroscopic),
+        ))
+    }
+}
+
+impl<T> Body<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: ConstZero,
+    T: ConstOne,
+{
+    pub fn new(motor: Motor<T>, velocity: Twist<T>) -> Self {
+        Body { motor, velocity }
+    }
+
+    /// Position of the center of mass in the world
+    pub fn center(&self) -> d3::Point<T> {
+        self.motor.transform_point(&d3::Point(d3::Vector::ZERO))
+    }
+
+    /// Velocity in the world frame
+    pub fn world_velocity(&self) -> Twist<T> {
+        self.velocity.transform(&self.motor)
+    }
+
+    /// Momentum in the world frame, constant when no forces act on the body
+    pub fn momentum(&self, inertia: &Inertia<T>) -> Wrench<T> {
+        inertia.momentum(&self.velocity).transform(&self.motor)
+    }
+
+    pub fn kinetic_energy(&self, inertia: &Inertia<T>) -> T {
+        let half = (T::one() + T::one()).recip();
+        half * self
+            .velocity
+            .reciprocal_product(&inertia.momentum(&self.velocity))
+    }
+
+    /// Advances the body by `dt` with a symplectic midpoint method: the world momentum is updated
+    /// first with the world `forces` at the current state, then the motor moves with the
+    /// exponential of the velocity that momentum has halfway through the step, which is found by
+    /// fixed-point iteration.
+    ///
+    /// The world momentum of a free body is kept exactly, and its energy error stays bounded
+    /// instead of drifting.
+    pub fn step_symplectic<F>(&self, inertia: &Inertia<T>, dt: T, forces: F) -> Self
+    where
+        F: Fn(&Body<T>) -> Wrench<T>,
+    {
+        let momentum = self.momentum(inertia) + forces(self) * dt;
+        let velocity =
+            |motor: &Motor<T>| inertia.velocity(&momentum.transform(&motor.antireverse()));
+
+        let half = (T::one() + T::one()).recip();
+        let mut step = velocity(&self.motor) * dt;
+        for _ in 0..ITERATIONS {
+            step = velocity(&self.motor.geometric_antiproduct(&(step * half).exp())) * dt;
+        }
+        let motor = unitized(&self.motor.geometric_antiproduct(&step.exp()));
+        Body {
+            motor,
+            velocity: velocity(&motor),
+        }
+    }
+
+    /// Advances the body by `dt` with the classic fourth order Runge-Kutta method on the motor and
+    /// the body velocity, the motor is unitized at the end of the step
+    pub fn step_rk4<F>(&self, inertia: &Inertia<T>, dt: T, forces: F) -> Self
+    where
+        F: Fn(&Body<T>) -> Wrench<T>,
+    {
+        let two = T::one() + T::one();
+        let six = two + two + two;
+        let state = State::from_body(self);
+        let derivative = |state: &State<T>| state.derivative(inertia, &forces);
+
+        let k1 = derivative(&state);
+        let k2 = derivative(&state.add_scaled(&k1, dt / two));
+        let k3 = derivative(&state.add_scaled(&k2, dt / two));
+        let k4 = derivative(&state.add_scaled(&k3, dt));
+        state
+            .add_scaled(&k1, dt / six)
+            .add_scaled(&k2, dt / six * two)
+            .add_scaled(&k3, dt / six * two)
+            .add_scaled(&k4, dt / six)
+            .to_body()
+    }
+}
+
+/// Fixed-point iterations for the midpoint velocity of [`Body::step_symplectic`], it has converged
+/// to rounding after three for any reasonable step
+const ITERATIONS: usize = 4;
+
+/// The unitized `motor`, or `motor` itself if it has no rotational part to scale
+fn unitized<T>(motor: &Motor<T>) -> Motor<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    motor.unitize().unwrap_or(*motor)
+}
+
+/// Integration variables of [`Body::step_rk4`], and also their derivatives
+#[derive(Clone, Copy)]
+struct State<T> {
+    motor: Motor<T>,
+    velocity: Twist<T>,
+}
+
+impl<T> State<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: ConstZero,
+    T: ConstOne,
+{
+    fn from_body(body: &Body<T>) -> Self {
+        State {
+            motor: body.motor,
+            velocity: body.velocity,
+        }
+    }
+
+    fn to_body(self) -> Body<T> {
+        Body {
+            motor: unitized(&self.motor),
+            velocity: self.velocity,
+        }
+    }
+
+    fn add_scaled(&self, derivative: &Self, factor: T) -> Self {
+        State {
+            motor: self.motor + derivative.motor * factor,
+            velocity: self.velocity + derivative.velocity * factor,
+        }
+    }
+
+    fn derivative<F>(&self, inertia: &Inertia<T>, forces: &F) -> Self
+    where
+        F: Fn(&Body<T>) -> Wrench<T>,
+    {
+        let body = self.to_body();
+        let wrench = forces(&body).transform(&body.motor.antireverse());
+        State {
+            motor: self.velocity.motor_derivative(&self.motor),
+            velocity: inertia.acceleration(&self.velocity, &wrench),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Fitting of primitives to point clouds
+//!
+//! The least-squares fits live with each primitive (like [`crate::homogeneous3d::Plane::fit`]),
+//! and [`Ransac`] drives them to fit data with outliers through the [`Model`] trait.
+
+use num::Float;
+
+/// Primitive that can be fitted to a set of points of type `P`
+pub trait Model<P>: Sized {
+    type Scalar;
+
+    /// Number of points that define a model
+    const SAMPLE_SIZE: usize;
+
+    /// Least-squares fit to `points` along with the root mean square of their distances to it,
+    /// `None` if the points don't define a model
+    fn fit(points: &[P]) -> Option<(Self, Self::Scalar)>;
+}
+
+/// Random sample consensus: fits a [`Model`] to the points that agree the most on one
+///
+/// Each iteration fits a model to [`Model::SAMPLE_SIZE`] random points and counts the points
+/// that the inlier test accepts for it. The model is then refitted to the inliers of the best
+/// iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ransac {
+    /// Number of random samples to try
+    pub iterations: usize,
+    /// Seed of the sample picking, the same seed and points always give the same fit
+    pub seed: u64,
+}
+
+/// Result of a [`Ransac`] fit
+#[derive(Debug, Clone, PartialEq)]
+pub struct RansacFit<M, T> {
+    pub model: M,
+    /// Root mean square of the distances from the inliers to the model
+    pub rms_error: T,
+    /// Indices of the points that agree with the model
+    pub inliers: Vec<usize>,
+}
+
+impl Default for Ransac {
+    fn default() -> Self {
+        Ransac {
+            iterations: 100,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+}
+
+impl Ransac {
+    /// Fits `M` to `points`, where `is_inlier` tells if a point agrees with a model, usually by
+    /// comparing their [`crate::Distance`] with a threshold
+    ///
+    /// Returns `None` if no sample defines a model.
+    pub fn fit<M, P>(
+        &self,
+        points: &[P],
+        is_inlier: impl Fn(&M, &P) -> bool,
+    ) -> Option<RansacFit<M, M::Scalar>>
+    where
+        M: Model<P>,
+        P: Clone,
+    {
+        if points.len() < M::SAMPLE_SIZE {
+            return None;
+        }
+
+        let mut random = XorShift(self.seed | 1);
+        let mut best: Option<(M, M::Scalar, Vec<usize>)> = None;
+        let mut sample = Vec::with_capacity(M::SAMPLE_SIZE);
+        for _ in 0..self.iterations {
+            sample.clear();
+            while sample.len() < M::SAMPLE_SIZE {
+                let index = random.below(points.len());
+                if !sample.contains(&index) {
+                    sample.push(index);
+                }
+            }
+            let sample_points: Vec<P> = sample.iter().map(|&i| points[i].clone()).collect();
+            let Some((model, error)) = M::fit(&sample_points) else {
+                continue;
+            };
+
+            let inliers: Vec<usize> = (0..points.len())
+                .filter(|&i| is_inlier(&model, &points[i]))
+                .collect();
+            if best
+                .as_ref()
+                .is_none_or(|(_, _, best_inliers)| inliers.len() > best_inliers.len())
+            {
+                best = Some((model, error, inliers));
+            }
+        }
+
+        let (model, error, inliers) = best?;
+        let inlier_points: Vec<P> = inliers.iter().map(|&i| points[i].clone()).collect();
+        let (model, rms_error) = M::fit(&inlier_points).unwrap_or((model, error));
+        Some(RansacFit {
+            model,
+            rms_error,
+            inliers,
+        })
+    }
+}
+
+/// Small generator for the sample picking, so fitting doesn't need a random number dependency
+struct XorShift(u64);
+
+impl XorShift {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Ratio to the machine epsilon under which a spread of the points is rounding error
+const SPREAD_TOLERANCE: u32 = 1000;
+
+/// Whether `spread`, an eigenvalue of a scatter matrix, is rounding error next to the eigenvalue
+/// `largest`
+pub(crate) fn negligible<T: Float>(spread: T, largest: T) -> bool {
+    spread <= largest * T::from(SPREAD_TOLERANCE).unwrap_or(T::one()) * T::epsilon()
+}
+
+/// Whether the points coincide, given the trace of their scatter matrix and the sum of their
+/// squared distances to the origin
+///
+/// The spread of the points is compared with the rounding error of their coordinates, so tiny
+/// clouds far from the origin still count as spread out.
+pub(crate) fn coincident<T: Float>(trace: T, moment: T) -> bool {
+    let tolerance = T::from(SPREAD_TOLERANCE).unwrap_or(T::one()) * T::epsilon();
+    trace <= moment * tolerance * tolerance
+}
+
+/// Eigenvalues of a 3x3 matrix paired with their unit eigenvectors
+pub(crate) type Eigen<T> = [(T, [T; 3]); 3];
+
+/// Eigenvalues of a symmetric matrix, with their unit eigenvectors, sorted from the smallest to
+/// the largest, found with Jacobi rotations
+pub(crate) fn symmetric_eigen<T: Float>(mut a: [[T; 3]; 3]) -> Eigen<T> {
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let mut vectors = [[one, zero, zero], [zero, one, zero], [zero, zero, one]];
+
+    for _ in 0..50 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= T::epsilon() * T::epsilon() * diagonal {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == zero {
+                continue;
+            }
+            // rotation in the pq plane that zeroes a[p][q]
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+            let c = (t * t + one).sqrt().recip();
+            let s = t * c;
+
+            let mut rotation = [[one, zero, zero], [zero, one, zero], [zero, zero, one]];
+            rotation[p][p] = c;
+            rotation[q][q] = c;
+            rotation[p][q] = s;
+            rotation[q][p] = -s;
+            a = multiply(&transpose(&rotation), &multiply(&a, &rotation));
+            vectors = multiply(&vectors, &rotation);
+        }
+    }
+
+    let mut eigen = [0, 1, 2].map(|i| (a[i][i], [vectors[0][i], vectors[1][i], vectors[2][i]]));
+    eigen.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    eigen
+}
+
+fn multiply<T: Float>(a: &[[T; 3]; 3], b: &[[T; 3]; 3]) -> [[T; 3]; 3] {
+    let mut result = [[T::zero(); 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    result
+}
+
+fn transpose<T: Float>(a: &[[T; 3]; 3]) -> [[T; 3]; 3] {
+    [0, 1, 2].map(|i| [a[0][i], a[1][i], a[2][i]])
+}
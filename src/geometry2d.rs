@@ -5,18 +5,24 @@ use crate::Euclidean;
 mod add;
 mod angle;
 mod antiwedge;
+#[cfg(feature = "proptest")]
+mod arbitrary;
 mod complement;
 mod contraction;
 mod copyclone;
 mod div;
 mod dot;
 mod dual;
+mod embedding;
 mod geometric_product;
+mod graded;
 mod initialization;
 mod metric;
 mod mul;
 mod neg;
 mod norm;
+#[cfg(feature = "operators")]
+mod operators;
 mod sub;
 mod vector_space;
 mod wedge;
@@ -46,3 +52,14 @@ pub struct Multivector<T, M = Euclidean> {
     pub v: Vector<T, M>,
     pub b: Bivector<T, M>,
 }
+
+/// Basis blades of the 2D algebra, in the canonical order of its tables
+///
+/// They name the components of the elements of the algebra through [`crate::Graded`] and indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BasisBlade {
+    Scalar,
+    X,
+    Y,
+    XY,
+}
@@ -0,0 +1,87 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use num::Float;
+use proptest::prelude::*;
+
+use crate::arbitrary::field;
+
+use super::{Bivector, Evenvector, Multivector, Vector};
+
+impl<T, M> Arbitrary for Vector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field())
+            .prop_map(|(x, y)| Vector {
+                x,
+                y,
+                _metric: PhantomData,
+            })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Bivector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        field()
+            .prop_map(|xy| Bivector {
+                xy,
+                _metric: PhantomData,
+            })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Evenvector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), any::<Bivector<T, M>>())
+            .prop_map(|(s, b)| Evenvector { s, b })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Multivector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), any::<Vector<T, M>>(), any::<Bivector<T, M>>())
+            .prop_map(|(s, v, b)| Multivector { s, v, b })
+            .boxed()
+    }
+}
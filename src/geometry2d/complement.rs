@@ -94,7 +94,7 @@ where
         Multivector {
             s: self.b.right_complement(),
             v: self.v.right_complement(),
-            b: Bivector::from_volume(self.s.clone()),
+            b: Bivector::from_volume(self.s),
         }
     }
 
@@ -102,7 +102,7 @@ where
         Multivector {
             s: self.b.left_complement(),
             v: self.v.left_complement(),
-            b: Bivector::from_volume(self.s.clone()),
+            b: Bivector::from_volume(self.s),
         }
     }
 }
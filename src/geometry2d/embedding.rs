@@ -0,0 +1,49 @@
+use std::marker::PhantomData;
+
+use super::{Bivector, Evenvector, Multivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Metric casts
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> Vector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Vector<T, N> {
+        Vector {
+            x: self.x,
+            y: self.y,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> Bivector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Bivector<T, N> {
+        Bivector {
+            xy: self.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> Evenvector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Evenvector<T, N> {
+        Evenvector {
+            s: self.s,
+            b: self.b.cast_metric(),
+        }
+    }
+}
+
+impl<T, M> Multivector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Multivector<T, N> {
+        Multivector {
+            s: self.s,
+            v: self.v.cast_metric(),
+            b: self.b.cast_metric(),
+        }
+    }
+}
@@ -3,7 +3,10 @@ use std::{
     ops::{Add, Mul, Neg, Sub},
 };
 
-use crate::{GeometricProduct, geometric_with_scalar_metric, reverse_geometric_metric};
+use crate::{
+    GeometricProduct, geometric_with_scalar_metric, reverse_geometric_anticommute_metric,
+    reverse_geometric_metric,
+};
 
 use super::{Bivector, Evenvector, Multivector, Vector};
 
@@ -155,7 +158,7 @@ where
 
 geometric_with_scalar_metric!(Bivector);
 
-reverse_geometric_metric!(Bivector, Vector);
+reverse_geometric_anticommute_metric!(Bivector, Vector);
 
 impl<T: Copy> GeometricProduct<Bivector<T>> for Bivector<T>
 where
@@ -253,9 +256,35 @@ where
     }
 }
 
-reverse_geometric_metric!(Multivector, Bivector);
+impl<T: Copy> GeometricProduct<Bivector<T>> for Multivector<T>
+where
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Multivector<T>;
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: self.b.geometric_product(rhs),
+            v: self.v.geometric_product(rhs),
+            b: *rhs * self.s,
+        }
+    }
+}
 
-reverse_geometric_metric!(Multivector, Evenvector);
+impl<T: Copy> GeometricProduct<Evenvector<T>> for Multivector<T>
+where
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Multivector<T>;
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        rhs.s.geometric_product(self) + self.geometric_product(&rhs.b)
+    }
+}
 
 impl<T: Copy> GeometricProduct<Multivector<T>> for Multivector<T>
 where
@@ -0,0 +1,31 @@
+use crate::{Scalar, graded};
+
+use super::{BasisBlade, Bivector, Evenvector, Multivector, Vector};
+
+impl BasisBlade {
+    /// Every basis blade, in canonical order
+    pub const ALL: [BasisBlade; 4] = [
+        BasisBlade::Scalar,
+        BasisBlade::X,
+        BasisBlade::Y,
+        BasisBlade::XY,
+    ];
+
+    /// Number of basis vectors that build the blade
+    pub const fn grade(self) -> u32 {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::X | BasisBlade::Y => 1,
+            BasisBlade::XY => 2,
+        }
+    }
+}
+
+graded!(Scalar<2, T, M>, [T, M]; BasisBlade; 0 => Scalar);
+graded!(Vector<T, M>, [T, M]; BasisBlade; x => X, y => Y);
+graded!(Bivector<T, M>, [T, M]; BasisBlade; xy => XY);
+graded!(Evenvector<T, M>, [T, M]; BasisBlade; s => Scalar, b.xy => XY);
+graded!(
+    Multivector<T, M>, [T, M]; BasisBlade;
+    s => Scalar, v.x => X, v.y => Y, b.xy => XY
+);
@@ -0,0 +1,17 @@
+use crate::{geometric_mul, operators};
+
+use super::{Bivector, Evenvector, Multivector, Vector};
+
+operators!(Vector<T, M>, T, M);
+operators!(Bivector<T, M>, T, M);
+operators!(Evenvector<T, M>, T, M);
+operators!(Multivector<T, M>, T, M);
+
+geometric_mul!(Vector<T, M>, Vector<T, M> => Evenvector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Bivector<T, M> => Vector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Evenvector<T, M> => Vector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Multivector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Vector<T, M> => Vector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Bivector<T, M> => T, T, M);
+geometric_mul!(Bivector<T, M>, Evenvector<T, M> => Evenvector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Multivector<T, M> => Multivector<T, M>, T, M);
@@ -38,29 +38,29 @@ where
 
 impl<T, M> Sub<Evenvector<T, M>> for Scalar<2, T, M>
 where
-    T: ConstZero,
     T: Sub<Output = T>,
+    T: Neg<Output = T>,
 {
     type Output = Evenvector<T, M>;
     fn sub(self, rhs: Evenvector<T, M>) -> Self::Output {
         Evenvector {
             s: self.0 - rhs.s,
-            b: rhs.b,
+            b: -rhs.b,
         }
     }
 }
 
 impl<T, M> Sub<Multivector<T, M>> for Scalar<2, T, M>
 where
-    T: ConstZero,
     T: Sub<Output = T>,
+    T: Neg<Output = T>,
 {
     type Output = Multivector<T, M>;
     fn sub(self, rhs: Multivector<T, M>) -> Self::Output {
         Multivector {
             s: self.0 - rhs.s,
-            v: rhs.v,
-            b: rhs.b,
+            v: -rhs.v,
+            b: -rhs.b,
         }
     }
 }
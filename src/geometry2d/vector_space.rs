@@ -5,9 +5,9 @@ use std::{
 
 use num::{Float, traits::ConstOne};
 
-use crate::{Antiscalar, Complement, VectorSpace};
+use crate::{Antiscalar, Complement, VectorSpace, assign_ops};
 
-use super::{Bivector, Multivector, Vector};
+use super::{Bivector, Evenvector, Multivector, Vector};
 
 impl<T: Clone, M> Antiscalar for Bivector<T, M>
 where
@@ -78,3 +78,8 @@ where
         }
     }
 }
+
+assign_ops!(Vector<T, M>, T, M);
+assign_ops!(Bivector<T, M>, T, M);
+assign_ops!(Evenvector<T, M>, T, M);
+assign_ops!(Multivector<T, M>, T, M);
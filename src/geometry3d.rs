@@ -130,32 +130,43 @@ use std::marker::PhantomData;
 
 use crate::Euclidean;
 
-mod angle_legacy;
-mod scalar3d;
-
 mod add;
 mod angle;
 mod antiwedge;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod blade;
+mod commutator;
 mod complement;
-mod contraction;
 mod copyclone;
 mod div;
-mod dot;
-mod dual;
-mod expansion;
+mod embedding;
+mod exterior3d;
+#[cfg(feature = "operators")]
+mod geometric_mul3d;
 mod geometric_product;
+mod graded;
 mod initialization;
+pub mod legacy;
 mod metric;
 mod mul;
 mod neg;
 mod norm;
-mod projective_geometric_product;
+#[cfg(feature = "operators")]
+mod operators;
+mod products3d;
+mod projective_products3d;
+mod scalar3d;
 mod sub;
+mod subspace;
 mod vector_space;
 mod wedge;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Scalar<T>(pub T);
+/// Scalar of the 3D algebra
+///
+/// Breaking change in 0.2.0: it used to be its own tuple struct, it is now built with
+/// `Scalar::new` or `Scalar::from`. The old type lives on, deprecated, as [`legacy::Scalar`].
+pub type Scalar<T, M = Euclidean> = crate::Scalar<3, T, M>;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Vector<T, M = Euclidean> {
@@ -201,3 +212,18 @@ pub struct Evenvector<T, M = Euclidean> {
     pub s: T,
     pub b: Bivector<T, M>,
 }
+
+/// Basis blades of the 3D algebra, in the canonical order of its tables
+///
+/// They name the components of the elements of the algebra through [`crate::Graded`] and indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BasisBlade {
+    Scalar,
+    X,
+    Y,
+    Z,
+    YZ,
+    ZX,
+    XY,
+    XYZ,
+}
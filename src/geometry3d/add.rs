@@ -2,7 +2,7 @@ use std::{marker::PhantomData, ops::Add};
 
 use num::traits::ConstZero;
 
-use crate::{Scalar, reverse_add_metric};
+use crate::{Scalar, reverse_add_metric, reverse_add_scalar_metric};
 
 use super::{Bivector, Evenvector, Multivector, Point, Trivector, Vector};
 
@@ -40,6 +40,8 @@ where
     }
 }
 
+reverse_add_scalar_metric!(Vector);
+
 impl<T, M> Add<Vector<T, M>> for Vector<T, M>
 where
     T: Add<T, Output = T>,
@@ -148,6 +150,8 @@ impl<T, M> Add<Scalar<3, T, M>> for Bivector<T, M> {
     }
 }
 
+reverse_add_scalar_metric!(Bivector);
+
 reverse_add_metric!(Bivector, Vector);
 
 impl<T, M> Add<Bivector<T, M>> for Bivector<T, M>
@@ -243,6 +247,8 @@ where
     }
 }
 
+reverse_add_scalar_metric!(Trivector);
+
 reverse_add_metric!(Trivector, Vector);
 reverse_add_metric!(Trivector, Bivector);
 
@@ -320,6 +326,8 @@ where
     }
 }
 
+reverse_add_scalar_metric!(Evenvector);
+
 reverse_add_metric!(Evenvector, Vector);
 
 reverse_add_metric!(Evenvector, Bivector);
@@ -388,10 +396,14 @@ where
     }
 }
 
+reverse_add_scalar_metric!(Multivector);
+
 reverse_add_metric!(Multivector, Vector);
 
 reverse_add_metric!(Multivector, Bivector);
 
+reverse_add_metric!(Multivector, Trivector);
+
 reverse_add_metric!(Multivector, Evenvector);
 
 impl<T, M> Add<Multivector<T, M>> for Multivector<T, M>
@@ -2,9 +2,9 @@ use std::marker::PhantomData;
 
 use num::{Float, Zero, traits::ConstOne};
 
-use crate::{Angle, Epsilon, Projective, reverse_angle};
+use crate::{Angle, Epsilon, Projective, Scalar, reverse_angle};
 
-use super::{Bivector, Trivector, Vector};
+use super::{Bivector, Trivector, UnitBivector, UnitVector, Vector};
 
 impl<T> Angle<Vector<T>> for Vector<T>
 where
@@ -199,3 +199,81 @@ where
         }
     }
 }
+
+impl<T> Angle<UnitVector<T>> for UnitVector<T>
+where
+    T: ConstOne,
+    T: Float,
+{
+    type Scalar = Scalar<3, T>;
+    type Antiscalar = Trivector<T>;
+
+    fn geometric_cosine(&self, rhs: &UnitVector<T>) -> (Self::Scalar, Self::Antiscalar) {
+        (
+            Scalar::new(self.0.x * rhs.0.x + self.0.y * rhs.0.y + self.0.z * rhs.0.z),
+            Trivector {
+                xyz: T::ONE,
+                _metric: PhantomData,
+            },
+        )
+    }
+
+    fn cosine(&self, rhs: &UnitVector<T>) -> Option<Self::Scalar> {
+        let geometric = self.geometric_cosine(rhs);
+        Some(Scalar::new(geometric.0.0))
+    }
+}
+
+impl<T> Angle<UnitVector<T>> for UnitBivector<T>
+where
+    T: ConstOne,
+    T: Float,
+{
+    type Scalar = Scalar<3, T>;
+    type Antiscalar = Trivector<T>;
+
+    fn geometric_cosine(&self, rhs: &UnitVector<T>) -> (Self::Scalar, Self::Antiscalar) {
+        let x = self.0.xy * rhs.0.y - self.0.zx * rhs.0.z;
+        let y = self.0.yz * rhs.0.z - self.0.xy * rhs.0.x;
+        let z = self.0.zx * rhs.0.x - self.0.yz * rhs.0.y;
+
+        (
+            Scalar::new(x * x + y * y + z * z),
+            Trivector {
+                xyz: T::ONE,
+                _metric: PhantomData,
+            },
+        )
+    }
+
+    fn cosine(&self, rhs: &UnitVector<T>) -> Option<Self::Scalar> {
+        let geometric = self.geometric_cosine(rhs);
+        Some(Scalar::new(geometric.0.0))
+    }
+}
+
+reverse_angle!(UnitVector<T>, UnitBivector<T>);
+
+impl<T> Angle<UnitBivector<T>> for UnitBivector<T>
+where
+    T: ConstOne,
+    T: Float,
+{
+    type Scalar = Scalar<3, T>;
+    type Antiscalar = Trivector<T>;
+
+    fn geometric_cosine(&self, rhs: &UnitBivector<T>) -> (Self::Scalar, Self::Antiscalar) {
+        (
+            Scalar::new(self.0.yz * rhs.0.yz + self.0.zx * rhs.0.zx + self.0.xy * rhs.0.xy),
+            Trivector {
+                xyz: T::ONE,
+                _metric: PhantomData,
+            },
+        )
+    }
+
+    fn cosine(&self, rhs: &UnitBivector<T>) -> Option<Self::Scalar> {
+        let geometric = self.geometric_cosine(rhs);
+        Some(Scalar::new(geometric.0.0))
+    }
+}
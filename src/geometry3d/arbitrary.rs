@@ -0,0 +1,166 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use num::Float;
+use proptest::prelude::*;
+
+use crate::{Epsilon, arbitrary::field};
+
+use super::{
+    Bivector, Evenvector, Multivector, Point, Trivector, UnitBivector, UnitVector, Vector,
+};
+
+impl<T, M> Arbitrary for Vector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field(), field())
+            .prop_map(|(x, y, z)| Vector {
+                x,
+                y,
+                z,
+                _metric: PhantomData,
+            })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Bivector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field(), field())
+            .prop_map(|(yz, zx, xy)| Bivector {
+                yz,
+                zx,
+                xy,
+                _metric: PhantomData,
+            })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Trivector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        field()
+            .prop_map(|xyz| Trivector {
+                xyz,
+                _metric: PhantomData,
+            })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Evenvector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), any::<Bivector<T, M>>())
+            .prop_map(|(s, b)| Evenvector { s, b })
+            .boxed()
+    }
+}
+
+impl<T, M> Arbitrary for Multivector<T, M>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+    M: Debug,
+    M: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (
+            field(),
+            any::<Vector<T, M>>(),
+            any::<Bivector<T, M>>(),
+            any::<Trivector<T, M>>(),
+        )
+            .prop_map(|(s, v, b, t)| Multivector { s, v, b, t })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Point<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<Vector<T>>().prop_map(Point).boxed()
+    }
+}
+
+impl<T> Arbitrary for UnitVector<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<Vector<T>>()
+            .prop_filter_map("vector too close to zero", |v| v.try_into().ok())
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for UnitBivector<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<Bivector<T>>()
+            .prop_filter_map("bivector too close to zero", |b| b.try_into().ok())
+            .boxed()
+    }
+}
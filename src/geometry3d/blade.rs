@@ -0,0 +1,114 @@
+use num::{
+    Float,
+    traits::{ConstOne, ConstZero},
+};
+
+use crate::Epsilon;
+
+use super::{Bivector, Trivector, Vector};
+
+// Frames are orthonormal with respect to the euclidean inner product of the coefficients, which is also
+// the metric inner product in the euclidean metric.
+
+fn squared_length<T, M>(v: &Vector<T, M>) -> T
+where
+    T: Float,
+{
+    v.x * v.x + v.y * v.y + v.z * v.z
+}
+
+fn scale<T, M>(v: &Vector<T, M>, factor: T) -> Vector<T, M>
+where
+    T: Float,
+{
+    Vector::new(v.x * factor, v.y * factor, v.z * factor)
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Bivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> Bivector<T, M>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Every bivector of a 3D algebra is the wedge product of two vectors
+    pub fn is_blade(&self) -> bool {
+        true
+    }
+
+    /// Contraction of the bivector with the vector `v`, as a linear map of the coefficients
+    fn contract(&self, v: &Vector<T, M>) -> Vector<T, M> {
+        Vector::new(
+            self.xy * v.y - self.zx * v.z,
+            self.yz * v.z - self.xy * v.x,
+            self.zx * v.x - self.yz * v.y,
+        )
+    }
+
+    /// Two vectors `[a, b]`, orthogonal to each other, such that `a ^ b` is the bivector
+    pub fn factorize(&self) -> [Vector<T, M>; 2] {
+        // the contraction of the largest basis vector lies on the plane, then contracting
+        // again gives the second direction
+        let a = [Vector::X, Vector::Y, Vector::Z]
+            .iter()
+            .map(|e| self.contract(e))
+            .fold(Vector::ZERO, |best, a| {
+                if squared_length(&a) > squared_length(&best) {
+                    a
+                } else {
+                    best
+                }
+            });
+        let length_squared = squared_length(&a);
+        if length_squared.is_zero() {
+            return [Vector::ZERO, Vector::ZERO];
+        }
+        let b = scale(&self.contract(&a), -length_squared.recip());
+        [a, b]
+    }
+
+    /// Two orthonormal vectors spanning the plane of the bivector with its orientation, `None` if the
+    /// bivector is zero
+    pub fn orthonormal_frame(&self) -> Option<[Vector<T, M>; 2]> {
+        let [a, b] = self.factorize();
+        let (a_length, b_length) = (squared_length(&a).sqrt(), squared_length(&b).sqrt());
+        if a_length.is_zero() || b_length.is_zero() {
+            return None;
+        }
+        Some([scale(&a, a_length.recip()), scale(&b, b_length.recip())])
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Trivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> Trivector<T, M>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Every trivector of a 3D algebra is the wedge product of three vectors
+    pub fn is_blade(&self) -> bool {
+        true
+    }
+
+    /// Three orthogonal vectors `[a, b, c]` such that `a ^ b ^ c` is the trivector
+    pub fn factorize(&self) -> [Vector<T, M>; 3] {
+        [scale(&Vector::X, self.xyz), Vector::Y, Vector::Z]
+    }
+
+    /// Three orthonormal vectors with the orientation of the trivector, `None` if it is zero
+    pub fn orthonormal_frame(&self) -> Option<[Vector<T, M>; 3]> {
+        if self.xyz.is_zero() {
+            return None;
+        }
+        Some([scale(&Vector::X, self.xyz.signum()), Vector::Y, Vector::Z])
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use num::traits::ConstZero;
+
+use crate::{AnticommutatorProduct, CommutatorProduct, Euclidean, Projective, WedgeProduct};
+
+use super::{Bivector, Evenvector, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Both metrics
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> AnticommutatorProduct<Vector<T, M>> for Bivector<T, M>
+where
+    Bivector<T, M>: WedgeProduct<Vector<T, M>, Output = Trivector<T, M>>,
+{
+    type Output = Trivector<T, M>;
+
+    fn anticommutator_product(&self, rhs: &Vector<T, M>) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
+
+impl<T, M> AnticommutatorProduct<Bivector<T, M>> for Vector<T, M>
+where
+    Vector<T, M>: WedgeProduct<Bivector<T, M>, Output = Trivector<T, M>>,
+{
+    type Output = Trivector<T, M>;
+
+    fn anticommutator_product(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
+
+impl<T, M> CommutatorProduct<Evenvector<T, M>> for Evenvector<T, M>
+where
+    Bivector<T, M>: CommutatorProduct<Bivector<T, M>, Output = Bivector<T, M>>,
+{
+    type Output = Bivector<T, M>;
+
+    fn commutator_product(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        // scalars commute with everything
+        self.b.commutator_product(&rhs.b)
+    }
+}
+
+impl<T, M> AnticommutatorProduct<Evenvector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Mul<Output = T>,
+    Bivector<T, M>: AnticommutatorProduct<Bivector<T, M>, Output = T>,
+{
+    type Output = Evenvector<T, M>;
+
+    fn anticommutator_product(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Evenvector {
+            s: self.s * rhs.s + self.b.anticommutator_product(&rhs.b),
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s,
+                zx: self.s * rhs.b.zx + self.b.zx * rhs.s,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Euclidean metric
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> CommutatorProduct<Bivector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Bivector<T, Euclidean>;
+
+    fn commutator_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Bivector {
+            yz: self.xy * rhs.zx - self.zx * rhs.xy,
+            zx: self.yz * rhs.xy - self.xy * rhs.yz,
+            xy: self.zx * rhs.yz - self.yz * rhs.zx,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> AnticommutatorProduct<Bivector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = T;
+
+    fn anticommutator_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        -(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy)
+    }
+}
+
+impl<T> CommutatorProduct<Vector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Vector<T, Euclidean>;
+
+    fn commutator_product(&self, rhs: &Vector<T, Euclidean>) -> Self::Output {
+        Vector {
+            x: self.xy * rhs.y - self.zx * rhs.z,
+            y: self.yz * rhs.z - self.xy * rhs.x,
+            z: self.zx * rhs.x - self.yz * rhs.y,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> CommutatorProduct<Bivector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Vector<T, Euclidean>;
+
+    fn commutator_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Vector {
+            x: self.z * rhs.zx - self.y * rhs.xy,
+            y: self.x * rhs.xy - self.z * rhs.yz,
+            z: self.y * rhs.yz - self.x * rhs.zx,
+            _metric: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Projective metric
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> CommutatorProduct<Bivector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Bivector<T, Projective>;
+
+    fn commutator_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Bivector {
+            yz: self.xy * rhs.zx - self.zx * rhs.xy,
+            zx: self.yz * rhs.xy - self.xy * rhs.yz,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> AnticommutatorProduct<Bivector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: Neg<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = T;
+
+    fn anticommutator_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        -(self.xy * rhs.xy)
+    }
+}
+
+impl<T> CommutatorProduct<Vector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: Sub<Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Vector<T, Projective>;
+
+    fn commutator_product(&self, rhs: &Vector<T, Projective>) -> Self::Output {
+        Vector {
+            x: self.xy * rhs.y,
+            y: -(self.xy * rhs.x),
+            z: self.zx * rhs.x - self.yz * rhs.y,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> CommutatorProduct<Bivector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: Sub<Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Vector<T, Projective>;
+
+    fn commutator_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Vector {
+            x: -self.y * rhs.xy,
+            y: self.x * rhs.xy,
+            z: self.y * rhs.yz - self.x * rhs.zx,
+            _metric: PhantomData,
+        }
+    }
+}
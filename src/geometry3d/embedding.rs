@@ -0,0 +1,187 @@
+use std::marker::PhantomData;
+
+use num::{Zero, traits::ConstZero};
+
+use crate::{Embed, Project, Scalar, geometry2d as d2};
+
+use super::{Bivector, Evenvector, Multivector, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Metric casts
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> Vector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Vector<T, N> {
+        Vector {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> Bivector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Bivector<T, N> {
+        Bivector {
+            yz: self.yz,
+            zx: self.zx,
+            xy: self.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> Trivector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Trivector<T, N> {
+        Trivector {
+            xyz: self.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> Evenvector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Evenvector<T, N> {
+        Evenvector {
+            s: self.s,
+            b: self.b.cast_metric(),
+        }
+    }
+}
+
+impl<T, M> Multivector<T, M> {
+    /// Reinterprets the same coefficients under the metric `N`
+    pub fn cast_metric<N>(self) -> Multivector<T, N> {
+        Multivector {
+            s: self.s,
+            v: self.v.cast_metric(),
+            b: self.b.cast_metric(),
+            t: self.t.cast_metric(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// 2D embedding, the plane is spanned by x and y
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> Embed<Scalar<3, T, M>> for Scalar<2, T, M>
+where
+    T: Copy,
+{
+    fn embed(&self) -> Scalar<3, T, M> {
+        Scalar::new(self.0)
+    }
+}
+
+impl<T, M> Embed<Vector<T, M>> for d2::Vector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Vector<T, M> {
+        Vector::new(self.x, self.y, T::ZERO)
+    }
+}
+
+impl<T, M> Embed<Bivector<T, M>> for d2::Bivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Bivector<T, M> {
+        Bivector::new(T::ZERO, T::ZERO, self.xy)
+    }
+}
+
+impl<T, M> Embed<Evenvector<T, M>> for d2::Evenvector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Evenvector<T, M> {
+        Evenvector {
+            s: self.s,
+            b: self.b.embed(),
+        }
+    }
+}
+
+impl<T, M> Embed<Multivector<T, M>> for d2::Multivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Multivector<T, M> {
+        Multivector {
+            s: self.s,
+            v: self.v.embed(),
+            b: self.b.embed(),
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> Project<Scalar<2, T, M>> for Scalar<3, T, M>
+where
+    T: Copy,
+{
+    fn project(&self) -> Option<Scalar<2, T, M>> {
+        Some(Scalar::new(self.0))
+    }
+}
+
+impl<T, M> Project<d2::Vector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d2::Vector<T, M>> {
+        self.z.is_zero().then(|| d2::Vector::new(self.x, self.y))
+    }
+}
+
+impl<T, M> Project<d2::Bivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d2::Bivector<T, M>> {
+        (self.yz.is_zero() && self.zx.is_zero()).then(|| d2::Bivector::new(self.xy))
+    }
+}
+
+impl<T, M> Project<d2::Evenvector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d2::Evenvector<T, M>> {
+        Some(d2::Evenvector {
+            s: self.s,
+            b: self.b.project()?,
+        })
+    }
+}
+
+impl<T, M> Project<d2::Multivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d2::Multivector<T, M>> {
+        if !self.t.xyz.is_zero() {
+            return None;
+        }
+        Some(d2::Multivector {
+            s: self.s,
+            v: self.v.project()?,
+            b: self.b.project()?,
+        })
+    }
+}
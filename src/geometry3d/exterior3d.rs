@@ -0,0 +1,1046 @@
+// This file is generated by `cargo run -p gual-codegen`, do not edit it by hand.
+//
+// Signature: x² = 1, y² = 1, z² = 1
+
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+
+use num::traits::ConstZero;
+
+use crate::{AntiwedgeProduct, WedgeProduct};
+
+use super::{Bivector, Evenvector, Multivector, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Wedge product
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> WedgeProduct<Vector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, M>;
+
+    fn wedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        Bivector {
+            yz: self.y * rhs.z - self.z * rhs.y,
+            zx: self.z * rhs.x - self.x * rhs.z,
+            xy: self.x * rhs.y - self.y * rhs.x,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Bivector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn wedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Trivector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn wedge(&self, _rhs: &Trivector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> WedgeProduct<Vector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn wedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.yz * rhs.x + self.zx * rhs.y + self.xy * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Bivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn wedge(&self, _rhs: &Bivector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> WedgeProduct<Trivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn wedge(&self, _rhs: &Trivector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> WedgeProduct<Vector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn wedge(&self, _rhs: &Vector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> WedgeProduct<Bivector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn wedge(&self, _rhs: &Bivector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> WedgeProduct<Trivector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn wedge(&self, _rhs: &Trivector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> WedgeProduct<Evenvector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.x * rhs.s,
+                y: self.y * rhs.s,
+                z: self.z * rhs.s,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Multivector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.x * rhs.s,
+                y: self.y * rhs.s,
+                z: self.z * rhs.s,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.y * rhs.v.z - self.z * rhs.v.y,
+                zx: self.z * rhs.v.x - self.x * rhs.v.z,
+                xy: self.x * rhs.v.y - self.y * rhs.v.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Evenvector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, M>;
+
+    fn wedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Bivector {
+            yz: self.yz * rhs.s,
+            zx: self.zx * rhs.s,
+            xy: self.xy * rhs.s,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Multivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector::ZERO,
+            b: Bivector {
+                yz: self.yz * rhs.s,
+                zx: self.zx * rhs.s,
+                xy: self.xy * rhs.s,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.yz * rhs.v.x + self.zx * rhs.v.y + self.xy * rhs.v.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Evenvector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn wedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.xyz * rhs.s,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Multivector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn wedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.xyz * rhs.s,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Vector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.s * rhs.x,
+                y: self.s * rhs.y,
+                z: self.s * rhs.z,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Bivector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, M>;
+
+    fn wedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Bivector {
+            yz: self.s * rhs.yz,
+            zx: self.s * rhs.zx,
+            xy: self.s * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Trivector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn wedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.s * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Evenvector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, M>;
+
+    fn wedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Evenvector {
+            s: self.s * rhs.s,
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s,
+                zx: self.s * rhs.b.zx + self.b.zx * rhs.s,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Multivector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s,
+            v: Vector {
+                x: self.s * rhs.v.x,
+                y: self.s * rhs.v.y,
+                z: self.s * rhs.v.z,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s,
+                zx: self.s * rhs.b.zx + self.b.zx * rhs.s,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.t.xyz
+                    + self.b.yz * rhs.v.x
+                    + self.b.zx * rhs.v.y
+                    + self.b.xy * rhs.v.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Vector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.s * rhs.x,
+                y: self.s * rhs.y,
+                z: self.s * rhs.z,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.v.y * rhs.z - self.v.z * rhs.y,
+                zx: self.v.z * rhs.x - self.v.x * rhs.z,
+                xy: self.v.x * rhs.y - self.v.y * rhs.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Bivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector::ZERO,
+            b: Bivector {
+                yz: self.s * rhs.yz,
+                zx: self.s * rhs.zx,
+                xy: self.s * rhs.xy,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.v.x * rhs.yz + self.v.y * rhs.zx + self.v.z * rhs.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Trivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn wedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.s * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Evenvector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s,
+            v: Vector {
+                x: self.v.x * rhs.s,
+                y: self.v.y * rhs.s,
+                z: self.v.z * rhs.s,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s,
+                zx: self.s * rhs.b.zx + self.b.zx * rhs.s,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.v.x * rhs.b.yz
+                    + self.v.y * rhs.b.zx
+                    + self.v.z * rhs.b.xy
+                    + self.t.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> WedgeProduct<Multivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn wedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s,
+            v: Vector {
+                x: self.s * rhs.v.x + self.v.x * rhs.s,
+                y: self.s * rhs.v.y + self.v.y * rhs.s,
+                z: self.s * rhs.v.z + self.v.z * rhs.s,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.v.y * rhs.v.z + self.b.yz * rhs.s - self.v.z * rhs.v.y,
+                zx: self.s * rhs.b.zx + self.v.z * rhs.v.x + self.b.zx * rhs.s - self.v.x * rhs.v.z,
+                xy: self.s * rhs.b.xy + self.v.x * rhs.v.y + self.b.xy * rhs.s - self.v.y * rhs.v.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.t.xyz
+                    + self.v.x * rhs.b.yz
+                    + self.v.y * rhs.b.zx
+                    + self.v.z * rhs.b.xy
+                    + self.b.yz * rhs.v.x
+                    + self.b.zx * rhs.v.y
+                    + self.b.xy * rhs.v.z
+                    + self.t.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Antiwedge product
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> AntiwedgeProduct<Vector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn antiwedge(&self, _rhs: &Vector<T, M>) -> Self::Output {}
+}
+
+impl<T, M> AntiwedgeProduct<Bivector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = T;
+
+    fn antiwedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Trivector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, M>;
+
+    fn antiwedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Vector {
+            x: self.x * rhs.xyz,
+            y: self.y * rhs.xyz,
+            z: self.z * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Vector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = T;
+
+    fn antiwedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        self.yz * rhs.x + self.zx * rhs.y + self.xy * rhs.z
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Bivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, M>;
+
+    fn antiwedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Vector {
+            x: self.zx * rhs.xy - self.xy * rhs.zx,
+            y: self.xy * rhs.yz - self.yz * rhs.xy,
+            z: self.yz * rhs.zx - self.zx * rhs.yz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Trivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Bivector {
+            yz: self.yz * rhs.xyz,
+            zx: self.zx * rhs.xyz,
+            xy: self.xy * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Vector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, M>;
+
+    fn antiwedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        Vector {
+            x: self.xyz * rhs.x,
+            y: self.xyz * rhs.y,
+            z: self.xyz * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Bivector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Bivector {
+            yz: self.xyz * rhs.yz,
+            zx: self.xyz * rhs.zx,
+            xy: self.xyz * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Trivector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Trivector {
+            xyz: self.xyz * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Evenvector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = T;
+
+    fn antiwedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Multivector<T, M>> for Vector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+            v: Vector {
+                x: self.x * rhs.t.xyz,
+                y: self.y * rhs.t.xyz,
+                z: self.z * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Evenvector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, M>;
+
+    fn antiwedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Vector {
+            x: self.zx * rhs.b.xy - self.xy * rhs.b.zx,
+            y: self.xy * rhs.b.yz - self.yz * rhs.b.xy,
+            z: self.yz * rhs.b.zx - self.zx * rhs.b.yz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Multivector<T, M>> for Bivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.yz * rhs.v.x + self.zx * rhs.v.y + self.xy * rhs.v.z,
+            v: Vector {
+                x: self.zx * rhs.b.xy - self.xy * rhs.b.zx,
+                y: self.xy * rhs.b.yz - self.yz * rhs.b.xy,
+                z: self.yz * rhs.b.zx - self.zx * rhs.b.yz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.yz * rhs.t.xyz,
+                zx: self.zx * rhs.t.xyz,
+                xy: self.xy * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Evenvector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, M>;
+
+    fn antiwedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Evenvector {
+            s: self.xyz * rhs.s,
+            b: Bivector {
+                yz: self.xyz * rhs.b.yz,
+                zx: self.xyz * rhs.b.zx,
+                xy: self.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Multivector<T, M>> for Trivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.xyz * rhs.s,
+            v: Vector {
+                x: self.xyz * rhs.v.x,
+                y: self.xyz * rhs.v.y,
+                z: self.xyz * rhs.v.z,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.xyz * rhs.b.yz,
+                zx: self.xyz * rhs.b.zx,
+                xy: self.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.xyz * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Vector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = T;
+
+    fn antiwedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Bivector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, M>;
+
+    fn antiwedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Vector {
+            x: self.b.zx * rhs.xy - self.b.xy * rhs.zx,
+            y: self.b.xy * rhs.yz - self.b.yz * rhs.xy,
+            z: self.b.yz * rhs.zx - self.b.zx * rhs.yz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Trivector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, M>;
+
+    fn antiwedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Evenvector {
+            s: self.s * rhs.xyz,
+            b: Bivector {
+                yz: self.b.yz * rhs.xyz,
+                zx: self.b.zx * rhs.xyz,
+                xy: self.b.xy * rhs.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Evenvector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, M>;
+
+    fn antiwedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Vector {
+            x: self.b.zx * rhs.b.xy - self.b.xy * rhs.b.zx,
+            y: self.b.xy * rhs.b.yz - self.b.yz * rhs.b.xy,
+            z: self.b.yz * rhs.b.zx - self.b.zx * rhs.b.yz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Multivector<T, M>> for Evenvector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.t.xyz + self.b.yz * rhs.v.x + self.b.zx * rhs.v.y + self.b.xy * rhs.v.z,
+            v: Vector {
+                x: self.b.zx * rhs.b.xy - self.b.xy * rhs.b.zx,
+                y: self.b.xy * rhs.b.yz - self.b.yz * rhs.b.xy,
+                z: self.b.yz * rhs.b.zx - self.b.zx * rhs.b.yz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.b.yz * rhs.t.xyz,
+                zx: self.b.zx * rhs.t.xyz,
+                xy: self.b.xy * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Vector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Vector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+            v: Vector {
+                x: self.t.xyz * rhs.x,
+                y: self.t.xyz * rhs.y,
+                z: self.t.xyz * rhs.z,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Bivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Bivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.v.x * rhs.yz + self.v.y * rhs.zx + self.v.z * rhs.xy,
+            v: Vector {
+                x: self.b.zx * rhs.xy - self.b.xy * rhs.zx,
+                y: self.b.xy * rhs.yz - self.b.yz * rhs.xy,
+                z: self.b.yz * rhs.zx - self.b.zx * rhs.yz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.t.xyz * rhs.yz,
+                zx: self.t.xyz * rhs.zx,
+                xy: self.t.xyz * rhs.xy,
+                _metric: PhantomData,
+            },
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Trivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.xyz,
+            v: Vector {
+                x: self.v.x * rhs.xyz,
+                y: self.v.y * rhs.xyz,
+                z: self.v.z * rhs.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.b.yz * rhs.xyz,
+                zx: self.b.zx * rhs.xyz,
+                xy: self.b.xy * rhs.xyz,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.t.xyz * rhs.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Evenvector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Evenvector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.v.x * rhs.b.yz + self.v.y * rhs.b.zx + self.v.z * rhs.b.xy + self.t.xyz * rhs.s,
+            v: Vector {
+                x: self.b.zx * rhs.b.xy - self.b.xy * rhs.b.zx,
+                y: self.b.xy * rhs.b.yz - self.b.yz * rhs.b.xy,
+                z: self.b.yz * rhs.b.zx - self.b.zx * rhs.b.yz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.t.xyz * rhs.b.yz,
+                zx: self.t.xyz * rhs.b.zx,
+                xy: self.t.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> AntiwedgeProduct<Multivector<T, M>> for Multivector<T, M>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, M>;
+
+    fn antiwedge(&self, rhs: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.t.xyz
+                + self.v.x * rhs.b.yz
+                + self.v.y * rhs.b.zx
+                + self.v.z * rhs.b.xy
+                + self.b.yz * rhs.v.x
+                + self.b.zx * rhs.v.y
+                + self.b.xy * rhs.v.z
+                + self.t.xyz * rhs.s,
+            v: Vector {
+                x: self.v.x * rhs.t.xyz + self.b.zx * rhs.b.xy + self.t.xyz * rhs.v.x
+                    - self.b.xy * rhs.b.zx,
+                y: self.v.y * rhs.t.xyz + self.b.xy * rhs.b.yz + self.t.xyz * rhs.v.y
+                    - self.b.yz * rhs.b.xy,
+                z: self.v.z * rhs.t.xyz + self.b.yz * rhs.b.zx + self.t.xyz * rhs.v.z
+                    - self.b.zx * rhs.b.yz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.b.yz * rhs.t.xyz + self.t.xyz * rhs.b.yz,
+                zx: self.b.zx * rhs.t.xyz + self.t.xyz * rhs.b.zx,
+                xy: self.b.xy * rhs.t.xyz + self.t.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.t.xyz * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
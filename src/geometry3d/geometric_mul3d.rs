@@ -0,0 +1,27 @@
+// This file is generated by `cargo run -p gual-codegen`, do not edit it by hand.
+//
+// Signature: x² = 1, y² = 1, z² = 1
+
+use crate::geometric_mul;
+
+use super::{Bivector, Evenvector, Multivector, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Geometric product operator
+// ----------------------------------------------------------------------------------------------------
+
+geometric_mul!(Vector<T, M>, Vector<T, M> => Evenvector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Bivector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Trivector<T, M> => Bivector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Evenvector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Vector<T, M>, Multivector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Vector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Bivector<T, M> => Evenvector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Trivector<T, M> => Vector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Evenvector<T, M> => Evenvector<T, M>, T, M);
+geometric_mul!(Bivector<T, M>, Multivector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Trivector<T, M>, Vector<T, M> => Bivector<T, M>, T, M);
+geometric_mul!(Trivector<T, M>, Bivector<T, M> => Vector<T, M>, T, M);
+geometric_mul!(Trivector<T, M>, Trivector<T, M> => T, T, M);
+geometric_mul!(Trivector<T, M>, Evenvector<T, M> => Multivector<T, M>, T, M);
+geometric_mul!(Trivector<T, M>, Multivector<T, M> => Multivector<T, M>, T, M);
@@ -0,0 +1,43 @@
+use crate::{Scalar, graded};
+
+use super::{BasisBlade, Bivector, Evenvector, Multivector, Trivector, Vector};
+
+impl BasisBlade {
+    /// Every basis blade, in canonical order
+    pub const ALL: [BasisBlade; 8] = [
+        BasisBlade::Scalar,
+        BasisBlade::X,
+        BasisBlade::Y,
+        BasisBlade::Z,
+        BasisBlade::YZ,
+        BasisBlade::ZX,
+        BasisBlade::XY,
+        BasisBlade::XYZ,
+    ];
+
+    /// Number of basis vectors that build the blade
+    pub const fn grade(self) -> u32 {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::X | BasisBlade::Y | BasisBlade::Z => 1,
+            BasisBlade::YZ | BasisBlade::ZX | BasisBlade::XY => 2,
+            BasisBlade::XYZ => 3,
+        }
+    }
+}
+
+graded!(Scalar<3, T, M>, [T, M]; BasisBlade; 0 => Scalar);
+graded!(Vector<T, M>, [T, M]; BasisBlade; x => X, y => Y, z => Z);
+graded!(Bivector<T, M>, [T, M]; BasisBlade; yz => YZ, zx => ZX, xy => XY);
+graded!(Trivector<T, M>, [T, M]; BasisBlade; xyz => XYZ);
+graded!(
+    Evenvector<T, M>, [T, M]; BasisBlade;
+    s => Scalar, b.yz => YZ, b.zx => ZX, b.xy => XY
+);
+graded!(
+    Multivector<T, M>, [T, M]; BasisBlade;
+    s => Scalar,
+    v.x => X, v.y => Y, v.z => Z,
+    b.yz => YZ, b.zx => ZX, b.xy => XY,
+    t.xyz => XYZ
+);
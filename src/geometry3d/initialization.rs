@@ -508,6 +508,10 @@ impl<T> UnitVector<T> {
     /// It is marked unsafe even though it is not "rust unsafe" to use,
     /// you should really initialize it with only a unit vector if you
     /// want other operations to have a meaningful result.
+    ///
+    /// # Safety
+    ///
+    /// `value` must have a norm of `1`.
     pub unsafe fn new(value: Vector<T>) -> Self {
         UnitVector(value)
     }
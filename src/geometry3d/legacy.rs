@@ -0,0 +1,57 @@
+//! Types of the 0.1 API, kept for a migration window
+//!
+//! Replacing `use gual::geometry3d::Scalar` with `use gual::geometry3d::legacy::Scalar` keeps the
+//! `Scalar(x)` constructor compiling, with a deprecation warning on every use. The legacy scalar
+//! converts both ways with the new one through [`From`].
+
+#![allow(deprecated)]
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Scalar of the 3D algebra before 0.2.0
+#[deprecated(
+    since = "0.2.0",
+    note = "use `geometry3d::Scalar`, built with `Scalar::new` or `Scalar::from`"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Scalar<T>(pub T);
+
+impl<T, M> From<Scalar<T>> for crate::Scalar<3, T, M> {
+    fn from(value: Scalar<T>) -> Self {
+        crate::Scalar::new(value.0)
+    }
+}
+
+impl<T, M> From<crate::Scalar<3, T, M>> for Scalar<T> {
+    fn from(value: crate::Scalar<3, T, M>) -> Self {
+        Scalar(value.0)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Scalar<T> {
+    type Output = Scalar<T>;
+    fn add(self, rhs: Scalar<T>) -> Self::Output {
+        Scalar(self.0 + rhs.0)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Scalar<T> {
+    type Output = Scalar<T>;
+    fn sub(self, rhs: Scalar<T>) -> Self::Output {
+        Scalar(self.0 - rhs.0)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Scalar<T> {
+    type Output = Scalar<T>;
+    fn neg(self) -> Self::Output {
+        Scalar(-self.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul for Scalar<T> {
+    type Output = Scalar<T>;
+    fn mul(self, rhs: Scalar<T>) -> Self::Output {
+        Scalar(self.0 * rhs.0)
+    }
+}
@@ -215,7 +215,7 @@ impl<T: Copy + ConstZero> Metric for d3::Trivector<T, Projective> {
         d3::Trivector::new(weight.xy)
     }
 
-    fn bulk(&self) -> () {}
+    fn bulk(&self) {}
 
     fn weight(&self) -> d2::Bivector<T> {
         d2::Bivector::new(self.xyz)
@@ -200,6 +200,16 @@ where
     }
 }
 
+impl<T, M> Mul<Trivector<T, M>> for Evenvector<T, M>
+where
+    Self: GeometricProduct<Trivector<T, M>>,
+{
+    type Output = <Self as GeometricProduct<Trivector<T, M>>>::Output;
+    fn mul(self, rhs: Trivector<T, M>) -> Self::Output {
+        self.geometric_product(&rhs)
+    }
+}
+
 impl<T, M> Mul<Evenvector<T, M>> for Evenvector<T, M>
 where
     Self: GeometricProduct<Evenvector<T, M>>,
@@ -274,6 +284,16 @@ where
     }
 }
 
+impl<T, M> Mul<Trivector<T, M>> for Multivector<T, M>
+where
+    Self: GeometricProduct<Trivector<T, M>>,
+{
+    type Output = <Self as GeometricProduct<Trivector<T, M>>>::Output;
+    fn mul(self, rhs: Trivector<T, M>) -> Self::Output {
+        self.geometric_product(&rhs)
+    }
+}
+
 impl<T, M> Mul<Evenvector<T, M>> for Multivector<T, M>
 where
     Self: GeometricProduct<Evenvector<T, M>>,
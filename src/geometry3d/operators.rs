@@ -0,0 +1,14 @@
+use crate::operators;
+
+use super::{
+    Bivector, Evenvector, Multivector, Point, Trivector, UnitBivector, UnitVector, Vector,
+};
+
+operators!(Vector<T, M>, T, M);
+operators!(Bivector<T, M>, T, M);
+operators!(Trivector<T, M>, T, M);
+operators!(Evenvector<T, M>, T, M);
+operators!(Multivector<T, M>, T, M);
+operators!(Point<T>, T);
+operators!(UnitVector<T>, T);
+operators!(UnitBivector<T>, T);
@@ -0,0 +1,1104 @@
+// This file is generated by `cargo run -p gual-codegen`, do not edit it by hand.
+//
+// Signature: x² = 1, y² = 1, z² = 1
+
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::traits::ConstZero;
+
+use crate::{Contraction, Dot, Dual, Euclidean, Expansion, GeometricProduct};
+
+use super::{Bivector, Evenvector, Multivector, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Vector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Euclidean>) -> Self::Output {
+        Evenvector {
+            s: self.x * rhs.x + self.y * rhs.y + self.z * rhs.z,
+            b: Bivector {
+                yz: self.y * rhs.z - self.z * rhs.y,
+                zx: self.z * rhs.x - self.x * rhs.z,
+                xy: self.x * rhs.y - self.y * rhs.x,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.z * rhs.zx - self.y * rhs.xy,
+                y: self.x * rhs.xy - self.z * rhs.yz,
+                z: self.y * rhs.yz - self.x * rhs.zx,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Euclidean>) -> Self::Output {
+        Bivector {
+            yz: self.x * rhs.xyz,
+            zx: self.y * rhs.xyz,
+            xy: self.z * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.x * rhs.s + self.z * rhs.b.zx - self.y * rhs.b.xy,
+                y: self.x * rhs.b.xy + self.y * rhs.s - self.z * rhs.b.yz,
+                z: self.y * rhs.b.yz + self.z * rhs.s - self.x * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: self.x * rhs.v.x + self.y * rhs.v.y + self.z * rhs.v.z,
+            v: Vector {
+                x: self.x * rhs.s + self.z * rhs.b.zx - self.y * rhs.b.xy,
+                y: self.x * rhs.b.xy + self.y * rhs.s - self.z * rhs.b.yz,
+                z: self.y * rhs.b.yz + self.z * rhs.s - self.x * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.x * rhs.t.xyz + self.y * rhs.v.z - self.z * rhs.v.y,
+                zx: self.y * rhs.t.xyz + self.z * rhs.v.x - self.x * rhs.v.z,
+                xy: self.x * rhs.v.y + self.z * rhs.t.xyz - self.y * rhs.v.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Bivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.xy * rhs.y - self.zx * rhs.z,
+                y: self.yz * rhs.z - self.xy * rhs.x,
+                z: self.zx * rhs.x - self.yz * rhs.y,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.yz * rhs.x + self.zx * rhs.y + self.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Evenvector {
+            s: -(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy),
+            b: Bivector {
+                yz: self.xy * rhs.zx - self.zx * rhs.xy,
+                zx: self.yz * rhs.xy - self.xy * rhs.yz,
+                xy: self.zx * rhs.yz - self.yz * rhs.zx,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Euclidean>) -> Self::Output {
+        Vector {
+            x: -self.yz * rhs.xyz,
+            y: -self.zx * rhs.xyz,
+            z: -self.xy * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Euclidean>) -> Self::Output {
+        Evenvector {
+            s: -(self.yz * rhs.b.yz + self.zx * rhs.b.zx + self.xy * rhs.b.xy),
+            b: Bivector {
+                yz: self.yz * rhs.s + self.xy * rhs.b.zx - self.zx * rhs.b.xy,
+                zx: self.yz * rhs.b.xy + self.zx * rhs.s - self.xy * rhs.b.yz,
+                xy: self.zx * rhs.b.yz + self.xy * rhs.s - self.yz * rhs.b.zx,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: -(self.yz * rhs.b.yz + self.zx * rhs.b.zx + self.xy * rhs.b.xy),
+            v: Vector {
+                x: self.xy * rhs.v.y - self.yz * rhs.t.xyz - self.zx * rhs.v.z,
+                y: self.yz * rhs.v.z - self.zx * rhs.t.xyz - self.xy * rhs.v.x,
+                z: self.zx * rhs.v.x - self.yz * rhs.v.y - self.xy * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.yz * rhs.s + self.xy * rhs.b.zx - self.zx * rhs.b.xy,
+                zx: self.yz * rhs.b.xy + self.zx * rhs.s - self.xy * rhs.b.yz,
+                xy: self.zx * rhs.b.yz + self.xy * rhs.s - self.yz * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.yz * rhs.v.x + self.zx * rhs.v.y + self.xy * rhs.v.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Trivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Euclidean>) -> Self::Output {
+        Bivector {
+            yz: self.xyz * rhs.x,
+            zx: self.xyz * rhs.y,
+            xy: self.xyz * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Vector {
+            x: -self.xyz * rhs.yz,
+            y: -self.xyz * rhs.zx,
+            z: -self.xyz * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = T;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Euclidean>) -> Self::Output {
+        -self.xyz * rhs.xyz
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: -self.xyz * rhs.b.yz,
+                y: -self.xyz * rhs.b.zx,
+                z: -self.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: -self.xyz * rhs.t.xyz,
+            v: Vector {
+                x: -self.xyz * rhs.b.yz,
+                y: -self.xyz * rhs.b.zx,
+                z: -self.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.xyz * rhs.v.x,
+                zx: self.xyz * rhs.v.y,
+                xy: self.xyz * rhs.v.z,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Evenvector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Euclidean>> for Evenvector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.s * rhs.x + self.b.xy * rhs.y - self.b.zx * rhs.z,
+                y: self.s * rhs.y + self.b.yz * rhs.z - self.b.xy * rhs.x,
+                z: self.s * rhs.z + self.b.zx * rhs.x - self.b.yz * rhs.y,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Euclidean>> for Evenvector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Evenvector {
+            s: -(self.b.yz * rhs.yz + self.b.zx * rhs.zx + self.b.xy * rhs.xy),
+            b: Bivector {
+                yz: self.s * rhs.yz + self.b.xy * rhs.zx - self.b.zx * rhs.xy,
+                zx: self.s * rhs.zx + self.b.yz * rhs.xy - self.b.xy * rhs.yz,
+                xy: self.s * rhs.xy + self.b.zx * rhs.yz - self.b.yz * rhs.zx,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Euclidean>> for Evenvector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: -self.b.yz * rhs.xyz,
+                y: -self.b.zx * rhs.xyz,
+                z: -self.b.xy * rhs.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.s * rhs.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Euclidean>> for Evenvector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Euclidean>) -> Self::Output {
+        Evenvector {
+            s: self.s * rhs.s - self.b.yz * rhs.b.yz - self.b.zx * rhs.b.zx - self.b.xy * rhs.b.xy,
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.b.zx * rhs.b.yz + self.b.xy * rhs.s
+                    - self.b.yz * rhs.b.zx,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Euclidean>> for Evenvector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s - self.b.yz * rhs.b.yz - self.b.zx * rhs.b.zx - self.b.xy * rhs.b.xy,
+            v: Vector {
+                x: self.s * rhs.v.x + self.b.xy * rhs.v.y
+                    - self.b.yz * rhs.t.xyz
+                    - self.b.zx * rhs.v.z,
+                y: self.s * rhs.v.y + self.b.yz * rhs.v.z
+                    - self.b.zx * rhs.t.xyz
+                    - self.b.xy * rhs.v.x,
+                z: self.s * rhs.v.z + self.b.zx * rhs.v.x
+                    - self.b.yz * rhs.v.y
+                    - self.b.xy * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.b.zx * rhs.b.yz + self.b.xy * rhs.s
+                    - self.b.yz * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.t.xyz
+                    + self.b.yz * rhs.v.x
+                    + self.b.zx * rhs.v.y
+                    + self.b.xy * rhs.v.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Multivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Euclidean>> for Multivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: self.v.x * rhs.x + self.v.y * rhs.y + self.v.z * rhs.z,
+            v: Vector {
+                x: self.s * rhs.x + self.b.xy * rhs.y - self.b.zx * rhs.z,
+                y: self.s * rhs.y + self.b.yz * rhs.z - self.b.xy * rhs.x,
+                z: self.s * rhs.z + self.b.zx * rhs.x - self.b.yz * rhs.y,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.v.y * rhs.z + self.t.xyz * rhs.x - self.v.z * rhs.y,
+                zx: self.v.z * rhs.x + self.t.xyz * rhs.y - self.v.x * rhs.z,
+                xy: self.v.x * rhs.y + self.t.xyz * rhs.z - self.v.y * rhs.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Euclidean>> for Multivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: -(self.b.yz * rhs.yz + self.b.zx * rhs.zx + self.b.xy * rhs.xy),
+            v: Vector {
+                x: self.v.z * rhs.zx - self.v.y * rhs.xy - self.t.xyz * rhs.yz,
+                y: self.v.x * rhs.xy - self.v.z * rhs.yz - self.t.xyz * rhs.zx,
+                z: self.v.y * rhs.yz - self.v.x * rhs.zx - self.t.xyz * rhs.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.yz + self.b.xy * rhs.zx - self.b.zx * rhs.xy,
+                zx: self.s * rhs.zx + self.b.yz * rhs.xy - self.b.xy * rhs.yz,
+                xy: self.s * rhs.xy + self.b.zx * rhs.yz - self.b.yz * rhs.zx,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.v.x * rhs.yz + self.v.y * rhs.zx + self.v.z * rhs.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Euclidean>> for Multivector<T, Euclidean>
+where
+    T: Copy,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: -self.t.xyz * rhs.xyz,
+            v: Vector {
+                x: -self.b.yz * rhs.xyz,
+                y: -self.b.zx * rhs.xyz,
+                z: -self.b.xy * rhs.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.v.x * rhs.xyz,
+                zx: self.v.y * rhs.xyz,
+                xy: self.v.z * rhs.xyz,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Euclidean>> for Multivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s - self.b.yz * rhs.b.yz - self.b.zx * rhs.b.zx - self.b.xy * rhs.b.xy,
+            v: Vector {
+                x: self.v.x * rhs.s + self.v.z * rhs.b.zx
+                    - self.v.y * rhs.b.xy
+                    - self.t.xyz * rhs.b.yz,
+                y: self.v.x * rhs.b.xy + self.v.y * rhs.s
+                    - self.v.z * rhs.b.yz
+                    - self.t.xyz * rhs.b.zx,
+                z: self.v.y * rhs.b.yz + self.v.z * rhs.s
+                    - self.v.x * rhs.b.zx
+                    - self.t.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.b.zx * rhs.b.yz + self.b.xy * rhs.s
+                    - self.b.yz * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.v.x * rhs.b.yz
+                    + self.v.y * rhs.b.zx
+                    + self.v.z * rhs.b.xy
+                    + self.t.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Euclidean>> for Multivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Euclidean>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Euclidean>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s + self.v.x * rhs.v.x + self.v.y * rhs.v.y + self.v.z * rhs.v.z
+                - self.b.yz * rhs.b.yz
+                - self.b.zx * rhs.b.zx
+                - self.b.xy * rhs.b.xy
+                - self.t.xyz * rhs.t.xyz,
+            v: Vector {
+                x: self.s * rhs.v.x + self.v.x * rhs.s + self.v.z * rhs.b.zx + self.b.xy * rhs.v.y
+                    - self.v.y * rhs.b.xy
+                    - self.b.yz * rhs.t.xyz
+                    - self.b.zx * rhs.v.z
+                    - self.t.xyz * rhs.b.yz,
+                y: self.s * rhs.v.y + self.v.x * rhs.b.xy + self.v.y * rhs.s + self.b.yz * rhs.v.z
+                    - self.v.z * rhs.b.yz
+                    - self.b.zx * rhs.t.xyz
+                    - self.b.xy * rhs.v.x
+                    - self.t.xyz * rhs.b.zx,
+                z: self.s * rhs.v.z + self.v.y * rhs.b.yz + self.v.z * rhs.s + self.b.zx * rhs.v.x
+                    - self.v.x * rhs.b.zx
+                    - self.b.yz * rhs.v.y
+                    - self.b.xy * rhs.t.xyz
+                    - self.t.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz
+                    + self.v.x * rhs.t.xyz
+                    + self.v.y * rhs.v.z
+                    + self.b.yz * rhs.s
+                    + self.b.xy * rhs.b.zx
+                    + self.t.xyz * rhs.v.x
+                    - self.v.z * rhs.v.y
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx
+                    + self.v.y * rhs.t.xyz
+                    + self.v.z * rhs.v.x
+                    + self.b.yz * rhs.b.xy
+                    + self.b.zx * rhs.s
+                    + self.t.xyz * rhs.v.y
+                    - self.v.x * rhs.v.z
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy
+                    + self.v.x * rhs.v.y
+                    + self.v.z * rhs.t.xyz
+                    + self.b.zx * rhs.b.yz
+                    + self.b.xy * rhs.s
+                    + self.t.xyz * rhs.v.z
+                    - self.v.y * rhs.v.x
+                    - self.b.yz * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.t.xyz
+                    + self.v.x * rhs.b.yz
+                    + self.v.y * rhs.b.zx
+                    + self.v.z * rhs.b.xy
+                    + self.b.yz * rhs.v.x
+                    + self.b.zx * rhs.v.y
+                    + self.b.xy * rhs.v.z
+                    + self.t.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Dot
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Dot for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Euclidean>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Trivector {
+            xyz: self.x * rhs.x + self.y * rhs.y + self.z * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dot for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Euclidean>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Trivector {
+            xyz: self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dot for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Euclidean>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        self.xyz * rhs.xyz
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Trivector {
+            xyz: self.xyz * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Dual
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Dual for Vector<T, Euclidean>
+where
+    T: Copy,
+{
+    type AntiKVector = Bivector<T, Euclidean>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: self.x,
+            zx: self.y,
+            xy: self.z,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: self.x,
+            zx: self.y,
+            xy: self.z,
+            _metric: PhantomData,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: self.x,
+            zx: self.y,
+            xy: self.z,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: self.x,
+            zx: self.y,
+            xy: self.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dual for Bivector<T, Euclidean>
+where
+    T: Copy,
+{
+    type AntiKVector = Vector<T, Euclidean>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.yz,
+            y: self.zx,
+            z: self.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.yz,
+            y: self.zx,
+            z: self.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.yz,
+            y: self.zx,
+            z: self.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.yz,
+            y: self.zx,
+            z: self.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dual for Trivector<T, Euclidean>
+where
+    T: Copy,
+{
+    type AntiKVector = T;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        self.xyz
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        self.xyz
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        self.xyz
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        self.xyz
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Contraction
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Contraction<Vector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T, Euclidean>;
+    type WeightOutput = Vector<T, Euclidean>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T, Euclidean>) -> Self::BulkOutput {
+        Vector {
+            x: self.zx * rhs.z - self.xy * rhs.y,
+            y: self.xy * rhs.x - self.yz * rhs.z,
+            z: self.yz * rhs.y - self.zx * rhs.x,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T, Euclidean>) -> Self::WeightOutput {
+        Vector {
+            x: self.zx * rhs.z - self.xy * rhs.y,
+            y: self.xy * rhs.x - self.yz * rhs.z,
+            z: self.yz * rhs.y - self.zx * rhs.x,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Contraction<Vector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T, Euclidean>;
+    type WeightOutput = Bivector<T, Euclidean>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T, Euclidean>) -> Self::BulkOutput {
+        Bivector {
+            yz: self.xyz * rhs.x,
+            zx: self.xyz * rhs.y,
+            xy: self.xyz * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T, Euclidean>) -> Self::WeightOutput {
+        Bivector {
+            yz: self.xyz * rhs.x,
+            zx: self.xyz * rhs.y,
+            xy: self.xyz * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Contraction<Bivector<T, Euclidean>> for Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T, Euclidean>;
+    type WeightOutput = Vector<T, Euclidean>;
+
+    fn bulk_contraction(&self, rhs: &Bivector<T, Euclidean>) -> Self::BulkOutput {
+        Vector {
+            x: self.xyz * rhs.yz,
+            y: self.xyz * rhs.zx,
+            z: self.xyz * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Bivector<T, Euclidean>) -> Self::WeightOutput {
+        Vector {
+            x: self.xyz * rhs.yz,
+            y: self.xyz * rhs.zx,
+            z: self.xyz * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Expansion
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Expansion<Bivector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T, Euclidean>;
+    type WeightOutput = Bivector<T, Euclidean>;
+
+    fn bulk_expansion(&self, rhs: &Bivector<T, Euclidean>) -> Self::BulkOutput {
+        Bivector {
+            yz: self.y * rhs.xy - self.z * rhs.zx,
+            zx: self.z * rhs.yz - self.x * rhs.xy,
+            xy: self.x * rhs.zx - self.y * rhs.yz,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Bivector<T, Euclidean>) -> Self::WeightOutput {
+        Bivector {
+            yz: self.y * rhs.xy - self.z * rhs.zx,
+            zx: self.z * rhs.yz - self.x * rhs.xy,
+            xy: self.x * rhs.zx - self.y * rhs.yz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T, Euclidean>> for Vector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T, Euclidean>;
+    type WeightOutput = Vector<T, Euclidean>;
+
+    fn bulk_expansion(&self, rhs: &Trivector<T, Euclidean>) -> Self::BulkOutput {
+        Vector {
+            x: self.x * rhs.xyz,
+            y: self.y * rhs.xyz,
+            z: self.z * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Trivector<T, Euclidean>) -> Self::WeightOutput {
+        Vector {
+            x: self.x * rhs.xyz,
+            y: self.y * rhs.xyz,
+            z: self.z * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T, Euclidean>> for Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T, Euclidean>;
+    type WeightOutput = Bivector<T, Euclidean>;
+
+    fn bulk_expansion(&self, rhs: &Trivector<T, Euclidean>) -> Self::BulkOutput {
+        Bivector {
+            yz: self.yz * rhs.xyz,
+            zx: self.zx * rhs.xyz,
+            xy: self.xy * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Trivector<T, Euclidean>) -> Self::WeightOutput {
+        Bivector {
+            yz: self.yz * rhs.xyz,
+            zx: self.zx * rhs.xyz,
+            xy: self.xy * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
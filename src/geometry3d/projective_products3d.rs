@@ -0,0 +1,1077 @@
+// This file is generated by `cargo run -p gual-codegen`, do not edit it by hand.
+//
+// Signature: x² = 1, y² = 1, z² = 0
+
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::traits::ConstZero;
+
+use crate::{Contraction, Dot, Dual, Expansion, GeometricProduct, Projective};
+
+use super::{Bivector, Evenvector, Multivector, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Vector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Projective>) -> Self::Output {
+        Evenvector {
+            s: self.x * rhs.x + self.y * rhs.y,
+            b: Bivector {
+                yz: self.y * rhs.z - self.z * rhs.y,
+                zx: self.z * rhs.x - self.x * rhs.z,
+                xy: self.x * rhs.y - self.y * rhs.x,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: -self.y * rhs.xy,
+                y: self.x * rhs.xy,
+                z: self.y * rhs.yz - self.x * rhs.zx,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Projective>) -> Self::Output {
+        Bivector {
+            yz: self.x * rhs.xyz,
+            zx: self.y * rhs.xyz,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.x * rhs.s - self.y * rhs.b.xy,
+                y: self.x * rhs.b.xy + self.y * rhs.s,
+                z: self.y * rhs.b.yz + self.z * rhs.s - self.x * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: self.x * rhs.v.x + self.y * rhs.v.y,
+            v: Vector {
+                x: self.x * rhs.s - self.y * rhs.b.xy,
+                y: self.x * rhs.b.xy + self.y * rhs.s,
+                z: self.y * rhs.b.yz + self.z * rhs.s - self.x * rhs.b.zx,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.x * rhs.t.xyz + self.y * rhs.v.z - self.z * rhs.v.y,
+                zx: self.y * rhs.t.xyz + self.z * rhs.v.x - self.x * rhs.v.z,
+                xy: self.x * rhs.v.y - self.y * rhs.v.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Bivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.xy * rhs.y,
+                y: -self.xy * rhs.x,
+                z: self.zx * rhs.x - self.yz * rhs.y,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.yz * rhs.x + self.zx * rhs.y + self.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Evenvector {
+            s: -self.xy * rhs.xy,
+            b: Bivector {
+                yz: self.xy * rhs.zx - self.zx * rhs.xy,
+                zx: self.yz * rhs.xy - self.xy * rhs.yz,
+                xy: T::ZERO,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Projective>) -> Self::Output {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: -self.xy * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Projective>) -> Self::Output {
+        Evenvector {
+            s: -self.xy * rhs.b.xy,
+            b: Bivector {
+                yz: self.yz * rhs.s + self.xy * rhs.b.zx - self.zx * rhs.b.xy,
+                zx: self.yz * rhs.b.xy + self.zx * rhs.s - self.xy * rhs.b.yz,
+                xy: self.xy * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: -self.xy * rhs.b.xy,
+            v: Vector {
+                x: self.xy * rhs.v.y,
+                y: -self.xy * rhs.v.x,
+                z: self.zx * rhs.v.x - self.yz * rhs.v.y - self.xy * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.yz * rhs.s + self.xy * rhs.b.zx - self.zx * rhs.b.xy,
+                zx: self.yz * rhs.b.xy + self.zx * rhs.s - self.xy * rhs.b.yz,
+                xy: self.xy * rhs.s,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.yz * rhs.v.x + self.zx * rhs.v.y + self.xy * rhs.v.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Trivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Projective>) -> Self::Output {
+        Bivector {
+            yz: self.xyz * rhs.x,
+            zx: self.xyz * rhs.y,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: -self.xyz * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    type Output = T;
+
+    fn geometric_product(&self, _rhs: &Trivector<T, Projective>) -> Self::Output {
+        T::ZERO
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: -self.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: -self.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.xyz * rhs.v.x,
+                zx: self.xyz * rhs.v.y,
+                xy: T::ZERO,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Evenvector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Projective>> for Evenvector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: self.s * rhs.x + self.b.xy * rhs.y,
+                y: self.s * rhs.y - self.b.xy * rhs.x,
+                z: self.s * rhs.z + self.b.zx * rhs.x - self.b.yz * rhs.y,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Projective>> for Evenvector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Evenvector {
+            s: -self.b.xy * rhs.xy,
+            b: Bivector {
+                yz: self.s * rhs.yz + self.b.xy * rhs.zx - self.b.zx * rhs.xy,
+                zx: self.s * rhs.zx + self.b.yz * rhs.xy - self.b.xy * rhs.yz,
+                xy: self.s * rhs.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Projective>> for Evenvector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: -self.b.xy * rhs.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                xyz: self.s * rhs.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Projective>> for Evenvector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Projective>) -> Self::Output {
+        Evenvector {
+            s: self.s * rhs.s - self.b.xy * rhs.b.xy,
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Projective>> for Evenvector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s - self.b.xy * rhs.b.xy,
+            v: Vector {
+                x: self.s * rhs.v.x + self.b.xy * rhs.v.y,
+                y: self.s * rhs.v.y - self.b.xy * rhs.v.x,
+                z: self.s * rhs.v.z + self.b.zx * rhs.v.x
+                    - self.b.yz * rhs.v.y
+                    - self.b.xy * rhs.t.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.t.xyz
+                    + self.b.yz * rhs.v.x
+                    + self.b.zx * rhs.v.y
+                    + self.b.xy * rhs.v.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Multivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T, Projective>> for Multivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Vector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: self.v.x * rhs.x + self.v.y * rhs.y,
+            v: Vector {
+                x: self.s * rhs.x + self.b.xy * rhs.y,
+                y: self.s * rhs.y - self.b.xy * rhs.x,
+                z: self.s * rhs.z + self.b.zx * rhs.x - self.b.yz * rhs.y,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.v.y * rhs.z + self.t.xyz * rhs.x - self.v.z * rhs.y,
+                zx: self.v.z * rhs.x + self.t.xyz * rhs.y - self.v.x * rhs.z,
+                xy: self.v.x * rhs.y - self.v.y * rhs.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T, Projective>> for Multivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Bivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: -self.b.xy * rhs.xy,
+            v: Vector {
+                x: -self.v.y * rhs.xy,
+                y: self.v.x * rhs.xy,
+                z: self.v.y * rhs.yz - self.v.x * rhs.zx - self.t.xyz * rhs.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.yz + self.b.xy * rhs.zx - self.b.zx * rhs.xy,
+                zx: self.s * rhs.zx + self.b.yz * rhs.xy - self.b.xy * rhs.yz,
+                xy: self.s * rhs.xy,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.v.x * rhs.yz + self.v.y * rhs.zx + self.v.z * rhs.xy,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T, Projective>> for Multivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Trivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: T::ZERO,
+            v: Vector {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: -self.b.xy * rhs.xyz,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.v.x * rhs.xyz,
+                zx: self.v.y * rhs.xyz,
+                xy: T::ZERO,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.xyz,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T, Projective>> for Multivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s - self.b.xy * rhs.b.xy,
+            v: Vector {
+                x: self.v.x * rhs.s - self.v.y * rhs.b.xy,
+                y: self.v.x * rhs.b.xy + self.v.y * rhs.s,
+                z: self.v.y * rhs.b.yz + self.v.z * rhs.s
+                    - self.v.x * rhs.b.zx
+                    - self.t.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz + self.b.yz * rhs.s + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.b.xy * rhs.s,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.v.x * rhs.b.yz
+                    + self.v.y * rhs.b.zx
+                    + self.v.z * rhs.b.xy
+                    + self.t.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T, Projective>> for Multivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T, Projective>;
+
+    fn geometric_product(&self, rhs: &Multivector<T, Projective>) -> Self::Output {
+        Multivector {
+            s: self.s * rhs.s + self.v.x * rhs.v.x + self.v.y * rhs.v.y - self.b.xy * rhs.b.xy,
+            v: Vector {
+                x: self.s * rhs.v.x + self.v.x * rhs.s + self.b.xy * rhs.v.y - self.v.y * rhs.b.xy,
+                y: self.s * rhs.v.y + self.v.x * rhs.b.xy + self.v.y * rhs.s - self.b.xy * rhs.v.x,
+                z: self.s * rhs.v.z + self.v.y * rhs.b.yz + self.v.z * rhs.s + self.b.zx * rhs.v.x
+                    - self.v.x * rhs.b.zx
+                    - self.b.yz * rhs.v.y
+                    - self.b.xy * rhs.t.xyz
+                    - self.t.xyz * rhs.b.xy,
+                _metric: PhantomData,
+            },
+            b: Bivector {
+                yz: self.s * rhs.b.yz
+                    + self.v.x * rhs.t.xyz
+                    + self.v.y * rhs.v.z
+                    + self.b.yz * rhs.s
+                    + self.b.xy * rhs.b.zx
+                    + self.t.xyz * rhs.v.x
+                    - self.v.z * rhs.v.y
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s * rhs.b.zx
+                    + self.v.y * rhs.t.xyz
+                    + self.v.z * rhs.v.x
+                    + self.b.yz * rhs.b.xy
+                    + self.b.zx * rhs.s
+                    + self.t.xyz * rhs.v.y
+                    - self.v.x * rhs.v.z
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s * rhs.b.xy + self.v.x * rhs.v.y + self.b.xy * rhs.s - self.v.y * rhs.v.x,
+                _metric: PhantomData,
+            },
+            t: Trivector {
+                xyz: self.s * rhs.t.xyz
+                    + self.v.x * rhs.b.yz
+                    + self.v.y * rhs.b.zx
+                    + self.v.z * rhs.b.xy
+                    + self.b.yz * rhs.v.x
+                    + self.b.zx * rhs.v.y
+                    + self.b.xy * rhs.v.z
+                    + self.t.xyz * rhs.s,
+                _metric: PhantomData,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Dot
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Dot for Vector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Projective>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Trivector {
+            xyz: self.z * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dot for Bivector<T, Projective>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Projective>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        self.xy * rhs.xy
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Trivector {
+            xyz: self.yz * rhs.yz + self.zx * rhs.zx,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dot for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Projective>;
+
+    fn dot(&self, _rhs: &Self) -> Self::Scalar {
+        T::ZERO
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Trivector {
+            xyz: self.xyz * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Dual
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Dual for Vector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    type AntiKVector = Bivector<T, Projective>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: self.x,
+            zx: self.y,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: self.x,
+            zx: self.y,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: self.z,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: self.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dual for Bivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    type AntiKVector = Vector<T, Projective>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: self.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: self.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.yz,
+            y: self.zx,
+            z: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.yz,
+            y: self.zx,
+            z: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Dual for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    type AntiKVector = T;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        T::ZERO
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        T::ZERO
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        self.xyz
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        self.xyz
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Contraction
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Contraction<Vector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T, Projective>;
+    type WeightOutput = Vector<T, Projective>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T, Projective>) -> Self::BulkOutput {
+        Vector {
+            x: -self.xy * rhs.y,
+            y: self.xy * rhs.x,
+            z: self.yz * rhs.y - self.zx * rhs.x,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T, Projective>) -> Self::WeightOutput {
+        Vector {
+            x: self.zx * rhs.z,
+            y: -self.yz * rhs.z,
+            z: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Contraction<Vector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T, Projective>;
+    type WeightOutput = Bivector<T, Projective>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T, Projective>) -> Self::BulkOutput {
+        Bivector {
+            yz: self.xyz * rhs.x,
+            zx: self.xyz * rhs.y,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T, Projective>) -> Self::WeightOutput {
+        Bivector {
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: self.xyz * rhs.z,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Contraction<Bivector<T, Projective>> for Trivector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T, Projective>;
+    type WeightOutput = Vector<T, Projective>;
+
+    fn bulk_contraction(&self, rhs: &Bivector<T, Projective>) -> Self::BulkOutput {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: self.xyz * rhs.xy,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Bivector<T, Projective>) -> Self::WeightOutput {
+        Vector {
+            x: self.xyz * rhs.yz,
+            y: self.xyz * rhs.zx,
+            z: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Expansion
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Expansion<Bivector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T, Projective>;
+    type WeightOutput = Bivector<T, Projective>;
+
+    fn bulk_expansion(&self, rhs: &Bivector<T, Projective>) -> Self::BulkOutput {
+        Bivector {
+            yz: self.y * rhs.xy,
+            zx: -self.x * rhs.xy,
+            xy: T::ZERO,
+            _metric: PhantomData,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Bivector<T, Projective>) -> Self::WeightOutput {
+        Bivector {
+            yz: -self.z * rhs.zx,
+            zx: self.z * rhs.yz,
+            xy: self.x * rhs.zx - self.y * rhs.yz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T, Projective>> for Vector<T, Projective>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Vector<T, Projective>;
+
+    fn bulk_expansion(&self, _rhs: &Trivector<T, Projective>) -> Self::BulkOutput {}
+
+    fn weight_expansion(&self, rhs: &Trivector<T, Projective>) -> Self::WeightOutput {
+        Vector {
+            x: self.x * rhs.xyz,
+            y: self.y * rhs.xyz,
+            z: self.z * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T, Projective>> for Bivector<T, Projective>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Bivector<T, Projective>;
+
+    fn bulk_expansion(&self, _rhs: &Trivector<T, Projective>) -> Self::BulkOutput {}
+
+    fn weight_expansion(&self, rhs: &Trivector<T, Projective>) -> Self::WeightOutput {
+        Bivector {
+            yz: self.yz * rhs.xyz,
+            zx: self.zx * rhs.xyz,
+            xy: self.xy * rhs.xyz,
+            _metric: PhantomData,
+        }
+    }
+}
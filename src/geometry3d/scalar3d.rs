@@ -1,99 +1,42 @@
-use std::{
-    marker::PhantomData,
-    ops::{Add, Mul, Neg, Sub},
-};
-
-use num::{
-    One, Zero,
-    traits::{ConstOne, ConstZero},
-};
+use std::{marker::PhantomData, ops::Mul};
 
 use crate::{
-    AntiwedgeProduct, GeometricProduct, KVector, WedgeProduct, reverse_antiwedge,
-    reverse_geometric, reverse_mul, reverse_wedge,
+    AntiwedgeProduct, GeometricProduct, KVector, Scalar, WedgeProduct, reverse_mul_scalar_metric,
 };
 
-use super::{Bivector, Multivector, Scalar, Trivector, Vector};
-
-impl<T> Zero for Scalar<T>
-where
-    T: Zero,
-{
-    fn zero() -> Self {
-        Scalar(T::zero())
-    }
-
-    fn is_zero(&self) -> bool {
-        self.0.is_zero()
-    }
-}
-
-impl<T> ConstZero for Scalar<T>
-where
-    T: ConstZero,
-{
-    const ZERO: Self = Scalar(T::ZERO);
-}
-
-impl<T> One for Scalar<T>
-where
-    T: One,
-    Scalar<T>: Mul<Output = Scalar<T>>,
-{
-    fn one() -> Self {
-        Scalar(T::one())
-    }
-}
-
-impl<T> ConstOne for Scalar<T>
-where
-    T: ConstOne,
-{
-    const ONE: Self = Scalar(T::ONE);
-}
-
-impl<T> Add for Scalar<T>
-where
-    T: Add<T, Output = T>,
-{
-    type Output = Scalar<T>;
-    fn add(self, rhs: Self) -> Self::Output {
-        Scalar(self.0 + rhs.0)
-    }
-}
-
-impl<T> Sub for Scalar<T>
-where
-    T: Sub<T, Output = T>,
-{
-    type Output = Scalar<T>;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Scalar(self.0 - rhs.0)
-    }
-}
-
-impl<T> Neg for Scalar<T>
-where
-    T: Neg<Output = T>,
-{
-    type Output = Scalar<T>;
-    fn neg(self) -> Self::Output {
-        Scalar(-self.0)
-    }
-}
+use super::{Bivector, Evenvector, Multivector, Trivector, Vector};
+
+/// Implements the geometric product of a scalar and `$v`, in both orders, as the scaling of `$v`
+macro_rules! geometric_scalar {
+    ($v:ident) => {
+        impl<T, M> GeometricProduct<$v<T, M>> for Scalar<3, T, M>
+        where
+            T: Copy,
+            $v<T, M>: Copy,
+            $v<T, M>: Mul<T, Output = $v<T, M>>,
+        {
+            type Output = $v<T, M>;
+            fn geometric_product(&self, rhs: &$v<T, M>) -> Self::Output {
+                *rhs * self.0
+            }
+        }
 
-impl<T> Mul<T> for Scalar<T>
-where
-    T: Mul<T, Output = T>,
-{
-    type Output = Scalar<T>;
-    fn mul(self, rhs: T) -> Self::Output {
-        Scalar(self.0 * rhs)
-    }
+        impl<T, M> GeometricProduct<Scalar<3, T, M>> for $v<T, M>
+        where
+            T: Copy,
+            $v<T, M>: Copy,
+            $v<T, M>: Mul<T, Output = $v<T, M>>,
+        {
+            type Output = $v<T, M>;
+            fn geometric_product(&self, rhs: &Scalar<3, T, M>) -> Self::Output {
+                *self * rhs.0
+            }
+        }
+    };
 }
 
-impl<T: Clone> KVector for Scalar<T> {
-    type AntiKVector = Trivector<T>;
+impl<T: Clone, M> KVector for Scalar<3, T, M> {
+    type AntiKVector = Trivector<T, M>;
 
     fn right_complement(&self) -> Self::AntiKVector {
         Trivector {
@@ -110,135 +53,60 @@ impl<T: Clone> KVector for Scalar<T> {
     }
 }
 
-impl<T> Mul<Scalar<T>> for Scalar<T>
-where
-    T: Mul<T, Output = T>,
-{
-    type Output = Scalar<T>;
-
-    fn mul(self, rhs: Scalar<T>) -> Self::Output {
-        Scalar(self.0 * rhs.0)
-    }
-}
+reverse_mul_scalar_metric!(Vector);
+reverse_mul_scalar_metric!(Bivector);
+reverse_mul_scalar_metric!(Trivector);
+reverse_mul_scalar_metric!(Evenvector);
+reverse_mul_scalar_metric!(Multivector);
 
-impl<T> Mul<Vector<T>> for Scalar<T>
+impl<T, M> WedgeProduct<Scalar<3, T, M>> for Scalar<3, T, M>
 where
     T: Copy,
-    T: Mul<T, Output = T>,
+    T: Mul<Output = T>,
 {
-    type Output = Vector<T>;
-
-    fn mul(self, rhs: Vector<T>) -> Self::Output {
-        Vector {
-            x: self.0 * rhs.x,
-            y: self.0 * rhs.y,
-            z: self.0 * rhs.z,
-            _metric: PhantomData,
-        }
+    type Output = Scalar<3, T, M>;
+    fn wedge(&self, rhs: &Scalar<3, T, M>) -> Self::Output {
+        Scalar::new(self.0 * rhs.0)
     }
 }
 
-impl<T> Mul<Bivector<T>> for Scalar<T>
+impl<T, M> AntiwedgeProduct<Trivector<T, M>> for Scalar<3, T, M>
 where
     T: Copy,
-    T: Mul<T, Output = T>,
+    T: Mul<Output = T>,
 {
-    type Output = Bivector<T>;
+    type Output = Scalar<3, T, M>;
 
-    fn mul(self, rhs: Bivector<T>) -> Self::Output {
-        Bivector {
-            yz: self.0 * rhs.yz,
-            zx: self.0 * rhs.zx,
-            xy: self.0 * rhs.xy,
-            _metric: PhantomData,
-        }
+    fn antiwedge(&self, rhs: &Trivector<T, M>) -> Self::Output {
+        Scalar::new(self.0 * rhs.xyz)
     }
 }
 
-impl<T> Mul<Trivector<T>> for Scalar<T>
+impl<T, M> AntiwedgeProduct<Scalar<3, T, M>> for Trivector<T, M>
 where
     T: Copy,
-    T: Mul<T, Output = T>,
+    T: Mul<Output = T>,
 {
-    type Output = Trivector<T>;
+    type Output = Scalar<3, T, M>;
 
-    fn mul(self, rhs: Trivector<T>) -> Self::Output {
-        Trivector {
-            xyz: self.0 * rhs.xyz,
-            _metric: PhantomData,
-        }
+    fn antiwedge(&self, rhs: &Scalar<3, T, M>) -> Self::Output {
+        Scalar::new(self.xyz * rhs.0)
     }
 }
 
-impl<T> Mul<Multivector<T>> for Scalar<T>
+impl<T, M> GeometricProduct<Scalar<3, T, M>> for Scalar<3, T, M>
 where
     T: Copy,
-    T: Mul<T, Output = T>,
+    T: Mul<Output = T>,
 {
-    type Output = Multivector<T>;
-
-    fn mul(self, _rhs: Multivector<T>) -> Self::Output {
-        unimplemented!();
-        // Multivector {
-        //     s: self * rhs.s,
-        //     v: self * rhs.v,
-        //     b: self * rhs.b,
-        //     a: self * rhs.a,
-        // }
+    type Output = Scalar<3, T, M>;
+    fn geometric_product(&self, rhs: &Scalar<3, T, M>) -> Self::Output {
+        Scalar::new(self.0 * rhs.0)
     }
 }
 
-impl<T, V> WedgeProduct<V> for Scalar<T>
-where
-    T: Copy,
-    V: Copy,
-    Scalar<T>: Mul<V, Output = V>,
-{
-    type Output = V;
-    fn wedge(&self, rhs: &V) -> Self::Output {
-        *self * *rhs
-    }
-}
-
-impl<T> AntiwedgeProduct<Trivector<T>> for Scalar<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-{
-    type Output = Scalar<T>;
-
-    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
-        Scalar(self.0 * rhs.xyz)
-    }
-}
-
-impl<T, V> GeometricProduct<V> for Scalar<T>
-where
-    T: Copy,
-    V: Copy,
-    Scalar<T>: Mul<V, Output = V>,
-{
-    type Output = V;
-    fn geometric_product(&self, rhs: &V) -> Self::Output {
-        *self * *rhs
-    }
-}
-
-reverse_mul!(Vector, Scalar);
-reverse_mul!(Bivector, Scalar);
-reverse_mul!(Trivector, Scalar);
-reverse_mul!(Multivector, Scalar);
-
-reverse_wedge!(Vector, Scalar);
-reverse_wedge!(Bivector, Scalar);
-reverse_wedge!(Trivector, Scalar);
-reverse_wedge!(Multivector, Scalar);
-
-reverse_antiwedge!(Trivector, Scalar);
-
-reverse_geometric!(Vector, Scalar);
-reverse_geometric!(Bivector, Scalar);
-reverse_geometric!(Trivector, Scalar);
-reverse_geometric!(Multivector, Scalar);
+geometric_scalar!(Vector);
+geometric_scalar!(Bivector);
+geometric_scalar!(Trivector);
+geometric_scalar!(Evenvector);
+geometric_scalar!(Multivector);
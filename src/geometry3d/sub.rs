@@ -9,6 +9,82 @@ use crate::Scalar;
 
 use super::{Bivector, Evenvector, Multivector, Point, Trivector, Vector};
 
+// ----------------------------------------------------------------------------------------------------
+// Scalar
+// ----------------------------------------------------------------------------------------------------
+
+impl<T, M> Sub<Vector<T, M>> for Scalar<3, T, M>
+where
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    type Output = Multivector<T, M>;
+    fn sub(self, rhs: Vector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.0,
+            v: -rhs,
+            b: Bivector::ZERO,
+            t: Trivector::ZERO,
+        }
+    }
+}
+
+impl<T, M> Sub<Bivector<T, M>> for Scalar<3, T, M>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Evenvector<T, M>;
+    fn sub(self, rhs: Bivector<T, M>) -> Self::Output {
+        Evenvector { s: self.0, b: -rhs }
+    }
+}
+
+impl<T, M> Sub<Trivector<T, M>> for Scalar<3, T, M>
+where
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    type Output = Multivector<T, M>;
+    fn sub(self, rhs: Trivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.0,
+            v: Vector::ZERO,
+            b: Bivector::ZERO,
+            t: -rhs,
+        }
+    }
+}
+
+impl<T, M> Sub<Evenvector<T, M>> for Scalar<3, T, M>
+where
+    T: Sub<Output = T>,
+    T: Neg<Output = T>,
+{
+    type Output = Evenvector<T, M>;
+    fn sub(self, rhs: Evenvector<T, M>) -> Self::Output {
+        Evenvector {
+            s: self.0 - rhs.s,
+            b: -rhs.b,
+        }
+    }
+}
+
+impl<T, M> Sub<Multivector<T, M>> for Scalar<3, T, M>
+where
+    T: Sub<Output = T>,
+    T: Neg<Output = T>,
+{
+    type Output = Multivector<T, M>;
+    fn sub(self, rhs: Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: self.0 - rhs.s,
+            v: -rhs.v,
+            b: -rhs.b,
+            t: -rhs.t,
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------
 // Vector
 // ----------------------------------------------------------------------------------------------------
@@ -0,0 +1,127 @@
+use num::{
+    Float,
+    traits::{ConstOne, ConstZero},
+};
+
+use crate::{Epsilon, SubspaceJoin, SubspaceMeet, WedgeProduct, subspace};
+
+use super::{Bivector, Multivector, Trivector, Vector};
+
+/// Vectors spanning a blade, as coefficient arrays, `None` for a zero blade which spans no subspace
+trait Frame<T> {
+    fn frame(&self) -> Option<Vec<[T; 3]>>;
+}
+
+impl<T> Frame<T> for Vector<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    fn frame(&self) -> Option<Vec<[T; 3]>> {
+        let coefficients = [self.x, self.y, self.z];
+        (!coefficients.iter().all(|c| c.is_zero())).then(|| vec![coefficients])
+    }
+}
+
+impl<T> Frame<T> for Bivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    fn frame(&self) -> Option<Vec<[T; 3]>> {
+        self.orthonormal_frame()
+            .map(|frame| frame.map(|v| [v.x, v.y, v.z]).to_vec())
+    }
+}
+
+impl<T> Frame<T> for Trivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    fn frame(&self) -> Option<Vec<[T; 3]>> {
+        self.orthonormal_frame()
+            .map(|frame| frame.map(|v| [v.x, v.y, v.z]).to_vec())
+    }
+}
+
+/// Unit blade spanned by an orthonormal frame
+fn blade<T>(frame: &[[T; 3]]) -> Multivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    let vectors: Vec<Vector<T>> = frame
+        .iter()
+        .map(|[x, y, z]| Vector::new(*x, *y, *z))
+        .collect();
+    match vectors.as_slice() {
+        [] => Multivector {
+            s: T::ONE,
+            ..Multivector::ZERO
+        },
+        [a] => Multivector {
+            v: *a,
+            ..Multivector::ZERO
+        },
+        [a, b] => Multivector {
+            b: a.wedge(b),
+            ..Multivector::ZERO
+        },
+        [a, b, c, ..] => Multivector {
+            t: a.wedge(b).wedge(c),
+            ..Multivector::ZERO
+        },
+    }
+}
+
+macro_rules! subspace_join_meet {
+    ($lht:ident, $rht:ident) => {
+        impl<T> SubspaceJoin<$rht<T>> for $lht<T>
+        where
+            T: Float,
+            T: ConstZero,
+            T: ConstOne,
+            T: Epsilon,
+        {
+            type Output = Multivector<T>;
+            fn subspace_join(&self, rhs: &$rht<T>) -> Multivector<T> {
+                match (self.frame(), rhs.frame()) {
+                    (Some(lhs), Some(rhs)) => blade(&subspace::join(&lhs, &rhs)),
+                    _ => Multivector::ZERO,
+                }
+            }
+        }
+
+        impl<T> SubspaceMeet<$rht<T>> for $lht<T>
+        where
+            T: Float,
+            T: ConstZero,
+            T: ConstOne,
+            T: Epsilon,
+        {
+            type Output = Multivector<T>;
+            fn subspace_meet(&self, rhs: &$rht<T>) -> Multivector<T> {
+                match (self.frame(), rhs.frame()) {
+                    (Some(lhs), Some(rhs)) => blade(&subspace::meet(&lhs, &rhs)),
+                    _ => Multivector::ZERO,
+                }
+            }
+        }
+    };
+}
+
+subspace_join_meet!(Vector, Vector);
+subspace_join_meet!(Vector, Bivector);
+subspace_join_meet!(Vector, Trivector);
+subspace_join_meet!(Bivector, Vector);
+subspace_join_meet!(Bivector, Bivector);
+subspace_join_meet!(Bivector, Trivector);
+subspace_join_meet!(Trivector, Vector);
+subspace_join_meet!(Trivector, Bivector);
+subspace_join_meet!(Trivector, Trivector);
@@ -6,11 +6,11 @@ use std::{
 use num::traits::ConstOne;
 
 use crate::{
-    Antiscalar, Complement, VectorSpace,
+    Antiscalar, Complement, VectorSpace, assign_ops,
     geometry3d::{Bivector, Multivector, Vector},
 };
 
-use super::Trivector;
+use super::{Evenvector, Point, Trivector};
 
 impl<T: Clone, M> Antiscalar for Trivector<T, M>
 where
@@ -82,3 +82,10 @@ where
         }
     }
 }
+
+assign_ops!(Vector<T, M>, T, M);
+assign_ops!(Bivector<T, M>, T, M);
+assign_ops!(Trivector<T, M>, T, M);
+assign_ops!(Evenvector<T, M>, T, M);
+assign_ops!(Multivector<T, M>, T, M);
+assign_ops!(Point<T>, T);
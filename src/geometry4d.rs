@@ -1,13 +1,29 @@
+use crate::Projective;
+
+#[cfg(feature = "proptest")]
+mod arbitrary4d;
 mod bivector4d;
+mod blade4d;
+mod commutator4d;
+mod embedding4d;
 mod evenvector4d;
+mod graded4d;
+pub mod legacy;
 mod multivector4d;
+#[cfg(feature = "operators")]
+mod operators;
+mod products4d;
 mod quadvector4d;
 mod scalar4d;
+mod subspace4d;
 mod trivector4d;
 mod vector4d;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Scalar<T>(pub T);
+/// Scalar of the 4D algebra
+///
+/// Breaking change in 0.2.0: it used to be its own tuple struct, it is now built with
+/// `Scalar::new` or `Scalar::from`. The old type lives on, deprecated, as [`legacy::Scalar`].
+pub type Scalar<T> = crate::Scalar<4, T, Projective>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Vector<T> {
@@ -55,3 +71,26 @@ pub struct Multivector<T> {
     pub t: Trivector<T>,
     pub a: Quadvector<T>,
 }
+
+/// Basis blades of the 4D algebra, in the canonical order of its tables
+///
+/// They name the components of the elements of the algebra through [`crate::Graded`] and indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BasisBlade {
+    Scalar,
+    X,
+    Y,
+    Z,
+    W,
+    WX,
+    WY,
+    WZ,
+    YZ,
+    ZX,
+    XY,
+    WYZ,
+    WZX,
+    WXY,
+    ZYX,
+    XYZW,
+}
@@ -0,0 +1,119 @@
+use std::fmt::Debug;
+
+use num::Float;
+use proptest::prelude::*;
+
+use crate::arbitrary::field;
+
+use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
+
+impl<T> Arbitrary for Vector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field(), field(), field())
+            .prop_map(|(x, y, z, w)| Vector { x, y, z, w })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Bivector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field(), field(), field(), field(), field())
+            .prop_map(|(wx, wy, wz, yz, zx, xy)| Bivector {
+                wx,
+                wy,
+                wz,
+                yz,
+                zx,
+                xy,
+            })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Trivector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field(), field(), field())
+            .prop_map(|(wyz, wzx, wxy, zyx)| Trivector { wyz, wzx, wxy, zyx })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Quadvector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        field().prop_map(|xyzw| Quadvector { xyzw }).boxed()
+    }
+}
+
+impl<T> Arbitrary for Evenvector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (
+            any::<Scalar<T>>(),
+            any::<Bivector<T>>(),
+            any::<Quadvector<T>>(),
+        )
+            .prop_map(|(s, b, a)| Evenvector { s, b, a })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Multivector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (
+            any::<Scalar<T>>(),
+            any::<Vector<T>>(),
+            any::<Bivector<T>>(),
+            any::<Trivector<T>>(),
+            any::<Quadvector<T>>(),
+        )
+            .prop_map(|(s, v, b, t, a)| Multivector { s, v, b, t, a })
+            .boxed()
+    }
+}
@@ -1,14 +1,11 @@
-use std::ops::{Add, Mul, Neg, Sub};
+use std::ops::{Add, Neg, Sub};
 
 use num::{
     Float, Zero,
     traits::{ConstOne, ConstZero},
 };
 
-use crate::{
-    AntiwedgeProduct, Epsilon, GeometricProduct, KVector, WedgeProduct, reverse_add,
-    reverse_antiwedge,
-};
+use crate::{Epsilon, KVector, assign_ops, default_sub, reverse_add};
 
 use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
 
@@ -116,9 +113,17 @@ where
     T: Float,
     T: Epsilon,
 {
+    /// Checks if the bivector is the wedge product of two vectors, `B ^ B = 0`, up to the
+    /// [`Epsilon`] tolerance relative to the squared norm of the bivector
     pub fn is_2_blade(&self) -> bool {
         let dot = (self.wx * self.yz + self.wy * self.zx + self.wz * self.xy).abs();
-        dot.is_near_zero()
+        let norm_squared = self.wx * self.wx
+            + self.wy * self.wy
+            + self.wz * self.wz
+            + self.yz * self.yz
+            + self.zx * self.zx
+            + self.xy * self.xy;
+        norm_squared.is_zero() || (dot / norm_squared).is_near_zero()
     }
 }
 
@@ -229,234 +234,14 @@ impl<T: Copy + Neg<Output = T>> KVector for Bivector<T> {
     }
 }
 
-impl<T> WedgeProduct<Bivector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-    T: Neg<Output = T>,
-{
-    type Output = Quadvector<T>;
-
-    fn wedge(&self, rhs: &Bivector<T>) -> Self::Output {
-        Quadvector {
-            xyzw: -(self.wx * rhs.yz
-                + self.wy * rhs.zx
-                + self.wz * rhs.xy
-                + self.yz * rhs.wx
-                + self.zx * rhs.wy
-                + self.xy * rhs.wz),
-        }
-    }
-}
-
-impl<T> AntiwedgeProduct<Bivector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-    T: Neg<Output = T>,
-{
-    type Output = Scalar<T>;
-
-    fn antiwedge(&self, rhs: &Bivector<T>) -> Self::Output {
-        Scalar(
-            -(self.wx * rhs.yz
-                + self.wy * rhs.zx
-                + self.wz * rhs.xy
-                + self.yz * rhs.wx
-                + self.zx * rhs.wy
-                + self.xy * rhs.wz),
-        )
-    }
-}
-
-impl<T> AntiwedgeProduct<Trivector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-{
-    type Output = Vector<T>;
-
-    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
-        Vector {
-            x: self.wx * rhs.zyx + self.zx * rhs.wxy - self.xy * rhs.wzx,
-            y: self.wy * rhs.zyx + self.xy * rhs.wyz - self.yz * rhs.wxy,
-            z: self.wz * rhs.zyx + self.yz * rhs.wzx - self.zx * rhs.wyz,
-            w: -(self.wx * rhs.wyz + self.wy * rhs.wzx + self.wz * rhs.wxy),
-        }
-    }
-}
-
-impl<T> AntiwedgeProduct<Quadvector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-{
-    type Output = Bivector<T>;
-
-    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Bivector {
-            wx: self.wx * rhs.xyzw,
-            wy: self.wy * rhs.xyzw,
-            wz: self.wz * rhs.xyzw,
-            yz: self.yz * rhs.xyzw,
-            zx: self.zx * rhs.xyzw,
-            xy: self.xy * rhs.xyzw,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Vector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
-        Multivector {
-            s: Scalar::ZERO,
-            v: Vector {
-                x: self.xy * rhs.y - self.zx * rhs.z,
-                y: self.yz * rhs.z - self.xy * rhs.x,
-                z: self.zx * rhs.x - self.yz * rhs.y,
-                w: -(self.wx * rhs.x + self.wy * rhs.y + self.wz * rhs.z),
-            },
-            b: Bivector::ZERO,
-            t: self.wedge(rhs),
-            a: Quadvector::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Bivector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
-        Evenvector {
-            s: -Scalar(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy),
-            b: Bivector {
-                wx: self.wz * rhs.zx + self.xy * rhs.wy - self.wy * rhs.xy - self.zx * rhs.wz,
-                wy: self.wx * rhs.xy + self.yz * rhs.wz - self.wz * rhs.yz - self.xy * rhs.wx,
-                wz: self.wy * rhs.yz + self.zx * rhs.wx - self.wx * rhs.zx - self.yz * rhs.wy,
-                yz: self.xy * rhs.zx - self.zx * rhs.xy,
-                zx: self.yz * rhs.xy - self.xy * rhs.yz,
-                xy: self.zx * rhs.yz - self.yz * rhs.zx,
-            },
-            a: self.wedge(rhs),
-        }
-    }
-}
-
-impl<T> GeometricProduct<Trivector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
-        Multivector {
-            s: Scalar::ZERO,
-            v: Vector {
-                x: self.yz * rhs.zyx,
-                y: self.zx * rhs.zyx,
-                z: self.xy * rhs.zyx,
-                w: -(self.yz * rhs.wyz + self.zx * rhs.wzx + self.xy * rhs.wxy),
-            },
-            b: Bivector::ZERO,
-            t: Trivector {
-                wyz: self.xy * rhs.wzx - self.zx * rhs.wxy - self.wx * rhs.zyx,
-                wzx: self.yz * rhs.wxy - self.xy * rhs.wyz - self.wy * rhs.zyx,
-                wxy: self.zx * rhs.wyz - self.yz * rhs.wzx - self.wz * rhs.zyx,
-                zyx: T::ZERO,
-            },
-            a: Quadvector::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Quadvector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Bivector<T>;
-
-    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Bivector {
-            wx: self.yz * -rhs.xyzw,
-            wy: self.zx * -rhs.xyzw,
-            wz: self.xy * -rhs.xyzw,
-            yz: T::ZERO,
-            zx: T::ZERO,
-            xy: T::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Evenvector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
-        self.geometric_product(&rhs.s)
-            + self.geometric_product(&rhs.b)
-            + self.geometric_product(&rhs.a)
-    }
-}
-
-impl<T> GeometricProduct<Multivector<T>> for Bivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
-        self.geometric_product(&rhs.s)
-            + self.geometric_product(&rhs.b)
-            + self.geometric_product(&rhs.a)
-            + self.geometric_product(&rhs.v)
-            + self.geometric_product(&rhs.t)
-    }
-}
-
 reverse_add!(Trivector, Bivector);
 reverse_add!(Quadvector, Bivector);
 
-reverse_antiwedge!(Trivector, Bivector);
-reverse_antiwedge!(Quadvector, Bivector);
+default_sub!(Bivector<T>, Scalar<T>);
+default_sub!(Bivector<T>, Vector<T>);
+default_sub!(Bivector<T>, Trivector<T>);
+default_sub!(Bivector<T>, Quadvector<T>);
+default_sub!(Bivector<T>, Evenvector<T>);
+default_sub!(Bivector<T>, Multivector<T>);
+
+assign_ops!(Bivector<T>, T);
@@ -0,0 +1,229 @@
+use num::{
+    Float,
+    traits::{ConstOne, ConstZero},
+};
+
+use crate::{Epsilon, WedgeProduct};
+
+use super::{Bivector, Trivector, Vector};
+
+// The projective metric of the algebra is degenerate, `w` squares to zero, so frames are orthonormal with
+// respect to the euclidean inner product of the coefficients instead.
+
+fn inner<T>(a: &Vector<T>, b: &Vector<T>) -> T
+where
+    T: Float,
+{
+    a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+}
+
+fn scale<T>(v: &Vector<T>, factor: T) -> Vector<T>
+where
+    T: Float,
+{
+    Vector {
+        x: v.x * factor,
+        y: v.y * factor,
+        z: v.z * factor,
+        w: v.w * factor,
+    }
+}
+
+/// Removes from `v` its component along the unit vector `u`
+fn reject<T>(v: &Vector<T>, u: &Vector<T>) -> Vector<T>
+where
+    T: Float,
+{
+    let projection = inner(v, u);
+    Vector {
+        x: v.x - u.x * projection,
+        y: v.y - u.y * projection,
+        z: v.z - u.z * projection,
+        w: v.w - u.w * projection,
+    }
+}
+
+fn normalize<T>(v: &Vector<T>) -> Option<Vector<T>>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let length = inner(v, v).sqrt();
+    if length.is_zero() {
+        None
+    } else {
+        Some(scale(v, length.recip()))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Bivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Bivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Checks if the bivector is the wedge product of two vectors, same as [`Bivector::is_2_blade`]
+    pub fn is_blade(&self) -> bool {
+        self.is_2_blade()
+    }
+
+    /// Contraction of the bivector with the vector `v`, as a linear map of the coefficients
+    fn contract(&self, v: &Vector<T>) -> Vector<T> {
+        Vector {
+            x: self.xy * v.y - self.zx * v.z - self.wx * v.w,
+            y: self.yz * v.z - self.xy * v.x - self.wy * v.w,
+            z: self.zx * v.x - self.yz * v.y - self.wz * v.w,
+            w: self.wx * v.x + self.wy * v.y + self.wz * v.z,
+        }
+    }
+
+    /// Two vectors `[a, b]`, orthogonal to each other, such that `a ^ b` is the bivector, `None` if it
+    /// isn't a blade.
+    ///
+    /// Non-simple bivectors can be split first with [`Bivector::invariant_decomposition`].
+    pub fn factorize(&self) -> Option<[Vector<T>; 2]> {
+        if !self.is_blade() {
+            return None;
+        }
+        // the contraction of the largest basis vector lies on the plane, then contracting
+        // again gives the second direction
+        let a = [Vector::X, Vector::Y, Vector::Z, Vector::W]
+            .iter()
+            .map(|e| self.contract(e))
+            .fold(Vector::ZERO, |best, a| {
+                if inner(&a, &a) > inner(&best, &best) {
+                    a
+                } else {
+                    best
+                }
+            });
+        let length_squared = inner(&a, &a);
+        if length_squared.is_zero() {
+            return Some([Vector::ZERO, Vector::ZERO]);
+        }
+        let b = scale(&self.contract(&a), -length_squared.recip());
+        Some([a, b])
+    }
+
+    /// Two orthonormal vectors spanning the plane of the bivector with its orientation, `None` if it
+    /// isn't a blade or is zero
+    pub fn orthonormal_frame(&self) -> Option<[Vector<T>; 2]> {
+        let [a, b] = self.factorize()?;
+        Some([normalize(&a)?, normalize(&b)?])
+    }
+
+    /// Translation per unit of rotation of the bivector seen as a screw,
+    /// `(wx yz + wy zx + wz xy) / (wx² + wy² + wz²)`, `None` if it has no direction
+    pub fn pitch(&self) -> Option<T> {
+        let direction_squared = self.wx * self.wx + self.wy * self.wy + self.wz * self.wz;
+        // no tolerance: the ideal part `pitch * direction` stays bounded for short directions, and
+        // small twists like the steps of an integrator are not pure translations
+        if direction_squared.is_zero() {
+            return None;
+        }
+        Some((self.wx * self.yz + self.wy * self.zx + self.wz * self.xy) / direction_squared)
+    }
+
+    /// Splits the bivector in two 2-blades that commute under the geometric antiproduct, the one
+    /// motors are built with: a line with its `wx`, `wy` and `wz` direction, and an ideal line (only
+    /// `yz`, `zx` and `xy`) holding the rest, which is that direction times the [`Bivector::pitch`].
+    ///
+    /// The second one is zero when the bivector already is a blade, and the whole bivector goes there
+    /// when it is ideal.
+    pub fn invariant_decomposition(&self) -> (Bivector<T>, Bivector<T>) {
+        let Some(pitch) = self.pitch() else {
+            return (Bivector::ZERO, *self);
+        };
+        let line = Bivector {
+            wx: self.wx,
+            wy: self.wy,
+            wz: self.wz,
+            yz: self.yz - pitch * self.wx,
+            zx: self.zx - pitch * self.wy,
+            xy: self.xy - pitch * self.wz,
+        };
+        let ideal = Bivector {
+            wx: T::ZERO,
+            wy: T::ZERO,
+            wz: T::ZERO,
+            yz: pitch * self.wx,
+            zx: pitch * self.wy,
+            xy: pitch * self.wz,
+        };
+        (line, ideal)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Trivector
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Trivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Every trivector of a 4D algebra is the wedge product of three vectors
+    pub fn is_blade(&self) -> bool {
+        true
+    }
+
+    /// Vector orthogonal to the subspace of the trivector, with its magnitude
+    fn normal(&self) -> Vector<T> {
+        Vector {
+            x: self.wyz,
+            y: self.wzx,
+            z: self.wxy,
+            w: self.zyx,
+        }
+    }
+
+    /// Three orthogonal vectors `[a, b, c]` such that `a ^ b ^ c` is the trivector
+    pub fn factorize(&self) -> [Vector<T>; 3] {
+        let normal = self.normal();
+        let magnitude = inner(&normal, &normal).sqrt();
+        match self.orthonormal_frame() {
+            Some([a, b, c]) => [scale(&a, magnitude), b, c],
+            None => [Vector::ZERO, Vector::ZERO, Vector::ZERO],
+        }
+    }
+
+    /// Three orthonormal vectors spanning the subspace of the trivector with its orientation, `None` if
+    /// it is zero
+    pub fn orthonormal_frame(&self) -> Option<[Vector<T>; 3]> {
+        let normal = normalize(&self.normal())?;
+        // the basis vector closest to the normal is the one to leave out
+        let [x, y, z, w] = [normal.x, normal.y, normal.z, normal.w].map(|c| c.abs());
+        let basis = if x >= y && x >= z && x >= w {
+            [Vector::Y, Vector::Z, Vector::W]
+        } else if y >= z && y >= w {
+            [Vector::X, Vector::Z, Vector::W]
+        } else if z >= w {
+            [Vector::X, Vector::Y, Vector::W]
+        } else {
+            [Vector::X, Vector::Y, Vector::Z]
+        };
+        // Gram-Schmidt on the subspace orthogonal to the normal
+        let a = normalize(&reject(&basis[0], &normal))?;
+        let b = normalize(&reject(&reject(&basis[1], &normal), &a))?;
+        let c = normalize(&reject(&reject(&reject(&basis[2], &normal), &a), &b))?;
+
+        let frame = a.wedge(&b.wedge(&c));
+        let orientation = frame.wyz * self.wyz
+            + frame.wzx * self.wzx
+            + frame.wxy * self.wxy
+            + frame.zyx * self.zyx;
+        if orientation < T::ZERO {
+            Some([scale(&a, -T::ONE), b, c])
+        } else {
+            Some([a, b, c])
+        }
+    }
+}
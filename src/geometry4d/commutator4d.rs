@@ -0,0 +1,140 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::traits::ConstZero;
+
+use crate::{AnticommutatorProduct, CommutatorProduct, GeometricProduct, WedgeProduct};
+
+use super::{Bivector, Evenvector, Quadvector, Scalar, Trivector, Vector};
+
+impl<T> CommutatorProduct<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn commutator_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Bivector {
+            wx: self.wz * rhs.zx + self.xy * rhs.wy - self.wy * rhs.xy - self.zx * rhs.wz,
+            wy: self.wx * rhs.xy + self.yz * rhs.wz - self.wz * rhs.yz - self.xy * rhs.wx,
+            wz: self.wy * rhs.yz + self.zx * rhs.wx - self.wx * rhs.zx - self.yz * rhs.wy,
+            yz: self.xy * rhs.zx - self.zx * rhs.xy,
+            zx: self.yz * rhs.xy - self.xy * rhs.yz,
+            xy: self.zx * rhs.yz - self.yz * rhs.zx,
+        }
+    }
+}
+
+impl<T> AnticommutatorProduct<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn anticommutator_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(-(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy)),
+            b: Bivector::ZERO,
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.yz
+                    + self.wy * rhs.zx
+                    + self.wz * rhs.xy
+                    + self.yz * rhs.wx
+                    + self.zx * rhs.wy
+                    + self.xy * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> CommutatorProduct<Vector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn commutator_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Vector {
+            x: self.xy * rhs.y - self.zx * rhs.z,
+            y: self.yz * rhs.z - self.xy * rhs.x,
+            z: self.zx * rhs.x - self.yz * rhs.y,
+            w: self.wx * rhs.x + self.wy * rhs.y + self.wz * rhs.z,
+        }
+    }
+}
+
+impl<T> CommutatorProduct<Bivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn commutator_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Vector {
+            x: self.z * rhs.zx - self.y * rhs.xy,
+            y: self.x * rhs.xy - self.z * rhs.yz,
+            z: self.y * rhs.yz - self.x * rhs.zx,
+            w: -(self.x * rhs.wx + self.y * rhs.wy + self.z * rhs.wz),
+        }
+    }
+}
+
+impl<T> AnticommutatorProduct<Vector<T>> for Bivector<T>
+where
+    Bivector<T>: WedgeProduct<Vector<T>, Output = Trivector<T>>,
+{
+    type Output = Trivector<T>;
+
+    fn anticommutator_product(&self, rhs: &Vector<T>) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
+
+impl<T> AnticommutatorProduct<Bivector<T>> for Vector<T>
+where
+    Vector<T>: WedgeProduct<Bivector<T>, Output = Trivector<T>>,
+{
+    type Output = Trivector<T>;
+
+    fn anticommutator_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
+
+impl<T> CommutatorProduct<Evenvector<T>> for Evenvector<T>
+where
+    Bivector<T>: CommutatorProduct<Bivector<T>, Output = Bivector<T>>,
+{
+    type Output = Bivector<T>;
+
+    fn commutator_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        // scalars and the pseudoscalar commute with every even element
+        self.b.commutator_product(&rhs.b)
+    }
+}
+
+impl<T> AnticommutatorProduct<Evenvector<T>> for Evenvector<T>
+where
+    Evenvector<T>: GeometricProduct<Evenvector<T>, Output = Evenvector<T>>,
+    Evenvector<T>: CommutatorProduct<Evenvector<T>, Output = Bivector<T>>,
+    Evenvector<T>: Sub<Bivector<T>, Output = Evenvector<T>>,
+{
+    type Output = Evenvector<T>;
+
+    fn anticommutator_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        self.geometric_product(rhs) - self.commutator_product(rhs)
+    }
+}
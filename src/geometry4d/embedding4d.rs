@@ -0,0 +1,177 @@
+use std::ops::Neg;
+
+use num::{Zero, traits::ConstZero};
+
+use crate::{Embed, Euclidean, Project, geometry3d as d3};
+
+use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
+
+// The euclidean 3D space is spanned by x, y and z, w being the null vector of the projective metric.
+// The 3D volume element `xyz` is `-zyx` in the 4D basis.
+
+impl<T> Embed<Scalar<T>> for d3::Scalar<T>
+where
+    T: Copy,
+{
+    fn embed(&self) -> Scalar<T> {
+        Scalar::new(self.0)
+    }
+}
+
+impl<T> Embed<Vector<T>> for d3::Vector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Vector<T> {
+        Vector {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: T::ZERO,
+        }
+    }
+}
+
+impl<T> Embed<Bivector<T>> for d3::Bivector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Bivector<T> {
+        Bivector {
+            wx: T::ZERO,
+            wy: T::ZERO,
+            wz: T::ZERO,
+            yz: self.yz,
+            zx: self.zx,
+            xy: self.xy,
+        }
+    }
+}
+
+impl<T> Embed<Trivector<T>> for d3::Trivector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    fn embed(&self) -> Trivector<T> {
+        Trivector {
+            wyz: T::ZERO,
+            wzx: T::ZERO,
+            wxy: T::ZERO,
+            zyx: -self.xyz,
+        }
+    }
+}
+
+impl<T> Embed<Evenvector<T>> for d3::Evenvector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    fn embed(&self) -> Evenvector<T> {
+        Evenvector {
+            s: Scalar::new(self.s),
+            b: self.b.embed(),
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> Embed<Multivector<T>> for d3::Multivector<T, Euclidean>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    fn embed(&self) -> Multivector<T> {
+        Multivector {
+            s: Scalar::new(self.s),
+            v: self.v.embed(),
+            b: self.b.embed(),
+            t: self.t.embed(),
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> Project<d3::Scalar<T>> for Scalar<T>
+where
+    T: Copy,
+{
+    fn project(&self) -> Option<d3::Scalar<T>> {
+        Some(d3::Scalar::new(self.0))
+    }
+}
+
+impl<T> Project<d3::Vector<T, Euclidean>> for Vector<T>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d3::Vector<T, Euclidean>> {
+        self.w
+            .is_zero()
+            .then(|| d3::Vector::new(self.x, self.y, self.z))
+    }
+}
+
+impl<T> Project<d3::Bivector<T, Euclidean>> for Bivector<T>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d3::Bivector<T, Euclidean>> {
+        (self.wx.is_zero() && self.wy.is_zero() && self.wz.is_zero())
+            .then(|| d3::Bivector::new(self.yz, self.zx, self.xy))
+    }
+}
+
+impl<T> Project<d3::Trivector<T, Euclidean>> for Trivector<T>
+where
+    T: Copy,
+    T: Zero,
+    T: Neg<Output = T>,
+{
+    fn project(&self) -> Option<d3::Trivector<T, Euclidean>> {
+        (self.wyz.is_zero() && self.wzx.is_zero() && self.wxy.is_zero())
+            .then(|| d3::Trivector::new(-self.zyx))
+    }
+}
+
+impl<T> Project<d3::Evenvector<T, Euclidean>> for Evenvector<T>
+where
+    T: Copy,
+    T: Zero,
+{
+    fn project(&self) -> Option<d3::Evenvector<T, Euclidean>> {
+        if !self.a.xyzw.is_zero() {
+            return None;
+        }
+        Some(d3::Evenvector {
+            s: self.s.0,
+            b: self.b.project()?,
+        })
+    }
+}
+
+impl<T> Project<d3::Multivector<T, Euclidean>> for Multivector<T>
+where
+    T: Copy,
+    T: Zero,
+    T: Neg<Output = T>,
+{
+    fn project(&self) -> Option<d3::Multivector<T, Euclidean>> {
+        if !self.a.xyzw.is_zero() {
+            return None;
+        }
+        Some(d3::Multivector {
+            s: self.s.0,
+            v: self.v.project()?,
+            b: self.b.project()?,
+            t: self.t.project()?,
+        })
+    }
+}
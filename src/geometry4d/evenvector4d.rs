@@ -5,7 +5,7 @@ use num::{
     traits::{ConstOne, ConstZero},
 };
 
-use crate::{GeometricProduct, reverse_add};
+use crate::{assign_ops, default_sub, reverse_add};
 
 use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
 
@@ -151,93 +151,15 @@ where
     }
 }
 
-impl<T> Mul<Evenvector<T>> for Evenvector<T>
-where
-    Evenvector<T>: GeometricProduct<Evenvector<T>, Output = Evenvector<T>>,
-{
-    type Output = Evenvector<T>;
-
-    fn mul(self, rhs: Evenvector<T>) -> Self::Output {
-        self.geometric_product(&rhs)
-    }
-}
-
-impl<T> GeometricProduct<Vector<T>> for Evenvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
-        self.s.geometric_product(rhs)
-            + self.b.geometric_product(rhs)
-            + self.a.geometric_product(rhs)
-    }
-}
-
-impl<T> GeometricProduct<Bivector<T>> for Evenvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
-        self.s.geometric_product(rhs)
-            + self.b.geometric_product(rhs)
-            + self.a.geometric_product(rhs)
-    }
-}
-
-impl<T> GeometricProduct<Trivector<T>> for Evenvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
-        self.s.geometric_product(rhs)
-            + self.b.geometric_product(rhs)
-            + self.a.geometric_product(rhs)
-    }
-}
-
-impl<T> GeometricProduct<Evenvector<T>> for Evenvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
-        Evenvector {
-            s: self.s * rhs.s,
-            b: self.s * rhs.b + self.b * rhs.s,
-            a: self.s * rhs.a + self.a * rhs.s,
-        } + self.b.geometric_product(&rhs.b)
-            + self.b.geometric_product(&rhs.a)
-            + self.a.geometric_product(&rhs.b)
-    }
-}
-
 reverse_add!(Scalar, Evenvector);
 reverse_add!(Bivector, Evenvector);
 reverse_add!(Quadvector, Evenvector);
+
+default_sub!(Evenvector<T>, Scalar<T>);
+default_sub!(Evenvector<T>, Vector<T>);
+default_sub!(Evenvector<T>, Bivector<T>);
+default_sub!(Evenvector<T>, Trivector<T>);
+default_sub!(Evenvector<T>, Quadvector<T>);
+default_sub!(Evenvector<T>, Multivector<T>);
+
+assign_ops!(Evenvector<T>, T);
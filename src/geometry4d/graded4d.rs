@@ -0,0 +1,64 @@
+use crate::graded;
+
+use super::{BasisBlade, Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
+
+impl BasisBlade {
+    /// Every basis blade, in canonical order
+    pub const ALL: [BasisBlade; 16] = [
+        BasisBlade::Scalar,
+        BasisBlade::X,
+        BasisBlade::Y,
+        BasisBlade::Z,
+        BasisBlade::W,
+        BasisBlade::WX,
+        BasisBlade::WY,
+        BasisBlade::WZ,
+        BasisBlade::YZ,
+        BasisBlade::ZX,
+        BasisBlade::XY,
+        BasisBlade::WYZ,
+        BasisBlade::WZX,
+        BasisBlade::WXY,
+        BasisBlade::ZYX,
+        BasisBlade::XYZW,
+    ];
+
+    /// Number of basis vectors that build the blade
+    pub const fn grade(self) -> u32 {
+        match self {
+            BasisBlade::Scalar => 0,
+            BasisBlade::X | BasisBlade::Y | BasisBlade::Z | BasisBlade::W => 1,
+            BasisBlade::WX
+            | BasisBlade::WY
+            | BasisBlade::WZ
+            | BasisBlade::YZ
+            | BasisBlade::ZX
+            | BasisBlade::XY => 2,
+            BasisBlade::WYZ | BasisBlade::WZX | BasisBlade::WXY | BasisBlade::ZYX => 3,
+            BasisBlade::XYZW => 4,
+        }
+    }
+}
+
+graded!(Scalar<T>, [T]; BasisBlade; 0 => Scalar);
+graded!(Vector<T>, [T]; BasisBlade; x => X, y => Y, z => Z, w => W);
+graded!(
+    Bivector<T>, [T]; BasisBlade;
+    wx => WX, wy => WY, wz => WZ, yz => YZ, zx => ZX, xy => XY
+);
+graded!(Trivector<T>, [T]; BasisBlade; wyz => WYZ, wzx => WZX, wxy => WXY, zyx => ZYX);
+graded!(Quadvector<T>, [T]; BasisBlade; xyzw => XYZW);
+graded!(
+    Evenvector<T>, [T]; BasisBlade;
+    s.0 => Scalar,
+    b.wx => WX, b.wy => WY, b.wz => WZ, b.yz => YZ, b.zx => ZX, b.xy => XY,
+    a.xyzw => XYZW
+);
+graded!(
+    Multivector<T>, [T]; BasisBlade;
+    s.0 => Scalar,
+    v.x => X, v.y => Y, v.z => Z, v.w => W,
+    b.wx => WX, b.wy => WY, b.wz => WZ, b.yz => YZ, b.zx => ZX, b.xy => XY,
+    t.wyz => WYZ, t.wzx => WZX, t.wxy => WXY, t.zyx => ZYX,
+    a.xyzw => XYZW
+);
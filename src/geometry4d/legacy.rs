@@ -0,0 +1,59 @@
+//! Types of the 0.1 API, kept for a migration window
+//!
+//! Replacing `use gual::geometry4d::Scalar` with `use gual::geometry4d::legacy::Scalar` keeps the
+//! `Scalar(x)` constructor compiling, with a deprecation warning on every use. The legacy scalar
+//! converts both ways with the new one through [`From`].
+
+#![allow(deprecated)]
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::Projective;
+
+/// Scalar of the 4D algebra before 0.2.0
+#[deprecated(
+    since = "0.2.0",
+    note = "use `geometry4d::Scalar`, built with `Scalar::new` or `Scalar::from`"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Scalar<T>(pub T);
+
+impl<T> From<Scalar<T>> for crate::Scalar<4, T, Projective> {
+    fn from(value: Scalar<T>) -> Self {
+        crate::Scalar::new(value.0)
+    }
+}
+
+impl<T> From<crate::Scalar<4, T, Projective>> for Scalar<T> {
+    fn from(value: crate::Scalar<4, T, Projective>) -> Self {
+        Scalar(value.0)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Scalar<T> {
+    type Output = Scalar<T>;
+    fn add(self, rhs: Scalar<T>) -> Self::Output {
+        Scalar(self.0 + rhs.0)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Scalar<T> {
+    type Output = Scalar<T>;
+    fn sub(self, rhs: Scalar<T>) -> Self::Output {
+        Scalar(self.0 - rhs.0)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Scalar<T> {
+    type Output = Scalar<T>;
+    fn neg(self) -> Self::Output {
+        Scalar(-self.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul for Scalar<T> {
+    type Output = Scalar<T>;
+    fn mul(self, rhs: Scalar<T>) -> Self::Output {
+        Scalar(self.0 * rhs.0)
+    }
+}
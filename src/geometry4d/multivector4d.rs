@@ -5,7 +5,10 @@ use num::{
     traits::{ConstOne, ConstZero},
 };
 
-use crate::{Antiscalar, AntiwedgeProduct, KVector, VectorSpace, WedgeProduct, reverse_add};
+use crate::{
+    Antiscalar, AntiwedgeProduct, KVector, VectorSpace, WedgeProduct, assign_ops, default_sub,
+    reverse_add,
+};
 
 use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
 
@@ -514,7 +517,7 @@ where
         let a5 = self.a.wedge(&rhs.s);
 
         Multivector {
-            s: s,
+            s,
             v: v1 + v2,
             b: b1 + b2 + b3,
             t: t1 + t2 + t3 + t4,
@@ -543,3 +546,12 @@ reverse_add!(Bivector, Multivector);
 reverse_add!(Trivector, Multivector);
 reverse_add!(Quadvector, Multivector);
 reverse_add!(Evenvector, Multivector);
+
+default_sub!(Multivector<T>, Scalar<T>);
+default_sub!(Multivector<T>, Vector<T>);
+default_sub!(Multivector<T>, Bivector<T>);
+default_sub!(Multivector<T>, Trivector<T>);
+default_sub!(Multivector<T>, Quadvector<T>);
+default_sub!(Multivector<T>, Evenvector<T>);
+
+assign_ops!(Multivector<T>, T);
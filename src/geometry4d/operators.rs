@@ -0,0 +1,10 @@
+use crate::operators;
+
+use super::{Bivector, Evenvector, Multivector, Quadvector, Trivector, Vector};
+
+operators!(Vector<T>, T);
+operators!(Bivector<T>, T);
+operators!(Trivector<T>, T);
+operators!(Quadvector<T>, T);
+operators!(Evenvector<T>, T);
+operators!(Multivector<T>, T);
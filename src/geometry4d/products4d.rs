@@ -0,0 +1,4433 @@
+// This file is generated by `cargo run -p gual-codegen`, do not edit it by hand.
+//
+// Signature: x² = 1, y² = 1, z² = 1, w² = 0
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::traits::ConstZero;
+
+use crate::{
+    AntiwedgeProduct, Contraction, Dot, Dual, Expansion, GeometricAntiproduct, GeometricProduct,
+    WedgeProduct, geometric_mul,
+};
+
+use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
+
+// ----------------------------------------------------------------------------------------------------
+// Wedge product
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> WedgeProduct<Vector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn wedge(&self, rhs: &Vector<T>) -> Self::Output {
+        Bivector {
+            wx: self.w * rhs.x - self.x * rhs.w,
+            wy: self.w * rhs.y - self.y * rhs.w,
+            wz: self.w * rhs.z - self.z * rhs.w,
+            yz: self.y * rhs.z - self.z * rhs.y,
+            zx: self.z * rhs.x - self.x * rhs.z,
+            xy: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+impl<T> WedgeProduct<Bivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn wedge(&self, rhs: &Bivector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.z * rhs.wy + self.w * rhs.yz - self.y * rhs.wz,
+            wzx: self.x * rhs.wz + self.w * rhs.zx - self.z * rhs.wx,
+            wxy: self.y * rhs.wx + self.w * rhs.xy - self.x * rhs.wy,
+            zyx: -(self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy),
+        }
+    }
+}
+
+impl<T> WedgeProduct<Trivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Quadvector<T>;
+
+    fn wedge(&self, rhs: &Trivector<T>) -> Self::Output {
+        Quadvector {
+            xyzw: self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx,
+        }
+    }
+}
+
+impl<T> WedgeProduct<Vector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn wedge(&self, rhs: &Vector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.wy * rhs.z + self.yz * rhs.w - self.wz * rhs.y,
+            wzx: self.wz * rhs.x + self.zx * rhs.w - self.wx * rhs.z,
+            wxy: self.wx * rhs.y + self.xy * rhs.w - self.wy * rhs.x,
+            zyx: -(self.yz * rhs.x + self.zx * rhs.y + self.xy * rhs.z),
+        }
+    }
+}
+
+impl<T> WedgeProduct<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Quadvector<T>;
+
+    fn wedge(&self, rhs: &Bivector<T>) -> Self::Output {
+        Quadvector {
+            xyzw: -(self.wx * rhs.yz
+                + self.wy * rhs.zx
+                + self.wz * rhs.xy
+                + self.yz * rhs.wx
+                + self.zx * rhs.wy
+                + self.xy * rhs.wz),
+        }
+    }
+}
+
+impl<T> WedgeProduct<Vector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Quadvector<T>;
+
+    fn wedge(&self, rhs: &Vector<T>) -> Self::Output {
+        Quadvector {
+            xyzw: -(self.wyz * rhs.x + self.wzx * rhs.y + self.wxy * rhs.z + self.zyx * rhs.w),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Antiwedge product
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> AntiwedgeProduct<Trivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Scalar<T>;
+
+    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
+        Scalar::new(self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx)
+    }
+}
+
+impl<T> AntiwedgeProduct<Quadvector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Vector {
+            x: self.x * rhs.xyzw,
+            y: self.y * rhs.xyzw,
+            z: self.z * rhs.xyzw,
+            w: self.w * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Scalar<T>;
+
+    fn antiwedge(&self, rhs: &Bivector<T>) -> Self::Output {
+        Scalar::new(
+            -(self.wx * rhs.yz
+                + self.wy * rhs.zx
+                + self.wz * rhs.xy
+                + self.yz * rhs.wx
+                + self.zx * rhs.wy
+                + self.xy * rhs.wz),
+        )
+    }
+}
+
+impl<T> AntiwedgeProduct<Trivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
+        Vector {
+            x: self.wx * rhs.zyx + self.zx * rhs.wxy - self.xy * rhs.wzx,
+            y: self.wy * rhs.zyx + self.xy * rhs.wyz - self.yz * rhs.wxy,
+            z: self.wz * rhs.zyx + self.yz * rhs.wzx - self.zx * rhs.wyz,
+            w: -(self.wx * rhs.wyz + self.wy * rhs.wzx + self.wz * rhs.wxy),
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Quadvector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Bivector {
+            wx: self.wx * rhs.xyzw,
+            wy: self.wy * rhs.xyzw,
+            wz: self.wz * rhs.xyzw,
+            yz: self.yz * rhs.xyzw,
+            zx: self.zx * rhs.xyzw,
+            xy: self.xy * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Vector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Scalar<T>;
+
+    fn antiwedge(&self, rhs: &Vector<T>) -> Self::Output {
+        Scalar::new(-(self.wyz * rhs.x + self.wzx * rhs.y + self.wxy * rhs.z + self.zyx * rhs.w))
+    }
+}
+
+impl<T> AntiwedgeProduct<Bivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn antiwedge(&self, rhs: &Bivector<T>) -> Self::Output {
+        Vector {
+            x: self.wxy * rhs.zx + self.zyx * rhs.wx - self.wzx * rhs.xy,
+            y: self.wyz * rhs.xy + self.zyx * rhs.wy - self.wxy * rhs.yz,
+            z: self.wzx * rhs.yz + self.zyx * rhs.wz - self.wyz * rhs.zx,
+            w: -(self.wyz * rhs.wx + self.wzx * rhs.wy + self.wxy * rhs.wz),
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Trivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
+        Bivector {
+            wx: self.wxy * rhs.wzx - self.wzx * rhs.wxy,
+            wy: self.wyz * rhs.wxy - self.wxy * rhs.wyz,
+            wz: self.wzx * rhs.wyz - self.wyz * rhs.wzx,
+            yz: self.wyz * rhs.zyx - self.zyx * rhs.wyz,
+            zx: self.wzx * rhs.zyx - self.zyx * rhs.wzx,
+            xy: self.wxy * rhs.zyx - self.zyx * rhs.wxy,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Quadvector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.wyz * rhs.xyzw,
+            wzx: self.wzx * rhs.xyzw,
+            wxy: self.wxy * rhs.xyzw,
+            zyx: self.zyx * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Vector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn antiwedge(&self, rhs: &Vector<T>) -> Self::Output {
+        Vector {
+            x: self.xyzw * rhs.x,
+            y: self.xyzw * rhs.y,
+            z: self.xyzw * rhs.z,
+            w: self.xyzw * rhs.w,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Bivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn antiwedge(&self, rhs: &Bivector<T>) -> Self::Output {
+        Bivector {
+            wx: self.xyzw * rhs.wx,
+            wy: self.xyzw * rhs.wy,
+            wz: self.xyzw * rhs.wz,
+            yz: self.xyzw * rhs.yz,
+            zx: self.xyzw * rhs.zx,
+            xy: self.xyzw * rhs.xy,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Trivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.xyzw * rhs.wyz,
+            wzx: self.xyzw * rhs.wzx,
+            wxy: self.xyzw * rhs.wxy,
+            zyx: self.xyzw * rhs.zyx,
+        }
+    }
+}
+
+impl<T> AntiwedgeProduct<Quadvector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Quadvector<T>;
+
+    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Quadvector {
+            xyzw: self.xyzw * rhs.xyzw,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Geometric product
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricProduct<Vector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(self.x * rhs.x + self.y * rhs.y + self.z * rhs.z),
+            b: Bivector {
+                wx: self.w * rhs.x - self.x * rhs.w,
+                wy: self.w * rhs.y - self.y * rhs.w,
+                wz: self.w * rhs.z - self.z * rhs.w,
+                yz: self.y * rhs.z - self.z * rhs.y,
+                zx: self.z * rhs.x - self.x * rhs.z,
+                xy: self.x * rhs.y - self.y * rhs.x,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.z * rhs.zx - self.y * rhs.xy,
+                y: self.x * rhs.xy - self.z * rhs.yz,
+                z: self.y * rhs.yz - self.x * rhs.zx,
+                w: -(self.x * rhs.wx + self.y * rhs.wy + self.z * rhs.wz),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.z * rhs.wy + self.w * rhs.yz - self.y * rhs.wz,
+                wzx: self.x * rhs.wz + self.w * rhs.zx - self.z * rhs.wx,
+                wxy: self.y * rhs.wx + self.w * rhs.xy - self.x * rhs.wy,
+                zyx: -(self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::ZERO,
+            b: Bivector {
+                wx: self.y * rhs.wxy - self.z * rhs.wzx,
+                wy: self.z * rhs.wyz - self.x * rhs.wxy,
+                wz: self.x * rhs.wzx - self.y * rhs.wyz,
+                yz: -self.x * rhs.zyx,
+                zx: -self.y * rhs.zyx,
+                xy: -self.z * rhs.zyx,
+            },
+            a: Quadvector {
+                xyzw: self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Quadvector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.x * rhs.xyzw,
+            wzx: self.y * rhs.xyzw,
+            wxy: self.z * rhs.xyzw,
+            zyx: T::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Vector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.xy * rhs.y - self.zx * rhs.z,
+                y: self.yz * rhs.z - self.xy * rhs.x,
+                z: self.zx * rhs.x - self.yz * rhs.y,
+                w: self.wx * rhs.x + self.wy * rhs.y + self.wz * rhs.z,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wy * rhs.z + self.yz * rhs.w - self.wz * rhs.y,
+                wzx: self.wz * rhs.x + self.zx * rhs.w - self.wx * rhs.z,
+                wxy: self.wx * rhs.y + self.xy * rhs.w - self.wy * rhs.x,
+                zyx: -(self.yz * rhs.x + self.zx * rhs.y + self.xy * rhs.z),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(-(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy)),
+            b: Bivector {
+                wx: self.wz * rhs.zx + self.xy * rhs.wy - self.wy * rhs.xy - self.zx * rhs.wz,
+                wy: self.wx * rhs.xy + self.yz * rhs.wz - self.wz * rhs.yz - self.xy * rhs.wx,
+                wz: self.wy * rhs.yz + self.zx * rhs.wx - self.wx * rhs.zx - self.yz * rhs.wy,
+                yz: self.xy * rhs.zx - self.zx * rhs.xy,
+                zx: self.yz * rhs.xy - self.xy * rhs.yz,
+                xy: self.zx * rhs.yz - self.yz * rhs.zx,
+            },
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.yz
+                    + self.wy * rhs.zx
+                    + self.wz * rhs.xy
+                    + self.yz * rhs.wx
+                    + self.zx * rhs.wy
+                    + self.xy * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.yz * rhs.zyx,
+                y: self.zx * rhs.zyx,
+                z: self.xy * rhs.zyx,
+                w: -(self.yz * rhs.wyz + self.zx * rhs.wzx + self.xy * rhs.wxy),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.xy * rhs.wzx - self.wx * rhs.zyx - self.zx * rhs.wxy,
+                wzx: self.yz * rhs.wxy - self.wy * rhs.zyx - self.xy * rhs.wyz,
+                wxy: self.zx * rhs.wyz - self.wz * rhs.zyx - self.yz * rhs.wzx,
+                zyx: T::ZERO,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Quadvector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Bivector {
+            wx: self.yz * rhs.xyzw,
+            wy: self.zx * rhs.xyzw,
+            wz: self.xy * rhs.xyzw,
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: T::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Vector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::ZERO,
+            b: Bivector {
+                wx: self.wxy * rhs.y - self.wzx * rhs.z,
+                wy: self.wyz * rhs.z - self.wxy * rhs.x,
+                wz: self.wzx * rhs.x - self.wyz * rhs.y,
+                yz: -self.zyx * rhs.x,
+                zx: -self.zyx * rhs.y,
+                xy: -self.zyx * rhs.z,
+            },
+            a: Quadvector {
+                xyzw: -(self.wyz * rhs.x + self.wzx * rhs.y + self.wxy * rhs.z + self.zyx * rhs.w),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.zyx * rhs.yz,
+                y: self.zyx * rhs.zx,
+                z: self.zyx * rhs.xy,
+                w: -(self.wyz * rhs.yz + self.wzx * rhs.zx + self.wxy * rhs.xy),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wxy * rhs.zx + self.zyx * rhs.wx - self.wzx * rhs.xy,
+                wzx: self.wyz * rhs.xy + self.zyx * rhs.wy - self.wxy * rhs.yz,
+                wxy: self.wzx * rhs.yz + self.zyx * rhs.wz - self.wyz * rhs.zx,
+                zyx: T::ZERO,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(-self.zyx * rhs.zyx),
+            b: Bivector {
+                wx: self.wyz * rhs.zyx - self.zyx * rhs.wyz,
+                wy: self.wzx * rhs.zyx - self.zyx * rhs.wzx,
+                wz: self.wxy * rhs.zyx - self.zyx * rhs.wxy,
+                yz: T::ZERO,
+                zx: T::ZERO,
+                xy: T::ZERO,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Quadvector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: self.zyx * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Vector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Trivector {
+            wyz: -self.xyzw * rhs.x,
+            wzx: -self.xyzw * rhs.y,
+            wxy: -self.xyzw * rhs.z,
+            zyx: T::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Bivector {
+            wx: self.xyzw * rhs.yz,
+            wy: self.xyzw * rhs.zx,
+            wz: self.xyzw * rhs.xy,
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: T::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: -self.xyzw * rhs.zyx,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Quadvector<T>> for Quadvector<T>
+where
+    T: Copy,
+{
+    type Output = ();
+
+    fn geometric_product(&self, _rhs: &Quadvector<T>) -> Self::Output {}
+}
+
+impl<T> GeometricProduct<Evenvector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.x * rhs.s.0 + self.z * rhs.b.zx - self.y * rhs.b.xy,
+                y: self.x * rhs.b.xy + self.y * rhs.s.0 - self.z * rhs.b.yz,
+                z: self.y * rhs.b.yz + self.z * rhs.s.0 - self.x * rhs.b.zx,
+                w: self.w * rhs.s.0 - self.x * rhs.b.wx - self.y * rhs.b.wy - self.z * rhs.b.wz,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.x * rhs.a.xyzw + self.z * rhs.b.wy + self.w * rhs.b.yz
+                    - self.y * rhs.b.wz,
+                wzx: self.x * rhs.b.wz + self.y * rhs.a.xyzw + self.w * rhs.b.zx
+                    - self.z * rhs.b.wx,
+                wxy: self.y * rhs.b.wx + self.z * rhs.a.xyzw + self.w * rhs.b.xy
+                    - self.x * rhs.b.wy,
+                zyx: -(self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(self.x * rhs.v.x + self.y * rhs.v.y + self.z * rhs.v.z),
+            v: Vector {
+                x: self.x * rhs.s.0 + self.z * rhs.b.zx - self.y * rhs.b.xy,
+                y: self.x * rhs.b.xy + self.y * rhs.s.0 - self.z * rhs.b.yz,
+                z: self.y * rhs.b.yz + self.z * rhs.s.0 - self.x * rhs.b.zx,
+                w: self.w * rhs.s.0 - self.x * rhs.b.wx - self.y * rhs.b.wy - self.z * rhs.b.wz,
+            },
+            b: Bivector {
+                wx: self.y * rhs.t.wxy + self.w * rhs.v.x - self.x * rhs.v.w - self.z * rhs.t.wzx,
+                wy: self.z * rhs.t.wyz + self.w * rhs.v.y - self.x * rhs.t.wxy - self.y * rhs.v.w,
+                wz: self.x * rhs.t.wzx + self.w * rhs.v.z - self.y * rhs.t.wyz - self.z * rhs.v.w,
+                yz: self.y * rhs.v.z - self.x * rhs.t.zyx - self.z * rhs.v.y,
+                zx: self.z * rhs.v.x - self.x * rhs.v.z - self.y * rhs.t.zyx,
+                xy: self.x * rhs.v.y - self.y * rhs.v.x - self.z * rhs.t.zyx,
+            },
+            t: Trivector {
+                wyz: self.x * rhs.a.xyzw + self.z * rhs.b.wy + self.w * rhs.b.yz
+                    - self.y * rhs.b.wz,
+                wzx: self.x * rhs.b.wz + self.y * rhs.a.xyzw + self.w * rhs.b.zx
+                    - self.z * rhs.b.wx,
+                wxy: self.y * rhs.b.wx + self.z * rhs.a.xyzw + self.w * rhs.b.xy
+                    - self.x * rhs.b.wy,
+                zyx: -(self.x * rhs.b.yz + self.y * rhs.b.zx + self.z * rhs.b.xy),
+            },
+            a: Quadvector {
+                xyzw: self.x * rhs.t.wyz
+                    + self.y * rhs.t.wzx
+                    + self.z * rhs.t.wxy
+                    + self.w * rhs.t.zyx,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(-(self.yz * rhs.b.yz + self.zx * rhs.b.zx + self.xy * rhs.b.xy)),
+            b: Bivector {
+                wx: self.wx * rhs.s.0
+                    + self.wz * rhs.b.zx
+                    + self.yz * rhs.a.xyzw
+                    + self.xy * rhs.b.wy
+                    - self.wy * rhs.b.xy
+                    - self.zx * rhs.b.wz,
+                wy: self.wx * rhs.b.xy
+                    + self.wy * rhs.s.0
+                    + self.yz * rhs.b.wz
+                    + self.zx * rhs.a.xyzw
+                    - self.wz * rhs.b.yz
+                    - self.xy * rhs.b.wx,
+                wz: self.wy * rhs.b.yz
+                    + self.wz * rhs.s.0
+                    + self.zx * rhs.b.wx
+                    + self.xy * rhs.a.xyzw
+                    - self.wx * rhs.b.zx
+                    - self.yz * rhs.b.wy,
+                yz: self.yz * rhs.s.0 + self.xy * rhs.b.zx - self.zx * rhs.b.xy,
+                zx: self.yz * rhs.b.xy + self.zx * rhs.s.0 - self.xy * rhs.b.yz,
+                xy: self.zx * rhs.b.yz + self.xy * rhs.s.0 - self.yz * rhs.b.zx,
+            },
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.b.yz
+                    + self.wy * rhs.b.zx
+                    + self.wz * rhs.b.xy
+                    + self.yz * rhs.b.wx
+                    + self.zx * rhs.b.wy
+                    + self.xy * rhs.b.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(-(self.yz * rhs.b.yz + self.zx * rhs.b.zx + self.xy * rhs.b.xy)),
+            v: Vector {
+                x: self.yz * rhs.t.zyx + self.xy * rhs.v.y - self.zx * rhs.v.z,
+                y: self.yz * rhs.v.z + self.zx * rhs.t.zyx - self.xy * rhs.v.x,
+                z: self.zx * rhs.v.x + self.xy * rhs.t.zyx - self.yz * rhs.v.y,
+                w: self.wx * rhs.v.x + self.wy * rhs.v.y + self.wz * rhs.v.z
+                    - self.yz * rhs.t.wyz
+                    - self.zx * rhs.t.wzx
+                    - self.xy * rhs.t.wxy,
+            },
+            b: Bivector {
+                wx: self.wx * rhs.s.0
+                    + self.wz * rhs.b.zx
+                    + self.yz * rhs.a.xyzw
+                    + self.xy * rhs.b.wy
+                    - self.wy * rhs.b.xy
+                    - self.zx * rhs.b.wz,
+                wy: self.wx * rhs.b.xy
+                    + self.wy * rhs.s.0
+                    + self.yz * rhs.b.wz
+                    + self.zx * rhs.a.xyzw
+                    - self.wz * rhs.b.yz
+                    - self.xy * rhs.b.wx,
+                wz: self.wy * rhs.b.yz
+                    + self.wz * rhs.s.0
+                    + self.zx * rhs.b.wx
+                    + self.xy * rhs.a.xyzw
+                    - self.wx * rhs.b.zx
+                    - self.yz * rhs.b.wy,
+                yz: self.yz * rhs.s.0 + self.xy * rhs.b.zx - self.zx * rhs.b.xy,
+                zx: self.yz * rhs.b.xy + self.zx * rhs.s.0 - self.xy * rhs.b.yz,
+                xy: self.zx * rhs.b.yz + self.xy * rhs.s.0 - self.yz * rhs.b.zx,
+            },
+            t: Trivector {
+                wyz: self.wy * rhs.v.z + self.yz * rhs.v.w + self.xy * rhs.t.wzx
+                    - self.wx * rhs.t.zyx
+                    - self.wz * rhs.v.y
+                    - self.zx * rhs.t.wxy,
+                wzx: self.wz * rhs.v.x + self.yz * rhs.t.wxy + self.zx * rhs.v.w
+                    - self.wx * rhs.v.z
+                    - self.wy * rhs.t.zyx
+                    - self.xy * rhs.t.wyz,
+                wxy: self.wx * rhs.v.y + self.zx * rhs.t.wyz + self.xy * rhs.v.w
+                    - self.wy * rhs.v.x
+                    - self.wz * rhs.t.zyx
+                    - self.yz * rhs.t.wzx,
+                zyx: -(self.yz * rhs.v.x + self.zx * rhs.v.y + self.xy * rhs.v.z),
+            },
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.b.yz
+                    + self.wy * rhs.b.zx
+                    + self.wz * rhs.b.xy
+                    + self.yz * rhs.b.wx
+                    + self.zx * rhs.b.wy
+                    + self.xy * rhs.b.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.zyx * rhs.b.yz,
+                y: self.zyx * rhs.b.zx,
+                z: self.zyx * rhs.b.xy,
+                w: self.zyx * rhs.a.xyzw
+                    - self.wyz * rhs.b.yz
+                    - self.wzx * rhs.b.zx
+                    - self.wxy * rhs.b.xy,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wyz * rhs.s.0 + self.wxy * rhs.b.zx + self.zyx * rhs.b.wx
+                    - self.wzx * rhs.b.xy,
+                wzx: self.wyz * rhs.b.xy + self.wzx * rhs.s.0 + self.zyx * rhs.b.wy
+                    - self.wxy * rhs.b.yz,
+                wxy: self.wzx * rhs.b.yz + self.wxy * rhs.s.0 + self.zyx * rhs.b.wz
+                    - self.wyz * rhs.b.zx,
+                zyx: self.zyx * rhs.s.0,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(-self.zyx * rhs.t.zyx),
+            v: Vector {
+                x: self.zyx * rhs.b.yz,
+                y: self.zyx * rhs.b.zx,
+                z: self.zyx * rhs.b.xy,
+                w: self.zyx * rhs.a.xyzw
+                    - self.wyz * rhs.b.yz
+                    - self.wzx * rhs.b.zx
+                    - self.wxy * rhs.b.xy,
+            },
+            b: Bivector {
+                wx: self.wyz * rhs.t.zyx + self.wxy * rhs.v.y
+                    - self.wzx * rhs.v.z
+                    - self.zyx * rhs.t.wyz,
+                wy: self.wyz * rhs.v.z + self.wzx * rhs.t.zyx
+                    - self.wxy * rhs.v.x
+                    - self.zyx * rhs.t.wzx,
+                wz: self.wzx * rhs.v.x + self.wxy * rhs.t.zyx
+                    - self.wyz * rhs.v.y
+                    - self.zyx * rhs.t.wxy,
+                yz: -self.zyx * rhs.v.x,
+                zx: -self.zyx * rhs.v.y,
+                xy: -self.zyx * rhs.v.z,
+            },
+            t: Trivector {
+                wyz: self.wyz * rhs.s.0 + self.wxy * rhs.b.zx + self.zyx * rhs.b.wx
+                    - self.wzx * rhs.b.xy,
+                wzx: self.wyz * rhs.b.xy + self.wzx * rhs.s.0 + self.zyx * rhs.b.wy
+                    - self.wxy * rhs.b.yz,
+                wxy: self.wzx * rhs.b.yz + self.wxy * rhs.s.0 + self.zyx * rhs.b.wz
+                    - self.wyz * rhs.b.zx,
+                zyx: self.zyx * rhs.s.0,
+            },
+            a: Quadvector {
+                xyzw: -(self.wyz * rhs.v.x
+                    + self.wzx * rhs.v.y
+                    + self.wxy * rhs.v.z
+                    + self.zyx * rhs.v.w),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::ZERO,
+            b: Bivector {
+                wx: self.xyzw * rhs.b.yz,
+                wy: self.xyzw * rhs.b.zx,
+                wz: self.xyzw * rhs.b.xy,
+                yz: T::ZERO,
+                zx: T::ZERO,
+                xy: T::ZERO,
+            },
+            a: Quadvector {
+                xyzw: self.xyzw * rhs.s.0,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: T::ZERO,
+                w: -self.xyzw * rhs.t.zyx,
+            },
+            b: Bivector {
+                wx: self.xyzw * rhs.b.yz,
+                wy: self.xyzw * rhs.b.zx,
+                wz: self.xyzw * rhs.b.xy,
+                yz: T::ZERO,
+                zx: T::ZERO,
+                xy: T::ZERO,
+            },
+            t: Trivector {
+                wyz: -self.xyzw * rhs.v.x,
+                wzx: -self.xyzw * rhs.v.y,
+                wxy: -self.xyzw * rhs.v.z,
+                zyx: T::ZERO,
+            },
+            a: Quadvector {
+                xyzw: self.xyzw * rhs.s.0,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Vector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.s.0 * rhs.x + self.b.xy * rhs.y - self.b.zx * rhs.z,
+                y: self.s.0 * rhs.y + self.b.yz * rhs.z - self.b.xy * rhs.x,
+                z: self.s.0 * rhs.z + self.b.zx * rhs.x - self.b.yz * rhs.y,
+                w: self.s.0 * rhs.w + self.b.wx * rhs.x + self.b.wy * rhs.y + self.b.wz * rhs.z,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.b.wy * rhs.z + self.b.yz * rhs.w
+                    - self.b.wz * rhs.y
+                    - self.a.xyzw * rhs.x,
+                wzx: self.b.wz * rhs.x + self.b.zx * rhs.w
+                    - self.b.wx * rhs.z
+                    - self.a.xyzw * rhs.y,
+                wxy: self.b.wx * rhs.y + self.b.xy * rhs.w
+                    - self.b.wy * rhs.x
+                    - self.a.xyzw * rhs.z,
+                zyx: -(self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(-(self.b.yz * rhs.yz + self.b.zx * rhs.zx + self.b.xy * rhs.xy)),
+            b: Bivector {
+                wx: self.s.0 * rhs.wx
+                    + self.b.wz * rhs.zx
+                    + self.b.xy * rhs.wy
+                    + self.a.xyzw * rhs.yz
+                    - self.b.wy * rhs.xy
+                    - self.b.zx * rhs.wz,
+                wy: self.s.0 * rhs.wy
+                    + self.b.wx * rhs.xy
+                    + self.b.yz * rhs.wz
+                    + self.a.xyzw * rhs.zx
+                    - self.b.wz * rhs.yz
+                    - self.b.xy * rhs.wx,
+                wz: self.s.0 * rhs.wz
+                    + self.b.wy * rhs.yz
+                    + self.b.zx * rhs.wx
+                    + self.a.xyzw * rhs.xy
+                    - self.b.wx * rhs.zx
+                    - self.b.yz * rhs.wy,
+                yz: self.s.0 * rhs.yz + self.b.xy * rhs.zx - self.b.zx * rhs.xy,
+                zx: self.s.0 * rhs.zx + self.b.yz * rhs.xy - self.b.xy * rhs.yz,
+                xy: self.s.0 * rhs.xy + self.b.zx * rhs.yz - self.b.yz * rhs.zx,
+            },
+            a: Quadvector {
+                xyzw: -(self.b.wx * rhs.yz
+                    + self.b.wy * rhs.zx
+                    + self.b.wz * rhs.xy
+                    + self.b.yz * rhs.wx
+                    + self.b.zx * rhs.wy
+                    + self.b.xy * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.b.yz * rhs.zyx,
+                y: self.b.zx * rhs.zyx,
+                z: self.b.xy * rhs.zyx,
+                w: -(self.b.yz * rhs.wyz
+                    + self.b.zx * rhs.wzx
+                    + self.b.xy * rhs.wxy
+                    + self.a.xyzw * rhs.zyx),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.s.0 * rhs.wyz + self.b.xy * rhs.wzx
+                    - self.b.wx * rhs.zyx
+                    - self.b.zx * rhs.wxy,
+                wzx: self.s.0 * rhs.wzx + self.b.yz * rhs.wxy
+                    - self.b.wy * rhs.zyx
+                    - self.b.xy * rhs.wyz,
+                wxy: self.s.0 * rhs.wxy + self.b.zx * rhs.wyz
+                    - self.b.wz * rhs.zyx
+                    - self.b.yz * rhs.wzx,
+                zyx: self.s.0 * rhs.zyx,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricProduct<Quadvector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::ZERO,
+            b: Bivector {
+                wx: self.b.yz * rhs.xyzw,
+                wy: self.b.zx * rhs.xyzw,
+                wz: self.b.xy * rhs.xyzw,
+                yz: T::ZERO,
+                zx: T::ZERO,
+                xy: T::ZERO,
+            },
+            a: Quadvector {
+                xyzw: self.s.0 * rhs.xyzw,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                self.s.0 * rhs.s.0
+                    - self.b.yz * rhs.b.yz
+                    - self.b.zx * rhs.b.zx
+                    - self.b.xy * rhs.b.xy,
+            ),
+            b: Bivector {
+                wx: self.s.0 * rhs.b.wx
+                    + self.b.wx * rhs.s.0
+                    + self.b.wz * rhs.b.zx
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wy
+                    + self.a.xyzw * rhs.b.yz
+                    - self.b.wy * rhs.b.xy
+                    - self.b.zx * rhs.b.wz,
+                wy: self.s.0 * rhs.b.wy
+                    + self.b.wx * rhs.b.xy
+                    + self.b.wy * rhs.s.0
+                    + self.b.yz * rhs.b.wz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.zx
+                    - self.b.wz * rhs.b.yz
+                    - self.b.xy * rhs.b.wx,
+                wz: self.s.0 * rhs.b.wz
+                    + self.b.wy * rhs.b.yz
+                    + self.b.wz * rhs.s.0
+                    + self.b.zx * rhs.b.wx
+                    + self.b.xy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.xy
+                    - self.b.wx * rhs.b.zx
+                    - self.b.yz * rhs.b.wy,
+                yz: self.s.0 * rhs.b.yz + self.b.yz * rhs.s.0 + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s.0 * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s.0
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s.0 * rhs.b.xy + self.b.zx * rhs.b.yz + self.b.xy * rhs.s.0
+                    - self.b.yz * rhs.b.zx,
+            },
+            a: Quadvector {
+                xyzw: self.s.0 * rhs.a.xyzw + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.s.0 * rhs.s.0
+                    - self.b.yz * rhs.b.yz
+                    - self.b.zx * rhs.b.zx
+                    - self.b.xy * rhs.b.xy,
+            ),
+            v: Vector {
+                x: self.s.0 * rhs.v.x + self.b.yz * rhs.t.zyx + self.b.xy * rhs.v.y
+                    - self.b.zx * rhs.v.z,
+                y: self.s.0 * rhs.v.y + self.b.yz * rhs.v.z + self.b.zx * rhs.t.zyx
+                    - self.b.xy * rhs.v.x,
+                z: self.s.0 * rhs.v.z + self.b.zx * rhs.v.x + self.b.xy * rhs.t.zyx
+                    - self.b.yz * rhs.v.y,
+                w: self.s.0 * rhs.v.w
+                    + self.b.wx * rhs.v.x
+                    + self.b.wy * rhs.v.y
+                    + self.b.wz * rhs.v.z
+                    - self.b.yz * rhs.t.wyz
+                    - self.b.zx * rhs.t.wzx
+                    - self.b.xy * rhs.t.wxy
+                    - self.a.xyzw * rhs.t.zyx,
+            },
+            b: Bivector {
+                wx: self.s.0 * rhs.b.wx
+                    + self.b.wx * rhs.s.0
+                    + self.b.wz * rhs.b.zx
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wy
+                    + self.a.xyzw * rhs.b.yz
+                    - self.b.wy * rhs.b.xy
+                    - self.b.zx * rhs.b.wz,
+                wy: self.s.0 * rhs.b.wy
+                    + self.b.wx * rhs.b.xy
+                    + self.b.wy * rhs.s.0
+                    + self.b.yz * rhs.b.wz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.zx
+                    - self.b.wz * rhs.b.yz
+                    - self.b.xy * rhs.b.wx,
+                wz: self.s.0 * rhs.b.wz
+                    + self.b.wy * rhs.b.yz
+                    + self.b.wz * rhs.s.0
+                    + self.b.zx * rhs.b.wx
+                    + self.b.xy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.xy
+                    - self.b.wx * rhs.b.zx
+                    - self.b.yz * rhs.b.wy,
+                yz: self.s.0 * rhs.b.yz + self.b.yz * rhs.s.0 + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s.0 * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s.0
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s.0 * rhs.b.xy + self.b.zx * rhs.b.yz + self.b.xy * rhs.s.0
+                    - self.b.yz * rhs.b.zx,
+            },
+            t: Trivector {
+                wyz: self.s.0 * rhs.t.wyz
+                    + self.b.wy * rhs.v.z
+                    + self.b.yz * rhs.v.w
+                    + self.b.xy * rhs.t.wzx
+                    - self.b.wx * rhs.t.zyx
+                    - self.b.wz * rhs.v.y
+                    - self.b.zx * rhs.t.wxy
+                    - self.a.xyzw * rhs.v.x,
+                wzx: self.s.0 * rhs.t.wzx
+                    + self.b.wz * rhs.v.x
+                    + self.b.yz * rhs.t.wxy
+                    + self.b.zx * rhs.v.w
+                    - self.b.wx * rhs.v.z
+                    - self.b.wy * rhs.t.zyx
+                    - self.b.xy * rhs.t.wyz
+                    - self.a.xyzw * rhs.v.y,
+                wxy: self.s.0 * rhs.t.wxy
+                    + self.b.wx * rhs.v.y
+                    + self.b.zx * rhs.t.wyz
+                    + self.b.xy * rhs.v.w
+                    - self.b.wy * rhs.v.x
+                    - self.b.wz * rhs.t.zyx
+                    - self.b.yz * rhs.t.wzx
+                    - self.a.xyzw * rhs.v.z,
+                zyx: self.s.0 * rhs.t.zyx
+                    - self.b.yz * rhs.v.x
+                    - self.b.zx * rhs.v.y
+                    - self.b.xy * rhs.v.z,
+            },
+            a: Quadvector {
+                xyzw: self.s.0 * rhs.a.xyzw + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Vector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(self.v.x * rhs.x + self.v.y * rhs.y + self.v.z * rhs.z),
+            v: Vector {
+                x: self.s.0 * rhs.x + self.b.xy * rhs.y - self.b.zx * rhs.z,
+                y: self.s.0 * rhs.y + self.b.yz * rhs.z - self.b.xy * rhs.x,
+                z: self.s.0 * rhs.z + self.b.zx * rhs.x - self.b.yz * rhs.y,
+                w: self.s.0 * rhs.w + self.b.wx * rhs.x + self.b.wy * rhs.y + self.b.wz * rhs.z,
+            },
+            b: Bivector {
+                wx: self.v.w * rhs.x + self.t.wxy * rhs.y - self.v.x * rhs.w - self.t.wzx * rhs.z,
+                wy: self.v.w * rhs.y + self.t.wyz * rhs.z - self.v.y * rhs.w - self.t.wxy * rhs.x,
+                wz: self.v.w * rhs.z + self.t.wzx * rhs.x - self.v.z * rhs.w - self.t.wyz * rhs.y,
+                yz: self.v.y * rhs.z - self.v.z * rhs.y - self.t.zyx * rhs.x,
+                zx: self.v.z * rhs.x - self.v.x * rhs.z - self.t.zyx * rhs.y,
+                xy: self.v.x * rhs.y - self.v.y * rhs.x - self.t.zyx * rhs.z,
+            },
+            t: Trivector {
+                wyz: self.b.wy * rhs.z + self.b.yz * rhs.w
+                    - self.b.wz * rhs.y
+                    - self.a.xyzw * rhs.x,
+                wzx: self.b.wz * rhs.x + self.b.zx * rhs.w
+                    - self.b.wx * rhs.z
+                    - self.a.xyzw * rhs.y,
+                wxy: self.b.wx * rhs.y + self.b.xy * rhs.w
+                    - self.b.wy * rhs.x
+                    - self.a.xyzw * rhs.z,
+                zyx: -(self.b.yz * rhs.x + self.b.zx * rhs.y + self.b.xy * rhs.z),
+            },
+            a: Quadvector {
+                xyzw: -(self.t.wyz * rhs.x
+                    + self.t.wzx * rhs.y
+                    + self.t.wxy * rhs.z
+                    + self.t.zyx * rhs.w),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Bivector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(-(self.b.yz * rhs.yz + self.b.zx * rhs.zx + self.b.xy * rhs.xy)),
+            v: Vector {
+                x: self.v.z * rhs.zx + self.t.zyx * rhs.yz - self.v.y * rhs.xy,
+                y: self.v.x * rhs.xy + self.t.zyx * rhs.zx - self.v.z * rhs.yz,
+                z: self.v.y * rhs.yz + self.t.zyx * rhs.xy - self.v.x * rhs.zx,
+                w: -(self.v.x * rhs.wx
+                    + self.v.y * rhs.wy
+                    + self.v.z * rhs.wz
+                    + self.t.wyz * rhs.yz
+                    + self.t.wzx * rhs.zx
+                    + self.t.wxy * rhs.xy),
+            },
+            b: Bivector {
+                wx: self.s.0 * rhs.wx
+                    + self.b.wz * rhs.zx
+                    + self.b.xy * rhs.wy
+                    + self.a.xyzw * rhs.yz
+                    - self.b.wy * rhs.xy
+                    - self.b.zx * rhs.wz,
+                wy: self.s.0 * rhs.wy
+                    + self.b.wx * rhs.xy
+                    + self.b.yz * rhs.wz
+                    + self.a.xyzw * rhs.zx
+                    - self.b.wz * rhs.yz
+                    - self.b.xy * rhs.wx,
+                wz: self.s.0 * rhs.wz
+                    + self.b.wy * rhs.yz
+                    + self.b.zx * rhs.wx
+                    + self.a.xyzw * rhs.xy
+                    - self.b.wx * rhs.zx
+                    - self.b.yz * rhs.wy,
+                yz: self.s.0 * rhs.yz + self.b.xy * rhs.zx - self.b.zx * rhs.xy,
+                zx: self.s.0 * rhs.zx + self.b.yz * rhs.xy - self.b.xy * rhs.yz,
+                xy: self.s.0 * rhs.xy + self.b.zx * rhs.yz - self.b.yz * rhs.zx,
+            },
+            t: Trivector {
+                wyz: self.v.z * rhs.wy
+                    + self.v.w * rhs.yz
+                    + self.t.wxy * rhs.zx
+                    + self.t.zyx * rhs.wx
+                    - self.v.y * rhs.wz
+                    - self.t.wzx * rhs.xy,
+                wzx: self.v.x * rhs.wz
+                    + self.v.w * rhs.zx
+                    + self.t.wyz * rhs.xy
+                    + self.t.zyx * rhs.wy
+                    - self.v.z * rhs.wx
+                    - self.t.wxy * rhs.yz,
+                wxy: self.v.y * rhs.wx
+                    + self.v.w * rhs.xy
+                    + self.t.wzx * rhs.yz
+                    + self.t.zyx * rhs.wz
+                    - self.v.x * rhs.wy
+                    - self.t.wyz * rhs.zx,
+                zyx: -(self.v.x * rhs.yz + self.v.y * rhs.zx + self.v.z * rhs.xy),
+            },
+            a: Quadvector {
+                xyzw: -(self.b.wx * rhs.yz
+                    + self.b.wy * rhs.zx
+                    + self.b.wz * rhs.xy
+                    + self.b.yz * rhs.wx
+                    + self.b.zx * rhs.wy
+                    + self.b.xy * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Trivector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(-self.t.zyx * rhs.zyx),
+            v: Vector {
+                x: self.b.yz * rhs.zyx,
+                y: self.b.zx * rhs.zyx,
+                z: self.b.xy * rhs.zyx,
+                w: -(self.b.yz * rhs.wyz
+                    + self.b.zx * rhs.wzx
+                    + self.b.xy * rhs.wxy
+                    + self.a.xyzw * rhs.zyx),
+            },
+            b: Bivector {
+                wx: self.v.y * rhs.wxy + self.t.wyz * rhs.zyx
+                    - self.v.z * rhs.wzx
+                    - self.t.zyx * rhs.wyz,
+                wy: self.v.z * rhs.wyz + self.t.wzx * rhs.zyx
+                    - self.v.x * rhs.wxy
+                    - self.t.zyx * rhs.wzx,
+                wz: self.v.x * rhs.wzx + self.t.wxy * rhs.zyx
+                    - self.v.y * rhs.wyz
+                    - self.t.zyx * rhs.wxy,
+                yz: -self.v.x * rhs.zyx,
+                zx: -self.v.y * rhs.zyx,
+                xy: -self.v.z * rhs.zyx,
+            },
+            t: Trivector {
+                wyz: self.s.0 * rhs.wyz + self.b.xy * rhs.wzx
+                    - self.b.wx * rhs.zyx
+                    - self.b.zx * rhs.wxy,
+                wzx: self.s.0 * rhs.wzx + self.b.yz * rhs.wxy
+                    - self.b.wy * rhs.zyx
+                    - self.b.xy * rhs.wyz,
+                wxy: self.s.0 * rhs.wxy + self.b.zx * rhs.wyz
+                    - self.b.wz * rhs.zyx
+                    - self.b.yz * rhs.wzx,
+                zyx: self.s.0 * rhs.zyx,
+            },
+            a: Quadvector {
+                xyzw: self.v.x * rhs.wyz
+                    + self.v.y * rhs.wzx
+                    + self.v.z * rhs.wxy
+                    + self.v.w * rhs.zyx,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Quadvector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: T::ZERO,
+                y: T::ZERO,
+                z: T::ZERO,
+                w: self.t.zyx * rhs.xyzw,
+            },
+            b: Bivector {
+                wx: self.b.yz * rhs.xyzw,
+                wy: self.b.zx * rhs.xyzw,
+                wz: self.b.xy * rhs.xyzw,
+                yz: T::ZERO,
+                zx: T::ZERO,
+                xy: T::ZERO,
+            },
+            t: Trivector {
+                wyz: self.v.x * rhs.xyzw,
+                wzx: self.v.y * rhs.xyzw,
+                wxy: self.v.z * rhs.xyzw,
+                zyx: T::ZERO,
+            },
+            a: Quadvector {
+                xyzw: self.s.0 * rhs.xyzw,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Evenvector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.s.0 * rhs.s.0
+                    - self.b.yz * rhs.b.yz
+                    - self.b.zx * rhs.b.zx
+                    - self.b.xy * rhs.b.xy,
+            ),
+            v: Vector {
+                x: self.v.x * rhs.s.0 + self.v.z * rhs.b.zx + self.t.zyx * rhs.b.yz
+                    - self.v.y * rhs.b.xy,
+                y: self.v.x * rhs.b.xy + self.v.y * rhs.s.0 + self.t.zyx * rhs.b.zx
+                    - self.v.z * rhs.b.yz,
+                z: self.v.y * rhs.b.yz + self.v.z * rhs.s.0 + self.t.zyx * rhs.b.xy
+                    - self.v.x * rhs.b.zx,
+                w: self.v.w * rhs.s.0 + self.t.zyx * rhs.a.xyzw
+                    - self.v.x * rhs.b.wx
+                    - self.v.y * rhs.b.wy
+                    - self.v.z * rhs.b.wz
+                    - self.t.wyz * rhs.b.yz
+                    - self.t.wzx * rhs.b.zx
+                    - self.t.wxy * rhs.b.xy,
+            },
+            b: Bivector {
+                wx: self.s.0 * rhs.b.wx
+                    + self.b.wx * rhs.s.0
+                    + self.b.wz * rhs.b.zx
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wy
+                    + self.a.xyzw * rhs.b.yz
+                    - self.b.wy * rhs.b.xy
+                    - self.b.zx * rhs.b.wz,
+                wy: self.s.0 * rhs.b.wy
+                    + self.b.wx * rhs.b.xy
+                    + self.b.wy * rhs.s.0
+                    + self.b.yz * rhs.b.wz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.zx
+                    - self.b.wz * rhs.b.yz
+                    - self.b.xy * rhs.b.wx,
+                wz: self.s.0 * rhs.b.wz
+                    + self.b.wy * rhs.b.yz
+                    + self.b.wz * rhs.s.0
+                    + self.b.zx * rhs.b.wx
+                    + self.b.xy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.xy
+                    - self.b.wx * rhs.b.zx
+                    - self.b.yz * rhs.b.wy,
+                yz: self.s.0 * rhs.b.yz + self.b.yz * rhs.s.0 + self.b.xy * rhs.b.zx
+                    - self.b.zx * rhs.b.xy,
+                zx: self.s.0 * rhs.b.zx + self.b.yz * rhs.b.xy + self.b.zx * rhs.s.0
+                    - self.b.xy * rhs.b.yz,
+                xy: self.s.0 * rhs.b.xy + self.b.zx * rhs.b.yz + self.b.xy * rhs.s.0
+                    - self.b.yz * rhs.b.zx,
+            },
+            t: Trivector {
+                wyz: self.v.x * rhs.a.xyzw
+                    + self.v.z * rhs.b.wy
+                    + self.v.w * rhs.b.yz
+                    + self.t.wyz * rhs.s.0
+                    + self.t.wxy * rhs.b.zx
+                    + self.t.zyx * rhs.b.wx
+                    - self.v.y * rhs.b.wz
+                    - self.t.wzx * rhs.b.xy,
+                wzx: self.v.x * rhs.b.wz
+                    + self.v.y * rhs.a.xyzw
+                    + self.v.w * rhs.b.zx
+                    + self.t.wyz * rhs.b.xy
+                    + self.t.wzx * rhs.s.0
+                    + self.t.zyx * rhs.b.wy
+                    - self.v.z * rhs.b.wx
+                    - self.t.wxy * rhs.b.yz,
+                wxy: self.v.y * rhs.b.wx
+                    + self.v.z * rhs.a.xyzw
+                    + self.v.w * rhs.b.xy
+                    + self.t.wzx * rhs.b.yz
+                    + self.t.wxy * rhs.s.0
+                    + self.t.zyx * rhs.b.wz
+                    - self.v.x * rhs.b.wy
+                    - self.t.wyz * rhs.b.zx,
+                zyx: self.t.zyx * rhs.s.0
+                    - self.v.x * rhs.b.yz
+                    - self.v.y * rhs.b.zx
+                    - self.v.z * rhs.b.xy,
+            },
+            a: Quadvector {
+                xyzw: self.s.0 * rhs.a.xyzw + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz,
+            },
+        }
+    }
+}
+
+impl<T> GeometricProduct<Multivector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.s.0 * rhs.s.0 + self.v.x * rhs.v.x + self.v.y * rhs.v.y + self.v.z * rhs.v.z
+                    - self.b.yz * rhs.b.yz
+                    - self.b.zx * rhs.b.zx
+                    - self.b.xy * rhs.b.xy
+                    - self.t.zyx * rhs.t.zyx,
+            ),
+            v: Vector {
+                x: self.s.0 * rhs.v.x
+                    + self.v.x * rhs.s.0
+                    + self.v.z * rhs.b.zx
+                    + self.b.yz * rhs.t.zyx
+                    + self.b.xy * rhs.v.y
+                    + self.t.zyx * rhs.b.yz
+                    - self.v.y * rhs.b.xy
+                    - self.b.zx * rhs.v.z,
+                y: self.s.0 * rhs.v.y
+                    + self.v.x * rhs.b.xy
+                    + self.v.y * rhs.s.0
+                    + self.b.yz * rhs.v.z
+                    + self.b.zx * rhs.t.zyx
+                    + self.t.zyx * rhs.b.zx
+                    - self.v.z * rhs.b.yz
+                    - self.b.xy * rhs.v.x,
+                z: self.s.0 * rhs.v.z
+                    + self.v.y * rhs.b.yz
+                    + self.v.z * rhs.s.0
+                    + self.b.zx * rhs.v.x
+                    + self.b.xy * rhs.t.zyx
+                    + self.t.zyx * rhs.b.xy
+                    - self.v.x * rhs.b.zx
+                    - self.b.yz * rhs.v.y,
+                w: self.s.0 * rhs.v.w
+                    + self.v.w * rhs.s.0
+                    + self.b.wx * rhs.v.x
+                    + self.b.wy * rhs.v.y
+                    + self.b.wz * rhs.v.z
+                    + self.t.zyx * rhs.a.xyzw
+                    - self.v.x * rhs.b.wx
+                    - self.v.y * rhs.b.wy
+                    - self.v.z * rhs.b.wz
+                    - self.b.yz * rhs.t.wyz
+                    - self.b.zx * rhs.t.wzx
+                    - self.b.xy * rhs.t.wxy
+                    - self.t.wyz * rhs.b.yz
+                    - self.t.wzx * rhs.b.zx
+                    - self.t.wxy * rhs.b.xy
+                    - self.a.xyzw * rhs.t.zyx,
+            },
+            b: Bivector {
+                wx: self.s.0 * rhs.b.wx
+                    + self.v.y * rhs.t.wxy
+                    + self.v.w * rhs.v.x
+                    + self.b.wx * rhs.s.0
+                    + self.b.wz * rhs.b.zx
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wy
+                    + self.t.wyz * rhs.t.zyx
+                    + self.t.wxy * rhs.v.y
+                    + self.a.xyzw * rhs.b.yz
+                    - self.v.x * rhs.v.w
+                    - self.v.z * rhs.t.wzx
+                    - self.b.wy * rhs.b.xy
+                    - self.b.zx * rhs.b.wz
+                    - self.t.wzx * rhs.v.z
+                    - self.t.zyx * rhs.t.wyz,
+                wy: self.s.0 * rhs.b.wy
+                    + self.v.z * rhs.t.wyz
+                    + self.v.w * rhs.v.y
+                    + self.b.wx * rhs.b.xy
+                    + self.b.wy * rhs.s.0
+                    + self.b.yz * rhs.b.wz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.t.wyz * rhs.v.z
+                    + self.t.wzx * rhs.t.zyx
+                    + self.a.xyzw * rhs.b.zx
+                    - self.v.x * rhs.t.wxy
+                    - self.v.y * rhs.v.w
+                    - self.b.wz * rhs.b.yz
+                    - self.b.xy * rhs.b.wx
+                    - self.t.wxy * rhs.v.x
+                    - self.t.zyx * rhs.t.wzx,
+                wz: self.s.0 * rhs.b.wz
+                    + self.v.x * rhs.t.wzx
+                    + self.v.w * rhs.v.z
+                    + self.b.wy * rhs.b.yz
+                    + self.b.wz * rhs.s.0
+                    + self.b.zx * rhs.b.wx
+                    + self.b.xy * rhs.a.xyzw
+                    + self.t.wzx * rhs.v.x
+                    + self.t.wxy * rhs.t.zyx
+                    + self.a.xyzw * rhs.b.xy
+                    - self.v.y * rhs.t.wyz
+                    - self.v.z * rhs.v.w
+                    - self.b.wx * rhs.b.zx
+                    - self.b.yz * rhs.b.wy
+                    - self.t.wyz * rhs.v.y
+                    - self.t.zyx * rhs.t.wxy,
+                yz: self.s.0 * rhs.b.yz
+                    + self.v.y * rhs.v.z
+                    + self.b.yz * rhs.s.0
+                    + self.b.xy * rhs.b.zx
+                    - self.v.x * rhs.t.zyx
+                    - self.v.z * rhs.v.y
+                    - self.b.zx * rhs.b.xy
+                    - self.t.zyx * rhs.v.x,
+                zx: self.s.0 * rhs.b.zx
+                    + self.v.z * rhs.v.x
+                    + self.b.yz * rhs.b.xy
+                    + self.b.zx * rhs.s.0
+                    - self.v.x * rhs.v.z
+                    - self.v.y * rhs.t.zyx
+                    - self.b.xy * rhs.b.yz
+                    - self.t.zyx * rhs.v.y,
+                xy: self.s.0 * rhs.b.xy
+                    + self.v.x * rhs.v.y
+                    + self.b.zx * rhs.b.yz
+                    + self.b.xy * rhs.s.0
+                    - self.v.y * rhs.v.x
+                    - self.v.z * rhs.t.zyx
+                    - self.b.yz * rhs.b.zx
+                    - self.t.zyx * rhs.v.z,
+            },
+            t: Trivector {
+                wyz: self.s.0 * rhs.t.wyz
+                    + self.v.x * rhs.a.xyzw
+                    + self.v.z * rhs.b.wy
+                    + self.v.w * rhs.b.yz
+                    + self.b.wy * rhs.v.z
+                    + self.b.yz * rhs.v.w
+                    + self.b.xy * rhs.t.wzx
+                    + self.t.wyz * rhs.s.0
+                    + self.t.wxy * rhs.b.zx
+                    + self.t.zyx * rhs.b.wx
+                    - self.v.y * rhs.b.wz
+                    - self.b.wx * rhs.t.zyx
+                    - self.b.wz * rhs.v.y
+                    - self.b.zx * rhs.t.wxy
+                    - self.t.wzx * rhs.b.xy
+                    - self.a.xyzw * rhs.v.x,
+                wzx: self.s.0 * rhs.t.wzx
+                    + self.v.x * rhs.b.wz
+                    + self.v.y * rhs.a.xyzw
+                    + self.v.w * rhs.b.zx
+                    + self.b.wz * rhs.v.x
+                    + self.b.yz * rhs.t.wxy
+                    + self.b.zx * rhs.v.w
+                    + self.t.wyz * rhs.b.xy
+                    + self.t.wzx * rhs.s.0
+                    + self.t.zyx * rhs.b.wy
+                    - self.v.z * rhs.b.wx
+                    - self.b.wx * rhs.v.z
+                    - self.b.wy * rhs.t.zyx
+                    - self.b.xy * rhs.t.wyz
+                    - self.t.wxy * rhs.b.yz
+                    - self.a.xyzw * rhs.v.y,
+                wxy: self.s.0 * rhs.t.wxy
+                    + self.v.y * rhs.b.wx
+                    + self.v.z * rhs.a.xyzw
+                    + self.v.w * rhs.b.xy
+                    + self.b.wx * rhs.v.y
+                    + self.b.zx * rhs.t.wyz
+                    + self.b.xy * rhs.v.w
+                    + self.t.wzx * rhs.b.yz
+                    + self.t.wxy * rhs.s.0
+                    + self.t.zyx * rhs.b.wz
+                    - self.v.x * rhs.b.wy
+                    - self.b.wy * rhs.v.x
+                    - self.b.wz * rhs.t.zyx
+                    - self.b.yz * rhs.t.wzx
+                    - self.t.wyz * rhs.b.zx
+                    - self.a.xyzw * rhs.v.z,
+                zyx: self.s.0 * rhs.t.zyx + self.t.zyx * rhs.s.0
+                    - self.v.x * rhs.b.yz
+                    - self.v.y * rhs.b.zx
+                    - self.v.z * rhs.b.xy
+                    - self.b.yz * rhs.v.x
+                    - self.b.zx * rhs.v.y
+                    - self.b.xy * rhs.v.z,
+            },
+            a: Quadvector {
+                xyzw: self.s.0 * rhs.a.xyzw
+                    + self.v.x * rhs.t.wyz
+                    + self.v.y * rhs.t.wzx
+                    + self.v.z * rhs.t.wxy
+                    + self.v.w * rhs.t.zyx
+                    + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz
+                    - self.t.wyz * rhs.v.x
+                    - self.t.wzx * rhs.v.y
+                    - self.t.wxy * rhs.v.z
+                    - self.t.zyx * rhs.v.w,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Geometric product operator
+// ----------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "operators")]
+geometric_mul!(Vector<T>, Vector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Vector<T>, Bivector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Vector<T>, Trivector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Vector<T>, Quadvector<T> => Trivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Vector<T>, Evenvector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Vector<T>, Multivector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Bivector<T>, Vector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Bivector<T>, Bivector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Bivector<T>, Trivector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Bivector<T>, Quadvector<T> => Bivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Bivector<T>, Evenvector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Bivector<T>, Multivector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Trivector<T>, Vector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Trivector<T>, Bivector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Trivector<T>, Trivector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Trivector<T>, Quadvector<T> => Vector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Trivector<T>, Evenvector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Trivector<T>, Multivector<T> => Multivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Quadvector<T>, Vector<T> => Trivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Quadvector<T>, Bivector<T> => Bivector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Quadvector<T>, Trivector<T> => Vector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Quadvector<T>, Quadvector<T> => (), T);
+#[cfg(feature = "operators")]
+geometric_mul!(Quadvector<T>, Evenvector<T> => Evenvector<T>, T);
+#[cfg(feature = "operators")]
+geometric_mul!(Quadvector<T>, Multivector<T> => Multivector<T>, T);
+geometric_mul!(Evenvector<T>, Vector<T> => Multivector<T>, T);
+geometric_mul!(Evenvector<T>, Bivector<T> => Evenvector<T>, T);
+geometric_mul!(Evenvector<T>, Trivector<T> => Multivector<T>, T);
+geometric_mul!(Evenvector<T>, Quadvector<T> => Evenvector<T>, T);
+geometric_mul!(Evenvector<T>, Evenvector<T> => Evenvector<T>, T);
+geometric_mul!(Evenvector<T>, Multivector<T> => Multivector<T>, T);
+geometric_mul!(Multivector<T>, Vector<T> => Multivector<T>, T);
+geometric_mul!(Multivector<T>, Bivector<T> => Multivector<T>, T);
+geometric_mul!(Multivector<T>, Trivector<T> => Multivector<T>, T);
+geometric_mul!(Multivector<T>, Quadvector<T> => Multivector<T>, T);
+geometric_mul!(Multivector<T>, Evenvector<T> => Multivector<T>, T);
+geometric_mul!(Multivector<T>, Multivector<T> => Multivector<T>, T);
+
+// ----------------------------------------------------------------------------------------------------
+// Geometric antiproduct
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> GeometricAntiproduct<Vector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Vector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::ZERO,
+            b: Bivector {
+                wx: T::ZERO,
+                wy: T::ZERO,
+                wz: T::ZERO,
+                yz: self.w * rhs.x - self.x * rhs.w,
+                zx: self.w * rhs.y - self.y * rhs.w,
+                xy: self.w * rhs.z - self.z * rhs.w,
+            },
+            a: Quadvector {
+                xyzw: -self.w * rhs.w,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Bivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.y * rhs.wz - self.z * rhs.wy - self.w * rhs.yz,
+                y: self.z * rhs.wx - self.x * rhs.wz - self.w * rhs.zx,
+                z: self.x * rhs.wy - self.y * rhs.wx - self.w * rhs.xy,
+                w: T::ZERO,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.w * rhs.wx,
+                wzx: self.w * rhs.wy,
+                wxy: self.w * rhs.wz,
+                zyx: -(self.x * rhs.wx + self.y * rhs.wy + self.z * rhs.wz),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Trivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Trivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx,
+            ),
+            b: Bivector {
+                wx: -self.w * rhs.wyz,
+                wy: -self.w * rhs.wzx,
+                wz: -self.w * rhs.wxy,
+                yz: self.z * rhs.wzx - self.y * rhs.wxy,
+                zx: self.x * rhs.wxy - self.z * rhs.wyz,
+                xy: self.y * rhs.wyz - self.x * rhs.wzx,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Quadvector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Vector {
+            x: self.x * rhs.xyzw,
+            y: self.y * rhs.xyzw,
+            z: self.z * rhs.xyzw,
+            w: self.w * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Vector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Vector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.wy * rhs.z + self.yz * rhs.w - self.wz * rhs.y,
+                y: self.wz * rhs.x + self.zx * rhs.w - self.wx * rhs.z,
+                z: self.wx * rhs.y + self.xy * rhs.w - self.wy * rhs.x,
+                w: T::ZERO,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wx * rhs.w,
+                wzx: self.wy * rhs.w,
+                wxy: self.wz * rhs.w,
+                zyx: -(self.wx * rhs.x + self.wy * rhs.y + self.wz * rhs.z),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Bivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                -(self.wx * rhs.yz
+                    + self.wy * rhs.zx
+                    + self.wz * rhs.xy
+                    + self.yz * rhs.wx
+                    + self.zx * rhs.wy
+                    + self.xy * rhs.wz),
+            ),
+            b: Bivector {
+                wx: self.wy * rhs.wz - self.wz * rhs.wy,
+                wy: self.wz * rhs.wx - self.wx * rhs.wz,
+                wz: self.wx * rhs.wy - self.wy * rhs.wx,
+                yz: self.wy * rhs.xy + self.zx * rhs.wz - self.wz * rhs.zx - self.xy * rhs.wy,
+                zx: self.wz * rhs.yz + self.xy * rhs.wx - self.wx * rhs.xy - self.yz * rhs.wz,
+                xy: self.wx * rhs.zx + self.yz * rhs.wy - self.wy * rhs.yz - self.zx * rhs.wx,
+            },
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.wx + self.wy * rhs.wy + self.wz * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Trivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Trivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.wx * rhs.zyx + self.zx * rhs.wxy - self.xy * rhs.wzx,
+                y: self.wy * rhs.zyx + self.xy * rhs.wyz - self.yz * rhs.wxy,
+                z: self.wz * rhs.zyx + self.yz * rhs.wzx - self.zx * rhs.wyz,
+                w: -(self.wx * rhs.wyz + self.wy * rhs.wzx + self.wz * rhs.wxy),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wy * rhs.wxy - self.wz * rhs.wzx,
+                wzx: self.wz * rhs.wyz - self.wx * rhs.wxy,
+                wxy: self.wx * rhs.wzx - self.wy * rhs.wyz,
+                zyx: -(self.yz * rhs.wyz + self.zx * rhs.wzx + self.xy * rhs.wxy),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Quadvector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Bivector {
+            wx: self.wx * rhs.xyzw,
+            wy: self.wy * rhs.xyzw,
+            wz: self.wz * rhs.xyzw,
+            yz: self.yz * rhs.xyzw,
+            zx: self.zx * rhs.xyzw,
+            xy: self.xy * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Vector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Vector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                -(self.wyz * rhs.x + self.wzx * rhs.y + self.wxy * rhs.z + self.zyx * rhs.w),
+            ),
+            b: Bivector {
+                wx: -self.wyz * rhs.w,
+                wy: -self.wzx * rhs.w,
+                wz: -self.wxy * rhs.w,
+                yz: self.wzx * rhs.z - self.wxy * rhs.y,
+                zx: self.wxy * rhs.x - self.wyz * rhs.z,
+                xy: self.wyz * rhs.y - self.wzx * rhs.x,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Bivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.wxy * rhs.zx + self.zyx * rhs.wx - self.wzx * rhs.xy,
+                y: self.wyz * rhs.xy + self.zyx * rhs.wy - self.wxy * rhs.yz,
+                z: self.wzx * rhs.yz + self.zyx * rhs.wz - self.wyz * rhs.zx,
+                w: -(self.wyz * rhs.wx + self.wzx * rhs.wy + self.wxy * rhs.wz),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wzx * rhs.wz - self.wxy * rhs.wy,
+                wzx: self.wxy * rhs.wx - self.wyz * rhs.wz,
+                wxy: self.wyz * rhs.wy - self.wzx * rhs.wx,
+                zyx: self.wyz * rhs.yz + self.wzx * rhs.zx + self.wxy * rhs.xy,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Trivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Trivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::ZERO,
+            b: Bivector {
+                wx: self.wxy * rhs.wzx - self.wzx * rhs.wxy,
+                wy: self.wyz * rhs.wxy - self.wxy * rhs.wyz,
+                wz: self.wzx * rhs.wyz - self.wyz * rhs.wzx,
+                yz: self.wyz * rhs.zyx - self.zyx * rhs.wyz,
+                zx: self.wzx * rhs.zyx - self.zyx * rhs.wzx,
+                xy: self.wxy * rhs.zyx - self.zyx * rhs.wxy,
+            },
+            a: Quadvector {
+                xyzw: self.wyz * rhs.wyz + self.wzx * rhs.wzx + self.wxy * rhs.wxy,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Quadvector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.wyz * rhs.xyzw,
+            wzx: self.wzx * rhs.xyzw,
+            wxy: self.wxy * rhs.xyzw,
+            zyx: self.zyx * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Vector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Vector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Vector<T>) -> Self::Output {
+        Vector {
+            x: self.xyzw * rhs.x,
+            y: self.xyzw * rhs.y,
+            z: self.xyzw * rhs.z,
+            w: self.xyzw * rhs.w,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Bivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Bivector<T>) -> Self::Output {
+        Bivector {
+            wx: self.xyzw * rhs.wx,
+            wy: self.xyzw * rhs.wy,
+            wz: self.xyzw * rhs.wz,
+            yz: self.xyzw * rhs.yz,
+            zx: self.xyzw * rhs.zx,
+            xy: self.xyzw * rhs.xy,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Trivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Trivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Trivector<T>) -> Self::Output {
+        Trivector {
+            wyz: self.xyzw * rhs.wyz,
+            wzx: self.xyzw * rhs.wzx,
+            wxy: self.xyzw * rhs.wxy,
+            zyx: self.xyzw * rhs.zyx,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Quadvector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Quadvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Quadvector {
+            xyzw: self.xyzw * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Evenvector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.x * rhs.a.xyzw + self.y * rhs.b.wz - self.z * rhs.b.wy - self.w * rhs.b.yz,
+                y: self.y * rhs.a.xyzw + self.z * rhs.b.wx - self.x * rhs.b.wz - self.w * rhs.b.zx,
+                z: self.x * rhs.b.wy + self.z * rhs.a.xyzw - self.y * rhs.b.wx - self.w * rhs.b.xy,
+                w: self.w * rhs.a.xyzw,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.w * rhs.b.wx,
+                wzx: self.w * rhs.b.wy,
+                wxy: self.w * rhs.b.wz,
+                zyx: -(self.x * rhs.b.wx
+                    + self.y * rhs.b.wy
+                    + self.z * rhs.b.wz
+                    + self.w * rhs.s.0),
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Multivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.x * rhs.t.wyz + self.y * rhs.t.wzx + self.z * rhs.t.wxy + self.w * rhs.t.zyx,
+            ),
+            v: Vector {
+                x: self.x * rhs.a.xyzw + self.y * rhs.b.wz - self.z * rhs.b.wy - self.w * rhs.b.yz,
+                y: self.y * rhs.a.xyzw + self.z * rhs.b.wx - self.x * rhs.b.wz - self.w * rhs.b.zx,
+                z: self.x * rhs.b.wy + self.z * rhs.a.xyzw - self.y * rhs.b.wx - self.w * rhs.b.xy,
+                w: self.w * rhs.a.xyzw,
+            },
+            b: Bivector {
+                wx: -self.w * rhs.t.wyz,
+                wy: -self.w * rhs.t.wzx,
+                wz: -self.w * rhs.t.wxy,
+                yz: self.z * rhs.t.wzx + self.w * rhs.v.x - self.x * rhs.v.w - self.y * rhs.t.wxy,
+                zx: self.x * rhs.t.wxy + self.w * rhs.v.y - self.y * rhs.v.w - self.z * rhs.t.wyz,
+                xy: self.y * rhs.t.wyz + self.w * rhs.v.z - self.x * rhs.t.wzx - self.z * rhs.v.w,
+            },
+            t: Trivector {
+                wyz: self.w * rhs.b.wx,
+                wzx: self.w * rhs.b.wy,
+                wxy: self.w * rhs.b.wz,
+                zyx: -(self.x * rhs.b.wx
+                    + self.y * rhs.b.wy
+                    + self.z * rhs.b.wz
+                    + self.w * rhs.s.0),
+            },
+            a: Quadvector {
+                xyzw: -self.w * rhs.v.w,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Evenvector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                -(self.wx * rhs.b.yz
+                    + self.wy * rhs.b.zx
+                    + self.wz * rhs.b.xy
+                    + self.yz * rhs.b.wx
+                    + self.zx * rhs.b.wy
+                    + self.xy * rhs.b.wz),
+            ),
+            b: Bivector {
+                wx: self.wx * rhs.a.xyzw + self.wy * rhs.b.wz - self.wz * rhs.b.wy,
+                wy: self.wy * rhs.a.xyzw + self.wz * rhs.b.wx - self.wx * rhs.b.wz,
+                wz: self.wx * rhs.b.wy + self.wz * rhs.a.xyzw - self.wy * rhs.b.wx,
+                yz: self.wx * rhs.s.0
+                    + self.wy * rhs.b.xy
+                    + self.yz * rhs.a.xyzw
+                    + self.zx * rhs.b.wz
+                    - self.wz * rhs.b.zx
+                    - self.xy * rhs.b.wy,
+                zx: self.wy * rhs.s.0
+                    + self.wz * rhs.b.yz
+                    + self.zx * rhs.a.xyzw
+                    + self.xy * rhs.b.wx
+                    - self.wx * rhs.b.xy
+                    - self.yz * rhs.b.wz,
+                xy: self.wx * rhs.b.zx
+                    + self.wz * rhs.s.0
+                    + self.yz * rhs.b.wy
+                    + self.xy * rhs.a.xyzw
+                    - self.wy * rhs.b.yz
+                    - self.zx * rhs.b.wx,
+            },
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.b.wx + self.wy * rhs.b.wy + self.wz * rhs.b.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Multivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                -(self.wx * rhs.b.yz
+                    + self.wy * rhs.b.zx
+                    + self.wz * rhs.b.xy
+                    + self.yz * rhs.b.wx
+                    + self.zx * rhs.b.wy
+                    + self.xy * rhs.b.wz),
+            ),
+            v: Vector {
+                x: self.wx * rhs.t.zyx
+                    + self.wy * rhs.v.z
+                    + self.yz * rhs.v.w
+                    + self.zx * rhs.t.wxy
+                    - self.wz * rhs.v.y
+                    - self.xy * rhs.t.wzx,
+                y: self.wy * rhs.t.zyx
+                    + self.wz * rhs.v.x
+                    + self.zx * rhs.v.w
+                    + self.xy * rhs.t.wyz
+                    - self.wx * rhs.v.z
+                    - self.yz * rhs.t.wxy,
+                z: self.wx * rhs.v.y
+                    + self.wz * rhs.t.zyx
+                    + self.yz * rhs.t.wzx
+                    + self.xy * rhs.v.w
+                    - self.wy * rhs.v.x
+                    - self.zx * rhs.t.wyz,
+                w: -(self.wx * rhs.t.wyz + self.wy * rhs.t.wzx + self.wz * rhs.t.wxy),
+            },
+            b: Bivector {
+                wx: self.wx * rhs.a.xyzw + self.wy * rhs.b.wz - self.wz * rhs.b.wy,
+                wy: self.wy * rhs.a.xyzw + self.wz * rhs.b.wx - self.wx * rhs.b.wz,
+                wz: self.wx * rhs.b.wy + self.wz * rhs.a.xyzw - self.wy * rhs.b.wx,
+                yz: self.wx * rhs.s.0
+                    + self.wy * rhs.b.xy
+                    + self.yz * rhs.a.xyzw
+                    + self.zx * rhs.b.wz
+                    - self.wz * rhs.b.zx
+                    - self.xy * rhs.b.wy,
+                zx: self.wy * rhs.s.0
+                    + self.wz * rhs.b.yz
+                    + self.zx * rhs.a.xyzw
+                    + self.xy * rhs.b.wx
+                    - self.wx * rhs.b.xy
+                    - self.yz * rhs.b.wz,
+                xy: self.wx * rhs.b.zx
+                    + self.wz * rhs.s.0
+                    + self.yz * rhs.b.wy
+                    + self.xy * rhs.a.xyzw
+                    - self.wy * rhs.b.yz
+                    - self.zx * rhs.b.wx,
+            },
+            t: Trivector {
+                wyz: self.wx * rhs.v.w + self.wy * rhs.t.wxy - self.wz * rhs.t.wzx,
+                wzx: self.wy * rhs.v.w + self.wz * rhs.t.wyz - self.wx * rhs.t.wxy,
+                wxy: self.wx * rhs.t.wzx + self.wz * rhs.v.w - self.wy * rhs.t.wyz,
+                zyx: -(self.wx * rhs.v.x
+                    + self.wy * rhs.v.y
+                    + self.wz * rhs.v.z
+                    + self.yz * rhs.t.wyz
+                    + self.zx * rhs.t.wzx
+                    + self.xy * rhs.t.wxy),
+            },
+            a: Quadvector {
+                xyzw: -(self.wx * rhs.b.wx + self.wy * rhs.b.wy + self.wz * rhs.b.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Evenvector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.wxy * rhs.b.zx + self.zyx * rhs.b.wx
+                    - self.wyz * rhs.s.0
+                    - self.wzx * rhs.b.xy,
+                y: self.wyz * rhs.b.xy + self.zyx * rhs.b.wy
+                    - self.wzx * rhs.s.0
+                    - self.wxy * rhs.b.yz,
+                z: self.wzx * rhs.b.yz + self.zyx * rhs.b.wz
+                    - self.wyz * rhs.b.zx
+                    - self.wxy * rhs.s.0,
+                w: -(self.wyz * rhs.b.wx + self.wzx * rhs.b.wy + self.wxy * rhs.b.wz),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.wyz * rhs.a.xyzw + self.wzx * rhs.b.wz - self.wxy * rhs.b.wy,
+                wzx: self.wzx * rhs.a.xyzw + self.wxy * rhs.b.wx - self.wyz * rhs.b.wz,
+                wxy: self.wyz * rhs.b.wy + self.wxy * rhs.a.xyzw - self.wzx * rhs.b.wx,
+                zyx: self.wyz * rhs.b.yz
+                    + self.wzx * rhs.b.zx
+                    + self.wxy * rhs.b.xy
+                    + self.zyx * rhs.a.xyzw,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Multivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                -(self.wyz * rhs.v.x
+                    + self.wzx * rhs.v.y
+                    + self.wxy * rhs.v.z
+                    + self.zyx * rhs.v.w),
+            ),
+            v: Vector {
+                x: self.wxy * rhs.b.zx + self.zyx * rhs.b.wx
+                    - self.wyz * rhs.s.0
+                    - self.wzx * rhs.b.xy,
+                y: self.wyz * rhs.b.xy + self.zyx * rhs.b.wy
+                    - self.wzx * rhs.s.0
+                    - self.wxy * rhs.b.yz,
+                z: self.wzx * rhs.b.yz + self.zyx * rhs.b.wz
+                    - self.wyz * rhs.b.zx
+                    - self.wxy * rhs.s.0,
+                w: -(self.wyz * rhs.b.wx + self.wzx * rhs.b.wy + self.wxy * rhs.b.wz),
+            },
+            b: Bivector {
+                wx: self.wxy * rhs.t.wzx - self.wyz * rhs.v.w - self.wzx * rhs.t.wxy,
+                wy: self.wyz * rhs.t.wxy - self.wzx * rhs.v.w - self.wxy * rhs.t.wyz,
+                wz: self.wzx * rhs.t.wyz - self.wyz * rhs.t.wzx - self.wxy * rhs.v.w,
+                yz: self.wyz * rhs.t.zyx + self.wzx * rhs.v.z
+                    - self.wxy * rhs.v.y
+                    - self.zyx * rhs.t.wyz,
+                zx: self.wzx * rhs.t.zyx + self.wxy * rhs.v.x
+                    - self.wyz * rhs.v.z
+                    - self.zyx * rhs.t.wzx,
+                xy: self.wyz * rhs.v.y + self.wxy * rhs.t.zyx
+                    - self.wzx * rhs.v.x
+                    - self.zyx * rhs.t.wxy,
+            },
+            t: Trivector {
+                wyz: self.wyz * rhs.a.xyzw + self.wzx * rhs.b.wz - self.wxy * rhs.b.wy,
+                wzx: self.wzx * rhs.a.xyzw + self.wxy * rhs.b.wx - self.wyz * rhs.b.wz,
+                wxy: self.wyz * rhs.b.wy + self.wxy * rhs.a.xyzw - self.wzx * rhs.b.wx,
+                zyx: self.wyz * rhs.b.yz
+                    + self.wzx * rhs.b.zx
+                    + self.wxy * rhs.b.xy
+                    + self.zyx * rhs.a.xyzw,
+            },
+            a: Quadvector {
+                xyzw: self.wyz * rhs.t.wyz + self.wzx * rhs.t.wzx + self.wxy * rhs.t.wxy,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Evenvector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(self.xyzw * rhs.s.0),
+            b: Bivector {
+                wx: self.xyzw * rhs.b.wx,
+                wy: self.xyzw * rhs.b.wy,
+                wz: self.xyzw * rhs.b.wz,
+                yz: self.xyzw * rhs.b.yz,
+                zx: self.xyzw * rhs.b.zx,
+                xy: self.xyzw * rhs.b.xy,
+            },
+            a: Quadvector {
+                xyzw: self.xyzw * rhs.a.xyzw,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Multivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(self.xyzw * rhs.s.0),
+            v: Vector {
+                x: self.xyzw * rhs.v.x,
+                y: self.xyzw * rhs.v.y,
+                z: self.xyzw * rhs.v.z,
+                w: self.xyzw * rhs.v.w,
+            },
+            b: Bivector {
+                wx: self.xyzw * rhs.b.wx,
+                wy: self.xyzw * rhs.b.wy,
+                wz: self.xyzw * rhs.b.wz,
+                yz: self.xyzw * rhs.b.yz,
+                zx: self.xyzw * rhs.b.zx,
+                xy: self.xyzw * rhs.b.xy,
+            },
+            t: Trivector {
+                wyz: self.xyzw * rhs.t.wyz,
+                wzx: self.xyzw * rhs.t.wzx,
+                wxy: self.xyzw * rhs.t.wxy,
+                zyx: self.xyzw * rhs.t.zyx,
+            },
+            a: Quadvector {
+                xyzw: self.xyzw * rhs.a.xyzw,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Vector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Vector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.b.wy * rhs.z + self.b.yz * rhs.w + self.a.xyzw * rhs.x - self.b.wz * rhs.y,
+                y: self.b.wz * rhs.x + self.b.zx * rhs.w + self.a.xyzw * rhs.y - self.b.wx * rhs.z,
+                z: self.b.wx * rhs.y + self.b.xy * rhs.w + self.a.xyzw * rhs.z - self.b.wy * rhs.x,
+                w: self.a.xyzw * rhs.w,
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.b.wx * rhs.w,
+                wzx: self.b.wy * rhs.w,
+                wxy: self.b.wz * rhs.w,
+                zyx: self.s.0 * rhs.w - self.b.wx * rhs.x - self.b.wy * rhs.y - self.b.wz * rhs.z,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Bivector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Bivector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                -(self.b.wx * rhs.yz
+                    + self.b.wy * rhs.zx
+                    + self.b.wz * rhs.xy
+                    + self.b.yz * rhs.wx
+                    + self.b.zx * rhs.wy
+                    + self.b.xy * rhs.wz),
+            ),
+            b: Bivector {
+                wx: self.b.wy * rhs.wz + self.a.xyzw * rhs.wx - self.b.wz * rhs.wy,
+                wy: self.b.wz * rhs.wx + self.a.xyzw * rhs.wy - self.b.wx * rhs.wz,
+                wz: self.b.wx * rhs.wy + self.a.xyzw * rhs.wz - self.b.wy * rhs.wx,
+                yz: self.s.0 * rhs.wx
+                    + self.b.wy * rhs.xy
+                    + self.b.zx * rhs.wz
+                    + self.a.xyzw * rhs.yz
+                    - self.b.wz * rhs.zx
+                    - self.b.xy * rhs.wy,
+                zx: self.s.0 * rhs.wy
+                    + self.b.wz * rhs.yz
+                    + self.b.xy * rhs.wx
+                    + self.a.xyzw * rhs.zx
+                    - self.b.wx * rhs.xy
+                    - self.b.yz * rhs.wz,
+                xy: self.s.0 * rhs.wz
+                    + self.b.wx * rhs.zx
+                    + self.b.yz * rhs.wy
+                    + self.a.xyzw * rhs.xy
+                    - self.b.wy * rhs.yz
+                    - self.b.zx * rhs.wx,
+            },
+            a: Quadvector {
+                xyzw: -(self.b.wx * rhs.wx + self.b.wy * rhs.wy + self.b.wz * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Trivector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Trivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::ZERO,
+            v: Vector {
+                x: self.s.0 * rhs.wyz + self.b.wx * rhs.zyx + self.b.zx * rhs.wxy
+                    - self.b.xy * rhs.wzx,
+                y: self.s.0 * rhs.wzx + self.b.wy * rhs.zyx + self.b.xy * rhs.wyz
+                    - self.b.yz * rhs.wxy,
+                z: self.s.0 * rhs.wxy + self.b.wz * rhs.zyx + self.b.yz * rhs.wzx
+                    - self.b.zx * rhs.wyz,
+                w: -(self.b.wx * rhs.wyz + self.b.wy * rhs.wzx + self.b.wz * rhs.wxy),
+            },
+            b: Bivector::ZERO,
+            t: Trivector {
+                wyz: self.b.wy * rhs.wxy + self.a.xyzw * rhs.wyz - self.b.wz * rhs.wzx,
+                wzx: self.b.wz * rhs.wyz + self.a.xyzw * rhs.wzx - self.b.wx * rhs.wxy,
+                wxy: self.b.wx * rhs.wzx + self.a.xyzw * rhs.wxy - self.b.wy * rhs.wyz,
+                zyx: self.a.xyzw * rhs.zyx
+                    - self.b.yz * rhs.wyz
+                    - self.b.zx * rhs.wzx
+                    - self.b.xy * rhs.wxy,
+            },
+            a: Quadvector::ZERO,
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Quadvector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(self.s.0 * rhs.xyzw),
+            b: Bivector {
+                wx: self.b.wx * rhs.xyzw,
+                wy: self.b.wy * rhs.xyzw,
+                wz: self.b.wz * rhs.xyzw,
+                yz: self.b.yz * rhs.xyzw,
+                zx: self.b.zx * rhs.xyzw,
+                xy: self.b.xy * rhs.xyzw,
+            },
+            a: Quadvector {
+                xyzw: self.a.xyzw * rhs.xyzw,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Evenvector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Evenvector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: Scalar::new(
+                self.s.0 * rhs.a.xyzw + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz,
+            ),
+            b: Bivector {
+                wx: self.b.wx * rhs.a.xyzw + self.b.wy * rhs.b.wz + self.a.xyzw * rhs.b.wx
+                    - self.b.wz * rhs.b.wy,
+                wy: self.b.wy * rhs.a.xyzw + self.b.wz * rhs.b.wx + self.a.xyzw * rhs.b.wy
+                    - self.b.wx * rhs.b.wz,
+                wz: self.b.wx * rhs.b.wy + self.b.wz * rhs.a.xyzw + self.a.xyzw * rhs.b.wz
+                    - self.b.wy * rhs.b.wx,
+                yz: self.s.0 * rhs.b.wx
+                    + self.b.wx * rhs.s.0
+                    + self.b.wy * rhs.b.xy
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.zx * rhs.b.wz
+                    + self.a.xyzw * rhs.b.yz
+                    - self.b.wz * rhs.b.zx
+                    - self.b.xy * rhs.b.wy,
+                zx: self.s.0 * rhs.b.wy
+                    + self.b.wy * rhs.s.0
+                    + self.b.wz * rhs.b.yz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wx
+                    + self.a.xyzw * rhs.b.zx
+                    - self.b.wx * rhs.b.xy
+                    - self.b.yz * rhs.b.wz,
+                xy: self.s.0 * rhs.b.wz
+                    + self.b.wx * rhs.b.zx
+                    + self.b.wz * rhs.s.0
+                    + self.b.yz * rhs.b.wy
+                    + self.b.xy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.xy
+                    - self.b.wy * rhs.b.yz
+                    - self.b.zx * rhs.b.wx,
+            },
+            a: Quadvector {
+                xyzw: self.a.xyzw * rhs.a.xyzw
+                    - self.b.wx * rhs.b.wx
+                    - self.b.wy * rhs.b.wy
+                    - self.b.wz * rhs.b.wz,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Multivector<T>> for Evenvector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.s.0 * rhs.a.xyzw + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz,
+            ),
+            v: Vector {
+                x: self.s.0 * rhs.t.wyz
+                    + self.b.wx * rhs.t.zyx
+                    + self.b.wy * rhs.v.z
+                    + self.b.yz * rhs.v.w
+                    + self.b.zx * rhs.t.wxy
+                    + self.a.xyzw * rhs.v.x
+                    - self.b.wz * rhs.v.y
+                    - self.b.xy * rhs.t.wzx,
+                y: self.s.0 * rhs.t.wzx
+                    + self.b.wy * rhs.t.zyx
+                    + self.b.wz * rhs.v.x
+                    + self.b.zx * rhs.v.w
+                    + self.b.xy * rhs.t.wyz
+                    + self.a.xyzw * rhs.v.y
+                    - self.b.wx * rhs.v.z
+                    - self.b.yz * rhs.t.wxy,
+                z: self.s.0 * rhs.t.wxy
+                    + self.b.wx * rhs.v.y
+                    + self.b.wz * rhs.t.zyx
+                    + self.b.yz * rhs.t.wzx
+                    + self.b.xy * rhs.v.w
+                    + self.a.xyzw * rhs.v.z
+                    - self.b.wy * rhs.v.x
+                    - self.b.zx * rhs.t.wyz,
+                w: self.a.xyzw * rhs.v.w
+                    - self.b.wx * rhs.t.wyz
+                    - self.b.wy * rhs.t.wzx
+                    - self.b.wz * rhs.t.wxy,
+            },
+            b: Bivector {
+                wx: self.b.wx * rhs.a.xyzw + self.b.wy * rhs.b.wz + self.a.xyzw * rhs.b.wx
+                    - self.b.wz * rhs.b.wy,
+                wy: self.b.wy * rhs.a.xyzw + self.b.wz * rhs.b.wx + self.a.xyzw * rhs.b.wy
+                    - self.b.wx * rhs.b.wz,
+                wz: self.b.wx * rhs.b.wy + self.b.wz * rhs.a.xyzw + self.a.xyzw * rhs.b.wz
+                    - self.b.wy * rhs.b.wx,
+                yz: self.s.0 * rhs.b.wx
+                    + self.b.wx * rhs.s.0
+                    + self.b.wy * rhs.b.xy
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.zx * rhs.b.wz
+                    + self.a.xyzw * rhs.b.yz
+                    - self.b.wz * rhs.b.zx
+                    - self.b.xy * rhs.b.wy,
+                zx: self.s.0 * rhs.b.wy
+                    + self.b.wy * rhs.s.0
+                    + self.b.wz * rhs.b.yz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wx
+                    + self.a.xyzw * rhs.b.zx
+                    - self.b.wx * rhs.b.xy
+                    - self.b.yz * rhs.b.wz,
+                xy: self.s.0 * rhs.b.wz
+                    + self.b.wx * rhs.b.zx
+                    + self.b.wz * rhs.s.0
+                    + self.b.yz * rhs.b.wy
+                    + self.b.xy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.xy
+                    - self.b.wy * rhs.b.yz
+                    - self.b.zx * rhs.b.wx,
+            },
+            t: Trivector {
+                wyz: self.b.wx * rhs.v.w + self.b.wy * rhs.t.wxy + self.a.xyzw * rhs.t.wyz
+                    - self.b.wz * rhs.t.wzx,
+                wzx: self.b.wy * rhs.v.w + self.b.wz * rhs.t.wyz + self.a.xyzw * rhs.t.wzx
+                    - self.b.wx * rhs.t.wxy,
+                wxy: self.b.wx * rhs.t.wzx + self.b.wz * rhs.v.w + self.a.xyzw * rhs.t.wxy
+                    - self.b.wy * rhs.t.wyz,
+                zyx: self.s.0 * rhs.v.w + self.a.xyzw * rhs.t.zyx
+                    - self.b.wx * rhs.v.x
+                    - self.b.wy * rhs.v.y
+                    - self.b.wz * rhs.v.z
+                    - self.b.yz * rhs.t.wyz
+                    - self.b.zx * rhs.t.wzx
+                    - self.b.xy * rhs.t.wxy,
+            },
+            a: Quadvector {
+                xyzw: self.a.xyzw * rhs.a.xyzw
+                    - self.b.wx * rhs.b.wx
+                    - self.b.wy * rhs.b.wy
+                    - self.b.wz * rhs.b.wz,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Vector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Vector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                -(self.t.wyz * rhs.x
+                    + self.t.wzx * rhs.y
+                    + self.t.wxy * rhs.z
+                    + self.t.zyx * rhs.w),
+            ),
+            v: Vector {
+                x: self.b.wy * rhs.z + self.b.yz * rhs.w + self.a.xyzw * rhs.x - self.b.wz * rhs.y,
+                y: self.b.wz * rhs.x + self.b.zx * rhs.w + self.a.xyzw * rhs.y - self.b.wx * rhs.z,
+                z: self.b.wx * rhs.y + self.b.xy * rhs.w + self.a.xyzw * rhs.z - self.b.wy * rhs.x,
+                w: self.a.xyzw * rhs.w,
+            },
+            b: Bivector {
+                wx: -self.t.wyz * rhs.w,
+                wy: -self.t.wzx * rhs.w,
+                wz: -self.t.wxy * rhs.w,
+                yz: self.v.w * rhs.x + self.t.wzx * rhs.z - self.v.x * rhs.w - self.t.wxy * rhs.y,
+                zx: self.v.w * rhs.y + self.t.wxy * rhs.x - self.v.y * rhs.w - self.t.wyz * rhs.z,
+                xy: self.v.w * rhs.z + self.t.wyz * rhs.y - self.v.z * rhs.w - self.t.wzx * rhs.x,
+            },
+            t: Trivector {
+                wyz: self.b.wx * rhs.w,
+                wzx: self.b.wy * rhs.w,
+                wxy: self.b.wz * rhs.w,
+                zyx: self.s.0 * rhs.w - self.b.wx * rhs.x - self.b.wy * rhs.y - self.b.wz * rhs.z,
+            },
+            a: Quadvector {
+                xyzw: -self.v.w * rhs.w,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Bivector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Bivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                -(self.b.wx * rhs.yz
+                    + self.b.wy * rhs.zx
+                    + self.b.wz * rhs.xy
+                    + self.b.yz * rhs.wx
+                    + self.b.zx * rhs.wy
+                    + self.b.xy * rhs.wz),
+            ),
+            v: Vector {
+                x: self.v.y * rhs.wz + self.t.wxy * rhs.zx + self.t.zyx * rhs.wx
+                    - self.v.z * rhs.wy
+                    - self.v.w * rhs.yz
+                    - self.t.wzx * rhs.xy,
+                y: self.v.z * rhs.wx + self.t.wyz * rhs.xy + self.t.zyx * rhs.wy
+                    - self.v.x * rhs.wz
+                    - self.v.w * rhs.zx
+                    - self.t.wxy * rhs.yz,
+                z: self.v.x * rhs.wy + self.t.wzx * rhs.yz + self.t.zyx * rhs.wz
+                    - self.v.y * rhs.wx
+                    - self.v.w * rhs.xy
+                    - self.t.wyz * rhs.zx,
+                w: -(self.t.wyz * rhs.wx + self.t.wzx * rhs.wy + self.t.wxy * rhs.wz),
+            },
+            b: Bivector {
+                wx: self.b.wy * rhs.wz + self.a.xyzw * rhs.wx - self.b.wz * rhs.wy,
+                wy: self.b.wz * rhs.wx + self.a.xyzw * rhs.wy - self.b.wx * rhs.wz,
+                wz: self.b.wx * rhs.wy + self.a.xyzw * rhs.wz - self.b.wy * rhs.wx,
+                yz: self.s.0 * rhs.wx
+                    + self.b.wy * rhs.xy
+                    + self.b.zx * rhs.wz
+                    + self.a.xyzw * rhs.yz
+                    - self.b.wz * rhs.zx
+                    - self.b.xy * rhs.wy,
+                zx: self.s.0 * rhs.wy
+                    + self.b.wz * rhs.yz
+                    + self.b.xy * rhs.wx
+                    + self.a.xyzw * rhs.zx
+                    - self.b.wx * rhs.xy
+                    - self.b.yz * rhs.wz,
+                xy: self.s.0 * rhs.wz
+                    + self.b.wx * rhs.zx
+                    + self.b.yz * rhs.wy
+                    + self.a.xyzw * rhs.xy
+                    - self.b.wy * rhs.yz
+                    - self.b.zx * rhs.wx,
+            },
+            t: Trivector {
+                wyz: self.v.w * rhs.wx + self.t.wzx * rhs.wz - self.t.wxy * rhs.wy,
+                wzx: self.v.w * rhs.wy + self.t.wxy * rhs.wx - self.t.wyz * rhs.wz,
+                wxy: self.v.w * rhs.wz + self.t.wyz * rhs.wy - self.t.wzx * rhs.wx,
+                zyx: self.t.wyz * rhs.yz + self.t.wzx * rhs.zx + self.t.wxy * rhs.xy
+                    - self.v.x * rhs.wx
+                    - self.v.y * rhs.wy
+                    - self.v.z * rhs.wz,
+            },
+            a: Quadvector {
+                xyzw: -(self.b.wx * rhs.wx + self.b.wy * rhs.wy + self.b.wz * rhs.wz),
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Trivector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Trivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.v.x * rhs.wyz + self.v.y * rhs.wzx + self.v.z * rhs.wxy + self.v.w * rhs.zyx,
+            ),
+            v: Vector {
+                x: self.s.0 * rhs.wyz + self.b.wx * rhs.zyx + self.b.zx * rhs.wxy
+                    - self.b.xy * rhs.wzx,
+                y: self.s.0 * rhs.wzx + self.b.wy * rhs.zyx + self.b.xy * rhs.wyz
+                    - self.b.yz * rhs.wxy,
+                z: self.s.0 * rhs.wxy + self.b.wz * rhs.zyx + self.b.yz * rhs.wzx
+                    - self.b.zx * rhs.wyz,
+                w: -(self.b.wx * rhs.wyz + self.b.wy * rhs.wzx + self.b.wz * rhs.wxy),
+            },
+            b: Bivector {
+                wx: self.t.wxy * rhs.wzx - self.v.w * rhs.wyz - self.t.wzx * rhs.wxy,
+                wy: self.t.wyz * rhs.wxy - self.v.w * rhs.wzx - self.t.wxy * rhs.wyz,
+                wz: self.t.wzx * rhs.wyz - self.v.w * rhs.wxy - self.t.wyz * rhs.wzx,
+                yz: self.v.z * rhs.wzx + self.t.wyz * rhs.zyx
+                    - self.v.y * rhs.wxy
+                    - self.t.zyx * rhs.wyz,
+                zx: self.v.x * rhs.wxy + self.t.wzx * rhs.zyx
+                    - self.v.z * rhs.wyz
+                    - self.t.zyx * rhs.wzx,
+                xy: self.v.y * rhs.wyz + self.t.wxy * rhs.zyx
+                    - self.v.x * rhs.wzx
+                    - self.t.zyx * rhs.wxy,
+            },
+            t: Trivector {
+                wyz: self.b.wy * rhs.wxy + self.a.xyzw * rhs.wyz - self.b.wz * rhs.wzx,
+                wzx: self.b.wz * rhs.wyz + self.a.xyzw * rhs.wzx - self.b.wx * rhs.wxy,
+                wxy: self.b.wx * rhs.wzx + self.a.xyzw * rhs.wxy - self.b.wy * rhs.wyz,
+                zyx: self.a.xyzw * rhs.zyx
+                    - self.b.yz * rhs.wyz
+                    - self.b.zx * rhs.wzx
+                    - self.b.xy * rhs.wxy,
+            },
+            a: Quadvector {
+                xyzw: self.t.wyz * rhs.wyz + self.t.wzx * rhs.wzx + self.t.wxy * rhs.wxy,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Quadvector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Quadvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(self.s.0 * rhs.xyzw),
+            v: Vector {
+                x: self.v.x * rhs.xyzw,
+                y: self.v.y * rhs.xyzw,
+                z: self.v.z * rhs.xyzw,
+                w: self.v.w * rhs.xyzw,
+            },
+            b: Bivector {
+                wx: self.b.wx * rhs.xyzw,
+                wy: self.b.wy * rhs.xyzw,
+                wz: self.b.wz * rhs.xyzw,
+                yz: self.b.yz * rhs.xyzw,
+                zx: self.b.zx * rhs.xyzw,
+                xy: self.b.xy * rhs.xyzw,
+            },
+            t: Trivector {
+                wyz: self.t.wyz * rhs.xyzw,
+                wzx: self.t.wzx * rhs.xyzw,
+                wxy: self.t.wxy * rhs.xyzw,
+                zyx: self.t.zyx * rhs.xyzw,
+            },
+            a: Quadvector {
+                xyzw: self.a.xyzw * rhs.xyzw,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Evenvector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.s.0 * rhs.a.xyzw + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz,
+            ),
+            v: Vector {
+                x: self.v.x * rhs.a.xyzw
+                    + self.v.y * rhs.b.wz
+                    + self.t.wxy * rhs.b.zx
+                    + self.t.zyx * rhs.b.wx
+                    - self.v.z * rhs.b.wy
+                    - self.v.w * rhs.b.yz
+                    - self.t.wyz * rhs.s.0
+                    - self.t.wzx * rhs.b.xy,
+                y: self.v.y * rhs.a.xyzw
+                    + self.v.z * rhs.b.wx
+                    + self.t.wyz * rhs.b.xy
+                    + self.t.zyx * rhs.b.wy
+                    - self.v.x * rhs.b.wz
+                    - self.v.w * rhs.b.zx
+                    - self.t.wzx * rhs.s.0
+                    - self.t.wxy * rhs.b.yz,
+                z: self.v.x * rhs.b.wy
+                    + self.v.z * rhs.a.xyzw
+                    + self.t.wzx * rhs.b.yz
+                    + self.t.zyx * rhs.b.wz
+                    - self.v.y * rhs.b.wx
+                    - self.v.w * rhs.b.xy
+                    - self.t.wyz * rhs.b.zx
+                    - self.t.wxy * rhs.s.0,
+                w: self.v.w * rhs.a.xyzw
+                    - self.t.wyz * rhs.b.wx
+                    - self.t.wzx * rhs.b.wy
+                    - self.t.wxy * rhs.b.wz,
+            },
+            b: Bivector {
+                wx: self.b.wx * rhs.a.xyzw + self.b.wy * rhs.b.wz + self.a.xyzw * rhs.b.wx
+                    - self.b.wz * rhs.b.wy,
+                wy: self.b.wy * rhs.a.xyzw + self.b.wz * rhs.b.wx + self.a.xyzw * rhs.b.wy
+                    - self.b.wx * rhs.b.wz,
+                wz: self.b.wx * rhs.b.wy + self.b.wz * rhs.a.xyzw + self.a.xyzw * rhs.b.wz
+                    - self.b.wy * rhs.b.wx,
+                yz: self.s.0 * rhs.b.wx
+                    + self.b.wx * rhs.s.0
+                    + self.b.wy * rhs.b.xy
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.zx * rhs.b.wz
+                    + self.a.xyzw * rhs.b.yz
+                    - self.b.wz * rhs.b.zx
+                    - self.b.xy * rhs.b.wy,
+                zx: self.s.0 * rhs.b.wy
+                    + self.b.wy * rhs.s.0
+                    + self.b.wz * rhs.b.yz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wx
+                    + self.a.xyzw * rhs.b.zx
+                    - self.b.wx * rhs.b.xy
+                    - self.b.yz * rhs.b.wz,
+                xy: self.s.0 * rhs.b.wz
+                    + self.b.wx * rhs.b.zx
+                    + self.b.wz * rhs.s.0
+                    + self.b.yz * rhs.b.wy
+                    + self.b.xy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.b.xy
+                    - self.b.wy * rhs.b.yz
+                    - self.b.zx * rhs.b.wx,
+            },
+            t: Trivector {
+                wyz: self.v.w * rhs.b.wx + self.t.wyz * rhs.a.xyzw + self.t.wzx * rhs.b.wz
+                    - self.t.wxy * rhs.b.wy,
+                wzx: self.v.w * rhs.b.wy + self.t.wzx * rhs.a.xyzw + self.t.wxy * rhs.b.wx
+                    - self.t.wyz * rhs.b.wz,
+                wxy: self.v.w * rhs.b.wz + self.t.wyz * rhs.b.wy + self.t.wxy * rhs.a.xyzw
+                    - self.t.wzx * rhs.b.wx,
+                zyx: self.t.wyz * rhs.b.yz
+                    + self.t.wzx * rhs.b.zx
+                    + self.t.wxy * rhs.b.xy
+                    + self.t.zyx * rhs.a.xyzw
+                    - self.v.x * rhs.b.wx
+                    - self.v.y * rhs.b.wy
+                    - self.v.z * rhs.b.wz
+                    - self.v.w * rhs.s.0,
+            },
+            a: Quadvector {
+                xyzw: self.a.xyzw * rhs.a.xyzw
+                    - self.b.wx * rhs.b.wx
+                    - self.b.wy * rhs.b.wy
+                    - self.b.wz * rhs.b.wz,
+            },
+        }
+    }
+}
+
+impl<T> GeometricAntiproduct<Multivector<T>> for Multivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Multivector<T>;
+
+    fn geometric_antiproduct(&self, rhs: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: Scalar::new(
+                self.s.0 * rhs.a.xyzw
+                    + self.v.x * rhs.t.wyz
+                    + self.v.y * rhs.t.wzx
+                    + self.v.z * rhs.t.wxy
+                    + self.v.w * rhs.t.zyx
+                    + self.a.xyzw * rhs.s.0
+                    - self.b.wx * rhs.b.yz
+                    - self.b.wy * rhs.b.zx
+                    - self.b.wz * rhs.b.xy
+                    - self.b.yz * rhs.b.wx
+                    - self.b.zx * rhs.b.wy
+                    - self.b.xy * rhs.b.wz
+                    - self.t.wyz * rhs.v.x
+                    - self.t.wzx * rhs.v.y
+                    - self.t.wxy * rhs.v.z
+                    - self.t.zyx * rhs.v.w,
+            ),
+            v: Vector {
+                x: self.s.0 * rhs.t.wyz
+                    + self.v.x * rhs.a.xyzw
+                    + self.v.y * rhs.b.wz
+                    + self.b.wx * rhs.t.zyx
+                    + self.b.wy * rhs.v.z
+                    + self.b.yz * rhs.v.w
+                    + self.b.zx * rhs.t.wxy
+                    + self.t.wxy * rhs.b.zx
+                    + self.t.zyx * rhs.b.wx
+                    + self.a.xyzw * rhs.v.x
+                    - self.v.z * rhs.b.wy
+                    - self.v.w * rhs.b.yz
+                    - self.b.wz * rhs.v.y
+                    - self.b.xy * rhs.t.wzx
+                    - self.t.wyz * rhs.s.0
+                    - self.t.wzx * rhs.b.xy,
+                y: self.s.0 * rhs.t.wzx
+                    + self.v.y * rhs.a.xyzw
+                    + self.v.z * rhs.b.wx
+                    + self.b.wy * rhs.t.zyx
+                    + self.b.wz * rhs.v.x
+                    + self.b.zx * rhs.v.w
+                    + self.b.xy * rhs.t.wyz
+                    + self.t.wyz * rhs.b.xy
+                    + self.t.zyx * rhs.b.wy
+                    + self.a.xyzw * rhs.v.y
+                    - self.v.x * rhs.b.wz
+                    - self.v.w * rhs.b.zx
+                    - self.b.wx * rhs.v.z
+                    - self.b.yz * rhs.t.wxy
+                    - self.t.wzx * rhs.s.0
+                    - self.t.wxy * rhs.b.yz,
+                z: self.s.0 * rhs.t.wxy
+                    + self.v.x * rhs.b.wy
+                    + self.v.z * rhs.a.xyzw
+                    + self.b.wx * rhs.v.y
+                    + self.b.wz * rhs.t.zyx
+                    + self.b.yz * rhs.t.wzx
+                    + self.b.xy * rhs.v.w
+                    + self.t.wzx * rhs.b.yz
+                    + self.t.zyx * rhs.b.wz
+                    + self.a.xyzw * rhs.v.z
+                    - self.v.y * rhs.b.wx
+                    - self.v.w * rhs.b.xy
+                    - self.b.wy * rhs.v.x
+                    - self.b.zx * rhs.t.wyz
+                    - self.t.wyz * rhs.b.zx
+                    - self.t.wxy * rhs.s.0,
+                w: self.v.w * rhs.a.xyzw + self.a.xyzw * rhs.v.w
+                    - self.b.wx * rhs.t.wyz
+                    - self.b.wy * rhs.t.wzx
+                    - self.b.wz * rhs.t.wxy
+                    - self.t.wyz * rhs.b.wx
+                    - self.t.wzx * rhs.b.wy
+                    - self.t.wxy * rhs.b.wz,
+            },
+            b: Bivector {
+                wx: self.b.wx * rhs.a.xyzw
+                    + self.b.wy * rhs.b.wz
+                    + self.t.wxy * rhs.t.wzx
+                    + self.a.xyzw * rhs.b.wx
+                    - self.v.w * rhs.t.wyz
+                    - self.b.wz * rhs.b.wy
+                    - self.t.wyz * rhs.v.w
+                    - self.t.wzx * rhs.t.wxy,
+                wy: self.b.wy * rhs.a.xyzw
+                    + self.b.wz * rhs.b.wx
+                    + self.t.wyz * rhs.t.wxy
+                    + self.a.xyzw * rhs.b.wy
+                    - self.v.w * rhs.t.wzx
+                    - self.b.wx * rhs.b.wz
+                    - self.t.wzx * rhs.v.w
+                    - self.t.wxy * rhs.t.wyz,
+                wz: self.b.wx * rhs.b.wy
+                    + self.b.wz * rhs.a.xyzw
+                    + self.t.wzx * rhs.t.wyz
+                    + self.a.xyzw * rhs.b.wz
+                    - self.v.w * rhs.t.wxy
+                    - self.b.wy * rhs.b.wx
+                    - self.t.wyz * rhs.t.wzx
+                    - self.t.wxy * rhs.v.w,
+                yz: self.s.0 * rhs.b.wx
+                    + self.v.z * rhs.t.wzx
+                    + self.v.w * rhs.v.x
+                    + self.b.wx * rhs.s.0
+                    + self.b.wy * rhs.b.xy
+                    + self.b.yz * rhs.a.xyzw
+                    + self.b.zx * rhs.b.wz
+                    + self.t.wyz * rhs.t.zyx
+                    + self.t.wzx * rhs.v.z
+                    + self.a.xyzw * rhs.b.yz
+                    - self.v.x * rhs.v.w
+                    - self.v.y * rhs.t.wxy
+                    - self.b.wz * rhs.b.zx
+                    - self.b.xy * rhs.b.wy
+                    - self.t.wxy * rhs.v.y
+                    - self.t.zyx * rhs.t.wyz,
+                zx: self.s.0 * rhs.b.wy
+                    + self.v.x * rhs.t.wxy
+                    + self.v.w * rhs.v.y
+                    + self.b.wy * rhs.s.0
+                    + self.b.wz * rhs.b.yz
+                    + self.b.zx * rhs.a.xyzw
+                    + self.b.xy * rhs.b.wx
+                    + self.t.wzx * rhs.t.zyx
+                    + self.t.wxy * rhs.v.x
+                    + self.a.xyzw * rhs.b.zx
+                    - self.v.y * rhs.v.w
+                    - self.v.z * rhs.t.wyz
+                    - self.b.wx * rhs.b.xy
+                    - self.b.yz * rhs.b.wz
+                    - self.t.wyz * rhs.v.z
+                    - self.t.zyx * rhs.t.wzx,
+                xy: self.s.0 * rhs.b.wz
+                    + self.v.y * rhs.t.wyz
+                    + self.v.w * rhs.v.z
+                    + self.b.wx * rhs.b.zx
+                    + self.b.wz * rhs.s.0
+                    + self.b.yz * rhs.b.wy
+                    + self.b.xy * rhs.a.xyzw
+                    + self.t.wyz * rhs.v.y
+                    + self.t.wxy * rhs.t.zyx
+                    + self.a.xyzw * rhs.b.xy
+                    - self.v.x * rhs.t.wzx
+                    - self.v.z * rhs.v.w
+                    - self.b.wy * rhs.b.yz
+                    - self.b.zx * rhs.b.wx
+                    - self.t.wzx * rhs.v.x
+                    - self.t.zyx * rhs.t.wxy,
+            },
+            t: Trivector {
+                wyz: self.v.w * rhs.b.wx
+                    + self.b.wx * rhs.v.w
+                    + self.b.wy * rhs.t.wxy
+                    + self.t.wyz * rhs.a.xyzw
+                    + self.t.wzx * rhs.b.wz
+                    + self.a.xyzw * rhs.t.wyz
+                    - self.b.wz * rhs.t.wzx
+                    - self.t.wxy * rhs.b.wy,
+                wzx: self.v.w * rhs.b.wy
+                    + self.b.wy * rhs.v.w
+                    + self.b.wz * rhs.t.wyz
+                    + self.t.wzx * rhs.a.xyzw
+                    + self.t.wxy * rhs.b.wx
+                    + self.a.xyzw * rhs.t.wzx
+                    - self.b.wx * rhs.t.wxy
+                    - self.t.wyz * rhs.b.wz,
+                wxy: self.v.w * rhs.b.wz
+                    + self.b.wx * rhs.t.wzx
+                    + self.b.wz * rhs.v.w
+                    + self.t.wyz * rhs.b.wy
+                    + self.t.wxy * rhs.a.xyzw
+                    + self.a.xyzw * rhs.t.wxy
+                    - self.b.wy * rhs.t.wyz
+                    - self.t.wzx * rhs.b.wx,
+                zyx: self.s.0 * rhs.v.w
+                    + self.t.wyz * rhs.b.yz
+                    + self.t.wzx * rhs.b.zx
+                    + self.t.wxy * rhs.b.xy
+                    + self.t.zyx * rhs.a.xyzw
+                    + self.a.xyzw * rhs.t.zyx
+                    - self.v.x * rhs.b.wx
+                    - self.v.y * rhs.b.wy
+                    - self.v.z * rhs.b.wz
+                    - self.v.w * rhs.s.0
+                    - self.b.wx * rhs.v.x
+                    - self.b.wy * rhs.v.y
+                    - self.b.wz * rhs.v.z
+                    - self.b.yz * rhs.t.wyz
+                    - self.b.zx * rhs.t.wzx
+                    - self.b.xy * rhs.t.wxy,
+            },
+            a: Quadvector {
+                xyzw: self.t.wyz * rhs.t.wyz
+                    + self.t.wzx * rhs.t.wzx
+                    + self.t.wxy * rhs.t.wxy
+                    + self.a.xyzw * rhs.a.xyzw
+                    - self.v.w * rhs.v.w
+                    - self.b.wx * rhs.b.wx
+                    - self.b.wy * rhs.b.wy
+                    - self.b.wz * rhs.b.wz,
+            },
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Dot
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Dot for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = Scalar<T>;
+    type Antiscalar = Quadvector<T>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        Scalar::new(self.x * rhs.x + self.y * rhs.y + self.z * rhs.z)
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Quadvector {
+            xyzw: self.w * rhs.w,
+        }
+    }
+}
+
+impl<T> Dot for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = Scalar<T>;
+    type Antiscalar = Quadvector<T>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        Scalar::new(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy)
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Quadvector {
+            xyzw: self.wx * rhs.wx + self.wy * rhs.wy + self.wz * rhs.wz,
+        }
+    }
+}
+
+impl<T> Dot for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = Scalar<T>;
+    type Antiscalar = Quadvector<T>;
+
+    fn dot(&self, rhs: &Self) -> Self::Scalar {
+        Scalar::new(self.zyx * rhs.zyx)
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Quadvector {
+            xyzw: self.wyz * rhs.wyz + self.wzx * rhs.wzx + self.wxy * rhs.wxy,
+        }
+    }
+}
+
+impl<T> Dot for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type Scalar = Scalar<T>;
+    type Antiscalar = Quadvector<T>;
+
+    fn dot(&self, _rhs: &Self) -> Self::Scalar {
+        Scalar::ZERO
+    }
+
+    fn antidot(&self, rhs: &Self) -> Self::Antiscalar {
+        Quadvector {
+            xyzw: self.xyzw * rhs.xyzw,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Dual
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Dual for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    type AntiKVector = Trivector<T>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Trivector {
+            wyz: self.x,
+            wzx: self.y,
+            wxy: self.z,
+            zyx: T::ZERO,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Trivector {
+            wyz: -self.x,
+            wzx: -self.y,
+            wxy: -self.z,
+            zyx: T::ZERO,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Trivector {
+            wyz: T::ZERO,
+            wzx: T::ZERO,
+            wxy: T::ZERO,
+            zyx: self.w,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Trivector {
+            wyz: T::ZERO,
+            wzx: T::ZERO,
+            wxy: T::ZERO,
+            zyx: -self.w,
+        }
+    }
+}
+
+impl<T> Dual for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    type AntiKVector = Bivector<T>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            wx: -self.yz,
+            wy: -self.zx,
+            wz: -self.xy,
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: T::ZERO,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            wx: -self.yz,
+            wy: -self.zx,
+            wz: -self.xy,
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: T::ZERO,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            wx: T::ZERO,
+            wy: T::ZERO,
+            wz: T::ZERO,
+            yz: -self.wx,
+            zx: -self.wy,
+            xy: -self.wz,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Bivector {
+            wx: T::ZERO,
+            wy: T::ZERO,
+            wz: T::ZERO,
+            yz: -self.wx,
+            zx: -self.wy,
+            xy: -self.wz,
+        }
+    }
+}
+
+impl<T> Dual for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+{
+    type AntiKVector = Vector<T>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: -self.zyx,
+        }
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: self.zyx,
+        }
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: -self.wyz,
+            y: -self.wzx,
+            z: -self.wxy,
+            w: T::ZERO,
+        }
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Vector {
+            x: self.wyz,
+            y: self.wzx,
+            z: self.wxy,
+            w: T::ZERO,
+        }
+    }
+}
+
+impl<T> Dual for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+{
+    type AntiKVector = Scalar<T>;
+
+    fn right_bulk_dual(&self) -> Self::AntiKVector {
+        Scalar::ZERO
+    }
+
+    fn left_bulk_dual(&self) -> Self::AntiKVector {
+        Scalar::ZERO
+    }
+
+    fn right_weight_dual(&self) -> Self::AntiKVector {
+        Scalar::new(self.xyzw)
+    }
+
+    fn left_weight_dual(&self) -> Self::AntiKVector {
+        Scalar::new(self.xyzw)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Contraction
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Contraction<Vector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Scalar<T>;
+    type WeightOutput = Scalar<T>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T>) -> Self::BulkOutput {
+        Scalar::new(self.x * rhs.x + self.y * rhs.y + self.z * rhs.z)
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T>) -> Self::WeightOutput {
+        Scalar::new(self.w * rhs.w)
+    }
+}
+
+impl<T> Contraction<Vector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T>;
+    type WeightOutput = Vector<T>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T>) -> Self::BulkOutput {
+        Vector {
+            x: self.zx * rhs.z - self.xy * rhs.y,
+            y: self.xy * rhs.x - self.yz * rhs.z,
+            z: self.yz * rhs.y - self.zx * rhs.x,
+            w: -(self.wx * rhs.x + self.wy * rhs.y + self.wz * rhs.z),
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T>) -> Self::WeightOutput {
+        Vector {
+            x: self.wx * rhs.w,
+            y: self.wy * rhs.w,
+            z: self.wz * rhs.w,
+            w: T::ZERO,
+        }
+    }
+}
+
+impl<T> Contraction<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Scalar<T>;
+    type WeightOutput = Scalar<T>;
+
+    fn bulk_contraction(&self, rhs: &Bivector<T>) -> Self::BulkOutput {
+        Scalar::new(self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy)
+    }
+
+    fn weight_contraction(&self, rhs: &Bivector<T>) -> Self::WeightOutput {
+        Scalar::new(self.wx * rhs.wx + self.wy * rhs.wy + self.wz * rhs.wz)
+    }
+}
+
+impl<T> Contraction<Vector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T>;
+    type WeightOutput = Bivector<T>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T>) -> Self::BulkOutput {
+        Bivector {
+            wx: self.wxy * rhs.y - self.wzx * rhs.z,
+            wy: self.wyz * rhs.z - self.wxy * rhs.x,
+            wz: self.wzx * rhs.x - self.wyz * rhs.y,
+            yz: -self.zyx * rhs.x,
+            zx: -self.zyx * rhs.y,
+            xy: -self.zyx * rhs.z,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T>) -> Self::WeightOutput {
+        Bivector {
+            wx: T::ZERO,
+            wy: T::ZERO,
+            wz: T::ZERO,
+            yz: self.wyz * rhs.w,
+            zx: self.wzx * rhs.w,
+            xy: self.wxy * rhs.w,
+        }
+    }
+}
+
+impl<T> Contraction<Bivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T>;
+    type WeightOutput = Vector<T>;
+
+    fn bulk_contraction(&self, rhs: &Bivector<T>) -> Self::BulkOutput {
+        Vector {
+            x: -self.zyx * rhs.yz,
+            y: -self.zyx * rhs.zx,
+            z: -self.zyx * rhs.xy,
+            w: self.wyz * rhs.yz + self.wzx * rhs.zx + self.wxy * rhs.xy,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Bivector<T>) -> Self::WeightOutput {
+        Vector {
+            x: self.wzx * rhs.wz - self.wxy * rhs.wy,
+            y: self.wxy * rhs.wx - self.wyz * rhs.wz,
+            z: self.wyz * rhs.wy - self.wzx * rhs.wx,
+            w: T::ZERO,
+        }
+    }
+}
+
+impl<T> Contraction<Trivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Scalar<T>;
+    type WeightOutput = Scalar<T>;
+
+    fn bulk_contraction(&self, rhs: &Trivector<T>) -> Self::BulkOutput {
+        Scalar::new(self.zyx * rhs.zyx)
+    }
+
+    fn weight_contraction(&self, rhs: &Trivector<T>) -> Self::WeightOutput {
+        Scalar::new(self.wyz * rhs.wyz + self.wzx * rhs.wzx + self.wxy * rhs.wxy)
+    }
+}
+
+impl<T> Contraction<Vector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Trivector<T>;
+    type WeightOutput = Trivector<T>;
+
+    fn bulk_contraction(&self, rhs: &Vector<T>) -> Self::BulkOutput {
+        Trivector {
+            wyz: self.xyzw * rhs.x,
+            wzx: self.xyzw * rhs.y,
+            wxy: self.xyzw * rhs.z,
+            zyx: T::ZERO,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Vector<T>) -> Self::WeightOutput {
+        Trivector {
+            wyz: T::ZERO,
+            wzx: T::ZERO,
+            wxy: T::ZERO,
+            zyx: self.xyzw * rhs.w,
+        }
+    }
+}
+
+impl<T> Contraction<Bivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T>;
+    type WeightOutput = Bivector<T>;
+
+    fn bulk_contraction(&self, rhs: &Bivector<T>) -> Self::BulkOutput {
+        Bivector {
+            wx: -self.xyzw * rhs.yz,
+            wy: -self.xyzw * rhs.zx,
+            wz: -self.xyzw * rhs.xy,
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: T::ZERO,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Bivector<T>) -> Self::WeightOutput {
+        Bivector {
+            wx: T::ZERO,
+            wy: T::ZERO,
+            wz: T::ZERO,
+            yz: -self.xyzw * rhs.wx,
+            zx: -self.xyzw * rhs.wy,
+            xy: -self.xyzw * rhs.wz,
+        }
+    }
+}
+
+impl<T> Contraction<Trivector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Vector<T>;
+    type WeightOutput = Vector<T>;
+
+    fn bulk_contraction(&self, rhs: &Trivector<T>) -> Self::BulkOutput {
+        Vector {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: -self.xyzw * rhs.zyx,
+        }
+    }
+
+    fn weight_contraction(&self, rhs: &Trivector<T>) -> Self::WeightOutput {
+        Vector {
+            x: -self.xyzw * rhs.wyz,
+            y: -self.xyzw * rhs.wzx,
+            z: -self.xyzw * rhs.wxy,
+            w: T::ZERO,
+        }
+    }
+}
+
+impl<T> Contraction<Quadvector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Scalar<T>;
+
+    fn bulk_contraction(&self, _rhs: &Quadvector<T>) -> Self::BulkOutput {}
+
+    fn weight_contraction(&self, rhs: &Quadvector<T>) -> Self::WeightOutput {
+        Scalar::new(self.xyzw * rhs.xyzw)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Expansion
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Expansion<Vector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Quadvector<T>;
+    type WeightOutput = Quadvector<T>;
+
+    fn bulk_expansion(&self, rhs: &Vector<T>) -> Self::BulkOutput {
+        Quadvector {
+            xyzw: self.x * rhs.x + self.y * rhs.y + self.z * rhs.z,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Vector<T>) -> Self::WeightOutput {
+        Quadvector {
+            xyzw: self.w * rhs.w,
+        }
+    }
+}
+
+impl<T> Expansion<Bivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Trivector<T>;
+    type WeightOutput = Trivector<T>;
+
+    fn bulk_expansion(&self, rhs: &Bivector<T>) -> Self::BulkOutput {
+        Trivector {
+            wyz: self.y * rhs.xy - self.z * rhs.zx,
+            wzx: self.z * rhs.yz - self.x * rhs.xy,
+            wxy: self.x * rhs.zx - self.y * rhs.yz,
+            zyx: T::ZERO,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Bivector<T>) -> Self::WeightOutput {
+        Trivector {
+            wyz: -self.w * rhs.wx,
+            wzx: -self.w * rhs.wy,
+            wxy: -self.w * rhs.wz,
+            zyx: self.x * rhs.wx + self.y * rhs.wy + self.z * rhs.wz,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T>> for Vector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Bivector<T>;
+    type WeightOutput = Bivector<T>;
+
+    fn bulk_expansion(&self, rhs: &Trivector<T>) -> Self::BulkOutput {
+        Bivector {
+            wx: self.x * rhs.zyx,
+            wy: self.y * rhs.zyx,
+            wz: self.z * rhs.zyx,
+            yz: T::ZERO,
+            zx: T::ZERO,
+            xy: T::ZERO,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Trivector<T>) -> Self::WeightOutput {
+        Bivector {
+            wx: -self.w * rhs.wyz,
+            wy: -self.w * rhs.wzx,
+            wz: -self.w * rhs.wxy,
+            yz: self.z * rhs.wzx - self.y * rhs.wxy,
+            zx: self.x * rhs.wxy - self.z * rhs.wyz,
+            xy: self.y * rhs.wyz - self.x * rhs.wzx,
+        }
+    }
+}
+
+impl<T> Expansion<Quadvector<T>> for Vector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Vector<T>;
+
+    fn bulk_expansion(&self, _rhs: &Quadvector<T>) -> Self::BulkOutput {}
+
+    fn weight_expansion(&self, rhs: &Quadvector<T>) -> Self::WeightOutput {
+        Vector {
+            x: self.x * rhs.xyzw,
+            y: self.y * rhs.xyzw,
+            z: self.z * rhs.xyzw,
+            w: self.w * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> Expansion<Bivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Quadvector<T>;
+    type WeightOutput = Quadvector<T>;
+
+    fn bulk_expansion(&self, rhs: &Bivector<T>) -> Self::BulkOutput {
+        Quadvector {
+            xyzw: self.yz * rhs.yz + self.zx * rhs.zx + self.xy * rhs.xy,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Bivector<T>) -> Self::WeightOutput {
+        Quadvector {
+            xyzw: self.wx * rhs.wx + self.wy * rhs.wy + self.wz * rhs.wz,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: ConstZero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Neg<Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Trivector<T>;
+    type WeightOutput = Trivector<T>;
+
+    fn bulk_expansion(&self, rhs: &Trivector<T>) -> Self::BulkOutput {
+        Trivector {
+            wyz: -self.yz * rhs.zyx,
+            wzx: -self.zx * rhs.zyx,
+            wxy: -self.xy * rhs.zyx,
+            zyx: T::ZERO,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Trivector<T>) -> Self::WeightOutput {
+        Trivector {
+            wyz: self.wz * rhs.wzx - self.wy * rhs.wxy,
+            wzx: self.wx * rhs.wxy - self.wz * rhs.wyz,
+            wxy: self.wy * rhs.wyz - self.wx * rhs.wzx,
+            zyx: self.yz * rhs.wyz + self.zx * rhs.wzx + self.xy * rhs.wxy,
+        }
+    }
+}
+
+impl<T> Expansion<Quadvector<T>> for Bivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Bivector<T>;
+
+    fn bulk_expansion(&self, _rhs: &Quadvector<T>) -> Self::BulkOutput {}
+
+    fn weight_expansion(&self, rhs: &Quadvector<T>) -> Self::WeightOutput {
+        Bivector {
+            wx: self.wx * rhs.xyzw,
+            wy: self.wy * rhs.xyzw,
+            wz: self.wz * rhs.xyzw,
+            yz: self.yz * rhs.xyzw,
+            zx: self.zx * rhs.xyzw,
+            xy: self.xy * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> Expansion<Trivector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = Quadvector<T>;
+    type WeightOutput = Quadvector<T>;
+
+    fn bulk_expansion(&self, rhs: &Trivector<T>) -> Self::BulkOutput {
+        Quadvector {
+            xyzw: self.zyx * rhs.zyx,
+        }
+    }
+
+    fn weight_expansion(&self, rhs: &Trivector<T>) -> Self::WeightOutput {
+        Quadvector {
+            xyzw: self.wyz * rhs.wyz + self.wzx * rhs.wzx + self.wxy * rhs.wxy,
+        }
+    }
+}
+
+impl<T> Expansion<Quadvector<T>> for Trivector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Trivector<T>;
+
+    fn bulk_expansion(&self, _rhs: &Quadvector<T>) -> Self::BulkOutput {}
+
+    fn weight_expansion(&self, rhs: &Quadvector<T>) -> Self::WeightOutput {
+        Trivector {
+            wyz: self.wyz * rhs.xyzw,
+            wzx: self.wzx * rhs.xyzw,
+            wxy: self.wxy * rhs.xyzw,
+            zyx: self.zyx * rhs.xyzw,
+        }
+    }
+}
+
+impl<T> Expansion<Quadvector<T>> for Quadvector<T>
+where
+    T: Copy,
+    T: Mul<T, Output = T>,
+{
+    type BulkOutput = ();
+    type WeightOutput = Quadvector<T>;
+
+    fn bulk_expansion(&self, _rhs: &Quadvector<T>) -> Self::BulkOutput {}
+
+    fn weight_expansion(&self, rhs: &Quadvector<T>) -> Self::WeightOutput {
+        Quadvector {
+            xyzw: self.xyzw * rhs.xyzw,
+        }
+    }
+}
@@ -1,64 +1,14 @@
 use std::ops::{Add, Mul, Neg, Sub};
 
-use num::{
-    One, Zero,
-    traits::{ConstOne, ConstZero},
-};
+use num::traits::ConstZero;
 
 use crate::{
-    AntiwedgeProduct, GeometricProduct, KVector, WedgeProduct, reverse_add, reverse_antiwedge,
-    reverse_geometric, reverse_mul, reverse_wedge,
+    AntiwedgeProduct, GeometricProduct, KVector, WedgeProduct, default_sub, reverse_add,
+    reverse_antiwedge, reverse_geometric, reverse_mul, reverse_wedge,
 };
 
 use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
 
-impl<T> Zero for Scalar<T>
-where
-    T: Zero,
-{
-    fn zero() -> Self {
-        Scalar(T::zero())
-    }
-
-    fn is_zero(&self) -> bool {
-        self.0.is_zero()
-    }
-}
-
-impl<T> ConstZero for Scalar<T>
-where
-    T: ConstZero,
-{
-    const ZERO: Self = Scalar(T::ZERO);
-}
-
-impl<T> One for Scalar<T>
-where
-    T: One,
-    Scalar<T>: Mul<Output = Scalar<T>>,
-{
-    fn one() -> Self {
-        Scalar(T::one())
-    }
-}
-
-impl<T> ConstOne for Scalar<T>
-where
-    T: ConstOne,
-{
-    const ONE: Self = Scalar(T::ONE);
-}
-
-impl<T> Add for Scalar<T>
-where
-    T: Add<T, Output = T>,
-{
-    type Output = Scalar<T>;
-    fn add(self, rhs: Self) -> Self::Output {
-        Scalar(self.0 + rhs.0)
-    }
-}
-
 impl<T> Add<Vector<T>> for Scalar<T>
 where
     T: ConstZero,
@@ -119,26 +69,6 @@ where
     }
 }
 
-impl<T> Sub for Scalar<T>
-where
-    T: Sub<T, Output = T>,
-{
-    type Output = Scalar<T>;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Scalar(self.0 - rhs.0)
-    }
-}
-
-impl<T> Neg for Scalar<T>
-where
-    T: Neg<Output = T>,
-{
-    type Output = Scalar<T>;
-    fn neg(self) -> Self::Output {
-        Scalar(-self.0)
-    }
-}
-
 impl<T: Clone> KVector for Scalar<T> {
     type AntiKVector = Quadvector<T>;
 
@@ -155,17 +85,6 @@ impl<T: Clone> KVector for Scalar<T> {
     }
 }
 
-impl<T> Mul<Scalar<T>> for Scalar<T>
-where
-    T: Mul<T, Output = T>,
-{
-    type Output = Scalar<T>;
-
-    fn mul(self, rhs: Scalar<T>) -> Self::Output {
-        Scalar(self.0 * rhs.0)
-    }
-}
-
 impl<T> Mul<Vector<T>> for Scalar<T>
 where
     T: Copy,
@@ -289,7 +208,7 @@ where
     type Output = Scalar<T>;
 
     fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Scalar(self.0 * rhs.xyzw)
+        Scalar::new(self.0 * rhs.xyzw)
     }
 }
 
@@ -332,3 +251,10 @@ reverse_geometric!(Trivector, Scalar);
 reverse_geometric!(Quadvector, Scalar);
 reverse_geometric!(Evenvector, Scalar);
 reverse_geometric!(Multivector, Scalar);
+
+default_sub!(Scalar<T>, Vector<T>);
+default_sub!(Scalar<T>, Bivector<T>);
+default_sub!(Scalar<T>, Trivector<T>);
+default_sub!(Scalar<T>, Quadvector<T>);
+default_sub!(Scalar<T>, Evenvector<T>);
+default_sub!(Scalar<T>, Multivector<T>);
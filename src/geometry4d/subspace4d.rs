@@ -0,0 +1,180 @@
+use num::{
+    Float,
+    traits::{ConstOne, ConstZero},
+};
+
+use crate::{Epsilon, SubspaceJoin, SubspaceMeet, WedgeProduct, subspace};
+
+use super::{Bivector, Multivector, Quadvector, Scalar, Trivector, Vector};
+
+// As with the blade frames, subspaces are measured with the euclidean inner product of the coefficients.
+
+fn coefficients<T>(v: &Vector<T>) -> [T; 4]
+where
+    T: Copy,
+{
+    [v.x, v.y, v.z, v.w]
+}
+
+/// Vectors spanning a blade, as coefficient arrays, `None` for a zero blade which spans no subspace
+trait Frame<T> {
+    fn frame(&self) -> Option<Vec<[T; 4]>>;
+}
+
+impl<T> Frame<T> for Vector<T>
+where
+    T: Float,
+{
+    fn frame(&self) -> Option<Vec<[T; 4]>> {
+        let vector = coefficients(self);
+        (!vector.iter().all(|c| c.is_zero())).then(|| vec![vector])
+    }
+}
+
+impl<T> Frame<T> for Bivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    fn frame(&self) -> Option<Vec<[T; 4]>> {
+        assert!(
+            self.is_blade(),
+            "subspaces are only defined for blades, split the bivector with its invariant decomposition"
+        );
+        self.orthonormal_frame()
+            .map(|frame| frame.map(|v| coefficients(&v)).to_vec())
+    }
+}
+
+impl<T> Frame<T> for Trivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    fn frame(&self) -> Option<Vec<[T; 4]>> {
+        self.orthonormal_frame()
+            .map(|frame| frame.map(|v| coefficients(&v)).to_vec())
+    }
+}
+
+impl<T> Frame<T> for Quadvector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    fn frame(&self) -> Option<Vec<[T; 4]>> {
+        if self.xyzw.is_zero() {
+            return None;
+        }
+        let frame = [
+            Vector {
+                x: self.xyzw.signum(),
+                ..Vector::ZERO
+            },
+            Vector::Y,
+            Vector::Z,
+            Vector::W,
+        ]
+        .map(|v| coefficients(&v));
+        Some(frame.to_vec())
+    }
+}
+
+/// Unit blade spanned by an orthonormal frame
+fn blade<T>(frame: &[[T; 4]]) -> Multivector<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    let vectors: Vec<Vector<T>> = frame
+        .iter()
+        .map(|[x, y, z, w]| Vector {
+            x: *x,
+            y: *y,
+            z: *z,
+            w: *w,
+        })
+        .collect();
+    match vectors.as_slice() {
+        [] => Multivector {
+            s: Scalar::ONE,
+            ..Multivector::ZERO
+        },
+        [a] => Multivector {
+            v: *a,
+            ..Multivector::ZERO
+        },
+        [a, b] => Multivector {
+            b: a.wedge(b),
+            ..Multivector::ZERO
+        },
+        [a, b, c] => Multivector {
+            t: a.wedge(&b.wedge(c)),
+            ..Multivector::ZERO
+        },
+        [a, b, c, d, ..] => Multivector {
+            a: a.wedge(b).wedge(&c.wedge(d)),
+            ..Multivector::ZERO
+        },
+    }
+}
+
+macro_rules! subspace_join_meet {
+    ($lht:ident, $rht:ident) => {
+        impl<T> SubspaceJoin<$rht<T>> for $lht<T>
+        where
+            T: Float,
+            T: ConstZero,
+            T: ConstOne,
+            T: Epsilon,
+        {
+            type Output = Multivector<T>;
+            fn subspace_join(&self, rhs: &$rht<T>) -> Multivector<T> {
+                match (self.frame(), rhs.frame()) {
+                    (Some(lhs), Some(rhs)) => blade(&subspace::join(&lhs, &rhs)),
+                    _ => Multivector::ZERO,
+                }
+            }
+        }
+
+        impl<T> SubspaceMeet<$rht<T>> for $lht<T>
+        where
+            T: Float,
+            T: ConstZero,
+            T: ConstOne,
+            T: Epsilon,
+        {
+            type Output = Multivector<T>;
+            fn subspace_meet(&self, rhs: &$rht<T>) -> Multivector<T> {
+                match (self.frame(), rhs.frame()) {
+                    (Some(lhs), Some(rhs)) => blade(&subspace::meet(&lhs, &rhs)),
+                    _ => Multivector::ZERO,
+                }
+            }
+        }
+    };
+}
+
+subspace_join_meet!(Vector, Vector);
+subspace_join_meet!(Vector, Bivector);
+subspace_join_meet!(Vector, Trivector);
+subspace_join_meet!(Vector, Quadvector);
+subspace_join_meet!(Bivector, Vector);
+subspace_join_meet!(Bivector, Bivector);
+subspace_join_meet!(Bivector, Trivector);
+subspace_join_meet!(Bivector, Quadvector);
+subspace_join_meet!(Trivector, Vector);
+subspace_join_meet!(Trivector, Bivector);
+subspace_join_meet!(Trivector, Trivector);
+subspace_join_meet!(Trivector, Quadvector);
+subspace_join_meet!(Quadvector, Vector);
+subspace_join_meet!(Quadvector, Bivector);
+subspace_join_meet!(Quadvector, Trivector);
+subspace_join_meet!(Quadvector, Quadvector);
@@ -1,13 +1,11 @@
-use std::ops::{Add, Mul, Neg, Sub};
+use std::ops::{Add, Neg, Sub};
 
 use num::{
     Zero,
     traits::{ConstOne, ConstZero},
 };
 
-use crate::{
-    AntiwedgeProduct, GeometricProduct, KVector, WedgeProduct, reverse_add, reverse_antiwedge,
-};
+use crate::{KVector, assign_ops, default_sub, reverse_add};
 
 use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
 
@@ -106,6 +104,22 @@ where
     }
 }
 
+impl<T> Add<Evenvector<T>> for Trivector<T>
+where
+    T: ConstZero,
+{
+    type Output = Multivector<T>;
+    fn add(self, rhs: Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: rhs.s,
+            v: Vector::ZERO,
+            b: rhs.b,
+            t: self,
+            a: rhs.a,
+        }
+    }
+}
+
 impl<T> Sub for Trivector<T>
 where
     T: Sub<T, Output = T>,
@@ -162,118 +176,14 @@ where
     }
 }
 
-impl<T> AntiwedgeProduct<Quadvector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-{
-    type Output = Trivector<T>;
-
-    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Trivector {
-            wyz: self.wyz * rhs.xyzw,
-            wzx: self.wzx * rhs.xyzw,
-            wxy: self.wxy * rhs.xyzw,
-            zyx: self.zyx * rhs.xyzw,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Vector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
-        Evenvector {
-            s: Scalar::ZERO,
-            b: Bivector {
-                wx: self.wxy * rhs.y - self.wzx * rhs.z,
-                wy: self.wyz * rhs.z - self.wxy * rhs.x,
-                wz: self.wzx * rhs.x - self.wyz * rhs.y,
-                yz: -self.zyx * rhs.x,
-                zx: -self.zyx * rhs.y,
-                xy: -self.zyx * rhs.z,
-            },
-            a: self.wedge(rhs),
-        }
-    }
-}
-
-impl<T> GeometricProduct<Bivector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
-        Multivector {
-            s: Scalar::ZERO,
-            v: Vector {
-                x: self.zyx * rhs.yz,
-                y: self.zyx * rhs.zx,
-                z: self.zyx * rhs.xy,
-                w: -(self.wyz * rhs.yz + self.wzx * rhs.zx + self.wxy * rhs.xy),
-            },
-            b: Bivector::ZERO,
-            t: Trivector {
-                wyz: self.zyx * rhs.wx + self.wxy * rhs.zx - self.wzx * rhs.xy,
-                wzx: self.zyx * rhs.wy + self.wyz * rhs.xy - self.wxy * rhs.yz,
-                wxy: self.zyx * rhs.wz + self.wzx * rhs.yz - self.wyz * rhs.zx,
-                zyx: T::ZERO,
-            },
-            a: Quadvector::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Quadvector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Mul<T, Output = T>,
-{
-    type Output = Vector<T>;
-
-    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Vector {
-            x: T::ZERO,
-            y: T::ZERO,
-            z: T::ZERO,
-            w: self.zyx * rhs.xyzw,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Evenvector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
-        self.geometric_product(&rhs.s)
-            + self.geometric_product(&rhs.b)
-            + self.geometric_product(&rhs.a)
-    }
-}
-
 reverse_add!(Quadvector, Trivector);
+reverse_add!(Evenvector, Trivector);
+
+default_sub!(Trivector<T>, Scalar<T>);
+default_sub!(Trivector<T>, Vector<T>);
+default_sub!(Trivector<T>, Bivector<T>);
+default_sub!(Trivector<T>, Quadvector<T>);
+default_sub!(Trivector<T>, Evenvector<T>);
+default_sub!(Trivector<T>, Multivector<T>);
 
-reverse_antiwedge!(Quadvector, Trivector);
+assign_ops!(Trivector<T>, T);
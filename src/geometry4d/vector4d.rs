@@ -1,14 +1,11 @@
-use std::ops::{Add, Mul, Neg, Sub};
+use std::ops::{Add, Neg, Sub};
 
 use num::{
     Zero,
     traits::{ConstOne, ConstZero},
 };
 
-use crate::{
-    AntiwedgeProduct, GeometricProduct, KVector, WedgeProduct, reverse_add, reverse_antiwedge,
-    reverse_wedge,
-};
+use crate::{KVector, assign_ops, default_sub, reverse_add};
 
 use super::{Bivector, Evenvector, Multivector, Quadvector, Scalar, Trivector, Vector};
 
@@ -139,6 +136,22 @@ where
     }
 }
 
+impl<T> Add<Evenvector<T>> for Vector<T>
+where
+    T: ConstZero,
+{
+    type Output = Multivector<T>;
+    fn add(self, rhs: Evenvector<T>) -> Self::Output {
+        Multivector {
+            s: rhs.s,
+            v: self,
+            b: rhs.b,
+            t: Trivector::ZERO,
+            a: rhs.a,
+        }
+    }
+}
+
 impl<T> Sub for Vector<T>
 where
     T: Sub<T, Output = T>,
@@ -195,261 +208,16 @@ where
     }
 }
 
-impl<T> WedgeProduct<Vector<T>> for Vector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Sub<T, Output = T>,
-{
-    type Output = Bivector<T>;
-
-    fn wedge(&self, rhs: &Vector<T>) -> Self::Output {
-        Bivector {
-            wx: self.w * rhs.x - self.x * rhs.w,
-            wy: self.w * rhs.y - self.y * rhs.w,
-            wz: self.w * rhs.z - self.z * rhs.w,
-            yz: self.y * rhs.z - self.z * rhs.y,
-            zx: self.z * rhs.x - self.x * rhs.z,
-            xy: self.x * rhs.y - self.y * rhs.x,
-        }
-    }
-}
-
-impl<T> WedgeProduct<Bivector<T>> for Vector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-{
-    type Output = Trivector<T>;
-
-    fn wedge(&self, rhs: &Bivector<T>) -> Self::Output {
-        Trivector {
-            wyz: self.w * rhs.yz - self.y * rhs.wz + self.z * rhs.wy,
-            wzx: self.w * rhs.zx - self.z * rhs.wx + self.x * rhs.wz,
-            wxy: self.w * rhs.xy - self.x * rhs.wy + self.y * rhs.wx,
-            zyx: -(self.x * rhs.yz + self.y * rhs.zx + self.z * rhs.xy),
-        }
-    }
-}
-
-impl<T> WedgeProduct<Trivector<T>> for Vector<T>
-where
-    T: Copy,
-    T: Add<T, Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Quadvector<T>;
-
-    fn wedge(&self, rhs: &Trivector<T>) -> Self::Output {
-        Quadvector {
-            xyzw: self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx,
-        }
-    }
-}
-
-impl<T> WedgeProduct<Vector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-{
-    type Output = Quadvector<T>;
-
-    fn wedge(&self, rhs: &Vector<T>) -> Self::Output {
-        -rhs.wedge(self)
-    }
-}
-
-impl<T> AntiwedgeProduct<Trivector<T>> for Vector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-{
-    type Output = Scalar<T>;
-
-    fn antiwedge(&self, rhs: &Trivector<T>) -> Self::Output {
-        Scalar(self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx)
-    }
-}
-
-impl<T> AntiwedgeProduct<Vector<T>> for Trivector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-    T: Add<T, Output = T>,
-    Scalar<T>: Neg<Output = Scalar<T>>,
-{
-    type Output = Scalar<T>;
-
-    fn antiwedge(&self, rhs: &Vector<T>) -> Self::Output {
-        -rhs.antiwedge(self)
-    }
-}
-
-impl<T> AntiwedgeProduct<Quadvector<T>> for Vector<T>
-where
-    T: Copy,
-    T: Mul<T, Output = T>,
-{
-    type Output = Vector<T>;
-
-    fn antiwedge(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Vector {
-            x: self.x * rhs.xyzw,
-            y: self.y * rhs.xyzw,
-            z: self.z * rhs.xyzw,
-            w: self.w * rhs.xyzw,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Vector<T>> for Vector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Vector<T>) -> Self::Output {
-        Evenvector {
-            s: Scalar(self.x * rhs.x + self.y * rhs.y + self.z * rhs.z),
-            b: self.wedge(rhs),
-            a: Quadvector::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Bivector<T>> for Vector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Bivector<T>) -> Self::Output {
-        Multivector {
-            s: Scalar::ZERO,
-            v: Vector {
-                x: self.z * rhs.zx - self.y * rhs.xy,
-                y: self.x * rhs.xy - self.z * rhs.yz,
-                z: self.y * rhs.yz - self.x * rhs.zx,
-                w: -(self.x * rhs.wx + self.y * rhs.wy + self.z * rhs.wz),
-            },
-            b: Bivector::ZERO,
-            t: self.wedge(rhs),
-            a: Quadvector::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Trivector<T>> for Vector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Evenvector<T>;
-
-    fn geometric_product(&self, rhs: &Trivector<T>) -> Self::Output {
-        Evenvector {
-            s: Scalar::ZERO,
-            b: Bivector {
-                wx: self.y * rhs.wxy - self.z * rhs.wzx,
-                wy: self.z * rhs.wyz - self.x * rhs.wxy,
-                wz: self.x * rhs.wzx - self.y * rhs.wyz,
-                yz: self.x * -rhs.zyx,
-                zx: self.y * -rhs.zyx,
-                xy: self.z * -rhs.zyx,
-            },
-            a: self.wedge(rhs),
-        }
-    }
-}
-
-impl<T> GeometricProduct<Quadvector<T>> for Vector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Mul<T, Output = T>,
-{
-    type Output = Trivector<T>;
-
-    fn geometric_product(&self, rhs: &Quadvector<T>) -> Self::Output {
-        Trivector {
-            wyz: self.x * rhs.xyzw,
-            wzx: self.y * rhs.xyzw,
-            wxy: self.z * rhs.xyzw,
-            zyx: T::ZERO,
-        }
-    }
-}
-
-impl<T> GeometricProduct<Evenvector<T>> for Vector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Evenvector<T>) -> Self::Output {
-        self.geometric_product(&rhs.s)
-            + self.geometric_product(&rhs.b)
-            + self.geometric_product(&rhs.a)
-    }
-}
-
-impl<T> GeometricProduct<Multivector<T>> for Vector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type Output = Multivector<T>;
-
-    fn geometric_product(&self, rhs: &Multivector<T>) -> Self::Output {
-        let v = self.geometric_product(&rhs.s);
-        let sb = self.geometric_product(&rhs.v);
-        let vt = self.geometric_product(&rhs.b);
-        let bq = self.geometric_product(&rhs.t);
-        let t = self.geometric_product(&rhs.a);
-
-        Multivector {
-            s: sb.s,
-            v: v + vt.v,
-            b: sb.b + bq.b,
-            t: vt.t + t,
-            a: bq.a,
-        }
-    }
-}
-
 reverse_add!(Bivector, Vector);
 reverse_add!(Trivector, Vector);
 reverse_add!(Quadvector, Vector);
+reverse_add!(Evenvector, Vector);
 
-reverse_wedge!(Bivector, Vector);
+default_sub!(Vector<T>, Scalar<T>);
+default_sub!(Vector<T>, Bivector<T>);
+default_sub!(Vector<T>, Trivector<T>);
+default_sub!(Vector<T>, Quadvector<T>);
+default_sub!(Vector<T>, Evenvector<T>);
+default_sub!(Vector<T>, Multivector<T>);
 
-reverse_antiwedge!(Quadvector, Vector);
+assign_ops!(Vector<T>, T);
@@ -1,5 +1,7 @@
 use crate::Epsilon;
 use crate::Unitizable;
+use crate::UnitizeError;
+use crate::ZeroTest;
 use crate::geometry3d as d3;
 use crate::geometry3d::Vector;
 use crate::geometry4d as d4;
@@ -9,7 +11,81 @@ pub use d4::Trivector as HomogeneusPlane;
 pub use d4::Vector as HomogeneusPoint;
 use num::Float;
 
+/// Implements a measure like `Distance` or `Angle` for every pair of a normalized type and another
+/// primitive by measuring their homogeneous counterparts
+macro_rules! normalized_measure {
+    ($trait:ident { $($method:ident -> $output:ty),* $(,)? }) => {
+        normalized_measure!(@rows ($trait { $($method -> $output),* }) [
+            d3::Point<T> => HomogeneusPoint,
+            NormalizedPoint<T> => HomogeneusPoint,
+            Line<T> => HomogeneusLine,
+            HorizonLine<T> => HomogeneusLine,
+            NormalizedLine<T> => HomogeneusLine,
+            Plane<T> => HomogeneusPlane,
+            NormalizedPlane<T> => HomogeneusPlane,
+        ] x [
+            HomogeneusPoint<T> => HomogeneusPoint,
+            d3::Point<T> => HomogeneusPoint,
+            NormalizedPoint<T> => HomogeneusPoint,
+            HomogeneusLine<T> => HomogeneusLine,
+            Line<T> => HomogeneusLine,
+            HorizonLine<T> => HomogeneusLine,
+            NormalizedLine<T> => HomogeneusLine,
+            HomogeneusPlane<T> => HomogeneusPlane,
+            Plane<T> => HomogeneusPlane,
+            NormalizedPlane<T> => HomogeneusPlane,
+        ]);
+        normalized_measure!(@rows ($trait { $($method -> $output),* }) [
+            HomogeneusPoint<T> => HomogeneusPoint,
+            HomogeneusLine<T> => HomogeneusLine,
+            HomogeneusPlane<T> => HomogeneusPlane,
+        ] x [
+            d3::Point<T> => HomogeneusPoint,
+            NormalizedPoint<T> => HomogeneusPoint,
+            Line<T> => HomogeneusLine,
+            HorizonLine<T> => HomogeneusLine,
+            NormalizedLine<T> => HomogeneusLine,
+            Plane<T> => HomogeneusPlane,
+            NormalizedPlane<T> => HomogeneusPlane,
+        ]);
+    };
+    (@rows $measure:tt [$($lht:ty => $lh:ident),* $(,)?] x $rhs:tt) => {
+        $(normalized_measure!(@row $measure $lht => $lh, $rhs);)*
+    };
+    (
+        @row ($trait:ident $methods:tt)
+        $lht:ty => $lh:ident,
+        [$($rht:ty => $rh:ident),* $(,)?]
+    ) => {
+        $(
+            impl<T> $trait<$rht> for $lht
+            where
+                $lht: Copy,
+                $rht: Copy,
+                $lh<T>: From<$lht>,
+                $rh<T>: From<$rht>,
+                $lh<T>: $trait<$rh<T>>,
+            {
+                type Scalar = <$lh<T> as $trait<$rh<T>>>::Scalar;
+                type Antiscalar = <$lh<T> as $trait<$rh<T>>>::Antiscalar;
+
+                normalized_measure!(@methods $rht => $lh, $rh $methods);
+            }
+        )*
+    };
+    (@methods $rht:ty => $lh:ident, $rh:ident { $($method:ident -> $output:ty),* }) => {
+        $(
+            fn $method(&self, rhs: &$rht) -> $output {
+                $lh::from(*self).$method(&$rh::from(*rhs))
+            }
+        )*
+    };
+}
+
+mod add;
 mod angle;
+#[cfg(feature = "proptest")]
+mod arbitrary;
 mod central_antiprojection;
 mod central_projection;
 mod contraction;
@@ -18,12 +94,20 @@ mod distance;
 mod dot;
 mod dual;
 mod expansion;
+mod fit;
 mod join;
+mod least_squares;
 mod meet;
 mod metric;
+mod motor;
+mod neg;
 mod norm;
+#[cfg(feature = "operators")]
+mod operators;
 mod orthogonal_antiprojection;
 mod orthogonal_projection;
+mod screw;
+mod sub;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NormalizedPoint<T> {
@@ -46,12 +130,54 @@ pub enum NormalizedLine<T> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Plane<T>(d4::Trivector<T>);
 
+/// Rigid motion, a rotation followed by a translation, stored as an even multivector of the 4D
+/// algebra
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Motor<T>(d4::Evenvector<T>);
+
+/// Velocity of a rigid body: angular velocity and the linear velocity of the point at the origin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Twist<T>(d4::Bivector<T>);
+
+/// Force and torque about the origin acting on a rigid body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wrench<T>(d4::Bivector<T>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NormalizedPlane<T> {
     Plane(Plane<T>),
+    /// The plane at infinity, only the `zyx` part of the plane is non-zero
+    ///
+    /// An all-zero plane is not the horizon: it fails to unitize with
+    /// [`UnitizeError::Degenerate`], before 0.2.0 it unitized to the horizon.
     Horizon,
 }
 
+/// Relative position of two lines, as returned by both their [`crate::Meet`] and [`crate::Join`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineIncidence<T> {
+    /// The lines share a single point
+    Intersecting(HomogeneusPoint<T>),
+    /// The lines share a direction but no point, the plane is the one that contains both
+    Parallel(HomogeneusPlane<T>),
+    /// The lines are not on a common plane
+    Skew,
+    /// Both lines are the same line
+    Coincident,
+    /// One of the lines is zero
+    Degenerate,
+}
+
+/// Reason why a least-squares point, like [`Line::nearest_point_to_all`], could not be found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeastSquaresError {
+    /// The inputs don't single out a point, `rank` is the number of independent directions
+    /// they constrain (`2` for parallel lines, `1` for parallel planes)
+    RankDeficient { rank: usize },
+    /// A weight is negative or there isn't one weight per input
+    InvalidWeights,
+}
+
 impl<T> Unitizable for HomogeneusPoint<T>
 where
     T: Float,
@@ -59,25 +185,35 @@ where
 {
     type Output = NormalizedPoint<T>;
     fn unitize(&self) -> Option<Self::Output> {
-        if self.w.is_near_zero() {
-            let len2 = self.x * self.x + self.y * self.y + self.z * self.z;
-            if len2.is_near_zero() {
-                None
-            } else {
-                let invlen = len2.sqrt().recip();
-                Some(NormalizedPoint::DirVector(d3::UnitVector(Vector::new(
-                    self.x * invlen,
-                    self.y * invlen,
-                    self.z * invlen,
+        self.try_unitize().ok()
+    }
+
+    fn try_unitize(&self) -> Result<Self::Output, UnitizeError> {
+        match self.w.zero_test() {
+            ZeroTest::Zero => {
+                let len2 = self.x * self.x + self.y * self.y + self.z * self.z;
+                match len2.zero_test() {
+                    ZeroTest::Zero => Err(UnitizeError::Degenerate),
+                    ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+                    ZeroTest::NonZero => {
+                        let invlen = len2.sqrt().recip();
+                        Ok(NormalizedPoint::DirVector(d3::UnitVector(Vector::new(
+                            self.x * invlen,
+                            self.y * invlen,
+                            self.z * invlen,
+                        ))))
+                    }
+                }
+            }
+            ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+            ZeroTest::NonZero => {
+                let w = self.w.recip();
+                Ok(NormalizedPoint::Point(d3::Point(Vector::new(
+                    self.x * w,
+                    self.y * w,
+                    self.z * w,
                 ))))
             }
-        } else {
-            let w = self.w.recip();
-            Some(NormalizedPoint::Point(d3::Point(Vector::new(
-                self.x * w,
-                self.y * w,
-                self.z * w,
-            ))))
         }
     }
 }
@@ -89,23 +225,38 @@ where
 {
     type Output = NormalizedLine<T>;
     fn unitize(&self) -> Option<Self::Output> {
-        if self.is_2_blade() {
-            let len2 = self.wx * self.wx + self.wy * self.wy + self.wz * self.wz;
-            if len2.is_near_zero() {
+        self.try_unitize().ok()
+    }
+
+    fn try_unitize(&self) -> Result<Self::Output, UnitizeError> {
+        let blade = self.wx * self.yz + self.wy * self.zx + self.wz * self.xy;
+        match blade.zero_test() {
+            ZeroTest::Zero => (),
+            ZeroTest::Unknown => return Err(UnitizeError::Ambiguous),
+            ZeroTest::NonZero => return Err(UnitizeError::Degenerate),
+        }
+
+        let len2 = self.wx * self.wx + self.wy * self.wy + self.wz * self.wz;
+        match len2.zero_test() {
+            ZeroTest::Zero => {
                 let len2 = self.yz * self.yz + self.zx * self.zx + self.xy * self.xy;
-                if len2.is_near_zero() {
-                    None
-                } else {
-                    let invlen = len2.sqrt().recip();
-                    Some(NormalizedLine::HorizonLine(HorizonLine(d3::Bivector::new(
-                        self.yz * invlen,
-                        self.zx * invlen,
-                        self.xy * invlen,
-                    ))))
+                match len2.zero_test() {
+                    ZeroTest::Zero => Err(UnitizeError::Degenerate),
+                    ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+                    ZeroTest::NonZero => {
+                        let invlen = len2.sqrt().recip();
+                        Ok(NormalizedLine::HorizonLine(HorizonLine(d3::Bivector::new(
+                            self.yz * invlen,
+                            self.zx * invlen,
+                            self.xy * invlen,
+                        ))))
+                    }
                 }
-            } else {
+            }
+            ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+            ZeroTest::NonZero => {
                 let invlen = len2.sqrt().recip();
-                Some(NormalizedLine::Line(Line(d4::Bivector {
+                Ok(NormalizedLine::Line(Line(d4::Bivector {
                     wx: self.wx * invlen,
                     wy: self.wy * invlen,
                     wz: self.wz * invlen,
@@ -114,8 +265,6 @@ where
                     xy: self.xy * invlen,
                 })))
             }
-        } else {
-            None
         }
     }
 }
@@ -127,21 +276,27 @@ where
 {
     type Output = NormalizedPlane<T>;
     fn unitize(&self) -> Option<Self::Output> {
+        self.try_unitize().ok()
+    }
+
+    fn try_unitize(&self) -> Result<Self::Output, UnitizeError> {
         let len2 = self.wyz * self.wyz + self.wzx * self.wzx + self.wxy * self.wxy;
-        if len2.is_near_zero() {
-            if self.zyx.is_near_zero() {
-                Some(NormalizedPlane::Horizon)
-            } else {
-                None
+        match len2.zero_test() {
+            ZeroTest::Zero => match self.zyx.zero_test() {
+                ZeroTest::Zero => Err(UnitizeError::Degenerate),
+                ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+                ZeroTest::NonZero => Ok(NormalizedPlane::Horizon),
+            },
+            ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+            ZeroTest::NonZero => {
+                let invlen = len2.sqrt().recip();
+                Ok(NormalizedPlane::Plane(Plane(d4::Trivector {
+                    wyz: self.wyz * invlen,
+                    wzx: self.wzx * invlen,
+                    wxy: self.wxy * invlen,
+                    zyx: self.zyx * invlen,
+                })))
             }
-        } else {
-            let invlen = len2.sqrt().recip();
-            Some(NormalizedPlane::Plane(Plane(d4::Trivector {
-                wyz: self.wyz * invlen,
-                wzx: self.wzx * invlen,
-                wxy: self.wxy * invlen,
-                zyx: self.zyx * invlen,
-            })))
         }
     }
 }
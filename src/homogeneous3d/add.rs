@@ -0,0 +1,32 @@
+use std::ops::Add;
+
+use super::{HomogeneusLine, HomogeneusPlane, HorizonLine, Line, Plane};
+
+/// Implements `+` of two primitives as the sum of their homogeneous k-vectors, which may not be a
+/// primitive anymore (like two skew lines adding to a non-simple bivector)
+macro_rules! add_as_homogeneous {
+    ($lht:ident as $lh:ident, $rht:ident as $rh:ident) => {
+        impl<T> Add<$rht<T>> for $lht<T>
+        where
+            $lh<T>: From<$lht<T>>,
+            $rh<T>: From<$rht<T>>,
+            $lh<T>: Add<$rh<T>>,
+        {
+            type Output = <$lh<T> as Add<$rh<T>>>::Output;
+
+            fn add(self, rhs: $rht<T>) -> Self::Output {
+                $lh::from(self) + $rh::from(rhs)
+            }
+        }
+    };
+}
+
+add_as_homogeneous!(Line as HomogeneusLine, Line as HomogeneusLine);
+add_as_homogeneous!(Line as HomogeneusLine, HorizonLine as HomogeneusLine);
+add_as_homogeneous!(Line as HomogeneusLine, Plane as HomogeneusPlane);
+add_as_homogeneous!(HorizonLine as HomogeneusLine, Line as HomogeneusLine);
+add_as_homogeneous!(HorizonLine as HomogeneusLine, HorizonLine as HomogeneusLine);
+add_as_homogeneous!(HorizonLine as HomogeneusLine, Plane as HomogeneusPlane);
+add_as_homogeneous!(Plane as HomogeneusPlane, Line as HomogeneusLine);
+add_as_homogeneous!(Plane as HomogeneusPlane, HorizonLine as HomogeneusLine);
+add_as_homogeneous!(Plane as HomogeneusPlane, Plane as HomogeneusPlane);
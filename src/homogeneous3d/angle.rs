@@ -1,136 +1,123 @@
+//! Angle between the primitives of the projective 3D space
+//!
+//! Angles are measured between attitudes: the direction of lines and points at infinity, and the
+//! normal of planes and horizon lines, which are the lines at infinity of a family of parallel
+//! planes. Finite points and the horizon have no attitude, so their angle with anything is undefined.
+
 use num::Float;
-use num::traits::ConstOne;
 
 use crate::Angle;
-
 use crate::Epsilon;
-use crate::Metric;
 use crate::geometry3d as d3;
 use crate::geometry4d as d4;
 
 use super::HomogeneusLine;
 use super::HomogeneusPlane;
+use super::HomogeneusPoint;
+use super::HorizonLine;
 use super::Line;
+use super::NormalizedLine;
+use super::NormalizedPlane;
+use super::NormalizedPoint;
 use super::Plane;
 
-impl<T> Angle<HomogeneusLine<T>> for HomogeneusLine<T>
-where
-    T: Float,
-    T: Epsilon,
-    HomogeneusLine<T>: Metric<Weight = d3::Vector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_cosine(&self, rhs: &HomogeneusLine<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dim3 = self.weight().geometric_cosine(&rhs.weight());
-        (d4::Scalar(dim3.0), d4::Quadvector { xyzw: dim3.1.xyz })
-    }
-
-    fn cosine(&self, rhs: &HomogeneusLine<T>) -> Option<Self::Scalar> {
-        let geometric = self.geometric_cosine(rhs);
-        Some(d4::Scalar(geometric.0.0 / geometric.1.xyzw))
-    }
+enum Orientation<T> {
+    Direction(d3::Vector<T>),
+    Normal(d3::Bivector<T>),
+    /// The primitive has no attitude
+    Unoriented,
 }
 
-impl<T> Angle<Line<T>> for Line<T>
-where
-    T: ConstOne,
-    T: Float,
-    Line<T>: Metric<Weight = d3::UnitVector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_cosine(&self, rhs: &Line<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dim3 = self.weight().cosine(&rhs.weight()).unwrap();
-        (d4::Scalar(dim3.0), d4::Quadvector { xyzw: T::ONE })
-    }
-
-    fn cosine(&self, rhs: &Line<T>) -> Option<Self::Scalar> {
-        let geometric = self.geometric_cosine(rhs);
-        Some(d4::Scalar(geometric.0.0))
-    }
-}
-
-impl<T> Angle<HomogeneusLine<T>> for HomogeneusPlane<T>
+impl<T> Orientation<T>
 where
     T: Float,
     T: Epsilon,
-    HomogeneusLine<T>: Metric<Weight = d3::Vector<T>>,
-    HomogeneusPlane<T>: Metric<Weight = d3::Bivector<T>>,
 {
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_cosine(&self, rhs: &HomogeneusLine<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dim3 = self.weight().geometric_cosine(&rhs.weight());
-        (d4::Scalar(dim3.0), d4::Quadvector { xyzw: dim3.1.xyz })
+    fn of_point(point: &HomogeneusPoint<T>) -> Self {
+        let direction = d3::Vector::new(point.x, point.y, point.z);
+        let len = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+        if (point.w / (len * len + point.w * point.w).sqrt()).is_near_zero() {
+            Orientation::Direction(direction)
+        } else {
+            Orientation::Unoriented
+        }
     }
 
-    fn cosine(&self, rhs: &HomogeneusLine<T>) -> Option<Self::Scalar> {
-        let geometric = self.geometric_cosine(rhs);
-        Some(d4::Scalar(geometric.0.0 / geometric.1.xyzw))
+    fn of_line(line: &HomogeneusLine<T>) -> Self {
+        let len2 = line.wx * line.wx + line.wy * line.wy + line.wz * line.wz;
+        let moment2 = line.yz * line.yz + line.zx * line.zx + line.xy * line.xy;
+        if (len2.sqrt() / (len2 + moment2).sqrt()).is_near_zero() {
+            Orientation::Normal(d3::Bivector::new(line.yz, line.zx, line.xy))
+        } else {
+            Orientation::Direction(d3::Vector::new(line.wx, line.wy, line.wz))
+        }
     }
-}
 
-impl<T> Angle<Line<T>> for Plane<T>
-where
-    T: ConstOne,
-    T: Float,
-    Line<T>: Metric<Weight = d3::UnitVector<T>>,
-    Plane<T>: Metric<Weight = d3::UnitBivector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_cosine(&self, rhs: &Line<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dim3 = self.weight().cosine(&rhs.weight()).unwrap();
-        (d4::Scalar(dim3.0), d4::Quadvector { xyzw: T::ONE })
+    fn of_plane(plane: &HomogeneusPlane<T>) -> Self {
+        let len2 = plane.wyz * plane.wyz + plane.wzx * plane.wzx + plane.wxy * plane.wxy;
+        if (len2.sqrt() / (len2 + plane.zyx * plane.zyx).sqrt()).is_near_zero() {
+            Orientation::Unoriented
+        } else {
+            Orientation::Normal(d3::Bivector::new(plane.wyz, plane.wzx, plane.wxy))
+        }
     }
 
-    fn cosine(&self, rhs: &Line<T>) -> Option<Self::Scalar> {
-        let geometric = self.geometric_cosine(rhs);
-        Some(d4::Scalar(geometric.0.0 / geometric.1.xyzw))
+    fn geometric_cosine(&self, rhs: &Self) -> (d4::Scalar<T>, d4::Quadvector<T>) {
+        let dim3 = match (self, rhs) {
+            (Orientation::Direction(a), Orientation::Direction(b)) => a.geometric_cosine(b),
+            (Orientation::Direction(a), Orientation::Normal(b)) => a.geometric_cosine(b),
+            (Orientation::Normal(a), Orientation::Direction(b)) => a.geometric_cosine(b),
+            (Orientation::Normal(a), Orientation::Normal(b)) => a.geometric_cosine(b),
+            // without an attitude the angle is undefined, `0 / 0`
+            _ => {
+                return (
+                    d4::Scalar::new(T::zero()),
+                    d4::Quadvector { xyzw: T::zero() },
+                );
+            }
+        };
+        (d4::Scalar::new(dim3.0), d4::Quadvector { xyzw: dim3.1.xyz })
     }
 }
 
-impl<T> Angle<HomogeneusPlane<T>> for HomogeneusPlane<T>
-where
-    T: Float,
-    T: Epsilon,
-    HomogeneusPlane<T>: Metric<Weight = d3::Bivector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_cosine(&self, rhs: &HomogeneusPlane<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dim3 = self.weight().geometric_cosine(&rhs.weight());
-        (d4::Scalar(dim3.0), d4::Quadvector { xyzw: dim3.1.xyz })
-    }
-
-    fn cosine(&self, rhs: &HomogeneusPlane<T>) -> Option<Self::Scalar> {
-        let geometric = self.geometric_cosine(rhs);
-        Some(d4::Scalar(geometric.0.0 / geometric.1.xyzw))
-    }
+/// Implements `Angle` between homogeneous primitives from their orientations
+macro_rules! homogeneous_angle {
+    ($lht:ident => $lhs:ident, $rht:ident => $rhs:ident) => {
+        impl<T> Angle<$rht<T>> for $lht<T>
+        where
+            T: Float,
+            T: Epsilon,
+        {
+            type Scalar = d4::Scalar<T>;
+            type Antiscalar = d4::Quadvector<T>;
+
+            fn geometric_cosine(&self, rhs: &$rht<T>) -> (Self::Scalar, Self::Antiscalar) {
+                Orientation::$lhs(self).geometric_cosine(&Orientation::$rhs(rhs))
+            }
+
+            fn cosine(&self, rhs: &$rht<T>) -> Option<Self::Scalar> {
+                let geometric = self.geometric_cosine(rhs);
+                if geometric.1.xyzw.is_near_zero() {
+                    None
+                } else {
+                    Some(d4::Scalar::new(geometric.0.0 / geometric.1.xyzw))
+                }
+            }
+        }
+    };
 }
 
-impl<T> Angle<Plane<T>> for Plane<T>
-where
-    T: ConstOne,
-    T: Float,
-    Plane<T>: Metric<Weight = d3::UnitBivector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_cosine(&self, rhs: &Plane<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dim3 = self.weight().cosine(&rhs.weight()).unwrap();
-        (d4::Scalar(dim3.0), d4::Quadvector { xyzw: T::ONE })
-    }
-
-    fn cosine(&self, rhs: &Plane<T>) -> Option<Self::Scalar> {
-        let geometric = self.weight().cosine(&rhs.weight()).unwrap();
-        Some(d4::Scalar(geometric.0))
-    }
-}
+homogeneous_angle!(HomogeneusPoint => of_point, HomogeneusPoint => of_point);
+homogeneous_angle!(HomogeneusPoint => of_point, HomogeneusLine => of_line);
+homogeneous_angle!(HomogeneusPoint => of_point, HomogeneusPlane => of_plane);
+homogeneous_angle!(HomogeneusLine => of_line, HomogeneusPoint => of_point);
+homogeneous_angle!(HomogeneusLine => of_line, HomogeneusLine => of_line);
+homogeneous_angle!(HomogeneusLine => of_line, HomogeneusPlane => of_plane);
+homogeneous_angle!(HomogeneusPlane => of_plane, HomogeneusPoint => of_point);
+homogeneous_angle!(HomogeneusPlane => of_plane, HomogeneusLine => of_line);
+homogeneous_angle!(HomogeneusPlane => of_plane, HomogeneusPlane => of_plane);
+
+normalized_measure!(Angle {
+    geometric_cosine -> (Self::Scalar, Self::Antiscalar),
+    cosine -> Option<Self::Scalar>,
+});
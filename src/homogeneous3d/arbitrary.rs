@@ -0,0 +1,236 @@
+use std::fmt::Debug;
+
+use num::Float;
+use num::traits::{ConstOne, ConstZero};
+use proptest::prelude::*;
+
+use crate::geometry3d as d3;
+use crate::geometry4d as d4;
+use crate::{Epsilon, WedgeProduct, arbitrary::field};
+
+use super::{
+    HomogeneusPoint, HorizonLine, Line, Motor, NormalizedLine, NormalizedPlane, NormalizedPoint,
+    Plane, Twist, Wrench,
+};
+
+impl<T> Arbitrary for NormalizedPoint<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            3 => any::<d3::Point<T>>().prop_map(NormalizedPoint::Point),
+            1 => any::<d3::UnitVector<T>>().prop_map(NormalizedPoint::DirVector),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> Arbitrary for Line<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<d3::Point<T>>(), any::<d3::UnitVector<T>>())
+            .prop_map(|(point, dir)| {
+                let dir = d3::Vector::from(dir);
+                let point = HomogeneusPoint {
+                    x: point.0.x,
+                    y: point.0.y,
+                    z: point.0.z,
+                    w: T::one(),
+                };
+                let dir = HomogeneusPoint {
+                    x: dir.x,
+                    y: dir.y,
+                    z: dir.z,
+                    w: T::zero(),
+                };
+                Line(point.wedge(&dir))
+            })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for HorizonLine<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<d3::UnitBivector<T>>()
+            .prop_map(|b| HorizonLine(b.into()))
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for NormalizedLine<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            3 => any::<Line<T>>().prop_map(NormalizedLine::Line),
+            1 => any::<HorizonLine<T>>().prop_map(NormalizedLine::HorizonLine),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> Arbitrary for Plane<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<d3::UnitVector<T>>(), field())
+            .prop_map(|(normal, zyx)| {
+                let normal = d3::Vector::from(normal);
+                Plane(d4::Trivector {
+                    wyz: normal.x,
+                    wzx: normal.y,
+                    wxy: normal.z,
+                    zyx,
+                })
+            })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for NormalizedPlane<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            3 => any::<Plane<T>>().prop_map(NormalizedPlane::Plane),
+            1 => Just(NormalizedPlane::Horizon),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> Arbitrary for Motor<T>
+where
+    T: Float,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Unitized motors: a rotation by any angle around an axis through the origin followed by a
+    /// translation
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (
+            any::<d3::UnitVector<T>>(),
+            -std::f64::consts::PI..std::f64::consts::PI,
+            any::<d3::Vector<T>>(),
+        )
+            .prop_map(|(axis, half_angle, offset)| {
+                let axis = d3::Vector::from(axis);
+                let (sin, cos) = T::from(half_angle)
+                    .expect("T should hold f64 values")
+                    .sin_cos();
+                Motor::from_parts(
+                    [cos, axis.x * sin, axis.y * sin, axis.z * sin],
+                    [offset.x, offset.y, offset.z],
+                )
+            })
+            .boxed()
+    }
+}
+
+/// Nonzero speeds or intensities of either sign, far enough from zero to keep a direction
+fn magnitude<T>() -> BoxedStrategy<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    (0.1f64..10.0, any::<bool>())
+        .prop_map(|(m, negative)| {
+            T::from(if negative { -m } else { m }).expect("T should hold f64 values")
+        })
+        .boxed()
+}
+
+impl<T> Arbitrary for Twist<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Screw motions around any line with a bounded pitch, and pure translations
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            3 => (any::<Line<T>>(), -5.0f64..5.0, magnitude()).prop_map(|(line, pitch, speed)| {
+                let pitch = T::from(pitch).expect("T should hold f64 values");
+                Twist::from_line(&line, pitch) * speed
+            }),
+            1 => (any::<d3::UnitVector<T>>(), magnitude::<T>()).prop_map(|(direction, speed)| {
+                Twist::new(&d3::Vector::ZERO, &(d3::Vector::from(direction) * speed))
+            }),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> Arbitrary for Wrench<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// The same screws as [`Twist`], forces along any line and pure torques
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<Twist<T>>().prop_map(|twist| Wrench(twist.0)).boxed()
+    }
+}
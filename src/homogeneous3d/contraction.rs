@@ -134,37 +134,6 @@ where
     fn weight_contraction(&self, _rhs: &d4::Scalar<T>) -> Self::WeightOutput {}
 }
 
-impl<T> Contraction<HomogeneusPoint<T>> for HomogeneusLine<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Add<T, Output = T>,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusPoint<T>;
-    type WeightOutput = HomogeneusPoint<T>;
-
-    fn bulk_contraction(&self, rhs: &HomogeneusPoint<T>) -> Self::BulkOutput {
-        HomogeneusPoint {
-            x: self.zx * rhs.z - self.xy * rhs.y,
-            y: self.xy * rhs.x - self.yz * rhs.z,
-            z: self.yz * rhs.y - self.zx * rhs.x,
-            w: -(self.wx * rhs.x + self.wy * rhs.y + self.wz * rhs.z),
-        }
-    }
-
-    fn weight_contraction(&self, rhs: &HomogeneusPoint<T>) -> Self::WeightOutput {
-        HomogeneusPoint {
-            x: self.wx * rhs.w,
-            y: self.wy * rhs.w,
-            z: self.wz * rhs.w,
-            w: T::ZERO,
-        }
-    }
-}
-
 impl<T> Contraction<d3::Point<T>> for Line<T>
 where
     T: Copy,
@@ -305,40 +274,6 @@ where
     fn weight_contraction(&self, _rhs: &d4::Scalar<T>) -> Self::WeightOutput {}
 }
 
-impl<T> Contraction<HomogeneusPoint<T>> for HomogeneusPlane<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusLine<T>;
-    type WeightOutput = HomogeneusLine<T>;
-
-    fn bulk_contraction(&self, rhs: &HomogeneusPoint<T>) -> Self::BulkOutput {
-        HomogeneusLine {
-            wx: self.wxy * rhs.y - self.wzx * rhs.z,
-            wy: self.wyz * rhs.z - self.wxy * rhs.x,
-            wz: self.wzx * rhs.x - self.wyz * rhs.y,
-            yz: -self.zyx * rhs.x,
-            zx: -self.zyx * rhs.y,
-            xy: -self.zyx * rhs.z,
-        }
-    }
-
-    fn weight_contraction(&self, rhs: &HomogeneusPoint<T>) -> Self::WeightOutput {
-        HomogeneusLine {
-            wx: T::ZERO,
-            wy: T::ZERO,
-            wz: T::ZERO,
-            yz: self.wyz * rhs.w,
-            zx: self.wzx * rhs.w,
-            xy: self.wxy * rhs.w,
-        }
-    }
-}
-
 impl<T> Contraction<d3::Point<T>> for Plane<T>
 where
     T: Copy,
@@ -420,34 +355,6 @@ where
     fn weight_contraction(&self, _rhs: &d4::Scalar<T>) -> Self::WeightOutput {}
 }
 
-impl<T> Contraction<HomogeneusPoint<T>> for d4::Quadvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusPlane<T>;
-    type WeightOutput = HomogeneusPlane<T>;
-
-    fn bulk_contraction(&self, rhs: &HomogeneusPoint<T>) -> Self::BulkOutput {
-        HomogeneusPlane {
-            wyz: self.xyzw * rhs.x,
-            wzx: self.xyzw * rhs.y,
-            wxy: self.xyzw * rhs.z,
-            zyx: T::ZERO,
-        }
-    }
-
-    fn weight_contraction(&self, rhs: &HomogeneusPoint<T>) -> Self::WeightOutput {
-        HomogeneusPlane {
-            wyz: T::ZERO,
-            wzx: T::ZERO,
-            wxy: T::ZERO,
-            zyx: self.xyzw * rhs.w,
-        }
-    }
-}
-
 impl<T> Contraction<d3::Point<T>> for d4::Quadvector<T>
 where
     T: Copy,
@@ -489,65 +396,3 @@ where
 
     fn weight_contraction(&self, _rhs: &d3::Vector<T>) -> Self::WeightOutput {}
 }
-
-impl<T> Contraction<HomogeneusLine<T>> for d4::Quadvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusLine<T>;
-    type WeightOutput = HomogeneusLine<T>;
-
-    fn bulk_contraction(&self, rhs: &HomogeneusLine<T>) -> Self::BulkOutput {
-        HomogeneusLine {
-            wx: -self.xyzw * rhs.yz,
-            wy: -self.xyzw * rhs.zx,
-            wz: -self.xyzw * rhs.xy,
-            yz: T::ZERO,
-            zx: T::ZERO,
-            xy: T::ZERO,
-        }
-    }
-
-    fn weight_contraction(&self, rhs: &HomogeneusLine<T>) -> Self::WeightOutput {
-        HomogeneusLine {
-            wx: T::ZERO,
-            wy: T::ZERO,
-            wz: T::ZERO,
-            yz: -self.xyzw * rhs.wx,
-            zx: -self.xyzw * rhs.wy,
-            xy: -self.xyzw * rhs.wz,
-        }
-    }
-}
-
-impl<T> Contraction<HomogeneusPlane<T>> for d4::Quadvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusPlane<T>;
-    type WeightOutput = HomogeneusPlane<T>;
-
-    fn bulk_contraction(&self, rhs: &HomogeneusPlane<T>) -> Self::BulkOutput {
-        HomogeneusPlane {
-            wyz: T::ZERO,
-            wzx: T::ZERO,
-            wxy: T::ZERO,
-            zyx: -self.xyzw * rhs.zyx,
-        }
-    }
-
-    fn weight_contraction(&self, rhs: &HomogeneusPlane<T>) -> Self::WeightOutput {
-        HomogeneusPlane {
-            wyz: -self.xyzw * rhs.wyz,
-            wzx: -self.xyzw * rhs.wzx,
-            wxy: -self.xyzw * rhs.wxy,
-            zyx: T::ZERO,
-        }
-    }
-}
@@ -1,8 +1,12 @@
 use num::traits::{ConstOne, ConstZero};
 
 use crate::geometry3d as d3;
+use crate::geometry4d as d4;
 
-use super::{HomogeneusLine, HomogeneusPlane, HomogeneusPoint, HorizonLine, Line, Plane};
+use super::{
+    HomogeneusLine, HomogeneusPlane, HomogeneusPoint, HorizonLine, Line, Motor, NormalizedLine,
+    NormalizedPlane, NormalizedPoint, Plane, Twist, Wrench,
+};
 
 impl<T> From<d3::Point<T>> for HomogeneusPoint<T>
 where
@@ -32,6 +36,28 @@ where
     }
 }
 
+impl<T> From<d3::UnitVector<T>> for HomogeneusPoint<T>
+where
+    T: ConstZero,
+{
+    fn from(value: d3::UnitVector<T>) -> Self {
+        d3::Vector::from(value).into()
+    }
+}
+
+impl<T> From<NormalizedPoint<T>> for HomogeneusPoint<T>
+where
+    T: ConstZero,
+    T: ConstOne,
+{
+    fn from(value: NormalizedPoint<T>) -> Self {
+        match value {
+            NormalizedPoint::Point(point) => point.into(),
+            NormalizedPoint::DirVector(direction) => direction.into(),
+        }
+    }
+}
+
 impl<T> From<Line<T>> for HomogeneusLine<T> {
     fn from(value: Line<T>) -> Self {
         value.0
@@ -54,8 +80,74 @@ where
     }
 }
 
+impl<T> From<Motor<T>> for d4::Evenvector<T> {
+    fn from(value: Motor<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> From<d4::Evenvector<T>> for Motor<T> {
+    fn from(value: d4::Evenvector<T>) -> Self {
+        Motor(value)
+    }
+}
+
+impl<T> From<Twist<T>> for HomogeneusLine<T> {
+    fn from(value: Twist<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> From<HomogeneusLine<T>> for Twist<T> {
+    fn from(value: HomogeneusLine<T>) -> Self {
+        Twist(value)
+    }
+}
+
+impl<T> From<Wrench<T>> for HomogeneusLine<T> {
+    fn from(value: Wrench<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> From<HomogeneusLine<T>> for Wrench<T> {
+    fn from(value: HomogeneusLine<T>) -> Self {
+        Wrench(value)
+    }
+}
+
 impl<T> From<Plane<T>> for HomogeneusPlane<T> {
     fn from(value: Plane<T>) -> Self {
         value.0
     }
 }
+
+impl<T> From<NormalizedLine<T>> for HomogeneusLine<T>
+where
+    T: ConstZero,
+{
+    fn from(value: NormalizedLine<T>) -> Self {
+        match value {
+            NormalizedLine::Line(line) => line.into(),
+            NormalizedLine::HorizonLine(line) => line.into(),
+        }
+    }
+}
+
+impl<T> From<NormalizedPlane<T>> for HomogeneusPlane<T>
+where
+    T: ConstZero,
+    T: ConstOne,
+{
+    fn from(value: NormalizedPlane<T>) -> Self {
+        match value {
+            NormalizedPlane::Plane(plane) => plane.into(),
+            NormalizedPlane::Horizon => HomogeneusPlane {
+                wyz: T::ZERO,
+                wzx: T::ZERO,
+                wxy: T::ZERO,
+                zyx: T::ONE,
+            },
+        }
+    }
+}
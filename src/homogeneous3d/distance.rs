@@ -1,153 +1,108 @@
+//! Euclidean distance between the primitives of the projective 3D space
+//!
+//! Every distance is returned as a homogeneous magnitude `(bulk, weight)`, whose value is
+//! `bulk / weight`. The primitives at infinity are handled as such instead of producing `0 / 0`:
+//! - finite primitives are measured as usual, parallel ones return their separation and the ones
+//!   that intersect return zero
+//! - a primitive at infinity is infinitely far (zero weight, nonzero bulk) from anything it
+//!   doesn't meet, and at distance zero of anything it meets, even if they meet at infinity
+//!
+//! The distances that have a side keep their sign: a point or a parallel line or plane is on the
+//! positive side of a plane when it lies where its normal points, and two skew lines are signed by
+//! their relative orientation. The absolute value of the bulk gives the unsigned distance.
+
 use num::Float;
-use num::traits::ConstOne;
-use num::traits::ConstZero;
+use num::traits::{ConstOne, ConstZero};
 
-use crate::Antiscalar;
-use crate::Metric;
+use crate::AntiwedgeProduct;
 use crate::Distance;
 use crate::Dot;
+use crate::Epsilon;
+use crate::Metric;
 use crate::Norm;
 use crate::WedgeProduct;
-use crate::geometry4d::Quadvector;
+use crate::geometry3d as d3;
+use crate::geometry4d as d4;
 
 use super::HomogeneusLine;
 use super::HomogeneusPlane;
 use super::HomogeneusPoint;
+use super::HorizonLine;
 use super::Line;
+use super::NormalizedLine;
+use super::NormalizedPlane;
+use super::NormalizedPoint;
 use super::Plane;
-use crate::geometry3d as d3;
-use crate::geometry4d as d4;
-
-impl<T> Distance<HomogeneusPoint<T>> for HomogeneusPoint<T>
-where
-    T: Float,
-    T: ConstOne,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
 
-    fn geometric_distance(&self, rhs: &HomogeneusPoint<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dist = *self - *rhs;
-        dist.geometric_dot(&dist)
-    }
-
-    fn distance(&self, rhs: &HomogeneusPoint<T>) -> Self::Scalar {
-        let dot = self.geometric_dot(rhs);
-        d4::Scalar(dot.0.0 / dot.1.xyzw)
-    }
-}
-
-impl<T> Distance<d3::Point<T>> for d3::Point<T>
+/// Distance between two primitives that are not both finite: zero if they meet, which is the
+/// case when `bulk` vanishes, and infinite otherwise
+fn at_infinity<T>(bulk: T) -> (d4::Scalar<T>, d4::Quadvector<T>)
 where
     T: Float,
-    T: ConstOne,
-    d3::Vector<T>: Dot<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_distance(&self, rhs: &d3::Point<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let dist = *self - *rhs;
-        (dist.dot(&dist), Quadvector::UNIT_VOLUME)
-    }
-
-    fn distance(&self, rhs: &d3::Point<T>) -> Self::Scalar {
-        d4::Scalar(self.dot(rhs).0)
+    if bulk.is_zero() {
+        (
+            d4::Scalar::new(T::zero()),
+            d4::Quadvector { xyzw: T::one() },
+        )
+    } else {
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: T::zero() })
     }
 }
 
-impl<T> Distance<HomogeneusLine<T>> for HomogeneusPoint<T>
-where
-    T: Float,
-    T: ConstZero,
-    T: ConstOne,
-    HomogeneusPoint<T>: Dot<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    HomogeneusLine<T>: Dot<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    HomogeneusPoint<T>: Norm,
-    HomogeneusLine<T>: Norm,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_distance(&self, rhs: &HomogeneusLine<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let a = self.bulk().wedge(&rhs.weight());
-        let b = self.weight().wedge(&rhs.bulk());
-        let bulk = a + b;
-        let weight = self.weight().wedge(&rhs.weight());
-        let bulk_norm = (bulk.yz * bulk.yz + bulk.zx * bulk.zx + bulk.xy * bulk.xy).sqrt();
-        let weight_norm = (weight.x * weight.x + weight.y * weight.y + weight.z * weight.z).sqrt();
-        (d4::Scalar(bulk_norm), d4::Quadvector { xyzw: weight_norm })
-    }
-
-    fn distance(&self, rhs: &HomogeneusLine<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0 / geometric_distance.1.xyzw)
-    }
+fn ratio<T: Float>((bulk, weight): (d4::Scalar<T>, d4::Quadvector<T>)) -> d4::Scalar<T> {
+    d4::Scalar::new(bulk.0 / weight.xyzw)
 }
 
-impl<T> Distance<HomogeneusPoint<T>> for HomogeneusLine<T>
+impl<T> Distance<HomogeneusPoint<T>> for HomogeneusPoint<T>
 where
     T: Float,
     T: ConstZero,
     T: ConstOne,
-    HomogeneusPoint<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    HomogeneusLine<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
     type Scalar = d4::Scalar<T>;
     type Antiscalar = d4::Quadvector<T>;
 
     fn geometric_distance(&self, rhs: &HomogeneusPoint<T>) -> (Self::Scalar, Self::Antiscalar) {
-        rhs.geometric_distance(self)
+        // the direction of the line through both points is `self.w * rhs - rhs.w * self`
+        let line = self.wedge(rhs);
+        let bulk = line.weight().bulk_norm();
+        let weight = (self.w * rhs.w).abs();
+        if bulk.is_zero() && weight.is_zero() {
+            // two points at infinity are the same point if they span no line
+            return at_infinity(line.bulk().bulk_norm());
+        }
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight })
     }
 
     fn distance(&self, rhs: &HomogeneusPoint<T>) -> Self::Scalar {
-        rhs.distance(self)
+        ratio(self.geometric_distance(rhs))
     }
 }
 
-impl<T> Distance<Line<T>> for d3::Point<T>
-where
-    T: Float,
-    T: ConstZero,
-    T: ConstOne,
-    d3::Point<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    Line<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_distance(&self, rhs: &Line<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let line_dir: d3::Vector<T> = rhs.weight().into();
-        let a = self.bulk().wedge(&line_dir);
-        let b = self.weight().wedge(&rhs.bulk());
-        let bulk = a + b;
-        let bulk_norm = (bulk.yz * bulk.yz + bulk.zx * bulk.zx + bulk.xy * bulk.xy).sqrt();
-        (d4::Scalar(bulk_norm), d4::Quadvector { xyzw: T::ONE })
-    }
-
-    fn distance(&self, rhs: &Line<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0)
-    }
-}
-
-impl<T> Distance<d3::Point<T>> for Line<T>
+impl<T> Distance<HomogeneusLine<T>> for HomogeneusPoint<T>
 where
     T: Float,
     T: ConstZero,
     T: ConstOne,
-    d3::Point<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    Line<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
     type Scalar = d4::Scalar<T>;
     type Antiscalar = d4::Quadvector<T>;
 
-    fn geometric_distance(&self, rhs: &d3::Point<T>) -> (Self::Scalar, Self::Antiscalar) {
-        rhs.geometric_distance(self)
+    fn geometric_distance(&self, rhs: &HomogeneusLine<T>) -> (Self::Scalar, Self::Antiscalar) {
+        // the normal of the plane through the point and the line grows with their distance
+        let plane = self.wedge(rhs);
+        let bulk = plane.weight().bulk_norm();
+        let weight = self.w.abs() * rhs.weight().bulk_norm();
+        if bulk.is_zero() && weight.is_zero() {
+            // a point at infinity and a line through it don't span a plane
+            return at_infinity(plane.bulk().bulk_norm());
+        }
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight })
     }
 
-    fn distance(&self, rhs: &d3::Point<T>) -> Self::Scalar {
-        rhs.distance(self)
+    fn distance(&self, rhs: &HomogeneusLine<T>) -> Self::Scalar {
+        ratio(self.geometric_distance(rhs))
     }
 }
 
@@ -156,150 +111,178 @@ where
     T: Float,
     T: ConstZero,
     T: ConstOne,
-    HomogeneusPoint<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    HomogeneusPlane<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
     type Scalar = d4::Scalar<T>;
     type Antiscalar = d4::Quadvector<T>;
 
     fn geometric_distance(&self, rhs: &HomogeneusPlane<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let signed_distance =
-            self.x * rhs.wyz + self.y * rhs.wzx + self.z * rhs.wxy + self.w * rhs.zyx;
-        let weight = self.w * (rhs.wyz * rhs.wyz + rhs.wzx * rhs.wzx + rhs.wxy * rhs.wxy).sqrt();
-        (d4::Scalar(signed_distance), d4::Quadvector { xyzw: weight })
+        let bulk = self.wedge(rhs).xyzw;
+        let weight = self.w * rhs.weight().bulk_norm();
+        if bulk.is_zero() && weight.is_zero() {
+            return at_infinity(bulk);
+        }
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight })
     }
 
     fn distance(&self, rhs: &HomogeneusPlane<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0 / geometric_distance.1.xyzw)
+        ratio(self.geometric_distance(rhs))
     }
 }
 
-impl<T> Distance<HomogeneusPoint<T>> for HomogeneusPlane<T>
+impl<T> Distance<HomogeneusLine<T>> for HomogeneusLine<T>
 where
     T: Float,
+    T: Epsilon,
     T: ConstZero,
     T: ConstOne,
-    HomogeneusPoint<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    HomogeneusPlane<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
     type Scalar = d4::Scalar<T>;
     type Antiscalar = d4::Quadvector<T>;
 
-    fn geometric_distance(&self, rhs: &HomogeneusPoint<T>) -> (Self::Scalar, Self::Antiscalar) {
-        rhs.geometric_distance(self)
+    fn geometric_distance(&self, rhs: &HomogeneusLine<T>) -> (Self::Scalar, Self::Antiscalar) {
+        let (lhs_direction, rhs_direction) = (self.weight(), rhs.weight());
+        let bulk = self.wedge(rhs).xyzw;
+
+        let lhs_length = lhs_direction.bulk_norm();
+        let rhs_length = rhs_direction.bulk_norm();
+        let lengths = lhs_length * rhs_length;
+        if lengths.is_zero() {
+            return at_infinity(bulk);
+        }
+
+        // the lines are parallel when the sine of the angle between their directions vanishes
+        let weight = lhs_direction.wedge(&rhs_direction).bulk_norm();
+        if !(weight / lengths).is_near_zero() {
+            return (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight });
+        }
+        let weight = lengths;
+
+        // parallel lines: the moments of the unitized lines differ by the separation crossed
+        // with their common direction, which has no sign
+        let rhs_length = if lhs_direction.dot(&rhs_direction) < T::zero() {
+            -rhs_length
+        } else {
+            rhs_length
+        };
+        let bulk = (self.bulk() * rhs_length - rhs.bulk() * lhs_length).bulk_norm();
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight })
     }
 
-    fn distance(&self, rhs: &HomogeneusPoint<T>) -> Self::Scalar {
-        rhs.distance(self)
+    fn distance(&self, rhs: &HomogeneusLine<T>) -> Self::Scalar {
+        ratio(self.geometric_distance(rhs))
     }
 }
 
-impl<T> Distance<Plane<T>> for d3::Point<T>
+impl<T> Distance<HomogeneusPlane<T>> for HomogeneusLine<T>
 where
     T: Float,
+    T: Epsilon,
     T: ConstZero,
     T: ConstOne,
-    d3::Point<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    Plane<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
     type Scalar = d4::Scalar<T>;
     type Antiscalar = d4::Quadvector<T>;
 
-    fn geometric_distance(&self, rhs: &Plane<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let signed_distance =
-            self.0.x * rhs.0.wyz + self.0.y * rhs.0.wzx + self.0.z * rhs.0.wxy + rhs.0.zyx;
-        (d4::Scalar(signed_distance), d4::Quadvector { xyzw: T::ONE })
+    fn geometric_distance(&self, rhs: &HomogeneusPlane<T>) -> (Self::Scalar, Self::Antiscalar) {
+        let (direction, normal) = (self.weight(), rhs.weight());
+        let direction_length = direction.bulk_norm();
+        let weight = direction_length * normal.bulk_norm();
+        if weight.is_zero() {
+            // a line and a plane always meet, at infinity if one of them is there
+            return at_infinity(T::zero());
+        }
+
+        // the line is parallel when the sine of its angle with the plane vanishes
+        let slope = direction.wedge(&normal).xyz;
+        if !(slope / weight).is_near_zero() {
+            return (d4::Scalar::new(T::zero()), d4::Quadvector { xyzw: weight });
+        }
+
+        // parallel line: measure from the point of the line closest to the origin, whose
+        // weight is the squared length of the direction
+        let support = HomogeneusPoint {
+            x: self.wy * self.xy - self.wz * self.zx,
+            y: self.wz * self.yz - self.wx * self.xy,
+            z: self.wx * self.zx - self.wy * self.yz,
+            w: direction_length * direction_length,
+        };
+        let bulk = support.wedge(rhs).xyzw / direction_length;
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight })
     }
 
-    fn distance(&self, rhs: &Plane<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0)
+    fn distance(&self, rhs: &HomogeneusPlane<T>) -> Self::Scalar {
+        ratio(self.geometric_distance(rhs))
     }
 }
 
-impl<T> Distance<d3::Point<T>> for Plane<T>
+impl<T> Distance<HomogeneusPlane<T>> for HomogeneusPlane<T>
 where
     T: Float,
+    T: Epsilon,
     T: ConstZero,
     T: ConstOne,
-    d3::Point<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-    Plane<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
 {
     type Scalar = d4::Scalar<T>;
     type Antiscalar = d4::Quadvector<T>;
 
-    fn geometric_distance(&self, rhs: &d3::Point<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let signed_distance =
-            self.0.wyz * rhs.0.x + self.0.wzx * rhs.0.y + self.0.wxy * rhs.0.z + self.0.zyx;
-        (
-            d4::Scalar(-signed_distance),
-            d4::Quadvector { xyzw: T::ONE },
-        )
+    fn geometric_distance(&self, rhs: &HomogeneusPlane<T>) -> (Self::Scalar, Self::Antiscalar) {
+        let (lhs_normal, rhs_normal) = (self.weight(), rhs.weight());
+        let lhs_length = lhs_normal.bulk_norm();
+        let rhs_length = rhs_normal.bulk_norm();
+        let weight = lhs_length * rhs_length;
+        if weight.is_zero() {
+            // every plane meets the horizon
+            return at_infinity(T::zero());
+        }
+
+        // the planes are parallel when the sine of the angle between their normals vanishes
+        let sine = lhs_normal.antiwedge(&rhs_normal).bulk_norm() / weight;
+        if !sine.is_near_zero() {
+            return (d4::Scalar::new(T::zero()), d4::Quadvector { xyzw: weight });
+        }
+
+        // parallel planes: the offsets of the unitized planes differ by their separation, positive
+        // when `rhs` is on the side the normal of `self` points to
+        let rhs_offset = if lhs_normal.dot(&rhs_normal) < T::zero() {
+            -rhs.zyx
+        } else {
+            rhs.zyx
+        };
+        let bulk = rhs_length * self.zyx - lhs_length * rhs_offset;
+        (d4::Scalar::new(bulk), d4::Quadvector { xyzw: weight })
     }
 
-    fn distance(&self, rhs: &d3::Point<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0)
+    fn distance(&self, rhs: &HomogeneusPlane<T>) -> Self::Scalar {
+        ratio(self.geometric_distance(rhs))
     }
 }
 
-impl<T> Distance<HomogeneusLine<T>> for HomogeneusLine<T>
-where
-    T: Float,
-    T: ConstZero,
-    T: ConstOne,
-    HomogeneusLine<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_distance(&self, rhs: &HomogeneusLine<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let signed_distance = -(self.wx * rhs.yz
-            + self.wy * rhs.zx
-            + self.wz * rhs.xy
-            + self.yz * rhs.wx
-            + self.zx * rhs.wy
-            + self.xy * rhs.wz);
-        let weight = self.weight().wedge(&rhs.weight());
-        let weight_norm =
-            (weight.yz * weight.yz + weight.zx * weight.zx + weight.xy * weight.xy).sqrt();
-
-        (
-            d4::Scalar(signed_distance),
-            d4::Quadvector { xyzw: weight_norm },
-        )
-    }
-
-    fn distance(&self, rhs: &HomogeneusLine<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0 / geometric_distance.1.xyzw)
-    }
+/// Implements `Distance` for `$rht` on `$lht` by swapping the operands, distances are symmetric
+macro_rules! reverse_distance {
+    ($lht:ty, $rht:ty) => {
+        impl<T> Distance<$rht> for $lht
+        where
+            $rht: Distance<$lht>,
+        {
+            type Scalar = <$rht as Distance<$lht>>::Scalar;
+            type Antiscalar = <$rht as Distance<$lht>>::Antiscalar;
+
+            fn geometric_distance(&self, rhs: &$rht) -> (Self::Scalar, Self::Antiscalar) {
+                rhs.geometric_distance(self)
+            }
+
+            fn distance(&self, rhs: &$rht) -> Self::Scalar {
+                rhs.distance(self)
+            }
+        }
+    };
 }
 
-impl<T> Distance<Line<T>> for Line<T>
-where
-    T: Float,
-    T: ConstZero,
-    T: ConstOne,
-    Line<T>: Norm<Scalar = d4::Scalar<T>, Antiscalar = d4::Quadvector<T>>,
-{
-    type Scalar = d4::Scalar<T>;
-    type Antiscalar = d4::Quadvector<T>;
-
-    fn geometric_distance(&self, rhs: &Line<T>) -> (Self::Scalar, Self::Antiscalar) {
-        let signed_distance = -(self.0.wx * rhs.0.yz
-            + self.0.wy * rhs.0.zx
-            + self.0.wz * rhs.0.xy
-            + self.0.yz * rhs.0.wx
-            + self.0.zx * rhs.0.wy
-            + self.0.xy * rhs.0.wz);
-
-        (d4::Scalar(signed_distance), d4::Quadvector { xyzw: T::ONE })
-    }
+reverse_distance!(HomogeneusLine<T>, HomogeneusPoint<T>);
+reverse_distance!(HomogeneusPlane<T>, HomogeneusPoint<T>);
+reverse_distance!(HomogeneusPlane<T>, HomogeneusLine<T>);
 
-    fn distance(&self, rhs: &Line<T>) -> Self::Scalar {
-        let geometric_distance = self.geometric_distance(rhs);
-        d4::Scalar(geometric_distance.0.0)
-    }
-}
+normalized_measure!(Distance {
+    geometric_distance -> (Self::Scalar, Self::Antiscalar),
+    distance -> Self::Scalar,
+});
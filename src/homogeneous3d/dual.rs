@@ -36,51 +36,6 @@ where
     }
 }
 
-impl<T> Dual for HomogeneusPoint<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Neg<Output = T>,
-{
-    type AntiKVector = HomogeneusPlane<T>;
-
-    fn right_bulk_dual(&self) -> HomogeneusPlane<T> {
-        HomogeneusPlane {
-            wyz: self.x,
-            wzx: self.y,
-            wxy: self.z,
-            zyx: T::ZERO,
-        }
-    }
-
-    fn left_bulk_dual(&self) -> HomogeneusPlane<T> {
-        HomogeneusPlane {
-            wyz: -self.x,
-            wzx: -self.y,
-            wxy: -self.z,
-            zyx: T::ZERO,
-        }
-    }
-
-    fn right_weight_dual(&self) -> HomogeneusPlane<T> {
-        HomogeneusPlane {
-            wyz: T::ZERO,
-            wzx: T::ZERO,
-            wxy: T::ZERO,
-            zyx: self.w,
-        }
-    }
-
-    fn left_weight_dual(&self) -> HomogeneusPlane<T> {
-        HomogeneusPlane {
-            wyz: T::ZERO,
-            wzx: T::ZERO,
-            wxy: T::ZERO,
-            zyx: -self.w,
-        }
-    }
-}
-
 impl<T> Dual for d3::Point<T>
 where
     T: Copy,
@@ -127,59 +82,6 @@ where
     }
 }
 
-impl<T> Dual for HomogeneusLine<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Neg<Output = T>,
-{
-    type AntiKVector = HomogeneusLine<T>;
-
-    fn right_bulk_dual(&self) -> HomogeneusLine<T> {
-        HomogeneusLine {
-            wx: -self.yz,
-            wy: -self.zx,
-            wz: -self.xy,
-            yz: T::ZERO,
-            zx: T::ZERO,
-            xy: T::ZERO,
-        }
-    }
-
-    fn left_bulk_dual(&self) -> HomogeneusLine<T> {
-        HomogeneusLine {
-            wx: -self.yz,
-            wy: -self.zx,
-            wz: -self.xy,
-            yz: T::ZERO,
-            zx: T::ZERO,
-            xy: T::ZERO,
-        }
-    }
-
-    fn right_weight_dual(&self) -> HomogeneusLine<T> {
-        HomogeneusLine {
-            wx: T::ZERO,
-            wy: T::ZERO,
-            wz: T::ZERO,
-            yz: -self.wx,
-            zx: -self.wy,
-            xy: -self.wz,
-        }
-    }
-
-    fn left_weight_dual(&self) -> HomogeneusLine<T> {
-        HomogeneusLine {
-            wx: T::ZERO,
-            wy: T::ZERO,
-            wz: T::ZERO,
-            yz: -self.wx,
-            zx: -self.wy,
-            xy: -self.wz,
-        }
-    }
-}
-
 impl<T> Dual for Line<T>
 where
     T: Copy,
@@ -272,51 +174,6 @@ where
     }
 }
 
-impl<T> Dual for HomogeneusPlane<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Neg<Output = T>,
-{
-    type AntiKVector = HomogeneusPoint<T>;
-
-    fn right_bulk_dual(&self) -> HomogeneusPoint<T> {
-        HomogeneusPoint {
-            x: T::ZERO,
-            y: T::ZERO,
-            z: T::ZERO,
-            w: -self.zyx,
-        }
-    }
-
-    fn left_bulk_dual(&self) -> HomogeneusPoint<T> {
-        HomogeneusPoint {
-            x: T::ZERO,
-            y: T::ZERO,
-            z: T::ZERO,
-            w: self.zyx,
-        }
-    }
-
-    fn right_weight_dual(&self) -> HomogeneusPoint<T> {
-        HomogeneusPoint {
-            x: -self.wyz,
-            y: -self.wzx,
-            z: -self.wxy,
-            w: T::ZERO,
-        }
-    }
-
-    fn left_weight_dual(&self) -> HomogeneusPoint<T> {
-        HomogeneusPoint {
-            x: self.wyz,
-            y: self.wzx,
-            z: self.wxy,
-            w: T::ZERO,
-        }
-    }
-}
-
 impl<T> Dual for Plane<T>
 where
     T: Copy,
@@ -361,27 +218,3 @@ where
         }
     }
 }
-
-impl<T> Dual for d4::Quadvector<T>
-where
-    T: Copy,
-    T: ConstZero,
-{
-    type AntiKVector = d4::Scalar<T>;
-
-    fn right_bulk_dual(&self) -> d4::Scalar<T> {
-        d4::Scalar::ZERO
-    }
-
-    fn left_bulk_dual(&self) -> d4::Scalar<T> {
-        d4::Scalar::ZERO
-    }
-
-    fn right_weight_dual(&self) -> d4::Scalar<T> {
-        d4::Scalar(self.xyzw)
-    }
-
-    fn left_weight_dual(&self) -> d4::Scalar<T> {
-        d4::Scalar(self.xyzw)
-    }
-}
@@ -4,10 +4,15 @@ use std::ops::Sub;
 
 use num::Float;
 use num::Zero;
+use num::traits::ConstOne;
 use num::traits::ConstZero;
 
 use crate::Epsilon;
 use crate::Expansion;
+use crate::Meet;
+use crate::OrthogonalProjection;
+use crate::Support;
+use crate::Unitizable;
 use crate::geometry3d as d3;
 use crate::geometry4d as d4;
 
@@ -16,6 +21,8 @@ use super::HomogeneusPlane;
 use super::HomogeneusPoint;
 use super::HorizonLine;
 use super::Line;
+use super::NormalizedLine;
+use super::NormalizedPoint;
 use super::Plane;
 
 impl<T> Expansion<HomogeneusPoint<T>> for d4::Scalar<T>
@@ -286,36 +293,6 @@ where
     }
 }
 
-impl<T> Expansion<HomogeneusLine<T>> for HomogeneusPoint<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusPlane<T>;
-    type WeightOutput = HomogeneusPlane<T>;
-
-    fn bulk_expansion(&self, rhs: &HomogeneusLine<T>) -> Self::BulkOutput {
-        HomogeneusPlane {
-            wyz: self.y * rhs.xy - self.z * rhs.zx,
-            wzx: self.z * rhs.yz - self.x * rhs.xy,
-            wxy: self.x * rhs.zx - self.y * rhs.yz,
-            zyx: T::ZERO,
-        }
-    }
-
-    fn weight_expansion(&self, rhs: &HomogeneusLine<T>) -> Self::WeightOutput {
-        HomogeneusPlane {
-            wyz: -self.w * rhs.wx,
-            wzx: -self.w * rhs.wy,
-            wxy: -self.w * rhs.wz,
-            zyx: self.x * rhs.wx + self.y * rhs.wy + self.z * rhs.wz,
-        }
-    }
-}
-
 impl<T> Expansion<Line<T>> for d3::Point<T>
 where
     T: Copy,
@@ -338,7 +315,7 @@ where
         if len2.is_near_zero() {
             None
         } else {
-            Some(Plane(plane * d4::Scalar(len2.sqrt().recip())))
+            Some(Plane(plane * d4::Scalar::new(len2.sqrt().recip())))
         }
     }
 
@@ -374,7 +351,7 @@ where
         if len2.is_near_zero() {
             None
         } else {
-            Some(Plane(plane * d4::Scalar(len2.sqrt().recip())))
+            Some(Plane(plane * d4::Scalar::new(len2.sqrt().recip())))
         }
     }
 
@@ -403,7 +380,7 @@ where
         if len2.is_near_zero() {
             None
         } else {
-            Some(Plane(plane * d4::Scalar(len2.sqrt().recip())))
+            Some(Plane(plane * d4::Scalar::new(len2.sqrt().recip())))
         }
     }
 
@@ -432,47 +409,13 @@ where
         if len2.is_near_zero() {
             None
         } else {
-            Some(Plane(plane * d4::Scalar(len2.sqrt().recip())))
+            Some(Plane(plane * d4::Scalar::new(len2.sqrt().recip())))
         }
     }
 
     fn weight_expansion(&self, _rhs: &HorizonLine<T>) -> Self::WeightOutput {}
 }
 
-impl<T> Expansion<HomogeneusPlane<T>> for HomogeneusPoint<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusLine<T>;
-    type WeightOutput = HomogeneusLine<T>;
-
-    fn bulk_expansion(&self, rhs: &HomogeneusPlane<T>) -> Self::BulkOutput {
-        HomogeneusLine {
-            wx: self.x * rhs.zyx,
-            wy: self.y * rhs.zyx,
-            wz: self.z * rhs.zyx,
-            yz: T::ZERO,
-            zx: T::ZERO,
-            xy: T::ZERO,
-        }
-    }
-
-    fn weight_expansion(&self, rhs: &HomogeneusPlane<T>) -> Self::WeightOutput {
-        HomogeneusLine {
-            wx: -self.w * rhs.wyz,
-            wy: -self.w * rhs.wzx,
-            wz: -self.w * rhs.wxy,
-            yz: self.z * rhs.wzx - self.y * rhs.wxy,
-            zx: self.x * rhs.wxy - self.z * rhs.wyz,
-            xy: self.y * rhs.wyz - self.x * rhs.wzx,
-        }
-    }
-}
-
 impl<T> Expansion<Plane<T>> for d3::Point<T>
 where
     T: Copy,
@@ -580,28 +523,7 @@ where
         if len2.is_near_zero() {
             None
         } else {
-            Some(HorizonLine(bivector * d3::Scalar(len2.sqrt().recip())))
-        }
-    }
-}
-
-impl<T> Expansion<d4::Quadvector<T>> for HomogeneusPoint<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = ();
-    type WeightOutput = HomogeneusPoint<T>;
-
-    fn bulk_expansion(&self, _rhs: &d4::Quadvector<T>) -> Self::BulkOutput {}
-
-    fn weight_expansion(&self, rhs: &d4::Quadvector<T>) -> Self::WeightOutput {
-        HomogeneusPoint {
-            x: self.x * rhs.xyzw,
-            y: self.y * rhs.xyzw,
-            z: self.z * rhs.xyzw,
-            w: self.w * rhs.xyzw,
+            Some(HorizonLine(bivector * d3::Scalar::new(len2.sqrt().recip())))
         }
     }
 }
@@ -637,36 +559,6 @@ where
     }
 }
 
-impl<T> Expansion<HomogeneusPlane<T>> for HomogeneusLine<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Sub<T, Output = T>,
-    T: Neg<Output = T>,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = HomogeneusPlane<T>;
-    type WeightOutput = HomogeneusPlane<T>;
-
-    fn bulk_expansion(&self, rhs: &HomogeneusPlane<T>) -> Self::BulkOutput {
-        HomogeneusPlane {
-            wyz: -self.yz * rhs.zyx,
-            wzx: -self.zx * rhs.zyx,
-            wxy: -self.xy * rhs.zyx,
-            zyx: T::ZERO,
-        }
-    }
-
-    fn weight_expansion(&self, rhs: &HomogeneusPlane<T>) -> Self::WeightOutput {
-        HomogeneusPlane {
-            wyz: self.wz * rhs.wzx - self.wy * rhs.wxy,
-            wzx: self.wx * rhs.wxy - self.wz * rhs.wyz,
-            wxy: self.wy * rhs.wyz - self.wx * rhs.wzx,
-            zyx: self.yz * rhs.wyz + self.zx * rhs.wzx + self.xy * rhs.wxy,
-        }
-    }
-}
-
 impl<T> Expansion<Plane<T>> for Line<T>
 where
     T: Copy,
@@ -711,7 +603,7 @@ where
         if len2.is_near_zero() {
             None
         } else {
-            Some(Plane(hp * d4::Scalar(len2.sqrt().recip())))
+            Some(Plane(hp * d4::Scalar::new(len2.sqrt().recip())))
         }
     }
 }
@@ -751,29 +643,6 @@ where
     fn weight_expansion(&self, _rhs: &Plane<T>) -> Self::WeightOutput {}
 }
 
-impl<T> Expansion<d4::Quadvector<T>> for HomogeneusLine<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = ();
-    type WeightOutput = HomogeneusLine<T>;
-
-    fn bulk_expansion(&self, _rhs: &d4::Quadvector<T>) -> Self::BulkOutput {}
-
-    fn weight_expansion(&self, rhs: &d4::Quadvector<T>) -> Self::WeightOutput {
-        HomogeneusLine {
-            wx: self.wx * rhs.xyzw,
-            wy: self.wy * rhs.xyzw,
-            wz: self.wz * rhs.xyzw,
-            yz: self.yz * rhs.xyzw,
-            zx: self.zx * rhs.xyzw,
-            xy: self.xy * rhs.xyzw,
-        }
-    }
-}
-
 impl<T> Expansion<d4::Quadvector<T>> for Line<T>
 where
     T: Copy,
@@ -827,27 +696,6 @@ where
     }
 }
 
-impl<T> Expansion<d4::Quadvector<T>> for HomogeneusPlane<T>
-where
-    T: Copy,
-    T: ConstZero,
-    T: Mul<T, Output = T>,
-{
-    type BulkOutput = ();
-    type WeightOutput = HomogeneusPlane<T>;
-
-    fn bulk_expansion(&self, _rhs: &d4::Quadvector<T>) -> Self::BulkOutput {}
-
-    fn weight_expansion(&self, rhs: &d4::Quadvector<T>) -> Self::WeightOutput {
-        HomogeneusPlane {
-            wyz: self.wyz * rhs.xyzw,
-            wzx: self.wzx * rhs.xyzw,
-            wxy: self.wxy * rhs.xyzw,
-            zyx: self.zyx * rhs.xyzw,
-        }
-    }
-}
-
 impl<T> Expansion<d4::Quadvector<T>> for Plane<T>
 where
     T: Copy,
@@ -945,3 +793,89 @@ where
         self.weight_expansion(line)
     }
 }
+
+impl<T> Line<T>
+where
+    T: ConstZero,
+    T: ConstOne,
+    T: Float,
+    T: Epsilon,
+{
+    /// Plane through the origin that is parallel to both lines, `None` if the lines are parallel
+    fn parallel_plane(a: &Self, b: &Self) -> Option<Plane<T>> {
+        let wyz = a.0.wy * b.0.wz - a.0.wz * b.0.wy;
+        let wzx = a.0.wz * b.0.wx - a.0.wx * b.0.wz;
+        let wxy = a.0.wx * b.0.wy - a.0.wy * b.0.wx;
+        let len2 = wyz * wyz + wzx * wzx + wxy * wxy;
+        if len2.is_near_zero() {
+            None
+        } else {
+            let invlen = len2.sqrt().recip();
+            Some(Plane(d4::Trivector {
+                wyz: wyz * invlen,
+                wzx: wzx * invlen,
+                wxy: wxy * invlen,
+                zyx: T::ZERO,
+            }))
+        }
+    }
+
+    /// Planes that contain each line and the direction of their common normal
+    fn normal_planes(a: &Self, b: &Self) -> Option<(Plane<T>, Plane<T>)> {
+        let plane = Self::parallel_plane(a, b)?;
+        Some((
+            a.plane_throught_orthogonal_to(&plane)?,
+            b.plane_throught_orthogonal_to(&plane)?,
+        ))
+    }
+
+    /// Line that crosses both lines at a right angle, `None` if the lines are parallel and it is
+    /// not unique
+    pub fn common_normal(a: &Self, b: &Self) -> Option<Self> {
+        let (a_plane, b_plane) = Self::normal_planes(a, b)?;
+        match a_plane.meet(&b_plane).unitize()? {
+            NormalizedLine::Line(line) => Some(line),
+            NormalizedLine::HorizonLine(_) => None,
+        }
+    }
+
+    /// Points of `a` and `b` that are closest to each other, both are the same point if the lines
+    /// intersect
+    ///
+    /// Parallel lines are equally close everywhere, so the points are the one of `a` closest to
+    /// the origin and its foot on `b`.
+    pub fn closest_points(a: &Self, b: &Self) -> (d3::Point<T>, d3::Point<T>) {
+        if let Some((a_plane, b_plane)) = Self::normal_planes(a, b)
+            && let (Some(NormalizedPoint::Point(on_a)), Some(NormalizedPoint::Point(on_b))) =
+                (b_plane.meet(a).unitize(), a_plane.meet(b).unitize())
+        {
+            return (on_a, on_b);
+        }
+        let on_a = a.support();
+        (on_a, b.foot_of(&on_a))
+    }
+
+    /// Point of the line closest to `point`
+    pub fn foot_of(&self, point: &d3::Point<T>) -> d3::Point<T> {
+        point.orthogonal_projection(self)
+    }
+}
+
+impl<T> Plane<T>
+where
+    T: ConstZero,
+    T: ConstOne,
+    T: Float,
+    T: Epsilon,
+{
+    /// Point of the plane closest to `line`, which is where they meet unless they are parallel
+    ///
+    /// A parallel line is equally close everywhere, so the point is the foot of its point
+    /// closest to the origin.
+    pub fn foot_of_line(&self, line: &Line<T>) -> d3::Point<T> {
+        match self.meet(line).unitize() {
+            Some(NormalizedPoint::Point(point)) => point,
+            _ => line.support().orthogonal_projection(self),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use num::Float;
+
+use crate::fitting::Eigen;
+use crate::fitting::Model;
+use crate::fitting::coincident;
+use crate::fitting::negligible;
+use crate::fitting::symmetric_eigen;
+use crate::geometry3d as d3;
+use crate::geometry4d as d4;
+
+use super::Line;
+use super::Plane;
+
+/// Centroid and scatter matrix of the points, with its eigen decomposition, `None` if there are
+/// fewer than `min_points` points or they all coincide
+fn principal_components<T>(points: &[d3::Point<T>], min_points: usize) -> Option<([T; 3], Eigen<T>)>
+where
+    T: Float,
+{
+    if points.len() < min_points.max(1) {
+        return None;
+    }
+    let n = T::from(points.len())?;
+    let sum = points.iter().fold([T::zero(); 3], |sum, p| {
+        [sum[0] + p.0.x, sum[1] + p.0.y, sum[2] + p.0.z]
+    });
+    let centroid = sum.map(|s| s / n);
+
+    let mut scatter = [[T::zero(); 3]; 3];
+    let mut moment = T::zero();
+    for p in points {
+        moment = moment + p.0.x * p.0.x + p.0.y * p.0.y + p.0.z * p.0.z;
+        let d = [
+            p.0.x - centroid[0],
+            p.0.y - centroid[1],
+            p.0.z - centroid[2],
+        ];
+        for i in 0..3 {
+            for j in 0..3 {
+                scatter[i][j] = scatter[i][j] + d[i] * d[j];
+            }
+        }
+    }
+    let trace = scatter[0][0] + scatter[1][1] + scatter[2][2];
+    if coincident(trace, moment) {
+        return None;
+    }
+    Some((centroid, symmetric_eigen(scatter)))
+}
+
+/// Root mean square distance from the eigenvalues of the scatter that are left out of the fit
+fn rms<T: Float>(residual: T, count: usize) -> T {
+    T::from(count).map_or(T::zero(), |n| (residual.max(T::zero()) / n).sqrt())
+}
+
+impl<T> Plane<T>
+where
+    T: Float,
+{
+    /// Plane that minimizes the sum of the squared distances to the points, along with the root
+    /// mean square of those distances
+    ///
+    /// The plane passes through the centroid of the points and is normal to their direction of
+    /// least spread. Returns `None` if there are fewer than three points or they are collinear.
+    pub fn fit(points: &[d3::Point<T>]) -> Option<(Self, T)> {
+        let (centroid, [least, middle, most]) = principal_components(points, 3)?;
+        if negligible(middle.0, most.0) {
+            return None;
+        }
+
+        let n = least.1;
+        let plane = Plane(d4::Trivector {
+            wyz: n[0],
+            wzx: n[1],
+            wxy: n[2],
+            zyx: -(n[0] * centroid[0] + n[1] * centroid[1] + n[2] * centroid[2]),
+        });
+        Some((plane, rms(least.0, points.len())))
+    }
+}
+
+impl<T> Line<T>
+where
+    T: Float,
+{
+    /// Line that minimizes the sum of the squared distances to the points, along with the root
+    /// mean square of those distances
+    ///
+    /// The line passes through the centroid of the points along their direction of most spread.
+    /// Returns `None` if there are fewer than two points or they all coincide.
+    pub fn fit(points: &[d3::Point<T>]) -> Option<(Self, T)> {
+        let (c, [least, middle, most]) = principal_components(points, 2)?;
+
+        // join of the centroid and the point at infinity in the direction `v`
+        let v = most.1;
+        let line = Line(d4::Bivector {
+            wx: v[0],
+            wy: v[1],
+            wz: v[2],
+            yz: c[1] * v[2] - c[2] * v[1],
+            zx: c[2] * v[0] - c[0] * v[2],
+            xy: c[0] * v[1] - c[1] * v[0],
+        });
+        Some((line, rms(least.0 + middle.0, points.len())))
+    }
+}
+
+impl<T> Model<d3::Point<T>> for Plane<T>
+where
+    T: Float,
+{
+    type Scalar = T;
+
+    const SAMPLE_SIZE: usize = 3;
+
+    fn fit(points: &[d3::Point<T>]) -> Option<(Self, T)> {
+        Plane::fit(points)
+    }
+}
+
+impl<T> Model<d3::Point<T>> for Line<T>
+where
+    T: Float,
+{
+    type Scalar = T;
+
+    const SAMPLE_SIZE: usize = 2;
+
+    fn fit(points: &[d3::Point<T>]) -> Option<(Self, T)> {
+        Line::fit(points)
+    }
+}
@@ -3,19 +3,25 @@ use std::ops::Mul;
 use std::ops::Neg;
 use std::ops::Sub;
 
+use num::Float;
 use num::Zero;
+use num::traits::ConstOne;
 use num::traits::ConstZero;
 use num::zero;
 
 use crate::Epsilon;
 use crate::Join;
+use crate::Meet;
 
 use crate::WedgeProduct;
 use crate::geometry3d as d3;
 use crate::geometry4d as d4;
 
 use super::HomogeneusPlane;
+use super::HorizonLine;
 use super::Line;
+use super::LineIncidence;
+use super::meet::line_weight_norm;
 use super::{HomogeneusLine, HomogeneusPoint};
 
 impl<T> Join<HomogeneusPoint<T>> for HomogeneusPoint<T>
@@ -48,6 +54,20 @@ where
     }
 }
 
+impl<T> Join<HomogeneusLine<T>> for HomogeneusLine<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    type Output = LineIncidence<T>;
+    /// Same as the [`Meet`] of the lines, which already gives the point and the plane they share
+    fn join(&self, rhs: &HomogeneusLine<T>) -> Self::Output {
+        self.meet(rhs)
+    }
+}
+
 impl<T> HomogeneusPoint<T>
 where
     T: Copy,
@@ -56,23 +76,75 @@ where
     T: Sub<T, Output = T>,
     T: Neg<Output = T>,
 {
+    /// Plane through three points, equal to `a.wedge(&b).wedge(&c)`
     pub fn join(a: Self, b: Self, c: Self) -> HomogeneusPlane<T> {
-        let acwz = a.w * c.z;
-        let abwz = a.w * b.z;
-        let bawz = b.w * a.z;
-        let bcwz = b.w * c.z;
-        let cawz = b.w * a.z;
-        let cbwz = b.w * b.z;
+        let bcyz = b.y * c.z - b.z * c.y;
+        let cayz = c.y * a.z - c.z * a.y;
+        let abyz = a.y * b.z - a.z * b.y;
+        let bczx = b.z * c.x - b.x * c.z;
+        let cazx = c.z * a.x - c.x * a.z;
+        let abzx = a.z * b.x - a.x * b.z;
         let bcxy = b.x * c.y - b.y * c.x;
         let caxy = c.x * a.y - c.y * a.x;
         let abxy = a.x * b.y - a.y * b.x;
         HomogeneusPlane {
-            wyz: acwz * b.y - abwz * c.y + bawz * c.y - bcwz * a.y + cbwz * a.y - cawz * b.y,
-            wzx: abwz * c.x - acwz * b.x + bcwz * a.x - bawz * c.x + cawz * b.x - cbwz * b.x,
+            wyz: a.w * bcyz + b.w * cayz + c.w * abyz,
+            wzx: a.w * bczx + b.w * cazx + c.w * abzx,
             wxy: a.w * bcxy + b.w * caxy + c.w * abxy,
             zyx: -(a.z * bcxy + b.z * caxy + c.z * abxy),
         }
     }
+
+    /// Same as [`HomogeneusPoint::join`], but returns `None` if the points are collinear and
+    /// don't define a plane
+    ///
+    /// Scaling the points changes nothing: each one is divided by its weight, or by the norm of
+    /// its direction if it is at infinity, and the test compares the distance from a point to
+    /// the longest side of the triangle.
+    pub fn try_join(a: Self, b: Self, c: Self) -> Option<HomogeneusPlane<T>>
+    where
+        T: Float,
+        T: Epsilon,
+    {
+        let plane = HomogeneusPoint::join(a, b, c);
+        let (a, b, c) = (unit_point(a)?, unit_point(b)?, unit_point(c)?);
+        // the weight of the line through two unit points is the side between them
+        let side = [a.wedge(&b), b.wedge(&c), c.wedge(&a)]
+            .iter()
+            .map(line_weight_norm)
+            .fold(T::zero(), T::max);
+        let unit = HomogeneusPoint::join(a, b, c);
+        let degenerate = if side.is_zero() {
+            // all the points are at infinity or at the same place, only the horizon may be left
+            unit.zyx.is_near_zero()
+        } else {
+            (plane_weight_norm(&unit) / side).is_near_zero()
+        };
+        if degenerate { None } else { Some(plane) }
+    }
+}
+
+/// Point divided by its weight, or by the norm of its direction if it is at infinity, `None` if
+/// it is zero
+fn unit_point<T: Float>(p: HomogeneusPoint<T>) -> Option<HomogeneusPoint<T>> {
+    let scale = if p.w.is_zero() {
+        (p.x * p.x + p.y * p.y + p.z * p.z).sqrt()
+    } else {
+        p.w.abs()
+    };
+    if scale.is_zero() {
+        return None;
+    }
+    Some(HomogeneusPoint {
+        x: p.x / scale,
+        y: p.y / scale,
+        z: p.z / scale,
+        w: p.w / scale,
+    })
+}
+
+fn plane_weight_norm<T: Float>(plane: &HomogeneusPlane<T>) -> T {
+    (plane.wyz * plane.wyz + plane.wzx * plane.wzx + plane.wxy * plane.wxy).sqrt()
 }
 
 impl<T> Join<d3::Point<T>> for d3::Point<T>
@@ -246,6 +318,34 @@ where
     }
 }
 
+/// Implements `Join` for the normalized types by joining their homogeneous counterparts
+macro_rules! normalized_join {
+    ($lht:ty => $lh:ident, $rht:ty => $rh:ident) => {
+        impl<T> Join<$rht> for $lht
+        where
+            $lht: Copy,
+            $rht: Copy,
+            $lh<T>: From<$lht>,
+            $rh<T>: From<$rht>,
+            $lh<T>: Join<$rh<T>>,
+        {
+            type Output = <$lh<T> as Join<$rh<T>>>::Output;
+            fn join(&self, rhs: &$rht) -> Self::Output {
+                $lh::from(*self).join(&$rh::from(*rhs))
+            }
+        }
+    };
+}
+
+normalized_join!(d3::Point<T> => HomogeneusPoint, HorizonLine<T> => HomogeneusLine);
+normalized_join!(HorizonLine<T> => HomogeneusLine, d3::Point<T> => HomogeneusPoint);
+normalized_join!(d3::Vector<T> => HomogeneusPoint, HorizonLine<T> => HomogeneusLine);
+normalized_join!(HorizonLine<T> => HomogeneusLine, d3::Vector<T> => HomogeneusPoint);
+normalized_join!(Line<T> => HomogeneusLine, Line<T> => HomogeneusLine);
+normalized_join!(Line<T> => HomogeneusLine, HorizonLine<T> => HomogeneusLine);
+normalized_join!(HorizonLine<T> => HomogeneusLine, Line<T> => HomogeneusLine);
+normalized_join!(HorizonLine<T> => HomogeneusLine, HorizonLine<T> => HomogeneusLine);
+
 pub enum Winding {
     Clockwise,
     CounterClockwise,
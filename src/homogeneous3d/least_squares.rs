@@ -0,0 +1,220 @@
+use num::Float;
+use num::traits::ConstOne;
+use num::traits::ConstZero;
+
+use crate::Distance;
+use crate::Metric;
+use crate::Support;
+use crate::fitting::symmetric_eigen;
+use crate::geometry3d as d3;
+
+use super::LeastSquaresError;
+use super::Line;
+use super::NormalizedPoint;
+use super::Plane;
+
+/// Ratio to the machine epsilon of the smallest eigenvalue of the normal equations, relative to the
+/// largest one, that still counts towards their rank
+const RANK_TOLERANCE: u32 = 1000;
+
+/// Normal equations `a * p = b` of a least-squares point, `a` is symmetric
+struct NormalEquations<T> {
+    a: [[T; 3]; 3],
+    b: [T; 3],
+    total_weight: T,
+}
+
+impl<T> NormalEquations<T>
+where
+    T: Float,
+    T: ConstZero,
+{
+    fn new() -> Self {
+        NormalEquations {
+            a: [[T::ZERO; 3]; 3],
+            b: [T::ZERO; 3],
+            total_weight: T::ZERO,
+        }
+    }
+
+    /// Adds `weight * (sign * u * uᵀ + diagonal * I)` to `a` and `weight * rhs` to `b`
+    fn add(&mut self, weight: T, diagonal: T, sign: T, u: [T; 3], rhs: [T; 3]) {
+        for i in 0..3 {
+            for j in 0..3 {
+                self.a[i][j] = self.a[i][j] + weight * sign * u[i] * u[j];
+            }
+            self.a[i][i] = self.a[i][i] + weight * diagonal;
+            self.b[i] = self.b[i] + weight * rhs[i];
+        }
+        self.total_weight = self.total_weight + weight;
+    }
+
+    /// Number of independent directions constrained by the equations
+    ///
+    /// A direction is constrained when its eigenvalue is not lost in the rounding error of the
+    /// largest one, so the rank doesn't depend on the scale of the weights or on how narrow the
+    /// angles between the primitives are.
+    fn rank(&self) -> usize {
+        let eigen = symmetric_eigen(self.a);
+        let threshold = eigen[2].0 * T::from(RANK_TOLERANCE).unwrap_or(T::one()) * T::epsilon();
+        eigen
+            .iter()
+            .filter(|(eigenvalue, _)| *eigenvalue > threshold)
+            .count()
+    }
+
+    fn solve(&self) -> Result<d3::Point<T>, LeastSquaresError> {
+        let a = self.a;
+        let cofactor = |i: usize, j: usize| {
+            let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+            let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+            a[r0][c0] * a[r1][c1] - a[r0][c1] * a[r1][c0]
+        };
+        let determinant =
+            a[0][0] * cofactor(0, 0) + a[0][1] * cofactor(0, 1) + a[0][2] * cofactor(0, 2);
+        match self.rank() {
+            3 => (),
+            rank => return Err(LeastSquaresError::RankDeficient { rank }),
+        }
+
+        // the inverse of a symmetric matrix is its cofactor matrix over the determinant
+        let solution = |i: usize| {
+            (cofactor(0, i) * self.b[0] + cofactor(1, i) * self.b[1] + cofactor(2, i) * self.b[2])
+                / determinant
+        };
+        Ok(d3::Point(d3::Vector::new(
+            solution(0),
+            solution(1),
+            solution(2),
+        )))
+    }
+}
+
+/// Root mean square of the weighted distances
+fn residual<T, I>(distances: I, total_weight: T) -> T
+where
+    T: Float,
+    T: ConstZero,
+    I: Iterator<Item = (T, T)>,
+{
+    let sum = distances.fold(T::ZERO, |sum, (weight, distance)| {
+        sum + weight * distance * distance
+    });
+    (sum / total_weight).sqrt()
+}
+
+fn check_weights<T, P>(primitives: &[P], weights: &[T]) -> Result<(), LeastSquaresError>
+where
+    T: Float,
+{
+    if primitives.len() != weights.len() || weights.iter().any(|w| w.is_nan() || *w < T::zero()) {
+        Err(LeastSquaresError::InvalidWeights)
+    } else {
+        Ok(())
+    }
+}
+
+impl<T> Line<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    /// Point that minimizes the sum of the squared distances to all the lines, along with the root
+    /// mean square of those distances
+    ///
+    /// Fails with [`LeastSquaresError::RankDeficient`] if the lines don't single out a point,
+    /// which is the case when they are all parallel.
+    pub fn nearest_point_to_all(
+        lines: &[Line<T>],
+    ) -> Result<(NormalizedPoint<T>, T), LeastSquaresError> {
+        Self::weighted_nearest_point_to_all(lines, &vec![T::ONE; lines.len()])
+    }
+
+    /// Same as [`Line::nearest_point_to_all`], with the squared distance to each line scaled by
+    /// its weight
+    ///
+    /// Fails with [`LeastSquaresError::InvalidWeights`] if a weight is negative or there isn't
+    /// one weight per line.
+    pub fn weighted_nearest_point_to_all(
+        lines: &[Line<T>],
+        weights: &[T],
+    ) -> Result<(NormalizedPoint<T>, T), LeastSquaresError> {
+        check_weights(lines, weights)?;
+
+        // the distance to a line is the length of the offset to its support point with the
+        // component along the line removed, `(I - v * vᵀ) * (p - support)`
+        let mut equations = NormalEquations::new();
+        for (line, weight) in lines.iter().zip(weights) {
+            let direction = d3::Vector::from(line.weight());
+            let support = line.support().0;
+            equations.add(
+                *weight,
+                T::ONE,
+                -T::ONE,
+                [direction.x, direction.y, direction.z],
+                [support.x, support.y, support.z],
+            );
+        }
+
+        let point = equations.solve()?;
+        let distances = lines
+            .iter()
+            .zip(weights)
+            .map(|(line, weight)| (*weight, point.distance(line).0));
+        let residual = residual(distances, equations.total_weight);
+        Ok((NormalizedPoint::Point(point), residual))
+    }
+}
+
+impl<T> Plane<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    /// Point that minimizes the sum of the squared distances to all the planes, along with the
+    /// root mean square of those distances
+    ///
+    /// Fails with [`LeastSquaresError::RankDeficient`] if the planes don't single out a point,
+    /// which is the case when they are all parallel or all share a direction.
+    pub fn least_squares_meet(
+        planes: &[Plane<T>],
+    ) -> Result<(NormalizedPoint<T>, T), LeastSquaresError> {
+        Self::weighted_least_squares_meet(planes, &vec![T::ONE; planes.len()])
+    }
+
+    /// Same as [`Plane::least_squares_meet`], with the squared distance to each plane scaled by
+    /// its weight
+    ///
+    /// Fails with [`LeastSquaresError::InvalidWeights`] if a weight is negative or there isn't
+    /// one weight per plane.
+    pub fn weighted_least_squares_meet(
+        planes: &[Plane<T>],
+        weights: &[T],
+    ) -> Result<(NormalizedPoint<T>, T), LeastSquaresError> {
+        check_weights(planes, weights)?;
+
+        // the distance to a plane is `n · p - offset`, where its bulk is the offset
+        let mut equations = NormalEquations::new();
+        for (plane, weight) in planes.iter().zip(weights) {
+            let normal = d3::Bivector::from(plane.weight());
+            let offset = plane.bulk().xyz;
+            equations.add(
+                *weight,
+                T::ZERO,
+                T::ONE,
+                [normal.yz, normal.zx, normal.xy],
+                [offset * normal.yz, offset * normal.zx, offset * normal.xy],
+            );
+        }
+
+        let point = equations.solve()?;
+        let distances = planes
+            .iter()
+            .zip(weights)
+            .map(|(plane, weight)| (*weight, point.distance(plane).0));
+        let residual = residual(distances, equations.total_weight);
+        Ok((NormalizedPoint::Point(point), residual))
+    }
+}
@@ -3,13 +3,23 @@ use std::ops::Mul;
 use std::ops::Neg;
 use std::ops::Sub;
 
+use num::Float;
+use num::traits::ConstOne;
+use num::traits::ConstZero;
+
 use super::HomogeneusLine;
 use super::HomogeneusPlane;
 use super::HomogeneusPoint;
+use super::HorizonLine;
+use super::Line;
+use super::LineIncidence;
+use super::Plane;
 
+use crate::Epsilon;
 use crate::KVector;
+use crate::geometry3d as d3;
 use crate::geometry4d as d4;
-use crate::{AntiwedgeProduct, Meet};
+use crate::{AntiwedgeProduct, Dot, Meet, WedgeProduct};
 
 impl<T> Meet<HomogeneusPlane<T>> for HomogeneusPlane<T>
 where
@@ -41,6 +51,176 @@ where
     }
 }
 
+impl<T> Meet<HomogeneusLine<T>> for HomogeneusLine<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    type Output = LineIncidence<T>;
+    fn meet(&self, rhs: &HomogeneusLine<T>) -> Self::Output {
+        let (Some(a), Some(b)) = (unit_line(self), unit_line(rhs)) else {
+            return LineIncidence::Degenerate;
+        };
+        // for unit lines the wedge is the distance between them times the sine of their angle
+        if !a.wedge(&b).xyzw.is_near_zero() {
+            return LineIncidence::Skew;
+        }
+
+        let (a_finite, b_finite) = (
+            !line_weight_norm(&a).is_zero(),
+            !line_weight_norm(&b).is_zero(),
+        );
+        if a_finite && b_finite {
+            let (da, db) = (weight(&a), weight(&b));
+            let cross = da.wedge(&db);
+            let sine = (cross.yz * cross.yz + cross.zx * cross.zx + cross.xy * cross.xy).sqrt();
+            if !sine.is_near_zero() {
+                return LineIncidence::Intersecting(crossing(self, rhs));
+            }
+        } else if a_finite || b_finite {
+            // a line at infinity on the plane of a finite line shares its point at infinity
+            return LineIncidence::Parallel(common_plane(self, rhs));
+        }
+
+        // parallel lines, or lines at infinity: with their directions (or their moments at
+        // infinity) aligned, their moments differ by the distance between them (or the chord of
+        // their angle at infinity)
+        let (ma, mb) = (bulk(&a), bulk(&b));
+        let aligned = if a_finite {
+            weight(&a).dot(&weight(&b))
+        } else {
+            ma.dot(&mb)
+        };
+        let offset = if aligned < T::ZERO { ma + mb } else { ma - mb };
+        if offset.dot(&offset).sqrt().is_near_zero() {
+            LineIncidence::Coincident
+        } else {
+            LineIncidence::Parallel(common_plane(self, rhs))
+        }
+    }
+}
+
+/// Point shared by two lines that cross
+fn crossing<T>(lhs: &HomogeneusLine<T>, rhs: &HomogeneusLine<T>) -> HomogeneusPoint<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    // joining rhs with each basis point gives planes that span every plane through rhs, so
+    // at least one of them crosses lhs
+    let mut point = HomogeneusPoint::ZERO;
+    let mut len2 = T::ZERO;
+    for basis in [
+        HomogeneusPoint::X,
+        HomogeneusPoint::Y,
+        HomogeneusPoint::Z,
+        HomogeneusPoint::W,
+    ] {
+        let candidate = lhs.antiwedge(&rhs.wedge(&basis));
+        let candidate_len2 = candidate.x * candidate.x
+            + candidate.y * candidate.y
+            + candidate.z * candidate.z
+            + candidate.w * candidate.w;
+        if candidate_len2 > len2 {
+            point = candidate;
+            len2 = candidate_len2;
+        }
+    }
+    point
+}
+
+/// Plane that contains two lines that meet
+fn common_plane<T>(lhs: &HomogeneusLine<T>, rhs: &HomogeneusLine<T>) -> HomogeneusPlane<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    // meeting rhs with each basis plane gives points that span every point of rhs, so at least
+    // one of them is not on lhs
+    let mut plane = HomogeneusPlane::ZERO;
+    let mut len2 = T::ZERO;
+    for basis in [
+        HomogeneusPlane::WYZ,
+        HomogeneusPlane::WZX,
+        HomogeneusPlane::WXY,
+        HomogeneusPlane::ZYX,
+    ] {
+        let candidate = lhs.wedge(&rhs.antiwedge(&basis));
+        let candidate_len2 = candidate.wyz * candidate.wyz
+            + candidate.wzx * candidate.wzx
+            + candidate.wxy * candidate.wxy
+            + candidate.zyx * candidate.zyx;
+        if candidate_len2 > len2 {
+            plane = candidate;
+            len2 = candidate_len2;
+        }
+    }
+    plane
+}
+
+/// Line divided by the norm of its direction, or of its moment if it is at infinity, `None` if
+/// it is zero
+fn unit_line<T: Float>(line: &HomogeneusLine<T>) -> Option<HomogeneusLine<T>> {
+    let direction = line_weight_norm(line);
+    let scale = if direction.is_zero() {
+        (line.yz * line.yz + line.zx * line.zx + line.xy * line.xy).sqrt()
+    } else {
+        direction
+    };
+    if scale.is_zero() {
+        return None;
+    }
+    Some(HomogeneusLine {
+        wx: line.wx / scale,
+        wy: line.wy / scale,
+        wz: line.wz / scale,
+        yz: line.yz / scale,
+        zx: line.zx / scale,
+        xy: line.xy / scale,
+    })
+}
+
+fn weight<T: Copy>(line: &HomogeneusLine<T>) -> d3::Vector<T> {
+    d3::Vector::new(line.wx, line.wy, line.wz)
+}
+
+fn bulk<T: Copy>(line: &HomogeneusLine<T>) -> d3::Vector<T> {
+    d3::Vector::new(line.yz, line.zx, line.xy)
+}
+
+/// Implements `Meet` for the normalized types by meeting their homogeneous counterparts
+macro_rules! normalized_meet {
+    ($lht:ty => $lh:ident, $rht:ty => $rh:ident) => {
+        impl<T> Meet<$rht> for $lht
+        where
+            $lht: Copy,
+            $rht: Copy,
+            $lh<T>: From<$lht>,
+            $rh<T>: From<$rht>,
+            $lh<T>: Meet<$rh<T>>,
+        {
+            type Output = <$lh<T> as Meet<$rh<T>>>::Output;
+            fn meet(&self, rhs: &$rht) -> Self::Output {
+                $lh::from(*self).meet(&$rh::from(*rhs))
+            }
+        }
+    };
+}
+
+normalized_meet!(Plane<T> => HomogeneusPlane, Plane<T> => HomogeneusPlane);
+normalized_meet!(Plane<T> => HomogeneusPlane, Line<T> => HomogeneusLine);
+normalized_meet!(Line<T> => HomogeneusLine, Plane<T> => HomogeneusPlane);
+normalized_meet!(Plane<T> => HomogeneusPlane, HorizonLine<T> => HomogeneusLine);
+normalized_meet!(HorizonLine<T> => HomogeneusLine, Plane<T> => HomogeneusPlane);
+normalized_meet!(Line<T> => HomogeneusLine, Line<T> => HomogeneusLine);
+normalized_meet!(Line<T> => HomogeneusLine, HorizonLine<T> => HomogeneusLine);
+normalized_meet!(HorizonLine<T> => HomogeneusLine, Line<T> => HomogeneusLine);
+normalized_meet!(HorizonLine<T> => HomogeneusLine, HorizonLine<T> => HomogeneusLine);
+
 impl<T> HomogeneusPlane<T>
 where
     T: Copy,
@@ -49,13 +229,72 @@ where
     T: Sub<T, Output = T>,
     T: Neg<Output = T>,
 {
+    /// Point shared by three planes, equal to `a.antiwedge(&b).antiwedge(&c)`
     pub fn meet(a: Self, b: Self, c: Self) -> HomogeneusPoint<T> {
-        // TODO check sign of this
+        // a v b v c = right_complement(left_complement(a) ^ left_complement(b) ^ left_complement(c))
         HomogeneusPoint::join(
             a.left_complement(),
             b.left_complement(),
             c.left_complement(),
         )
-        .left_complement()
+        .right_complement()
     }
+
+    /// Same as [`HomogeneusPlane::meet`], but returns `None` if the planes share a line and
+    /// don't define a point
+    ///
+    /// Scaling the planes changes nothing: each one is divided by the norm of its normal, or by
+    /// its distance if it is the horizon, and the point is compared with the line where the two
+    /// least parallel planes meet.
+    pub fn try_meet(a: Self, b: Self, c: Self) -> Option<HomogeneusPoint<T>>
+    where
+        T: Float,
+        T: Epsilon,
+    {
+        let point = HomogeneusPlane::meet(a, b, c);
+        let (a, b, c) = (unit_plane(a)?, unit_plane(b)?, unit_plane(c)?);
+        // the weight of the line where two unit planes meet is the sine of their angle
+        let sine = [a.antiwedge(&b), b.antiwedge(&c), c.antiwedge(&a)]
+            .iter()
+            .map(line_weight_norm)
+            .fold(T::zero(), T::max);
+        if sine.is_zero() {
+            // the planes are parallel, they only share a line at infinity
+            return None;
+        }
+        // the weight of the unit point is the cosine between the line and the third plane, its
+        // bulk the distance between them when they are parallel
+        let unit = HomogeneusPlane::meet(a, b, c);
+        let bulk = (unit.x * unit.x + unit.y * unit.y + unit.z * unit.z).sqrt();
+        if (unit.w / sine).is_near_zero() && (bulk / sine).is_near_zero() {
+            None
+        } else {
+            Some(point)
+        }
+    }
+}
+
+/// Plane divided by the norm of its normal, or by its distance if it is the horizon, `None` if
+/// it is zero
+fn unit_plane<T: Float>(plane: HomogeneusPlane<T>) -> Option<HomogeneusPlane<T>> {
+    let normal = (plane.wyz * plane.wyz + plane.wzx * plane.wzx + plane.wxy * plane.wxy).sqrt();
+    let scale = if normal.is_zero() {
+        plane.zyx.abs()
+    } else {
+        normal
+    };
+    if scale.is_zero() {
+        return None;
+    }
+    Some(HomogeneusPlane {
+        wyz: plane.wyz / scale,
+        wzx: plane.wzx / scale,
+        wxy: plane.wxy / scale,
+        zyx: plane.zyx / scale,
+    })
+}
+
+/// Norm of the direction of a line
+pub(super) fn line_weight_norm<T: Float>(line: &HomogeneusLine<T>) -> T {
+    (line.wx * line.wx + line.wy * line.wy + line.wz * line.wz).sqrt()
 }
@@ -47,7 +47,7 @@ impl<T: Copy + ConstZero> Metric for HomogeneusPoint<T> {
     }
 
     fn weight(&self) -> Self::Weight {
-        d3::Scalar(self.w)
+        d3::Scalar::new(self.w)
     }
 
     fn proper_bulk(&self) -> Self {
@@ -0,0 +1,188 @@
+//! Rigid motions of the projective 3D space
+//!
+//! A motor `q = r + u` is applied to a point or a line `x` with the geometric antiproduct sandwich
+//! `q ⟇ x ⟇ ~q`, where `~q` is the antireverse of `q`. Its rotational part `r` is made of the
+//! `wx`, `wy`, `wz` bivector components and the antiscalar, and its translational part `u` of the
+//! `yz`, `zx`, `xy` components and the scalar. The motor `a ⟇ b` applies `b` first and then `a`.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::Float;
+use num::traits::ConstOne;
+use num::traits::ConstZero;
+
+use crate::Epsilon;
+use crate::GeometricAntiproduct;
+use crate::Unitizable;
+use crate::UnitizeError;
+use crate::ZeroTest;
+use crate::geometry3d as d3;
+use crate::geometry4d as d4;
+
+use super::HomogeneusLine;
+use super::HomogeneusPoint;
+use super::Motor;
+
+impl<T> Motor<T>
+where
+    T: Float,
+{
+    /// Rotation by `angle` radians, counterclockwise around `axis` through the origin
+    pub fn rotation(axis: &d3::UnitVector<T>, angle: T) -> Self {
+        let axis = d3::Vector::from(*axis);
+        let half = angle / (T::one() + T::one());
+        let (sin, cos) = half.sin_cos();
+        Self::from_parts(
+            [cos, axis.x * sin, axis.y * sin, axis.z * sin],
+            [T::zero(); 3],
+        )
+    }
+
+    /// Translation by `offset`
+    pub fn translation(offset: &d3::Vector<T>) -> Self {
+        Self::from_parts(
+            [T::one(), T::zero(), T::zero(), T::zero()],
+            [offset.x, offset.y, offset.z],
+        )
+    }
+
+    /// Rotation by the unit quaternion `[w, x, y, z]` followed by a translation by `offset`
+    pub(crate) fn from_parts(rotor: [T; 4], offset: [T; 3]) -> Self {
+        let two = T::one() + T::one();
+        let [rw, rx, ry, rz] = rotor;
+        let [ux, uy, uz] = offset.map(|t| t / two);
+        // the translator `u + 1` antimultiplied by the rotor `r + rw`
+        Motor(d4::Evenvector {
+            s: d4::Scalar::new(-(ux * rx + uy * ry + uz * rz)),
+            b: d4::Bivector {
+                wx: rx,
+                wy: ry,
+                wz: rz,
+                yz: rw * ux + uy * rz - uz * ry,
+                zx: rw * uy + uz * rx - ux * rz,
+                xy: rw * uz + ux * ry - uy * rx,
+            },
+            a: d4::Quadvector { xyzw: rw },
+        })
+    }
+
+    /// The antireverse `~q`, which undoes the motor when it is unitized
+    pub fn antireverse(&self) -> Self {
+        Motor(d4::Evenvector {
+            s: self.0.s,
+            b: -self.0.b,
+            a: self.0.a,
+        })
+    }
+}
+
+impl<T> Motor<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+{
+    /// Applies the motor to `point`, the motor must be unitized
+    pub fn transform_point(&self, point: &d3::Point<T>) -> d3::Point<T> {
+        let p = HomogeneusPoint::from(*point);
+        let moved = self
+            .0
+            .geometric_antiproduct(&p)
+            .geometric_antiproduct(&self.antireverse().0)
+            .v;
+        d3::Point(d3::Vector::new(
+            moved.x / moved.w,
+            moved.y / moved.w,
+            moved.z / moved.w,
+        ))
+    }
+
+    /// Applies the motor to `line`, the motor must be unitized
+    pub fn transform_line(&self, line: &HomogeneusLine<T>) -> HomogeneusLine<T> {
+        self.0
+            .geometric_antiproduct(line)
+            .geometric_antiproduct(&self.antireverse().0)
+            .b
+    }
+}
+
+impl<T> GeometricAntiproduct<Motor<T>> for Motor<T>
+where
+    d4::Evenvector<T>: GeometricAntiproduct<d4::Evenvector<T>, Output = d4::Evenvector<T>>,
+{
+    type Output = Motor<T>;
+
+    /// Composition of the motors, applying `rhs` first
+    fn geometric_antiproduct(&self, rhs: &Motor<T>) -> Self::Output {
+        Motor(self.0.geometric_antiproduct(&rhs.0))
+    }
+}
+
+impl<T> Unitizable for Motor<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    type Output = Motor<T>;
+    fn unitize(&self) -> Option<Self::Output> {
+        self.try_unitize().ok()
+    }
+
+    /// Scales the motor so that its rotational part, the weight, has norm `1`
+    fn try_unitize(&self) -> Result<Self::Output, UnitizeError> {
+        let Motor(d4::Evenvector { b, a, .. }) = self;
+        let len2 = a.xyzw * a.xyzw + b.wx * b.wx + b.wy * b.wy + b.wz * b.wz;
+        match len2.zero_test() {
+            ZeroTest::Zero => Err(UnitizeError::Degenerate),
+            ZeroTest::Unknown => Err(UnitizeError::Ambiguous),
+            ZeroTest::NonZero => Ok(Motor(self.0 * d4::Scalar::new(len2.sqrt().recip()))),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Arithmetic
+// ----------------------------------------------------------------------------------------------------
+
+// linear combinations of motors are not motions, but integrators step through them before
+// unitizing the result
+
+impl<T> Add for Motor<T>
+where
+    d4::Evenvector<T>: Add<Output = d4::Evenvector<T>>,
+{
+    type Output = Motor<T>;
+    fn add(self, rhs: Motor<T>) -> Self::Output {
+        Motor(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub for Motor<T>
+where
+    d4::Evenvector<T>: Sub<Output = d4::Evenvector<T>>,
+{
+    type Output = Motor<T>;
+    fn sub(self, rhs: Motor<T>) -> Self::Output {
+        Motor(self.0 - rhs.0)
+    }
+}
+
+impl<T> Neg for Motor<T>
+where
+    d4::Evenvector<T>: Neg<Output = d4::Evenvector<T>>,
+{
+    type Output = Motor<T>;
+    fn neg(self) -> Self::Output {
+        Motor(-self.0)
+    }
+}
+
+impl<T> Mul<T> for Motor<T>
+where
+    d4::Evenvector<T>: Mul<d4::Scalar<T>, Output = d4::Evenvector<T>>,
+{
+    type Output = Motor<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        Motor(self.0 * d4::Scalar::new(rhs))
+    }
+}
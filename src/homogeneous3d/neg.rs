@@ -0,0 +1,33 @@
+use std::ops::Neg;
+
+use super::{HorizonLine, Line, Plane};
+
+impl<T> Neg for Line<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Line<T>;
+    fn neg(self) -> Self::Output {
+        Line(-self.0)
+    }
+}
+
+impl<T> Neg for HorizonLine<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = HorizonLine<T>;
+    fn neg(self) -> Self::Output {
+        HorizonLine(-self.0)
+    }
+}
+
+impl<T> Neg for Plane<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Plane<T>;
+    fn neg(self) -> Self::Output {
+        Plane(-self.0)
+    }
+}
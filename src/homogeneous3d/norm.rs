@@ -17,7 +17,7 @@
 //     type Antiscalar = d4::Quadvector<T>;
 
 //     fn bulk_norm_squared(&self) -> Self::Scalar {
-//         d4::Scalar(self.dot(self).0)
+//         d4::Scalar::new(self.dot(self).0)
 //     }
 
 //     fn weight_norm_squared(&self) -> Self::Antiscalar {
@@ -27,7 +27,7 @@
 //     }
 
 //     fn bulk_norm(&self) -> d4::Scalar<T> {
-//         d4::Scalar(self.dot(self).0.sqrt())
+//         d4::Scalar::new(self.dot(self).0.sqrt())
 //     }
 
 //     fn weight_norm(&self) -> d4::Quadvector<T> {
@@ -45,7 +45,7 @@
 //     type Antiscalar = d4::Quadvector<T>;
 
 //     fn bulk_norm_squared(&self) -> Self::Scalar {
-//         d4::Scalar(self.dot(self).0)
+//         d4::Scalar::new(self.dot(self).0)
 //     }
 
 //     fn weight_norm_squared(&self) -> Self::Antiscalar {
@@ -55,7 +55,7 @@
 //     }
 
 //     fn bulk_norm(&self) -> d4::Scalar<T> {
-//         d4::Scalar(self.dot(self).0.sqrt())
+//         d4::Scalar::new(self.dot(self).0.sqrt())
 //     }
 
 //     fn weight_norm(&self) -> d4::Quadvector<T> {
@@ -74,7 +74,7 @@
 //     type Antiscalar = d4::Quadvector<T>;
 
 //     fn bulk_norm_squared(&self) -> Self::Scalar {
-//         d4::Scalar(self.zyx * self.zyx)
+//         d4::Scalar::new(self.zyx * self.zyx)
 //     }
 
 //     fn weight_norm_squared(&self) -> Self::Antiscalar {
@@ -82,7 +82,7 @@
 //     }
 
 //     fn bulk_norm(&self) -> d4::Scalar<T> {
-//         d4::Scalar(self.zyx.abs())
+//         d4::Scalar::new(self.zyx.abs())
 //     }
 
 //     fn weight_norm(&self) -> d4::Quadvector<T> {
@@ -0,0 +1,7 @@
+use crate::operators;
+
+use super::{HorizonLine, Line, Plane};
+
+operators!(Line<T>, T);
+operators!(HorizonLine<T>, T);
+operators!(Plane<T>, T);
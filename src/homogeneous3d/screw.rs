@@ -0,0 +1,257 @@
+//! Screws: twists and wrenches stored as lines
+//!
+//! Both are bivectors laid out like a [`HomogeneusLine`]: the weight (`wx`, `wy`, `wz`) holds the
+//! angular velocity of a twist or the force of a wrench, and the bulk (`yz`, `zx`, `xy`) holds the
+//! linear velocity of the point at the origin or the torque about the origin. A screw of pitch `h`
+//! around a unit line `(d; m)` is `(d; m + h d)`.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::Float;
+use num::traits::{ConstOne, ConstZero};
+
+use crate::geometry3d as d3;
+use crate::geometry4d as d4;
+use crate::{Dot, Epsilon, GeometricAntiproduct, Unitizable};
+
+use super::{HomogeneusLine, Line, Motor, NormalizedLine, Twist, Wrench};
+
+fn weight<T: Copy>(b: &d4::Bivector<T>) -> d3::Vector<T> {
+    d3::Vector::new(b.wx, b.wy, b.wz)
+}
+
+fn bulk<T: Copy>(b: &d4::Bivector<T>) -> d3::Vector<T> {
+    d3::Vector::new(b.yz, b.zx, b.xy)
+}
+
+fn screw<T: Copy>(weight: &d3::Vector<T>, bulk: &d3::Vector<T>) -> d4::Bivector<T> {
+    d4::Bivector {
+        wx: weight.x,
+        wy: weight.y,
+        wz: weight.z,
+        yz: bulk.x,
+        zx: bulk.y,
+        xy: bulk.z,
+    }
+}
+
+fn from_line<T: Float>(line: &Line<T>, pitch: T) -> d4::Bivector<T> {
+    let direction = weight(&line.0);
+    screw(&direction, &(bulk(&line.0) + direction * pitch))
+}
+
+/// The line of the screw, or its ideal part when it has no direction
+fn axis<T>(b: &d4::Bivector<T>) -> Option<NormalizedLine<T>>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    match b.pitch() {
+        // pure translations and pure torques have their axis at infinity
+        None => b.unitize(),
+        Some(_) => b.invariant_decomposition().0.unitize(),
+    }
+}
+
+/// Power between two screws, `w1 · v2 + v1 · w2`
+fn reciprocal_product<T: Float>(a: &d4::Bivector<T>, b: &d4::Bivector<T>) -> T {
+    weight(a).dot(&bulk(b)) + bulk(a).dot(&weight(b))
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Twist
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Twist<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Twist with angular velocity `angular` and linear velocity `linear` of the point at the origin
+    pub fn new(angular: &d3::Vector<T>, linear: &d3::Vector<T>) -> Self {
+        Twist(screw(angular, linear))
+    }
+
+    /// Unit twist around `line`, advancing `pitch` along it per radian
+    pub fn from_line(line: &Line<T>, pitch: T) -> Self {
+        Twist(from_line(line, pitch))
+    }
+
+    pub fn angular(&self) -> d3::Vector<T> {
+        weight(&self.0)
+    }
+
+    /// Linear velocity of the point at the origin
+    pub fn linear(&self) -> d3::Vector<T> {
+        bulk(&self.0)
+    }
+
+    /// Translation per radian of rotation, `None` for a pure translation
+    pub fn pitch(&self) -> Option<T> {
+        self.0.pitch()
+    }
+
+    /// Screw axis, a [`NormalizedLine::HorizonLine`] for a pure translation and `None` for a zero
+    /// twist
+    pub fn axis(&self) -> Option<NormalizedLine<T>> {
+        axis(&self.0)
+    }
+
+    /// Power of `wrench` acting on a body moving with this twist, zero when they are reciprocal
+    pub fn reciprocal_product(&self, wrench: &Wrench<T>) -> T {
+        reciprocal_product(&self.0, &wrench.0)
+    }
+
+    /// The twist seen after moving the body with `motor`, which must be unitized
+    pub fn transform(&self, motor: &Motor<T>) -> Self {
+        Twist(motor.transform_line(&self.0))
+    }
+
+    /// Motor reached by moving with the twist for a unit of time, the exponential of half the
+    /// twist
+    ///
+    /// The twist splits into a rotation around its line and a translation along it, which commute:
+    /// `exp(B / 2) = (cos(θ / 2) + sin(θ / 2) L / θ) ⟇ (1 + I / 2)` where `θ` is the angular
+    /// speed, `L` the line and `I` the ideal part of the invariant decomposition.
+    pub fn exp(&self) -> Motor<T> {
+        let two = T::one() + T::one();
+        let (line, ideal) = self.0.invariant_decomposition();
+        let translation = d4::Evenvector {
+            s: d4::Scalar::new(T::zero()),
+            b: ideal * d4::Scalar::new(two.recip()),
+            a: d4::Quadvector { xyzw: T::one() },
+        };
+        let direction = weight(&line);
+        let angle = direction.dot(&direction).sqrt();
+        if angle.is_zero() {
+            return Motor::from(translation);
+        }
+        let (sin, cos) = (angle / two).sin_cos();
+        let rotation = d4::Evenvector {
+            s: d4::Scalar::new(T::zero()),
+            b: line * d4::Scalar::new(sin / angle),
+            a: d4::Quadvector { xyzw: cos },
+        };
+        Motor::from(rotation.geometric_antiproduct(&translation))
+    }
+
+    /// Rate of change of `motor` while the body it moves has this velocity in its own frame,
+    /// `motor ⟇ twist / 2`
+    pub(crate) fn motor_derivative(&self, motor: &Motor<T>) -> Motor<T> {
+        Motor::from(motor.0.geometric_antiproduct(&self.0)) * (T::one() + T::one()).recip()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Wrench
+// ----------------------------------------------------------------------------------------------------
+
+impl<T> Wrench<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Wrench with resultant `force` and `torque` about the origin
+    pub fn new(force: &d3::Vector<T>, torque: &d3::Vector<T>) -> Self {
+        Wrench(screw(force, torque))
+    }
+
+    /// Unit force along `line` together with a torque of `pitch` around it
+    pub fn from_line(line: &Line<T>, pitch: T) -> Self {
+        Wrench(from_line(line, pitch))
+    }
+
+    pub fn force(&self) -> d3::Vector<T> {
+        weight(&self.0)
+    }
+
+    /// Torque about the origin
+    pub fn torque(&self) -> d3::Vector<T> {
+        bulk(&self.0)
+    }
+
+    /// Torque per unit of force along the axis, `None` for a pure torque
+    pub fn pitch(&self) -> Option<T> {
+        self.0.pitch()
+    }
+
+    /// Screw axis, a [`NormalizedLine::HorizonLine`] for a pure torque and `None` for a zero wrench
+    pub fn axis(&self) -> Option<NormalizedLine<T>> {
+        axis(&self.0)
+    }
+
+    /// Power of the wrench acting on a body moving with `twist`, zero when they are reciprocal
+    pub fn reciprocal_product(&self, twist: &Twist<T>) -> T {
+        reciprocal_product(&self.0, &twist.0)
+    }
+
+    /// The wrench seen after moving the body with `motor`, which must be unitized
+    pub fn transform(&self, motor: &Motor<T>) -> Self {
+        Wrench(motor.transform_line(&self.0))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------
+// Arithmetic
+// ----------------------------------------------------------------------------------------------------
+
+macro_rules! screw_arithmetic {
+    ($t:ident) => {
+        impl<T> Add for $t<T>
+        where
+            HomogeneusLine<T>: Add<Output = HomogeneusLine<T>>,
+        {
+            type Output = $t<T>;
+            fn add(self, rhs: $t<T>) -> Self::Output {
+                $t(self.0 + rhs.0)
+            }
+        }
+
+        impl<T> Sub for $t<T>
+        where
+            HomogeneusLine<T>: Sub<Output = HomogeneusLine<T>>,
+        {
+            type Output = $t<T>;
+            fn sub(self, rhs: $t<T>) -> Self::Output {
+                $t(self.0 - rhs.0)
+            }
+        }
+
+        impl<T> Neg for $t<T>
+        where
+            HomogeneusLine<T>: Neg<Output = HomogeneusLine<T>>,
+        {
+            type Output = $t<T>;
+            fn neg(self) -> Self::Output {
+                $t(-self.0)
+            }
+        }
+
+        impl<T> Mul<T> for $t<T>
+        where
+            T: Copy,
+            T: Mul<T, Output = T>,
+        {
+            type Output = $t<T>;
+            fn mul(self, rhs: T) -> Self::Output {
+                $t(d4::Bivector {
+                    wx: self.0.wx * rhs,
+                    wy: self.0.wy * rhs,
+                    wz: self.0.wz * rhs,
+                    yz: self.0.yz * rhs,
+                    zx: self.0.zx * rhs,
+                    xy: self.0.xy * rhs,
+                })
+            }
+        }
+    };
+}
+
+screw_arithmetic!(Twist);
+screw_arithmetic!(Wrench);
@@ -0,0 +1,32 @@
+use std::ops::Sub;
+
+use super::{HomogeneusLine, HomogeneusPlane, HorizonLine, Line, Plane};
+
+/// Implements `-` of two primitives as the difference of their homogeneous k-vectors, which may not be a
+/// primitive anymore (like the non-simple bivector between two skew lines)
+macro_rules! sub_as_homogeneous {
+    ($lht:ident as $lh:ident, $rht:ident as $rh:ident) => {
+        impl<T> Sub<$rht<T>> for $lht<T>
+        where
+            $lh<T>: From<$lht<T>>,
+            $rh<T>: From<$rht<T>>,
+            $lh<T>: Sub<$rh<T>>,
+        {
+            type Output = <$lh<T> as Sub<$rh<T>>>::Output;
+
+            fn sub(self, rhs: $rht<T>) -> Self::Output {
+                $lh::from(self) - $rh::from(rhs)
+            }
+        }
+    };
+}
+
+sub_as_homogeneous!(Line as HomogeneusLine, Line as HomogeneusLine);
+sub_as_homogeneous!(Line as HomogeneusLine, HorizonLine as HomogeneusLine);
+sub_as_homogeneous!(Line as HomogeneusLine, Plane as HomogeneusPlane);
+sub_as_homogeneous!(HorizonLine as HomogeneusLine, Line as HomogeneusLine);
+sub_as_homogeneous!(HorizonLine as HomogeneusLine, HorizonLine as HomogeneusLine);
+sub_as_homogeneous!(HorizonLine as HomogeneusLine, Plane as HomogeneusPlane);
+sub_as_homogeneous!(Plane as HomogeneusPlane, Line as HomogeneusLine);
+sub_as_homogeneous!(Plane as HomogeneusPlane, HorizonLine as HomogeneusLine);
+sub_as_homogeneous!(Plane as HomogeneusPlane, Plane as HomogeneusPlane);
@@ -0,0 +1,757 @@
+use std::{
+    cmp::Ordering,
+    num::FpCategory,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num::{
+    Float, Num, NumCast, One, ToPrimitive, Zero,
+    traits::{ConstOne, ConstZero},
+};
+
+use crate::{Epsilon, ZeroTest};
+
+/// Closed interval of floating point numbers `[lo, hi]`.
+///
+/// Every operation rounds its bounds outwards, so the exact (real number) result of a computation
+/// is always contained in the resulting interval. It implements [`Float`] so it can be plugged in as
+/// the `T` of any element of the crate to get certified bounds of a computation.
+///
+/// Elementary functions (`sin`, `exp`, ...) rely on the platform implementation being faithfully
+/// rounded, their results are widened by one ulp.
+///
+/// Comparisons only succeed when they are certain: `a < b` means that every value of `a` is smaller
+/// than every value of `b`, and `a == b` only holds when both are the same single value. Overlapping
+/// intervals are not ordered, and two wide intervals are not equal even when their bounds match.
+#[derive(Debug, Clone, Copy)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+/// Largest representable value that is smaller than `x`, or a bit less than that
+#[inline(always)]
+fn round_down<T: Float>(x: T) -> T {
+    if x.is_infinite() || x.is_nan() {
+        x
+    } else {
+        x - x.abs() * T::epsilon() - T::min_positive_value()
+    }
+}
+
+/// Smallest representable value that is bigger than `x`, or a bit more than that
+#[inline(always)]
+fn round_up<T: Float>(x: T) -> T {
+    if x.is_infinite() || x.is_nan() {
+        x
+    } else {
+        x + x.abs() * T::epsilon() + T::min_positive_value()
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: Float,
+{
+    /// Creates the interval between two values, in any order
+    pub fn new(a: T, b: T) -> Self {
+        Interval {
+            lo: a.min(b),
+            hi: a.max(b),
+        }
+    }
+
+    /// Creates an interval that contains exactly one value
+    pub fn point(value: T) -> Self {
+        Interval {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    /// Creates an interval around a value that was already rounded, like a constant that can't be
+    /// represented exactly.
+    pub fn enclosing(value: T) -> Self {
+        Interval {
+            lo: round_down(value),
+            hi: round_up(value),
+        }
+    }
+
+    /// Interval containing all numbers
+    pub fn entire() -> Self {
+        Interval {
+            lo: T::neg_infinity(),
+            hi: T::infinity(),
+        }
+    }
+
+    /// Lower bound
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    /// Upper bound
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    /// Value in the middle of the interval
+    pub fn midpoint(&self) -> T {
+        if self.lo == self.hi {
+            self.lo
+        } else {
+            let two = T::one() + T::one();
+            self.lo / two + self.hi / two
+        }
+    }
+
+    /// Distance between both bounds
+    pub fn width(&self) -> T {
+        round_up(self.hi - self.lo)
+    }
+
+    /// Returns true if `value` is inside the interval
+    pub fn contains(&self, value: T) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Smallest interval that contains both intervals
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    fn increasing(self, f: impl Fn(T) -> T) -> Self {
+        Interval {
+            lo: round_down(f(self.lo)),
+            hi: round_up(f(self.hi)),
+        }
+    }
+
+    fn decreasing(self, f: impl Fn(T) -> T) -> Self {
+        Interval {
+            lo: round_down(f(self.hi)),
+            hi: round_up(f(self.lo)),
+        }
+    }
+
+    fn clamped(self, lo: T, hi: T) -> Self {
+        Interval {
+            lo: self.lo.max(lo),
+            hi: self.hi.min(hi),
+        }
+    }
+
+    fn pi() -> Self {
+        Interval::enclosing(T::from(std::f64::consts::PI).expect("T should hold pi"))
+    }
+
+    /// True if the interval may contain a value of the form `offset + k * period` for an integer `k`
+    fn may_contain_multiple(self, offset: Self, period: Self) -> bool {
+        let q = (self - offset) / period;
+        q.hi.floor() >= q.lo.ceil()
+    }
+
+    /// Shared implementation of `sin` and `cos` given the position of their maximums and minimums
+    fn periodic(self, f: impl Fn(T) -> T, max_at: Self, min_at: Self) -> Self {
+        let two_pi = Self::pi() + Self::pi();
+        if !self.is_finite() || self.hi - self.lo >= two_pi.lo {
+            return Interval::new(-T::one(), T::one());
+        }
+
+        let a = f(self.lo);
+        let b = f(self.hi);
+        let lo = if self.may_contain_multiple(min_at, two_pi) {
+            -T::one()
+        } else {
+            round_down(a.min(b)).max(-T::one())
+        };
+        let hi = if self.may_contain_multiple(max_at, two_pi) {
+            T::one()
+        } else {
+            round_up(a.max(b)).min(T::one())
+        };
+        Interval { lo, hi }
+    }
+}
+
+impl<T> From<T> for Interval<T>
+where
+    T: Float,
+{
+    fn from(value: T) -> Self {
+        Interval::point(value)
+    }
+}
+
+impl<T> Epsilon for Interval<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    fn eps() -> Self {
+        Interval::point(T::eps())
+    }
+
+    /// Returns true unless the whole interval is certainly away from zero
+    fn is_near_zero(&self) -> bool {
+        self.zero_test() != ZeroTest::NonZero
+    }
+
+    fn zero_test(&self) -> ZeroTest {
+        let eps = T::eps();
+        if -eps < self.lo && self.hi < eps {
+            ZeroTest::Zero
+        } else if self.lo >= eps || self.hi <= -eps {
+            ZeroTest::NonZero
+        } else {
+            ZeroTest::Unknown
+        }
+    }
+}
+
+impl<T> PartialEq for Interval<T>
+where
+    T: Float,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // two wide intervals may hold different values even when their bounds match
+        self.lo == self.hi && self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl<T> PartialOrd for Interval<T>
+where
+    T: Float,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Add for Interval<T>
+where
+    T: Float,
+{
+    type Output = Interval<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Interval {
+            lo: round_down(self.lo + rhs.lo),
+            hi: round_up(self.hi + rhs.hi),
+        }
+    }
+}
+
+impl<T> Sub for Interval<T>
+where
+    T: Float,
+{
+    type Output = Interval<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Interval {
+            lo: round_down(self.lo - rhs.hi),
+            hi: round_up(self.hi - rhs.lo),
+        }
+    }
+}
+
+impl<T> Mul for Interval<T>
+where
+    T: Float,
+{
+    type Output = Interval<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let a = self.lo * rhs.lo;
+        let b = self.lo * rhs.hi;
+        let c = self.hi * rhs.lo;
+        let d = self.hi * rhs.hi;
+        Interval {
+            lo: round_down(a.min(b).min(c).min(d)),
+            hi: round_up(a.max(b).max(c).max(d)),
+        }
+    }
+}
+
+impl<T> Div for Interval<T>
+where
+    T: Float,
+{
+    type Output = Interval<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.lo <= T::zero() && rhs.hi >= T::zero() {
+            return Interval::entire();
+        }
+        let a = self.lo / rhs.lo;
+        let b = self.lo / rhs.hi;
+        let c = self.hi / rhs.lo;
+        let d = self.hi / rhs.hi;
+        Interval {
+            lo: round_down(a.min(b).min(c).min(d)),
+            hi: round_up(a.max(b).max(c).max(d)),
+        }
+    }
+}
+
+impl<T> Rem for Interval<T>
+where
+    T: Float,
+{
+    type Output = Interval<T>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+impl<T> Neg for Interval<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Interval<T>;
+
+    fn neg(self) -> Self::Output {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<T> Zero for Interval<T>
+where
+    T: Float,
+{
+    fn zero() -> Self {
+        Interval::point(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+}
+
+impl<T> ConstZero for Interval<T>
+where
+    T: Float,
+    T: ConstZero,
+{
+    const ZERO: Self = Interval {
+        lo: T::ZERO,
+        hi: T::ZERO,
+    };
+}
+
+impl<T> One for Interval<T>
+where
+    T: Float,
+{
+    fn one() -> Self {
+        Interval::point(T::one())
+    }
+}
+
+impl<T> ConstOne for Interval<T>
+where
+    T: Float,
+    T: ConstOne,
+{
+    const ONE: Self = Interval {
+        lo: T::ONE,
+        hi: T::ONE,
+    };
+}
+
+impl<T> Num for Interval<T>
+where
+    T: Float,
+{
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix).map(Interval::enclosing)
+    }
+}
+
+impl<T> ToPrimitive for Interval<T>
+where
+    T: Float,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.midpoint().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.midpoint().to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.midpoint().to_f64()
+    }
+}
+
+impl<T> NumCast for Interval<T>
+where
+    T: Float,
+{
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        let exact = n.to_f64();
+        let value = T::from(n)?;
+        if value.to_f64() == exact {
+            Some(Interval::point(value))
+        } else {
+            Some(Interval::enclosing(value))
+        }
+    }
+}
+
+impl<T> Float for Interval<T>
+where
+    T: Float,
+{
+    fn nan() -> Self {
+        Interval::point(T::nan())
+    }
+
+    fn infinity() -> Self {
+        Interval::point(T::infinity())
+    }
+
+    fn neg_infinity() -> Self {
+        Interval::point(T::neg_infinity())
+    }
+
+    fn neg_zero() -> Self {
+        Interval::point(T::neg_zero())
+    }
+
+    fn min_value() -> Self {
+        Interval::point(T::min_value())
+    }
+
+    fn min_positive_value() -> Self {
+        Interval::point(T::min_positive_value())
+    }
+
+    fn epsilon() -> Self {
+        Interval::point(T::epsilon())
+    }
+
+    fn max_value() -> Self {
+        Interval::point(T::max_value())
+    }
+
+    fn is_nan(self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.lo.is_infinite() || self.hi.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.lo.is_normal() && self.hi.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        if self.lo == self.hi {
+            self.lo.classify()
+        } else if self.is_nan() {
+            FpCategory::Nan
+        } else if self.is_infinite() {
+            FpCategory::Infinite
+        } else {
+            FpCategory::Normal
+        }
+    }
+
+    fn floor(self) -> Self {
+        Interval {
+            lo: self.lo.floor(),
+            hi: self.hi.floor(),
+        }
+    }
+
+    fn ceil(self) -> Self {
+        Interval {
+            lo: self.lo.ceil(),
+            hi: self.hi.ceil(),
+        }
+    }
+
+    fn round(self) -> Self {
+        Interval {
+            lo: self.lo.round(),
+            hi: self.hi.round(),
+        }
+    }
+
+    fn trunc(self) -> Self {
+        Interval {
+            lo: self.lo.trunc(),
+            hi: self.hi.trunc(),
+        }
+    }
+
+    fn fract(self) -> Self {
+        if self.lo.trunc() == self.hi.trunc() {
+            Interval {
+                lo: self.lo.fract(),
+                hi: self.hi.fract(),
+            }
+        } else if self.lo >= T::zero() {
+            Interval::new(T::zero(), T::one())
+        } else if self.hi <= T::zero() {
+            Interval::new(-T::one(), T::zero())
+        } else {
+            Interval::new(-T::one(), T::one())
+        }
+    }
+
+    fn abs(self) -> Self {
+        if self.lo >= T::zero() {
+            self
+        } else if self.hi <= T::zero() {
+            -self
+        } else {
+            Interval {
+                lo: T::zero(),
+                hi: (-self.lo).max(self.hi),
+            }
+        }
+    }
+
+    fn signum(self) -> Self {
+        Interval {
+            lo: self.lo.signum(),
+            hi: self.hi.signum(),
+        }
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.lo.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        if n < 0 {
+            return self.powi(-n).recip();
+        }
+
+        if n % 2 != 0 && self.lo < T::zero() && self.hi > T::zero() {
+            // odd powers are increasing, but multiplying intervals with both signs loses precision
+            let lo = Interval::point(self.lo).powi(n).lo;
+            let hi = Interval::point(self.hi).powi(n).hi;
+            return Interval { lo, hi };
+        }
+
+        let mut base = if n % 2 == 0 { self.abs() } else { self };
+        let mut result = Self::one();
+        let mut n = n;
+        while n > 0 {
+            if n % 2 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            n /= 2;
+        }
+        result
+    }
+
+    fn powf(self, n: Self) -> Self {
+        (self.ln() * n).exp()
+    }
+
+    /// Square root of the non-negative part of the interval, `NaN` on both ends when the whole
+    /// interval is negative
+    fn sqrt(self) -> Self {
+        if self.hi < T::zero() {
+            return Interval::nan();
+        }
+        let clamped = self.clamped(T::zero(), T::infinity());
+        Interval {
+            lo: round_down(clamped.lo.sqrt()).max(T::zero()),
+            hi: round_up(clamped.hi.sqrt()),
+        }
+    }
+
+    fn exp(self) -> Self {
+        let result = self.increasing(T::exp);
+        Interval {
+            lo: result.lo.max(T::zero()),
+            hi: result.hi,
+        }
+    }
+
+    fn exp2(self) -> Self {
+        let result = self.increasing(T::exp2);
+        Interval {
+            lo: result.lo.max(T::zero()),
+            hi: result.hi,
+        }
+    }
+
+    fn ln(self) -> Self {
+        self.clamped(T::zero(), T::infinity()).increasing(T::ln)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.clamped(T::zero(), T::infinity()).increasing(T::log2)
+    }
+
+    fn log10(self) -> Self {
+        self.clamped(T::zero(), T::infinity()).increasing(T::log10)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Interval {
+            lo: self.lo.max(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.min(other.hi),
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).max(Self::zero())
+    }
+
+    fn cbrt(self) -> Self {
+        self.increasing(T::cbrt)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let a = self.abs();
+        let b = other.abs();
+        (a * a + b * b).sqrt()
+    }
+
+    fn sin(self) -> Self {
+        let half_pi = Self::pi() / (Self::one() + Self::one());
+        self.periodic(T::sin, half_pi, -half_pi)
+    }
+
+    fn cos(self) -> Self {
+        self.periodic(T::cos, Self::zero(), Self::pi())
+    }
+
+    fn tan(self) -> Self {
+        let half_pi = Self::pi() / (Self::one() + Self::one());
+        if !self.is_finite() || self.may_contain_multiple(half_pi, Self::pi()) {
+            Interval::entire()
+        } else {
+            self.increasing(T::tan)
+        }
+    }
+
+    fn asin(self) -> Self {
+        self.clamped(-T::one(), T::one()).increasing(T::asin)
+    }
+
+    fn acos(self) -> Self {
+        self.clamped(-T::one(), T::one()).decreasing(T::acos)
+    }
+
+    fn atan(self) -> Self {
+        self.increasing(T::atan)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let half_pi = Self::pi() / (Self::one() + Self::one());
+        if other.lo > T::zero() {
+            (self / other).atan()
+        } else if self.lo > T::zero() {
+            half_pi - (other / self).atan()
+        } else if self.hi < T::zero() {
+            -half_pi - (other / self).atan()
+        } else {
+            let pi = Self::pi();
+            Interval {
+                lo: -pi.hi,
+                hi: pi.hi,
+            }
+        }
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        self.increasing(T::exp_m1)
+    }
+
+    fn ln_1p(self) -> Self {
+        self.clamped(-T::one(), T::infinity()).increasing(T::ln_1p)
+    }
+
+    fn sinh(self) -> Self {
+        self.increasing(T::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        let result = self.abs().increasing(T::cosh);
+        Interval {
+            lo: result.lo.max(T::one()),
+            hi: result.hi,
+        }
+    }
+
+    fn tanh(self) -> Self {
+        self.increasing(T::tanh).clamped(-T::one(), T::one())
+    }
+
+    fn asinh(self) -> Self {
+        self.increasing(T::asinh)
+    }
+
+    fn acosh(self) -> Self {
+        self.clamped(T::one(), T::infinity()).increasing(T::acosh)
+    }
+
+    fn atanh(self) -> Self {
+        self.clamped(-T::one(), T::one()).increasing(T::atanh)
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.midpoint().integer_decode()
+    }
+}
@@ -1,40 +1,72 @@
 use std::marker::PhantomData;
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 pub mod canonical;
+pub mod dynamics;
+pub mod fitting;
 pub mod geometry2d;
 pub mod geometry3d;
 pub mod geometry4d;
+pub mod interval;
+pub mod outermorphism;
 pub mod projective2d;
+pub mod registration;
 
 mod blanket_impls;
 mod macros;
 mod scalar;
+mod subspace;
 
 pub mod homogeneous3d;
 
 /// Marker type used to define an Euclidean metric.
 ///
 /// Euclidean metrics are those whose all basis vectors square to 1
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Euclidean {}
 
 /// Marker type used to define a Projective metric.
 ///
 /// A Projective metric is build on top of an euclidean metric, with an additional vector
 /// that squares to 0. This is used to build projective geometries.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Projective {}
 
 /// Wrapper type for scalars so we can overwrite + and * (and other) operators
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// It is the scalar of every algebra: `D` is the dimension of the algebra and `M` its metric.
+/// [`geometry3d::Scalar`] and [`geometry4d::Scalar`] are aliases of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Scalar<const D: u32, T, M = Euclidean>(pub T, PhantomData<M>);
 
+/// Outcome of comparing a number against [`Epsilon::eps`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroTest {
+    /// The number is certainly smaller than [`Epsilon::eps`]
+    Zero,
+    /// The number is certainly not smaller than [`Epsilon::eps`]
+    NonZero,
+    /// The number may be on either side of [`Epsilon::eps`], this happens with [`interval::Interval`]
+    Unknown,
+}
+
 /// Helper trait to avoid division by (nearly) zero
 pub trait Epsilon {
     /// Returns an arbitrarily small positive number
     fn eps() -> Self;
-    /// Returns true if the absolute value of the number is smaller than [`Epsilon::eps`]
+    /// Returns true if the absolute value of the number could be smaller than [`Epsilon::eps`]
     fn is_near_zero(&self) -> bool;
+    /// Three-valued version of [`Epsilon::is_near_zero`].
+    ///
+    /// Plain floats are never [`ZeroTest::Unknown`], but types carrying an uncertainty are.
+    fn zero_test(&self) -> ZeroTest {
+        if self.is_near_zero() {
+            ZeroTest::Zero
+        } else {
+            ZeroTest::NonZero
+        }
+    }
 }
 
 /// Marks the element with the largest dimensionality of a geometry.
@@ -80,6 +112,48 @@ pub trait GeometricElement {
     type Scalar; // = Scalar<ALGEBRA_DIMENSION, Field, Metric>;
 }
 
+/// Grade introspection of the elements of an algebra.
+///
+/// Every coefficient of an element multiplies one of the basis blades of its algebra, which are listed
+/// by the `BasisBlade` enum of each geometry module. The grade of a blade is the number of basis vectors
+/// it is built from: the scalar has grade 0, vectors grade 1, bivectors grade 2 and so on.
+pub trait Graded {
+    /// Basis blades of the algebra, usually its `BasisBlade` enum
+    type Blade;
+    /// Type of the coefficients
+    type Field;
+
+    /// Projects the element onto grade `k`, keeping only the coefficients of the grade `k` blades.
+    fn grade(&self, k: u32) -> Self;
+
+    /// Grades with a non-zero coefficient, in increasing order
+    fn grades(&self) -> Vec<u32>;
+
+    /// Checks that there is at most one grade with non-zero coefficients, zero is homogeneous of any grade
+    fn is_homogeneous(&self) -> bool {
+        self.grades().len() <= 1
+    }
+
+    /// Pairs of basis blade and coefficient of every component stored by the element, including zeros
+    fn components(&self) -> Vec<(Self::Blade, Self::Field)>;
+}
+
+/// Grade preserving embedding of an element into a higher dimensional algebra.
+///
+/// The lower algebra is spanned by the first basis vectors of the higher one, so the embedding is linear
+/// and commutes with the [`WedgeProduct`]: `a.wedge(&b).embed() == a.embed().wedge(&b.embed())`.
+pub trait Embed<Target> {
+    fn embed(&self) -> Target;
+}
+
+/// Inverse of [`Embed`]: brings an element back to the lower dimensional algebra.
+///
+/// It is `None` when some coefficient of a blade outside of the lower algebra is not zero, as the
+/// projection would lose it.
+pub trait Project<Target> {
+    fn project(&self) -> Option<Target>;
+}
+
 /// Complement operation for a geometric algebra.
 ///
 /// This is one of the "arbitrary" operations for a geometric algebra, it needs
@@ -152,6 +226,27 @@ pub trait Meet<Rhs> {
     fn meet(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Join of the subspaces spanned by two euclidean blades.
+///
+/// Unlike [`Join`], which is a single wedge of projective primitives, this is the smallest subspace that
+/// contains both blades even when they share directions: the subspace join of two parallel vectors is
+/// their common line and not zero. Rank decisions use [`Epsilon`] relative to the length of each
+/// direction, and the result is a unit blade. A zero blade spans no subspace, so joining it gives zero.
+pub trait SubspaceJoin<Rhs> {
+    type Output;
+    fn subspace_join(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Meet of the subspaces spanned by two euclidean blades.
+///
+/// It is the largest subspace contained in both blades, as a unit blade. Disjoint blades meet at the
+/// origin, which is the unit scalar. Rank decisions use [`Epsilon`] relative to the length of each
+/// direction. A zero blade spans no subspace, so meeting it gives zero and not the origin.
+pub trait SubspaceMeet<Rhs> {
+    type Output;
+    fn subspace_meet(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Basic operation from the metric.
 ///
 /// Toguether with [`Antiscalar`] this defines the behaviour of the algebra.
@@ -278,6 +373,21 @@ pub trait Unitizable {
     /// Gives a version of itself whose weight norm is equal to `1`.
     /// Returns null if the original weight is too close to `0`.
     fn unitize(&self) -> Option<Self::Output>;
+
+    /// Same as [`Unitizable::unitize`] but tells apart an element whose weight is too close to `0`
+    /// from one that can't be classified with certainty (see [`Epsilon::zero_test`]).
+    fn try_unitize(&self) -> Result<Self::Output, UnitizeError> {
+        self.unitize().ok_or(UnitizeError::Degenerate)
+    }
+}
+
+/// Reason why [`Unitizable::try_unitize`] failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitizeError {
+    /// The weight of the element is too close to `0`
+    Degenerate,
+    /// The element is too uncertain to tell which kind of normalized object it is
+    Ambiguous,
 }
 
 /// Dual operations.
@@ -418,6 +528,23 @@ pub trait GeometricAntiproduct<Rhs> {
     fn geometric_antiproduct(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Commutator product `a × b = (ab - ba) / 2`, the antisymmetric part of the [`GeometricProduct`].
+///
+/// Bivectors are closed under it, it is the Lie bracket of the bivectors as generators of rotations
+/// and motions.
+pub trait CommutatorProduct<Rhs> {
+    type Output;
+    fn commutator_product(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Anticommutator product `(ab + ba) / 2`, the symmetric part of the [`GeometricProduct`].
+///
+/// Added to the [`CommutatorProduct`] it gives back the geometric product.
+pub trait AnticommutatorProduct<Rhs> {
+    type Output;
+    fn anticommutator_product(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Computes the angle between 2 primitives
 ///
 /// The definition for the geometric cosine is: `bulk_norm(weight_expansion(a, b)) + weight_norm(a) * weight_norm(b)`
@@ -450,6 +577,13 @@ pub trait Distance<Rhs> {
     fn distance(&self, rhs: &Rhs) -> Self::Scalar;
 }
 
+/// Applies a transformation, like an [`outermorphism::Outermorphism`], to an element
+pub trait Transform<Element> {
+    type Output;
+
+    fn transform(&self, element: &Element) -> Self::Output;
+}
+
 pub trait Support {
     type Point;
 
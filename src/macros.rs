@@ -49,6 +49,20 @@ macro_rules! reverse_mul_scalar_metric {
 
 #[macro_export]
 macro_rules! default_sub {
+    ($lht:ident<T>, $rht:ident<T>) => {
+        impl<T> Sub<$rht<T>> for $lht<T>
+        where
+            $lht<T>: Add<$rht<T>>,
+            $rht<T>: Neg<Output = $rht<T>>,
+        {
+            type Output = <$lht<T> as Add<$rht<T>>>::Output;
+
+            fn sub(self, rhs: $rht<T>) -> Self::Output {
+                self + -rhs
+            }
+        }
+    };
+
     ($lht:ident, $rht:ident) => {
         impl<T, M> Sub<$rht<T, M>> for $lht<T, M>
         where
@@ -260,14 +274,15 @@ macro_rules! reverse_geometric_metric {
 #[macro_export]
 macro_rules! reverse_geometric_anticommute_metric {
     ($lht:ident, $rht:ident) => {
-        impl<T, M> GeometricProduct<rht<T, M>> for $lht<T, M>
+        impl<T, M> GeometricProduct<$rht<T, M>> for $lht<T, M>
         where
             $rht<T, M>: GeometricProduct<$lht<T, M>>,
-            GeometricProduct<$lht<T, M>>::Output: Neg<Output = GeometricProduct<$lht<T, M>>>::Output>,
+            <$rht<T, M> as GeometricProduct<$lht<T, M>>>::Output:
+                std::ops::Neg<Output = <$rht<T, M> as GeometricProduct<$lht<T, M>>>::Output>,
         {
             type Output = <$rht<T, M> as GeometricProduct<$lht<T, M>>>::Output;
 
-            fn geometric_product(&self, rhs: &T) -> Self::Output {
+            fn geometric_product(&self, rhs: &$rht<T, M>) -> Self::Output {
                 -rhs.geometric_product(self)
             }
         }
@@ -351,3 +366,286 @@ macro_rules! reverse_angle {
         }
     };
 }
+
+/// Implements the operator sugar of the products for `$t`, both for owned values and references:
+/// `^` [`crate::WedgeProduct`], `&` [`crate::AntiwedgeProduct`], `|` [`crate::Dot`], `!`
+/// [`crate::Dual`], `%` [`crate::GeometricAntiproduct`] and `*` [`crate::GeometricProduct`] for
+/// references (the owned `*` is generated for every pair of the product tables with
+/// [`crate::geometric_mul`], as it would clash with the scaling by a `T`).
+///
+/// The generic parameters of `$t` follow it: `operators!(Vector<T, M>, T, M)`.
+#[macro_export]
+macro_rules! operators {
+    ($t:ty, $($g:tt)*) => {
+        impl<$($g)*, Rhs> std::ops::BitXor<Rhs> for $t
+        where
+            $t: $crate::WedgeProduct<Rhs>,
+        {
+            type Output = <$t as $crate::WedgeProduct<Rhs>>::Output;
+
+            fn bitxor(self, rhs: Rhs) -> Self::Output {
+                $crate::WedgeProduct::wedge(&self, &rhs)
+            }
+        }
+
+        impl<'a, 'b, $($g)*, Rhs> std::ops::BitXor<&'b Rhs> for &'a $t
+        where
+            $t: $crate::WedgeProduct<Rhs>,
+        {
+            type Output = <$t as $crate::WedgeProduct<Rhs>>::Output;
+
+            fn bitxor(self, rhs: &'b Rhs) -> Self::Output {
+                $crate::WedgeProduct::wedge(self, rhs)
+            }
+        }
+
+        impl<$($g)*, Rhs> std::ops::BitAnd<Rhs> for $t
+        where
+            $t: $crate::AntiwedgeProduct<Rhs>,
+        {
+            type Output = <$t as $crate::AntiwedgeProduct<Rhs>>::Output;
+
+            fn bitand(self, rhs: Rhs) -> Self::Output {
+                $crate::AntiwedgeProduct::antiwedge(&self, &rhs)
+            }
+        }
+
+        impl<'a, 'b, $($g)*, Rhs> std::ops::BitAnd<&'b Rhs> for &'a $t
+        where
+            $t: $crate::AntiwedgeProduct<Rhs>,
+        {
+            type Output = <$t as $crate::AntiwedgeProduct<Rhs>>::Output;
+
+            fn bitand(self, rhs: &'b Rhs) -> Self::Output {
+                $crate::AntiwedgeProduct::antiwedge(self, rhs)
+            }
+        }
+
+        impl<$($g)*> std::ops::BitOr for $t
+        where
+            $t: $crate::Dot,
+        {
+            type Output = <$t as $crate::Dot>::Scalar;
+
+            fn bitor(self, rhs: $t) -> Self::Output {
+                $crate::Dot::dot(&self, &rhs)
+            }
+        }
+
+        impl<'a, 'b, $($g)*> std::ops::BitOr<&'b $t> for &'a $t
+        where
+            $t: $crate::Dot,
+        {
+            type Output = <$t as $crate::Dot>::Scalar;
+
+            fn bitor(self, rhs: &'b $t) -> Self::Output {
+                $crate::Dot::dot(self, rhs)
+            }
+        }
+
+        impl<$($g)*> std::ops::Not for $t
+        where
+            $t: $crate::Dual,
+        {
+            type Output = <$t as $crate::Dual>::AntiKVector;
+
+            fn not(self) -> Self::Output {
+                $crate::Dual::dual(&self)
+            }
+        }
+
+        impl<'a, $($g)*> std::ops::Not for &'a $t
+        where
+            $t: $crate::Dual,
+        {
+            type Output = <$t as $crate::Dual>::AntiKVector;
+
+            fn not(self) -> Self::Output {
+                $crate::Dual::dual(self)
+            }
+        }
+
+        impl<$($g)*, Rhs> std::ops::Rem<Rhs> for $t
+        where
+            $t: $crate::GeometricAntiproduct<Rhs>,
+        {
+            type Output = <$t as $crate::GeometricAntiproduct<Rhs>>::Output;
+
+            fn rem(self, rhs: Rhs) -> Self::Output {
+                $crate::GeometricAntiproduct::geometric_antiproduct(&self, &rhs)
+            }
+        }
+
+        impl<'a, 'b, $($g)*, Rhs> std::ops::Rem<&'b Rhs> for &'a $t
+        where
+            $t: $crate::GeometricAntiproduct<Rhs>,
+        {
+            type Output = <$t as $crate::GeometricAntiproduct<Rhs>>::Output;
+
+            fn rem(self, rhs: &'b Rhs) -> Self::Output {
+                $crate::GeometricAntiproduct::geometric_antiproduct(self, rhs)
+            }
+        }
+
+        impl<'a, 'b, $($g)*, Rhs> std::ops::Mul<&'b Rhs> for &'a $t
+        where
+            $t: $crate::GeometricProduct<Rhs>,
+        {
+            type Output = <$t as $crate::GeometricProduct<Rhs>>::Output;
+
+            fn mul(self, rhs: &'b Rhs) -> Self::Output {
+                $crate::GeometricProduct::geometric_product(self, rhs)
+            }
+        }
+    };
+}
+
+/// Implements `*` as the [`crate::GeometricProduct`] of owned `$lht` and `$rht`, whose generic
+/// parameters follow them:
+/// `geometric_mul!(Vector<T, M>, Bivector<T, M> => Multivector<T, M>, T, M)`.
+///
+/// The output is spelled out so that the blades don't look like a field `T: Mul<Output = T>` to
+/// the type inference.
+#[macro_export]
+macro_rules! geometric_mul {
+    ($lht:ty, $rht:ty => $out:ty, $($g:tt)*) => {
+        impl<$($g)*> std::ops::Mul<$rht> for $lht
+        where
+            $lht: $crate::GeometricProduct<$rht, Output = $out>,
+        {
+            type Output = $out;
+
+            fn mul(self, rhs: $rht) -> Self::Output {
+                $crate::GeometricProduct::geometric_product(&self, &rhs)
+            }
+        }
+    };
+}
+
+/// Implements the compound assignments `+=`, `-=` and `*=` of `$t` with any right hand side whose
+/// result is again a `$t`, and its [`std::iter::Sum`] over owned values and references.
+///
+/// The generic parameters of `$t` follow it: `assign_ops!(Vector<T, M>, T, M)`.
+#[macro_export]
+macro_rules! assign_ops {
+    ($t:ty, $($g:tt)*) => {
+        impl<$($g)*, Rhs> std::ops::AddAssign<Rhs> for $t
+        where
+            $t: Clone,
+            $t: std::ops::Add<Rhs, Output = $t>,
+        {
+            fn add_assign(&mut self, rhs: Rhs) {
+                *self = self.clone() + rhs;
+            }
+        }
+
+        impl<$($g)*, Rhs> std::ops::SubAssign<Rhs> for $t
+        where
+            $t: Clone,
+            $t: std::ops::Sub<Rhs, Output = $t>,
+        {
+            fn sub_assign(&mut self, rhs: Rhs) {
+                *self = self.clone() - rhs;
+            }
+        }
+
+        impl<$($g)*, Rhs> std::ops::MulAssign<Rhs> for $t
+        where
+            $t: Clone,
+            $t: std::ops::Mul<Rhs, Output = $t>,
+        {
+            fn mul_assign(&mut self, rhs: Rhs) {
+                *self = self.clone() * rhs;
+            }
+        }
+
+        impl<$($g)*> std::iter::Sum for $t
+        where
+            $t: num::Zero,
+        {
+            fn sum<I: Iterator<Item = $t>>(iter: I) -> Self {
+                iter.fold(num::Zero::zero(), |sum, element| sum + element)
+            }
+        }
+
+        impl<'a, $($g)*> std::iter::Sum<&'a $t> for $t
+        where
+            $t: 'a,
+            $t: Clone,
+            $t: num::Zero,
+        {
+            fn sum<I: Iterator<Item = &'a $t>>(iter: I) -> Self {
+                iter.fold(num::Zero::zero(), |sum, element| sum + element.clone())
+            }
+        }
+    };
+}
+
+/// Implements [`crate::Graded`] and indexing by basis blade for `$t`, from the list of its coefficients
+/// and the basis blade each of them multiplies.
+///
+/// Indexing with a blade that `$t` doesn't store panics, as an out of bounds index would.
+///
+/// `graded!(Bivector<T, M>, [T, M]; BasisBlade; yz => YZ, zx => ZX, xy => XY)`
+#[macro_export]
+macro_rules! graded {
+    ($t:ty, [$($g:tt)*]; $blade:ident; $($($f:tt).+ => $b:ident),+ $(,)?) => {
+        impl<$($g)*> $crate::Graded for $t
+        where
+            $t: Clone,
+            T: Clone,
+            T: num::Zero,
+        {
+            type Blade = $blade;
+            type Field = T;
+
+            fn grade(&self, k: u32) -> Self {
+                let mut projection = self.clone();
+                $(
+                    if $blade::$b.grade() != k {
+                        projection.$($f).+ = num::Zero::zero();
+                    }
+                )+
+                projection
+            }
+
+            fn grades(&self) -> Vec<u32> {
+                let mut grades = Vec::new();
+                $(
+                    let grade = $blade::$b.grade();
+                    if !num::Zero::is_zero(&self.$($f).+) && !grades.contains(&grade) {
+                        grades.push(grade);
+                    }
+                )+
+                grades.sort_unstable();
+                grades
+            }
+
+            fn components(&self) -> Vec<($blade, T)> {
+                vec![$(($blade::$b, self.$($f).+.clone())),+]
+            }
+        }
+
+        impl<$($g)*> std::ops::Index<$blade> for $t {
+            type Output = T;
+
+            fn index(&self, blade: $blade) -> &T {
+                match blade {
+                    $($blade::$b => &self.$($f).+,)+
+                    #[allow(unreachable_patterns)]
+                    _ => panic!("{blade:?} is not a component of {}", stringify!($t)),
+                }
+            }
+        }
+
+        impl<$($g)*> std::ops::IndexMut<$blade> for $t {
+            fn index_mut(&mut self, blade: $blade) -> &mut T {
+                match blade {
+                    $($blade::$b => &mut self.$($f).+,)+
+                    #[allow(unreachable_patterns)]
+                    _ => panic!("{blade:?} is not a component of {}", stringify!($t)),
+                }
+            }
+        }
+    };
+}
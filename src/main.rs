@@ -1,59 +1,94 @@
-//use gual::{AntiwedgeProduct, VectorSpace, WedgeProduct, antiwedge_reference, geometry2d as g2d};
-//use num::zero;
-
-fn main() {
-    // let a = g2d::Multivector {
-    //     s: zero(),
-    //     v: g2d::Vector { x: 1.0, y: 2.0 },
-    //     a: zero(),
-    // };
-    // let b = g2d::Multivector {
-    //     s: zero(),
-    //     v: g2d::Vector { x: 3.0, y: 4.0 },
-    //     a: zero(),
-    // };
-
-    // println!("volume: {:?}", g2d::Multivector::<f32>::UNIT_VOLUME);
-    // println!("a: {:?}", a);
-    // println!("b: {:?}", b);
-    // println!("lc(a): {:?}", a.left_complement());
-    // println!("rc(a): {:?}", a.right_complement());
-    // println!("a ^ b: {:?}", a.wedge(&b));
-    // println!("a v b: {:?}", a.vector().antiwedge(&b.vector()));
-    // println!("a v b: {:?}", antiwedge_reference(a.vector(), b.vector()));
-    // println!("a v b: {:?}", a.antiwedge(&b));
-    // println!(
-    //     "a v I: {:?}",
-    //     a.vector().antiwedge(&g2d::Multivector::<f32>::UNIT_VOLUME)
-    // );
-    // println!(
-    //     "a v I: {:?}",
-    //     antiwedge_reference(a.vector(), g2d::Multivector::<f32>::UNIT_VOLUME)
-    // );
-    // println!("a ^ rc(b): {:?}", a.wedge(&b.right_complement()));
-    // println!("lc(a) ^ b: {:?}", a.left_complement().wedge(&b));
-
-    // let m3 = Multivector3D {
-    //     s: 0,
-    //     x: 1,
-    //     y: 2,
-    //     z: 3,
-    //     yz: 0,
-    //     zx: 0,
-    //     xy: 0,
-    //     xyz: 0,
-    // };
-    // let n3 = Multivector3D {
-    //     s: 0,
-    //     x: 4,
-    //     y: 5,
-    //     z: 6,
-    //     yz: 0,
-    //     zx: 0,
-    //     xy: 0,
-    //     xyz: 0,
-    // };
-
-    // println!("{:?}", m3 + n3);
-    // println!("{:?}", m3.wedge(n3));
+//! Geometric algebra calculator.
+//!
+//! ```text
+//! gual [--dim 2|3|4] [--metric euclidean|projective] [--batch]
+//! ```
+//!
+//! Reads one expression per line, like `(x + 2y) ^ z` or `p = join(x + w, y + w)`, and prints
+//! its value. With `--batch`, or when stdin is not a terminal, it reads a script from stdin
+//! without prompting, printing one line per result and `error: ...` for lines that fail.
+
+mod calculator;
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use calculator::Calculator;
+
+const USAGE: &str = "usage: gual [--dim 2|3|4] [--metric euclidean|projective] [--batch]";
+
+struct Options {
+    dimension: usize,
+    metric: String,
+    batch: bool,
+}
+
+fn parse_options(mut arguments: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        dimension: 3,
+        metric: "euclidean".to_string(),
+        batch: false,
+    };
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--dim" => {
+                let dimension = arguments.next().ok_or("--dim needs a value")?;
+                options.dimension = dimension
+                    .parse()
+                    .map_err(|_| format!("invalid dimension `{dimension}`"))?;
+            }
+            "--metric" => options.metric = arguments.next().ok_or("--metric needs a value")?,
+            "--batch" => options.batch = true,
+            _ => return Err(format!("unknown argument `{argument}`")),
+        }
+    }
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let algebra = match calculator::algebra(options.dimension, &options.metric) {
+        Ok(algebra) => algebra,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut calculator = Calculator::new(algebra);
+    let batch = options.batch || !io::stdin().is_terminal();
+    if !batch {
+        println!(
+            "gual calculator, {}D {} (type `help` for help)",
+            options.dimension, options.metric
+        );
+    }
+
+    let mut stdout = io::stdout();
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if !batch {
+            print!("> ");
+            let _ = stdout.flush();
+        }
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match line.trim() {
+            "quit" | "exit" => break,
+            "help" => println!("{}", calculator.help()),
+            line => match calculator.execute(line) {
+                Ok(Some(output)) => println!("{output}"),
+                Ok(None) => {}
+                Err(error) => println!("error: {error}"),
+            },
+        }
+    }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,114 @@
+//! Linear maps extended to every grade
+//!
+//! An outermorphism is the unique extension of a linear map `f` of the vectors to the whole
+//! algebra that preserves the wedge product: `f(a ∧ b) = f(a) ∧ f(b)`. Every basis k-vector maps
+//! to the wedge product of the images of its factors, so the antiscalar scales by the
+//! determinant of the map and the join of transformed elements is the transformed join. The meet
+//! of transformed elements is the transformed meet scaled by the determinant.
+//!
+//! Applied to homogeneous coordinates, a 3x3 or 4x4 outermorphism is a projective transformation
+//! of the plane or the space, which includes non-rigid transformations like scaling, shear and
+//! perspective.
+
+use num::Float;
+
+mod outermorphism2d;
+mod outermorphism3d;
+mod outermorphism4d;
+
+/// Linear map of an `N` dimensional space, built from the images of the basis vectors, that can
+/// [`crate::Transform`] elements of any grade
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outermorphism<T, const N: usize> {
+    /// Matrix of the map, the column `j` is the image of the basis vector `j`
+    matrix: [[T; N]; N],
+}
+
+impl<T, const N: usize> Outermorphism<T, N>
+where
+    T: Float,
+{
+    /// Map that multiplies column vectors by the matrix with these `rows`
+    ///
+    /// The basis vectors are taken in the order of their fields, like `x, y, z, w` in
+    /// [`crate::geometry4d::Vector`].
+    pub fn from_matrix(rows: [[T; N]; N]) -> Self {
+        Outermorphism { matrix: rows }
+    }
+
+    /// Rows of the matrix of the map
+    pub fn matrix(&self) -> [[T; N]; N] {
+        self.matrix
+    }
+
+    /// Map that leaves every element unchanged
+    pub fn identity() -> Self {
+        Outermorphism {
+            matrix: std::array::from_fn(|i| {
+                std::array::from_fn(|j| if i == j { T::one() } else { T::zero() })
+            }),
+        }
+    }
+
+    /// Map that applies `rhs` and then `self`
+    pub fn compose(&self, rhs: &Self) -> Self {
+        Outermorphism {
+            matrix: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    (0..N).fold(T::zero(), |sum, k| {
+                        sum + self.matrix[i][k] * rhs.matrix[k][j]
+                    })
+                })
+            }),
+        }
+    }
+
+    /// Map that undoes `self`, `None` if the map is singular
+    pub fn inverse(&self) -> Option<Self> {
+        // Gauss-Jordan elimination with partial pivoting
+        let scale = self
+            .matrix
+            .iter()
+            .flatten()
+            .fold(T::zero(), |max, x| max.max(x.abs()));
+        let mut a = self.matrix;
+        let mut inverse = Self::identity().matrix;
+        for column in 0..N {
+            let pivot = (column..N).max_by(|&i, &j| {
+                a[i][column]
+                    .abs()
+                    .partial_cmp(&a[j][column].abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })?;
+            if a[pivot][column].abs() <= T::epsilon() * scale {
+                return None;
+            }
+            a.swap(column, pivot);
+            inverse.swap(column, pivot);
+
+            let p = a[column][column];
+            for j in 0..N {
+                a[column][j] = a[column][j] / p;
+                inverse[column][j] = inverse[column][j] / p;
+            }
+            for i in (0..N).filter(|&i| i != column) {
+                let factor = a[i][column];
+                for j in 0..N {
+                    a[i][j] = a[i][j] - factor * a[column][j];
+                    inverse[i][j] = inverse[i][j] - factor * inverse[column][j];
+                }
+            }
+        }
+        Some(Outermorphism { matrix: inverse })
+    }
+
+    /// Coordinates of the image of the basis vector `j`
+    fn column(&self, j: usize) -> [T; N] {
+        std::array::from_fn(|i| self.matrix[i][j])
+    }
+
+    /// Image of a vector with these coordinates
+    fn map(&self, v: [T; N]) -> [T; N] {
+        std::array::from_fn(|i| (0..N).fold(T::zero(), |sum, j| sum + self.matrix[i][j] * v[j]))
+    }
+}
@@ -0,0 +1,89 @@
+use num::Float;
+use num::traits::ConstOne;
+
+use crate::Antiscalar;
+use crate::Transform;
+use crate::WedgeProduct;
+use crate::geometry2d::Bivector;
+use crate::geometry2d::Evenvector;
+use crate::geometry2d::Multivector;
+use crate::geometry2d::Vector;
+
+use super::Outermorphism;
+
+impl<T> Outermorphism<T, 2>
+where
+    T: Float,
+    T: ConstOne,
+{
+    /// Map that sends `x` and `y` to these vectors
+    pub fn from_images<M>(x: &Vector<T, M>, y: &Vector<T, M>) -> Self {
+        Outermorphism::from_matrix([[x.x, y.x], [x.y, y.y]])
+    }
+
+    /// Factor that scales areas, the image of the unit area
+    pub fn determinant(&self) -> Bivector<T> {
+        self.transform(&Bivector::UNIT_VOLUME)
+    }
+
+    fn image<M>(&self, j: usize) -> Vector<T, M> {
+        let [x, y] = self.column(j);
+        Vector::new(x, y)
+    }
+}
+
+impl<T, M> Transform<Vector<T, M>> for Outermorphism<T, 2>
+where
+    T: Float,
+{
+    type Output = Vector<T, M>;
+
+    fn transform(&self, element: &Vector<T, M>) -> Self::Output {
+        let [x, y] = self.map([element.x, element.y]);
+        Vector::new(x, y)
+    }
+}
+
+impl<T, M> Transform<Bivector<T, M>> for Outermorphism<T, 2>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Bivector<T, M>;
+
+    fn transform(&self, element: &Bivector<T, M>) -> Self::Output {
+        let xy: Bivector<T, M> = self.image(0).wedge(&self.image(1));
+        Bivector::new(element.xy * xy.xy)
+    }
+}
+
+impl<T, M> Transform<Evenvector<T, M>> for Outermorphism<T, 2>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Evenvector<T, M>;
+
+    fn transform(&self, element: &Evenvector<T, M>) -> Self::Output {
+        Evenvector {
+            s: element.s,
+            b: self.transform(&element.b),
+        }
+    }
+}
+
+impl<T, M> Transform<Multivector<T, M>> for Outermorphism<T, 2>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Multivector<T, M>;
+
+    fn transform(&self, element: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: element.s,
+            v: self.transform(&element.v),
+            b: self.transform(&element.b),
+        }
+    }
+}
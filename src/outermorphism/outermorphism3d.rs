@@ -0,0 +1,110 @@
+use num::Float;
+use num::traits::ConstOne;
+
+use crate::Antiscalar;
+use crate::Transform;
+use crate::WedgeProduct;
+use crate::geometry3d::Bivector;
+use crate::geometry3d::Evenvector;
+use crate::geometry3d::Multivector;
+use crate::geometry3d::Trivector;
+use crate::geometry3d::Vector;
+
+use super::Outermorphism;
+
+impl<T> Outermorphism<T, 3>
+where
+    T: Float,
+    T: ConstOne,
+{
+    /// Map that sends `x`, `y` and `z` to these vectors
+    pub fn from_images<M>(x: &Vector<T, M>, y: &Vector<T, M>, z: &Vector<T, M>) -> Self {
+        Outermorphism::from_matrix([[x.x, y.x, z.x], [x.y, y.y, z.y], [x.z, y.z, z.z]])
+    }
+
+    /// Factor that scales volumes, the image of the unit volume
+    pub fn determinant(&self) -> Trivector<T> {
+        self.transform(&Trivector::UNIT_VOLUME)
+    }
+
+    fn image<M>(&self, j: usize) -> Vector<T, M> {
+        let [x, y, z] = self.column(j);
+        Vector::new(x, y, z)
+    }
+}
+
+impl<T, M> Transform<Vector<T, M>> for Outermorphism<T, 3>
+where
+    T: Float,
+{
+    type Output = Vector<T, M>;
+
+    fn transform(&self, element: &Vector<T, M>) -> Self::Output {
+        let [x, y, z] = self.map([element.x, element.y, element.z]);
+        Vector::new(x, y, z)
+    }
+}
+
+impl<T, M> Transform<Bivector<T, M>> for Outermorphism<T, 3>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Bivector<T, M>;
+
+    fn transform(&self, element: &Bivector<T, M>) -> Self::Output {
+        let (x, y, z): (Vector<T, M>, Vector<T, M>, Vector<T, M>) =
+            (self.image(0), self.image(1), self.image(2));
+        let (yz, zx, xy) = (y.wedge(&z), z.wedge(&x), x.wedge(&y));
+        let image = |f: fn(&Bivector<T, M>) -> T| {
+            element.yz * f(&yz) + element.zx * f(&zx) + element.xy * f(&xy)
+        };
+        Bivector::new(image(|b| b.yz), image(|b| b.zx), image(|b| b.xy))
+    }
+}
+
+impl<T, M> Transform<Trivector<T, M>> for Outermorphism<T, 3>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Trivector<T, M>;
+
+    fn transform(&self, element: &Trivector<T, M>) -> Self::Output {
+        let x: Vector<T, M> = self.image(0);
+        let xyz = x.wedge(&self.image::<M>(1).wedge(&self.image(2)));
+        Trivector::new(element.xyz * xyz.xyz)
+    }
+}
+
+impl<T, M> Transform<Evenvector<T, M>> for Outermorphism<T, 3>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Evenvector<T, M>;
+
+    fn transform(&self, element: &Evenvector<T, M>) -> Self::Output {
+        Evenvector {
+            s: element.s,
+            b: self.transform(&element.b),
+        }
+    }
+}
+
+impl<T, M> Transform<Multivector<T, M>> for Outermorphism<T, 3>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Multivector<T, M>;
+
+    fn transform(&self, element: &Multivector<T, M>) -> Self::Output {
+        Multivector {
+            s: element.s,
+            v: self.transform(&element.v),
+            b: self.transform(&element.b),
+            t: self.transform(&element.t),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use num::Float;
+use num::traits::ConstOne;
+
+use crate::Antiscalar;
+use crate::Transform;
+use crate::WedgeProduct;
+use crate::geometry4d::Bivector;
+use crate::geometry4d::Evenvector;
+use crate::geometry4d::Multivector;
+use crate::geometry4d::Quadvector;
+use crate::geometry4d::Scalar;
+use crate::geometry4d::Trivector;
+use crate::geometry4d::Vector;
+
+use super::Outermorphism;
+
+impl<T> Outermorphism<T, 4>
+where
+    T: Float,
+    T: ConstOne,
+{
+    /// Map that sends `x`, `y`, `z` and `w` to these vectors
+    pub fn from_images(x: &Vector<T>, y: &Vector<T>, z: &Vector<T>, w: &Vector<T>) -> Self {
+        Outermorphism::from_matrix([
+            [x.x, y.x, z.x, w.x],
+            [x.y, y.y, z.y, w.y],
+            [x.z, y.z, z.z, w.z],
+            [x.w, y.w, z.w, w.w],
+        ])
+    }
+
+    /// Factor that scales 4D volumes, the image of the unit volume
+    pub fn determinant(&self) -> Quadvector<T> {
+        self.transform(&Quadvector::UNIT_VOLUME)
+    }
+
+    /// Images of the `x`, `y`, `z` and `w` basis vectors
+    fn images(&self) -> [Vector<T>; 4] {
+        std::array::from_fn(|j| {
+            let [x, y, z, w] = self.column(j);
+            Vector { x, y, z, w }
+        })
+    }
+}
+
+impl<T> Transform<Scalar<T>> for Outermorphism<T, 4>
+where
+    T: Copy,
+{
+    type Output = Scalar<T>;
+
+    fn transform(&self, element: &Scalar<T>) -> Self::Output {
+        *element
+    }
+}
+
+impl<T> Transform<Vector<T>> for Outermorphism<T, 4>
+where
+    T: Float,
+{
+    type Output = Vector<T>;
+
+    fn transform(&self, element: &Vector<T>) -> Self::Output {
+        let [x, y, z, w] = self.map([element.x, element.y, element.z, element.w]);
+        Vector { x, y, z, w }
+    }
+}
+
+impl<T> Transform<Bivector<T>> for Outermorphism<T, 4>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Bivector<T>;
+
+    fn transform(&self, element: &Bivector<T>) -> Self::Output {
+        let [x, y, z, w] = self.images();
+        let basis = [
+            (element.wx, w.wedge(&x)),
+            (element.wy, w.wedge(&y)),
+            (element.wz, w.wedge(&z)),
+            (element.yz, y.wedge(&z)),
+            (element.zx, z.wedge(&x)),
+            (element.xy, x.wedge(&y)),
+        ];
+        let image =
+            |f: fn(&Bivector<T>) -> T| basis.iter().fold(T::zero(), |sum, (c, b)| sum + *c * f(b));
+        Bivector {
+            wx: image(|b| b.wx),
+            wy: image(|b| b.wy),
+            wz: image(|b| b.wz),
+            yz: image(|b| b.yz),
+            zx: image(|b| b.zx),
+            xy: image(|b| b.xy),
+        }
+    }
+}
+
+impl<T> Transform<Trivector<T>> for Outermorphism<T, 4>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Trivector<T>;
+
+    fn transform(&self, element: &Trivector<T>) -> Self::Output {
+        let [x, y, z, w] = self.images();
+        let basis = [
+            (element.wyz, w.wedge(&y.wedge(&z))),
+            (element.wzx, w.wedge(&z.wedge(&x))),
+            (element.wxy, w.wedge(&x.wedge(&y))),
+            (element.zyx, z.wedge(&y.wedge(&x))),
+        ];
+        let image =
+            |f: fn(&Trivector<T>) -> T| basis.iter().fold(T::zero(), |sum, (c, t)| sum + *c * f(t));
+        Trivector {
+            wyz: image(|t| t.wyz),
+            wzx: image(|t| t.wzx),
+            wxy: image(|t| t.wxy),
+            zyx: image(|t| t.zyx),
+        }
+    }
+}
+
+impl<T> Transform<Quadvector<T>> for Outermorphism<T, 4>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Quadvector<T>;
+
+    fn transform(&self, element: &Quadvector<T>) -> Self::Output {
+        let [x, y, z, w] = self.images();
+        let xyzw = x.wedge(&y).wedge(&z.wedge(&w));
+        Quadvector {
+            xyzw: element.xyzw * xyzw.xyzw,
+        }
+    }
+}
+
+impl<T> Transform<Evenvector<T>> for Outermorphism<T, 4>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Evenvector<T>;
+
+    fn transform(&self, element: &Evenvector<T>) -> Self::Output {
+        Evenvector {
+            s: element.s,
+            b: self.transform(&element.b),
+            a: self.transform(&element.a),
+        }
+    }
+}
+
+impl<T> Transform<Multivector<T>> for Outermorphism<T, 4>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Multivector<T>;
+
+    fn transform(&self, element: &Multivector<T>) -> Self::Output {
+        Multivector {
+            s: element.s,
+            v: self.transform(&element.v),
+            b: self.transform(&element.b),
+            t: self.transform(&element.t),
+            a: self.transform(&element.a),
+        }
+    }
+}
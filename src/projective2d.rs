@@ -1,11 +1,21 @@
 mod add;
 mod angle;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod conic;
+mod cross_ratio;
+mod distance;
 mod div;
+mod fit;
 mod geometric_product;
+mod homography;
 mod initialization;
+mod motion;
 mod mul;
 mod neg;
 mod norm;
+#[cfg(feature = "operators")]
+mod operators;
 mod sub;
 mod unitize;
 mod wedge;
@@ -28,3 +38,49 @@ pub struct ParametricLine<T> {
     pub origin: Point<T>,
     pub dir: UnitVector<T>,
 }
+
+/// Rigid motion of the plane: a rotation about the origin followed by a translation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Motion<T> {
+    /// Rotor `cos(θ/2) + sin(θ/2) xy` of the counterclockwise rotation by `θ`
+    pub rotor: crate::geometry2d::Evenvector<T>,
+    pub translation: DirVector<T>,
+}
+
+/// Projective transformation of the plane, it maps points to points and lines to lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Homography<T>(crate::outermorphism::Outermorphism<T, 3>);
+
+/// Point that can be at infinity, like the image of a point under a [`Homography`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedPoint<T> {
+    Point(Point<T>),
+    AtInfinity(crate::geometry3d::Vector<T, crate::Projective>),
+}
+
+/// Conic of the projective plane: the homogeneous points `v` with `vᵀ M v = 0`, where `M` is the
+/// symmetric matrix of a bilinear form on [`crate::geometry3d::Vector`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conic<T>([[T; 3]; 3]);
+
+/// Affine type of a [`Conic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConicKind {
+    Ellipse,
+    Parabola,
+    Hyperbola,
+    /// Conic without real points, like `x² + y² + 1 = 0`
+    Empty,
+    /// Pair of lines, double line or single point
+    Degenerate,
+}
+
+/// Intersection of a [`Conic`] and a [`Line`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConicIntersection<T> {
+    Disjoint,
+    Tangent(MappedPoint<T>),
+    Secant(MappedPoint<T>, MappedPoint<T>),
+    /// The line is part of a degenerate conic
+    Contained,
+}
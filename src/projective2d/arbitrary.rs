@@ -0,0 +1,146 @@
+use std::fmt::Debug;
+
+use num::Float;
+use num::traits::{ConstOne, ConstZero};
+use proptest::prelude::*;
+
+use crate::Epsilon;
+use crate::arbitrary::field;
+use crate::outermorphism::Outermorphism;
+
+use super::{Conic, DirVector, Homography, ParametricLine, Point, UnitLine, UnitVector};
+
+impl<T> Arbitrary for UnitVector<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (-std::f64::consts::PI..std::f64::consts::PI)
+            .prop_map(|angle| {
+                let angle = T::from(angle).expect("T should hold f64 values");
+                UnitVector(DirVector::new(angle.cos(), angle.sin()))
+            })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Point<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (field(), field())
+            .prop_map(|(x, y)| Point::new(x, y))
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for ParametricLine<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<Point<T>>(), any::<UnitVector<T>>())
+            .prop_map(|(origin, dir)| ParametricLine { origin, dir })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for UnitLine<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<ParametricLine<T>>().prop_map(UnitLine::from).boxed()
+    }
+}
+
+impl<T> Arbitrary for Homography<T>
+where
+    T: Float,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Invertible homographies with entries in `[-10, 10]`, far enough from being degenerate for
+    /// their inverse to be accurate
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let entry = || -10.0..10.0f64;
+        let row = move || [entry(), entry(), entry()];
+        [row(), row(), row()]
+            .prop_filter("degenerate homographies have no inverse", |m| {
+                let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                    - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                    + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+                determinant.abs() > 1.0
+            })
+            .prop_map(|rows| {
+                let rows =
+                    rows.map(|row| row.map(|x| T::from(x).expect("T should hold f64 values")));
+                Homography(Outermorphism::from_matrix(rows))
+            })
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Conic<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+    T: Debug,
+    T: 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Ellipses and hyperbolas centered in `[-10, 10]²`, with semi-diameters of length `[0.5, 5]`
+    /// at least `0.3` radians apart, so that they are never close to degenerate
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let angle = -std::f64::consts::PI..std::f64::consts::PI;
+        let spread = 0.3..std::f64::consts::PI - 0.3;
+        (
+            (-10.0..10.0f64, -10.0..10.0f64),
+            (0.5..5.0f64, 0.5..5.0f64),
+            (angle, spread),
+            any::<bool>(),
+        )
+            .prop_map(|((x, y), (a, b), (angle, spread), ellipse)| {
+                let t = |x: f64| T::from(x).expect("T should hold f64 values");
+                let center = Point::new(t(x), t(y));
+                let u = DirVector::new(t(a * angle.cos()), t(a * angle.sin()));
+                let v =
+                    DirVector::new(t(b * (angle + spread).cos()), t(b * (angle + spread).sin()));
+                let conic = if ellipse {
+                    Conic::ellipse(&center, &u, &v)
+                } else {
+                    Conic::hyperbola(&center, &u, &v)
+                };
+                conic.expect("the semi-diameters are not parallel")
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,340 @@
+//! Conics as symmetric bilinear forms on homogeneous points
+//!
+//! A point `p` is on the conic `M` when `pᵀ M p = 0`. The polar of `p` is the line with the
+//! coefficients `M p`, which is the tangent at `p` when `p` is on the conic, and the pole of a
+//! line is the point that has it as polar.
+
+use std::array::from_fn;
+
+use num::Float;
+use num::traits::ConstOne;
+use num::traits::ConstZero;
+
+use crate::Epsilon;
+use crate::Projective;
+use crate::WedgeProduct;
+use crate::geometry3d::Vector;
+
+use super::Conic;
+use super::ConicIntersection;
+use super::ConicKind;
+use super::DirVector;
+use super::Line;
+use super::MappedPoint;
+use super::Point;
+
+type Vector3<T> = Vector<T, Projective>;
+
+type Matrix<T> = [[T; 3]; 3];
+
+fn coefficients<T: Copy>(line: &Line<T>) -> [T; 3] {
+    [line.yz, line.zx, line.xy]
+}
+
+fn coordinates<T: Copy>(point: &Vector3<T>) -> [T; 3] {
+    [point.x, point.y, point.z]
+}
+
+fn apply<T: Float>(m: &Matrix<T>, v: [T; 3]) -> [T; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn bilinear<T: Float>(m: &Matrix<T>, a: [T; 3], b: [T; 3]) -> T {
+    let mb = apply(m, b);
+    a[0] * mb[0] + a[1] * mb[1] + a[2] * mb[2]
+}
+
+fn cofactor<T: Float>(m: &Matrix<T>, i: usize, j: usize) -> T {
+    let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+    let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+    m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+}
+
+fn determinant<T: Float>(m: &Matrix<T>) -> T {
+    (0..3).fold(T::zero(), |sum, j| sum + m[0][j] * cofactor(m, 0, j))
+}
+
+/// Point with homogeneous coordinates that are either exactly at infinity or have a weight of one
+fn mapped_point<T: Float>(v: [T; 3]) -> MappedPoint<T> {
+    if v[2] == T::zero() {
+        MappedPoint::AtInfinity(Vector3::new(v[0], v[1], v[2]))
+    } else {
+        MappedPoint::Point(Point::new(v[0] / v[2], v[1] / v[2]))
+    }
+}
+
+fn homogeneous<T>(point: &MappedPoint<T>) -> Vector3<T>
+where
+    T: Copy,
+    T: ConstOne,
+{
+    match point {
+        MappedPoint::Point(p) => Vector3::from(p),
+        MappedPoint::AtInfinity(v) => *v,
+    }
+}
+
+impl<T> Conic<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Conic of the symmetric part of the matrix with these `rows`
+    pub fn from_matrix(rows: [[T; 3]; 3]) -> Self {
+        let two = T::ONE + T::ONE;
+        Conic(from_fn(|i| from_fn(|j| (rows[i][j] + rows[j][i]) / two)))
+    }
+
+    /// Symmetric matrix of the conic
+    pub fn matrix(&self) -> [[T; 3]; 3] {
+        self.0
+    }
+
+    /// Conic through five points
+    ///
+    /// Returns `None` if two points coincide or four of them are collinear, then more than one
+    /// conic goes through them. Three collinear points give a pair of lines.
+    pub fn from_points(points: &[Point<T>; 5]) -> Option<Self> {
+        for (i, p) in points.iter().enumerate() {
+            for q in &points[i + 1..] {
+                let (dx, dy) = (q.0.x - p.0.x, q.0.y - p.0.y);
+                if (dx * dx + dy * dy).sqrt().is_near_zero() {
+                    return None;
+                }
+            }
+        }
+
+        let [a, b, c, d, e] = (*points).map(Vector3::from);
+        let unit = |line: Line<T>| {
+            let [yz, zx, xy] = coefficients(&line);
+            let len = (yz * yz + zx * zx + xy * xy).sqrt();
+            [yz / len, zx / len, xy / len]
+        };
+        let pair = |l: [T; 3], m: [T; 3]| Conic::from_matrix(from_fn(|i| from_fn(|j| l[i] * m[j])));
+        // the conics through `a`, `b`, `c` and `d` are the combinations of two pairs of lines
+        let first = pair(unit(a.wedge(&b)), unit(c.wedge(&d)));
+        let second = pair(unit(a.wedge(&c)), unit(b.wedge(&d)));
+        let (w1, w2) = (first.bilinear_form(&e, &e), second.bilinear_form(&e, &e));
+        let conic: Matrix<T> = from_fn(|i| from_fn(|j| w2 * first.0[i][j] - w1 * second.0[i][j]));
+
+        // the combination vanishes when the pairs are the same double line
+        let size = conic.iter().flatten().fold(T::ZERO, |sum, x| sum + *x * *x);
+        let relative = size.sqrt() / (w1.abs() + w2.abs());
+        if relative.is_nan() || relative.is_near_zero() {
+            None
+        } else {
+            Some(Conic(conic))
+        }
+    }
+
+    /// Ellipse of the points `center + cos(t) u + sin(t) v`, where `u` and `v` are the semi-axes,
+    /// or any pair of conjugate semi-diameters
+    ///
+    /// Returns `None` if `u` and `v` are parallel.
+    pub fn ellipse(center: &Point<T>, u: &DirVector<T>, v: &DirVector<T>) -> Option<Self> {
+        Self::central(center, u, v, T::ONE)
+    }
+
+    /// Hyperbola of the points `center ± cosh(t) u + sinh(t) v`, where `u` is the transverse
+    /// semi-axis and `v` the conjugate one
+    ///
+    /// Returns `None` if `u` and `v` are parallel.
+    pub fn hyperbola(center: &Point<T>, u: &DirVector<T>, v: &DirVector<T>) -> Option<Self> {
+        Self::central(center, u, v, -T::ONE)
+    }
+
+    /// Conic `α² + sign β² = 1` in the coordinates `α` and `β` of `p - center` along `u` and `v`
+    fn central(center: &Point<T>, u: &DirVector<T>, v: &DirVector<T>, sign: T) -> Option<Self> {
+        let area = u.wedge(v).xy;
+        let lengths = (u.x * u.x + u.y * u.y).sqrt() * (v.x * v.x + v.y * v.y).sqrt();
+        let relative = area / lengths;
+        if relative.is_nan() || relative.is_near_zero() {
+            return None;
+        }
+
+        let alpha = [v.y / area, -v.x / area];
+        let beta = [-u.y / area, u.x / area];
+        let q = |i: usize, j: usize| alpha[i] * alpha[j] + sign * beta[i] * beta[j];
+        let c = [center.0.x, center.0.y];
+        let qc = |i: usize| q(i, 0) * c[0] + q(i, 1) * c[1];
+        Some(Conic([
+            [q(0, 0), q(0, 1), -qc(0)],
+            [q(1, 0), q(1, 1), -qc(1)],
+            [-qc(0), -qc(1), qc(0) * c[0] + qc(1) * c[1] - T::ONE],
+        ]))
+    }
+
+    /// Value `aᵀ M b` of the bilinear form of the conic
+    pub fn bilinear_form(&self, a: &Vector3<T>, b: &Vector3<T>) -> T {
+        bilinear(&self.0, coordinates(a), coordinates(b))
+    }
+
+    /// Whether `point` is on the conic, up to the [`Epsilon`] tolerance on its distance
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        let p = coordinates(&Vector3::from(point));
+        let gradient = apply(&self.0, p);
+        let value = p[0] * gradient[0] + p[1] * gradient[1] + p[2] * gradient[2];
+        // the distance to first order is the value over the length of its gradient, which is
+        // twice the first two coordinates of `M p`
+        let slope = (gradient[0] * gradient[0] + gradient[1] * gradient[1]).sqrt();
+        value == T::ZERO || (value / (slope + slope)).is_near_zero()
+    }
+
+    /// Polar line of `point`, the tangent at `point` if it is on the conic
+    ///
+    /// The polar of a point outside of the conic joins the points where the tangents through it
+    /// touch the conic.
+    pub fn polar(&self, point: &Point<T>) -> Line<T> {
+        let [yz, zx, xy] = apply(&self.0, coordinates(&Vector3::from(point)));
+        Line::new(yz, zx, xy)
+    }
+
+    /// Point that has `line` as polar, which is at infinity when `line` goes through the centre
+    ///
+    /// Returns `None` if the conic is degenerate.
+    pub fn pole(&self, line: &Line<T>) -> Option<MappedPoint<T>> {
+        if self.kind() == ConicKind::Degenerate {
+            return None;
+        }
+        // the adjugate is the inverse of the matrix up to a factor
+        let l = coefficients(line);
+        let term = |i: usize, j: usize| cofactor(&self.0, i, j) * l[j];
+        let pole: [T; 3] = from_fn(|i| term(i, 0) + term(i, 1) + term(i, 2));
+        let rounding = T::epsilon() * (T::ONE + T::ONE).powi(4);
+        if pole[2].abs() <= rounding * (term(2, 0).abs() + term(2, 1).abs() + term(2, 2).abs()) {
+            Some(MappedPoint::AtInfinity(Vector3::new(
+                pole[0],
+                pole[1],
+                T::ZERO,
+            )))
+        } else {
+            Some(MappedPoint::Point(Point::new(
+                pole[0] / pole[2],
+                pole[1] / pole[2],
+            )))
+        }
+    }
+
+    /// Both tangents to the conic through `point`
+    ///
+    /// Returns `None` if `point` is inside the conic or on it, see [`Conic::polar`] for the
+    /// tangent at a point of the conic.
+    pub fn tangents_from(&self, point: &Point<T>) -> Option<[Line<T>; 2]> {
+        if self.contains(point) {
+            return None;
+        }
+        match self.meet(&self.polar(point)) {
+            ConicIntersection::Secant(a, b) => {
+                let p = Vector3::from(point);
+                Some([p.wedge(&homogeneous(&a)), p.wedge(&homogeneous(&b))])
+            }
+            _ => None,
+        }
+    }
+
+    /// Points where `line` crosses or touches the conic
+    pub fn meet(&self, line: &Line<T>) -> ConicIntersection<T> {
+        let [a, b, c] = coefficients(line);
+        let n2 = a * a + b * b;
+        // a point of the line and its direction, the line at infinity only has points at infinity
+        let (point, direction) = if n2 == T::ZERO {
+            ([T::ONE, T::ZERO, T::ZERO], [T::ZERO, T::ONE, T::ZERO])
+        } else {
+            let n = n2.sqrt();
+            ([-a * c / n2, -b * c / n2, T::ONE], [-b / n, a / n, T::ZERO])
+        };
+        let at = |u: T| mapped_point(from_fn(|i| point[i] + u * direction[i]));
+
+        // the points `point + u * direction` of the conic solve `pp + 2 pd u + dd u² = 0`
+        let m = self.normalized();
+        let pp = bilinear(&m, point, point);
+        let pd = bilinear(&m, point, direction);
+        let dd = bilinear(&m, direction, direction);
+        // `dd` only vanishes up to its rounding error, a tiny but real value still gives a far
+        // point of the conic
+        let magnitude = m.map(|row| row.map(|x| x.abs()));
+        let terms = bilinear(
+            &magnitude,
+            direction.map(|x| x.abs()),
+            direction.map(|x| x.abs()),
+        );
+        let rounding = T::epsilon() * (T::ONE + T::ONE).powi(4);
+        if dd.abs() <= rounding * terms {
+            // the conic goes through the point at infinity of the line
+            let infinity =
+                MappedPoint::AtInfinity(Vector3::new(direction[0], direction[1], T::ZERO));
+            if !pd.is_near_zero() {
+                ConicIntersection::Secant(at(-pp / (pd + pd)), infinity)
+            } else if pp.is_near_zero() {
+                ConicIntersection::Contained
+            } else {
+                ConicIntersection::Tangent(infinity)
+            }
+        } else {
+            let discriminant = pd * pd - pp * dd;
+            let half_chord = discriminant.abs().sqrt() / dd.abs();
+            if half_chord.is_near_zero() {
+                ConicIntersection::Tangent(at(-pd / dd))
+            } else if discriminant < T::ZERO {
+                ConicIntersection::Disjoint
+            } else {
+                // roots without cancellation, their product is `pp / dd`
+                let q = -(pd + discriminant.sqrt() * pd.signum());
+                ConicIntersection::Secant(at(q / dd), at(pp / q))
+            }
+        }
+    }
+
+    /// Whether the conic is an ellipse, a parabola or a hyperbola, up to the [`Epsilon`]
+    /// tolerance
+    pub fn kind(&self) -> ConicKind {
+        let m = self.normalized();
+        let (a, b, c) = (m[0][0], m[0][1], m[1][1]);
+        let quadratic = a * c - b * b;
+        if m[2][2].is_nan() || (a == T::ZERO && b == T::ZERO && c == T::ZERO) {
+            ConicKind::Degenerate
+        } else if quadratic.is_near_zero() {
+            // the quadratic part only has one direction, it is a double line unless the linear
+            // part leaves it
+            let row = if a.abs() >= c.abs() { [a, b] } else { [b, c] };
+            let len = (row[0] * row[0] + row[1] * row[1]).sqrt();
+            let linear = (m[1][2] * row[0] - m[0][2] * row[1]) / len;
+            if linear.is_near_zero() {
+                ConicKind::Degenerate
+            } else {
+                ConicKind::Parabola
+            }
+        } else {
+            // the conic is `uᵀ Q u + offset = 0` around its centre
+            let offset = determinant(&m) / quadratic;
+            if offset.is_near_zero() {
+                ConicKind::Degenerate
+            } else if quadratic < T::ZERO {
+                ConicKind::Hyperbola
+            } else if offset * (a + c) > T::ZERO {
+                ConicKind::Empty
+            } else {
+                ConicKind::Ellipse
+            }
+        }
+    }
+
+    /// Matrix scaled so that its quadratic part has a unit norm, which makes the values of the
+    /// form at finite points comparable to squared lengths
+    fn normalized(&self) -> Matrix<T> {
+        let m = self.0;
+        let quadratic =
+            m[0][0] * m[0][0] + m[0][1] * m[0][1] + m[1][0] * m[1][0] + m[1][1] * m[1][1];
+        let all = m.iter().flatten().fold(T::ZERO, |sum, x| sum + *x * *x);
+        let scale = if quadratic > T::ZERO {
+            quadratic.sqrt()
+        } else if all > T::ZERO {
+            all.sqrt()
+        } else {
+            T::ONE
+        };
+        m.map(|row| row.map(|x| x / scale))
+    }
+}
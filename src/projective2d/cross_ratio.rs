@@ -0,0 +1,200 @@
+//! Projective invariants of four collinear points or four concurrent lines
+//!
+//! The cross ratio `(a, b; c, d)` is `[a c][b d] / ([a d][b c])`, where `[p q]` is the wedge of
+//! `p`, `q` and a fixed point off their line (or the antiwedge of two lines and a fixed line off
+//! their common point). Every element appears once above and once below, so the ratio doesn't
+//! depend on their weights and holds for points at infinity. It is `-1` for harmonic sets.
+
+use num::Float;
+
+use crate::AntiwedgeProduct;
+use crate::Epsilon;
+use crate::Projective;
+use crate::WedgeProduct;
+use crate::geometry3d::Bivector;
+use crate::geometry3d::Vector;
+
+use super::Line;
+use super::Point;
+
+type Vector3<T> = Vector<T, Projective>;
+
+fn norm<T: Float>(x: T, y: T, z: T) -> T {
+    (x * x + y * y + z * z).sqrt()
+}
+
+fn unit_point<T>(p: &Vector3<T>) -> Option<Vector3<T>>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let len = norm(p.x, p.y, p.z);
+    if len.is_near_zero() {
+        None
+    } else {
+        Some(Vector3::new(p.x / len, p.y / len, p.z / len))
+    }
+}
+
+fn unit_line<T>(l: &Line<T>) -> Option<Line<T>>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let len = norm(l.yz, l.zx, l.xy);
+    if len.is_near_zero() {
+        None
+    } else {
+        Some(Bivector::new(l.yz / len, l.zx / len, l.xy / len))
+    }
+}
+
+/// Unit points, `None` if two of them coincide or they are not on a common line, which is
+/// returned along with them
+fn collinear<T, const N: usize>(points: [&Vector3<T>; N]) -> Option<([Vector3<T>; N], Line<T>)>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let mut units = [Vector3::new(T::zero(), T::zero(), T::zero()); N];
+    for (unit, p) in units.iter_mut().zip(points) {
+        *unit = unit_point(p)?;
+    }
+    for (i, p) in units.iter().enumerate() {
+        for q in &units[i + 1..] {
+            unit_line(&p.wedge(q))?;
+        }
+    }
+    let line = unit_line(&units[0].wedge(&units[1]))?;
+    if units[2..]
+        .iter()
+        .any(|p| !p.antiwedge(&line).is_near_zero())
+    {
+        return None;
+    }
+    Some((units, line))
+}
+
+/// Unit lines, `None` if two of them coincide or they don't share a point, which is returned
+/// along with them
+fn concurrent<T, const N: usize>(lines: [&Line<T>; N]) -> Option<([Line<T>; N], Vector3<T>)>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let mut units = [Bivector::new(T::zero(), T::zero(), T::zero()); N];
+    for (unit, l) in units.iter_mut().zip(lines) {
+        *unit = unit_line(l)?;
+    }
+    for (i, l) in units.iter().enumerate() {
+        for m in &units[i + 1..] {
+            unit_point(&l.antiwedge(m))?;
+        }
+    }
+    let point = unit_point(&units[0].antiwedge(&units[1]))?;
+    if units[2..]
+        .iter()
+        .any(|l| !point.antiwedge(l).is_near_zero())
+    {
+        return None;
+    }
+    Some((units, point))
+}
+
+impl<T> Vector3<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    /// Cross ratio `(a, b; c, d)` of four collinear points, finite or at infinity
+    ///
+    /// Returns `None` if two points coincide or the points are not collinear.
+    pub fn cross_ratio(a: &Self, b: &Self, c: &Self, d: &Self) -> Option<T> {
+        let ([a, b, c, d], line) = collinear([a, b, c, d])?;
+        // a point that is never on the line
+        let off = Vector3::new(line.yz, line.zx, line.xy);
+        let bracket = |p: &Self, q: &Self| off.wedge(&p.wedge(q)).xyz;
+        Some(bracket(&a, &c) * bracket(&b, &d) / (bracket(&a, &d) * bracket(&b, &c)))
+    }
+
+    /// Point `d` such that `(a, b; c, d) = -1`, which is at infinity when `c` is the midpoint of
+    /// `a` and `b`
+    ///
+    /// Returns `None` if two points coincide or the points are not collinear.
+    pub fn harmonic_conjugate(a: &Self, b: &Self, c: &Self) -> Option<Self> {
+        let ([a, b, c], line) = collinear([a, b, c])?;
+        let off = Vector3::new(line.yz, line.zx, line.xy);
+        let bracket = |p: &Self, q: &Self| off.wedge(&p.wedge(q)).xyz;
+        // `c = alpha * a + beta * b`, and its conjugate is `alpha * a - beta * b`
+        let ab = bracket(&a, &b);
+        let (alpha, beta) = (bracket(&c, &b) / ab, bracket(&a, &c) / ab);
+        Some(Vector3::new(
+            alpha * a.x - beta * b.x,
+            alpha * a.y - beta * b.y,
+            alpha * a.z - beta * b.z,
+        ))
+    }
+
+    /// Cross ratio of four points on a conic, the one of the lines that join them to another
+    /// point `viewpoint` on the conic, which doesn't depend on which point it is
+    ///
+    /// Returns `None` if two of the five points coincide.
+    pub fn cross_ratio_on_conic(
+        a: &Self,
+        b: &Self,
+        c: &Self,
+        d: &Self,
+        viewpoint: &Self,
+    ) -> Option<T> {
+        let e = unit_point(viewpoint)?;
+        let [a, b, c, d] = [a, b, c, d].map(|p| unit_point(p).map(|p| e.wedge(&p)));
+        Line::cross_ratio(&a?, &b?, &c?, &d?)
+    }
+}
+
+impl<T> Line<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    /// Cross ratio `(a, b; c, d)` of four concurrent lines, or four parallel lines, which meet
+    /// at infinity
+    ///
+    /// Returns `None` if two lines coincide or the lines don't share a point.
+    pub fn cross_ratio(a: &Self, b: &Self, c: &Self, d: &Self) -> Option<T> {
+        let ([a, b, c, d], point) = concurrent([a, b, c, d])?;
+        // a line that never passes through the point
+        let off = Bivector::new(point.x, point.y, point.z);
+        let bracket = |l: &Self, m: &Self| l.antiwedge(m).antiwedge(&off);
+        Some(bracket(&a, &c) * bracket(&b, &d) / (bracket(&a, &d) * bracket(&b, &c)))
+    }
+
+    /// Line `d` such that `(a, b; c, d) = -1`
+    ///
+    /// Returns `None` if two lines coincide or the lines don't share a point.
+    pub fn harmonic_conjugate(a: &Self, b: &Self, c: &Self) -> Option<Self> {
+        let ([a, b, c], point) = concurrent([a, b, c])?;
+        let off = Bivector::new(point.x, point.y, point.z);
+        let bracket = |l: &Self, m: &Self| l.antiwedge(m).antiwedge(&off);
+        let ab = bracket(&a, &b);
+        let (alpha, beta) = (bracket(&c, &b) / ab, bracket(&a, &c) / ab);
+        Some(Bivector::new(
+            alpha * a.yz - beta * b.yz,
+            alpha * a.zx - beta * b.zx,
+            alpha * a.xy - beta * b.xy,
+        ))
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Float,
+    T: Epsilon,
+{
+    /// Cross ratio `(a, b; c, d)` of four collinear points, see [`Vector::cross_ratio`] to
+    /// include points at infinity
+    pub fn cross_ratio(a: &Self, b: &Self, c: &Self, d: &Self) -> Option<T> {
+        let [a, b, c, d] = [a, b, c, d].map(|p| Vector3::new(p.0.x, p.0.y, T::one()));
+        Vector3::cross_ratio(&a, &b, &c, &d)
+    }
+}
@@ -0,0 +1,44 @@
+use num::Float;
+use num::traits::ConstOne;
+
+use crate::Antiscalar;
+use crate::Distance;
+use crate::Projective;
+use crate::geometry3d::Trivector;
+
+use super::Point;
+use super::UnitLine;
+
+impl<T> Distance<UnitLine<T>> for Point<T>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Scalar = T;
+    type Antiscalar = Trivector<T, Projective>;
+
+    fn geometric_distance(&self, rhs: &UnitLine<T>) -> (Self::Scalar, Self::Antiscalar) {
+        (self.distance(rhs), Trivector::UNIT_VOLUME)
+    }
+
+    fn distance(&self, rhs: &UnitLine<T>) -> Self::Scalar {
+        let line = rhs.0;
+        (self.0.x * line.yz + self.0.y * line.zx + line.xy).abs()
+    }
+}
+
+impl<T> Distance<Point<T>> for UnitLine<T>
+where
+    Point<T>: Distance<UnitLine<T>>,
+{
+    type Scalar = <Point<T> as Distance<UnitLine<T>>>::Scalar;
+    type Antiscalar = <Point<T> as Distance<UnitLine<T>>>::Antiscalar;
+
+    fn geometric_distance(&self, rhs: &Point<T>) -> (Self::Scalar, Self::Antiscalar) {
+        rhs.geometric_distance(self)
+    }
+
+    fn distance(&self, rhs: &Point<T>) -> Self::Scalar {
+        rhs.distance(self)
+    }
+}
@@ -0,0 +1,134 @@
+use num::Float;
+use num::traits::ConstOne;
+use num::traits::ConstZero;
+
+use crate::Epsilon;
+use crate::fitting::Model;
+use crate::fitting::coincident;
+use crate::fitting::negligible;
+
+use super::Conic;
+use super::DirVector;
+use super::Line;
+use super::Point;
+use super::UnitLine;
+
+impl<T> UnitLine<T>
+where
+    T: Float,
+{
+    /// Line that minimizes the sum of the squared distances to the points, along with the root
+    /// mean square of those distances
+    ///
+    /// The line passes through the centroid of the points along their direction of most spread.
+    /// Returns `None` if there are fewer than two points or they all coincide.
+    pub fn fit(points: &[Point<T>]) -> Option<(Self, T)> {
+        if points.len() < 2 {
+            return None;
+        }
+        let n = T::from(points.len())?;
+        let (sx, sy) = points.iter().fold((T::zero(), T::zero()), |(sx, sy), p| {
+            (sx + p.0.x, sy + p.0.y)
+        });
+        let (cx, cy) = (sx / n, sy / n);
+
+        let (mut sxx, mut sxy, mut syy) = (T::zero(), T::zero(), T::zero());
+        let mut moment = T::zero();
+        for p in points {
+            moment = moment + p.0.x * p.0.x + p.0.y * p.0.y;
+            let (dx, dy) = (p.0.x - cx, p.0.y - cy);
+            sxx = sxx + dx * dx;
+            sxy = sxy + dx * dy;
+            syy = syy + dy * dy;
+        }
+        if coincident(sxx + syy, moment) {
+            return None;
+        }
+
+        // the direction of most spread diagonalizes the 2x2 scatter matrix
+        let two = T::one() + T::one();
+        let angle = (two * sxy).atan2(sxx - syy) / two;
+        let (dy, dx) = angle.sin_cos();
+        let half_trace = (sxx + syy) / two;
+        let radius = ((sxx - syy) * (sxx - syy) / (two * two) + sxy * sxy).sqrt();
+        let least = (half_trace - radius).max(T::zero());
+
+        let line = UnitLine(Line::new(-dy, dx, cx * dy - cy * dx));
+        Some((line, (least / n).sqrt()))
+    }
+}
+
+impl<T> Conic<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Circle that best fits the points, along with the root mean square of their distances to
+    /// it
+    ///
+    /// The circle minimizes the algebraic error `|p - c|² - r²` of the points, which matches the
+    /// geometric fit when they are close to the circle. Returns `None` if there are fewer than
+    /// three points or they are collinear.
+    pub fn fit_circle(points: &[Point<T>]) -> Option<(Self, T)> {
+        if points.len() < 3 {
+            return None;
+        }
+        let n = T::from(points.len())?;
+        let (sx, sy) = points.iter().fold((T::zero(), T::zero()), |(sx, sy), p| {
+            (sx + p.0.x, sy + p.0.y)
+        });
+        let (cx, cy) = (sx / n, sy / n);
+
+        let (mut sxx, mut sxy, mut syy) = (T::zero(), T::zero(), T::zero());
+        let (mut rx, mut ry) = (T::zero(), T::zero());
+        let mut moment = T::zero();
+        for p in points {
+            moment = moment + p.0.x * p.0.x + p.0.y * p.0.y;
+            let (dx, dy) = (p.0.x - cx, p.0.y - cy);
+            let d2 = dx * dx + dy * dy;
+            sxx = sxx + dx * dx;
+            sxy = sxy + dx * dy;
+            syy = syy + dy * dy;
+            rx = rx + dx * d2;
+            ry = ry + dy * d2;
+        }
+        let det = sxx * syy - sxy * sxy;
+        let trace = sxx + syy;
+        // the determinant is the product of the eigenvalues, their sum bounds the largest one
+        if coincident(trace, moment) || negligible(det, trace * trace) {
+            return None;
+        }
+
+        // the center, relative to the centroid, solves the normal equations of the algebraic error
+        let two = T::one() + T::one();
+        let (rx, ry) = (rx / two, ry / two);
+        let (ax, ay) = ((syy * rx - sxy * ry) / det, (sxx * ry - sxy * rx) / det);
+        let radius = (ax * ax + ay * ay + trace / n).sqrt();
+        let center = Point::new(cx + ax, cy + ay);
+
+        let residual = points.iter().fold(T::zero(), |sum, p| {
+            let (dx, dy) = (p.0.x - center.0.x, p.0.y - center.0.y);
+            let d = (dx * dx + dy * dy).sqrt() - radius;
+            sum + d * d
+        });
+        let u = DirVector::new(radius, T::zero());
+        let v = DirVector::new(T::zero(), radius);
+        let circle = Conic::ellipse(&center, &u, &v)?;
+        Some((circle, (residual / n).sqrt()))
+    }
+}
+
+impl<T> Model<Point<T>> for UnitLine<T>
+where
+    T: Float,
+{
+    type Scalar = T;
+
+    const SAMPLE_SIZE: usize = 2;
+
+    fn fit(points: &[Point<T>]) -> Option<(Self, T)> {
+        UnitLine::fit(points)
+    }
+}
@@ -0,0 +1,168 @@
+use num::Float;
+use num::traits::ConstOne;
+use num::traits::ConstZero;
+
+use crate::Epsilon;
+use crate::Projective;
+use crate::Transform;
+use crate::WedgeProduct;
+use crate::geometry3d::Vector;
+use crate::outermorphism::Outermorphism;
+
+use super::DirVector;
+use super::Homography;
+use super::Line;
+use super::MappedPoint;
+use super::Point;
+
+type Vector3<T> = Vector<T, Projective>;
+
+impl<T> Homography<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    /// Homography that multiplies the homogeneous coordinates `(x, y, 1)` of the points by the
+    /// matrix with these `rows`
+    pub fn from_matrix(rows: [[T; 3]; 3]) -> Self {
+        Homography(Outermorphism::from_matrix(rows))
+    }
+
+    /// Linear map of the homogeneous coordinates
+    pub fn outermorphism(&self) -> &Outermorphism<T, 3> {
+        &self.0
+    }
+
+    /// Homography that maps each `source` point to the `target` point at the same index
+    ///
+    /// Returns `None` if three of the source or the target points are collinear.
+    pub fn from_correspondences(source: &[Point<T>; 4], target: &[Point<T>; 4]) -> Option<Self> {
+        let from_basis = |points: &[Point<T>; 4]| {
+            let [a, b, c] = basis_images(points)?;
+            Some(Outermorphism::<T, 3>::from_images(&a, &b, &c))
+        };
+        // maps the source to the basis and the basis to the target
+        let from_source = from_basis(source)?.inverse()?;
+        Some(Homography(from_basis(target)?.compose(&from_source)))
+    }
+
+    /// Homography that applies `rhs` and then `self`
+    pub fn compose(&self, rhs: &Self) -> Self {
+        Homography(self.0.compose(&rhs.0))
+    }
+
+    /// Homography that undoes `self`, `None` if `self` is degenerate
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Homography)
+    }
+
+    /// Image of the homogeneous point `v`, which is at infinity if its weight is zero up to the
+    /// rounding of its computation
+    fn map_point(&self, v: Vector3<T>) -> MappedPoint<T> {
+        let image = self.0.transform(&v);
+        let [wx, wy, wz] = self.0.matrix()[2];
+        let terms = (wx * v.x).abs() + (wy * v.y).abs() + (wz * v.z).abs();
+        let rounding = T::epsilon() * (T::one() + T::one()).powi(4);
+        if image.z.abs() <= rounding * terms {
+            MappedPoint::AtInfinity(image)
+        } else {
+            MappedPoint::Point(Point::new(image.x / image.z, image.y / image.z))
+        }
+    }
+}
+
+/// Images of the `x`, `y` and `z` basis vectors of the map that sends `x + y + z` to the last
+/// point, `None` if three of the points are collinear
+///
+/// The first three points are weighted so that their sum is the last one, each weight is a ratio
+/// of volumes (Cramer's rule written with wedge products).
+fn basis_images<T>(points: &[Point<T>; 4]) -> Option<[Vector3<T>; 3]>
+where
+    T: Float,
+    T: ConstOne,
+    T: Epsilon,
+{
+    // twice the signed area of the triangle, `None` if it is negligible next to its longest side
+    let volume = |p: &Point<T>, q: &Point<T>, r: &Point<T>| {
+        let side = |a: &Point<T>, b: &Point<T>| {
+            let (dx, dy) = (b.0.x - a.0.x, b.0.y - a.0.y);
+            dx * dx + dy * dy
+        };
+        let longest = side(p, q).max(side(q, r)).max(side(r, p));
+        let volume = Vector3::from(p)
+            .wedge(&Vector3::from(q))
+            .wedge(&Vector3::from(r))
+            .xyz;
+        let relative = volume / longest;
+        if relative.is_nan() || relative.is_near_zero() {
+            None
+        } else {
+            Some(volume)
+        }
+    };
+    let [a, b, c, d] = points;
+    let abc = volume(a, b, c)?;
+    let weights = [
+        volume(d, b, c)? / abc,
+        volume(a, d, c)? / abc,
+        volume(a, b, d)? / abc,
+    ];
+    Some([
+        Vector3::from(a) * weights[0],
+        Vector3::from(b) * weights[1],
+        Vector3::from(c) * weights[2],
+    ])
+}
+
+impl<T> Transform<Vector3<T>> for Homography<T>
+where
+    T: Float,
+{
+    type Output = Vector3<T>;
+
+    fn transform(&self, element: &Vector3<T>) -> Self::Output {
+        self.0.transform(element)
+    }
+}
+
+impl<T> Transform<Point<T>> for Homography<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    type Output = MappedPoint<T>;
+
+    fn transform(&self, element: &Point<T>) -> Self::Output {
+        self.map_point(Vector3::from(element))
+    }
+}
+
+impl<T> Transform<DirVector<T>> for Homography<T>
+where
+    T: Float,
+    T: ConstZero,
+    T: ConstOne,
+    T: Epsilon,
+{
+    type Output = MappedPoint<T>;
+
+    fn transform(&self, element: &DirVector<T>) -> Self::Output {
+        self.map_point(Vector3::from(element))
+    }
+}
+
+impl<T> Transform<Line<T>> for Homography<T>
+where
+    T: Float,
+    T: ConstOne,
+{
+    type Output = Line<T>;
+
+    fn transform(&self, element: &Line<T>) -> Self::Output {
+        self.0.transform(element)
+    }
+}
@@ -0,0 +1,47 @@
+use num::Float;
+
+use crate::geometry2d::Bivector;
+use crate::geometry2d::Evenvector;
+
+use super::DirVector;
+use super::Motion;
+use super::Point;
+
+impl<T> Motion<T>
+where
+    T: Float,
+{
+    /// Counterclockwise rotation by `angle` radians about the origin followed by a translation
+    pub fn new(angle: T, translation: DirVector<T>) -> Self {
+        let (sin, cos) = (angle / (T::one() + T::one())).sin_cos();
+        Motion {
+            rotor: Evenvector {
+                s: cos,
+                b: Bivector::new(sin),
+            },
+            translation,
+        }
+    }
+
+    /// Rotation angle in radians, in `(-π, π]`
+    pub fn angle(&self) -> T {
+        let (sin, cos) = self.sin_cos();
+        sin.atan2(cos)
+    }
+
+    /// Applies the motion to `point`, the rotor must be unitized
+    pub fn transform_point(&self, point: &Point<T>) -> Point<T> {
+        let (sin, cos) = self.sin_cos();
+        let p = point.0;
+        Point(DirVector::new(
+            cos * p.x - sin * p.y + self.translation.x,
+            sin * p.x + cos * p.y + self.translation.y,
+        ))
+    }
+
+    /// Sine and cosine of the full rotation angle
+    fn sin_cos(&self) -> (T, T) {
+        let (c, s) = (self.rotor.s, self.rotor.b.xy);
+        ((c + c) * s, c * c - s * s)
+    }
+}
@@ -0,0 +1,7 @@
+use crate::operators;
+
+use super::{Point, UnitLine, UnitVector};
+
+operators!(Point<T>, T);
+operators!(UnitVector<T>, T);
+operators!(UnitLine<T>, T);
@@ -0,0 +1,317 @@
+//! Registration of corresponding point sets
+//!
+//! Finds the motion that moves each source point as close as possible to its target, minimizing
+//! the weighted sum of the squared distances. Rotations are solved in closed form as rotors: in
+//! 3D the rotor is the dominant eigenvector of a symmetric 4x4 matrix (Horn's method), and in 2D
+//! its angle follows from two sums, so no singular value decomposition is needed.
+
+use std::array::from_fn;
+
+use num::Float;
+use num::traits::{ConstOne, ConstZero};
+
+use crate::Epsilon;
+use crate::geometry2d;
+use crate::geometry3d as d3;
+use crate::homogeneous3d::Motor;
+use crate::projective2d;
+use crate::projective2d::Motion;
+
+/// Whether the registration may also scale the source points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scaling {
+    /// Only rotate and translate
+    Rigid,
+    /// Also scale uniformly about the origin, before the motion is applied
+    Uniform,
+}
+
+/// Result of a registration, the source points map to `motion(scale * source)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alignment<M, T> {
+    pub motion: M,
+    /// Scale factor, always `1` for [`Scaling::Rigid`]
+    pub scale: T,
+    /// Weighted root mean square of the distances from the moved source points to the targets
+    pub rms_error: T,
+}
+
+/// Reason why two point sets could not be registered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrationError {
+    /// There aren't as many target points as source points
+    MismatchedPoints,
+    /// A weight is negative or there isn't one weight per point pair
+    InvalidWeights,
+    /// The points don't single out a motion, like when they are all collinear in 3D or they
+    /// have no weight
+    Degenerate,
+}
+
+/// Point pairs relative to their weighted centroids
+struct Centered<T, const N: usize> {
+    weights: Vec<T>,
+    source_centroid: [T; N],
+    target_centroid: [T; N],
+    pairs: Vec<([T; N], [T; N])>,
+    /// Square root of the product of the weighted spreads of both sets, an upper bound of the
+    /// correlation between them
+    spread: T,
+    source_spread: T,
+}
+
+impl<T, const N: usize> Centered<T, N>
+where
+    T: Float,
+    T: Epsilon,
+{
+    fn new(
+        source: &[[T; N]],
+        target: &[[T; N]],
+        weights: Option<&[T]>,
+    ) -> Result<Self, RegistrationError> {
+        if source.len() != target.len() {
+            return Err(RegistrationError::MismatchedPoints);
+        }
+        let weights = match weights {
+            Some(weights) if weights.len() != source.len() => {
+                return Err(RegistrationError::InvalidWeights);
+            }
+            Some(weights) if weights.iter().any(|w| w.is_nan() || *w < T::zero()) => {
+                return Err(RegistrationError::InvalidWeights);
+            }
+            Some(weights) => weights.to_vec(),
+            None => vec![T::one(); source.len()],
+        };
+        let total = weights.iter().fold(T::zero(), |sum, w| sum + *w);
+        if total.is_near_zero() {
+            return Err(RegistrationError::Degenerate);
+        }
+
+        let centroid = |points: &[[T; N]]| {
+            let mut centroid = [T::zero(); N];
+            for (point, weight) in points.iter().zip(&weights) {
+                for i in 0..N {
+                    centroid[i] = centroid[i] + *weight * point[i];
+                }
+            }
+            centroid.map(|c| c / total)
+        };
+        let source_centroid = centroid(source);
+        let target_centroid = centroid(target);
+
+        let pairs: Vec<_> = source
+            .iter()
+            .zip(target)
+            .map(|(a, b)| {
+                (
+                    std::array::from_fn(|i| a[i] - source_centroid[i]),
+                    std::array::from_fn(|i| b[i] - target_centroid[i]),
+                )
+            })
+            .collect();
+        let (mut source_spread, mut target_spread) = (T::zero(), T::zero());
+        for ((a, b), weight) in pairs.iter().zip(&weights) {
+            source_spread = source_spread + *weight * dot(a, a);
+            target_spread = target_spread + *weight * dot(b, b);
+        }
+
+        Ok(Centered {
+            weights,
+            source_centroid,
+            target_centroid,
+            pairs,
+            spread: (source_spread * target_spread).sqrt(),
+            source_spread,
+        })
+    }
+
+    /// Scale factor for a rotation that correlates the sets by `correlation`
+    fn scale(&self, scaling: Scaling, correlation: T) -> Result<T, RegistrationError> {
+        match scaling {
+            Scaling::Rigid => Ok(T::one()),
+            Scaling::Uniform if self.source_spread.is_near_zero() => {
+                Err(RegistrationError::Degenerate)
+            }
+            Scaling::Uniform => Ok(correlation / self.source_spread),
+        }
+    }
+
+    /// Weighted root mean square distance between the moved source points and the targets
+    fn rms_error(&self, source: &[[T; N]], target: &[[T; N]], map: impl Fn([T; N]) -> [T; N]) -> T {
+        let mut sum = T::zero();
+        let mut total = T::zero();
+        for ((a, b), weight) in source.iter().zip(target).zip(&self.weights) {
+            let moved = map(*a);
+            let d: [T; N] = std::array::from_fn(|i| moved[i] - b[i]);
+            sum = sum + *weight * dot(&d, &d);
+            total = total + *weight;
+        }
+        (sum / total).sqrt()
+    }
+}
+
+/// Eigenvalues of the symmetric 4x4 matrix `a`, with their unit eigenvectors, sorted from the
+/// smallest to the largest, found with Jacobi rotations
+fn rotor_eigen<T: Float>(mut a: [[T; 4]; 4]) -> [(T, [T; 4]); 4] {
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let mut vectors: [[T; 4]; 4] = from_fn(|i| from_fn(|j| if i == j { one } else { zero }));
+    let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+
+    for _ in 0..50 {
+        let off = pairs
+            .iter()
+            .fold(zero, |sum, &(p, q)| sum + a[p][q] * a[p][q]);
+        let diagonal = (0..4).fold(zero, |sum, i| sum + a[i][i] * a[i][i]);
+        if off <= T::epsilon() * T::epsilon() * diagonal {
+            break;
+        }
+        for (p, q) in pairs {
+            if a[p][q] == zero {
+                continue;
+            }
+            // rotation in the pq plane that zeroes a[p][q], applied to the columns and then to
+            // the rows of `a`
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+            let c = (t * t + one).sqrt().recip();
+            let s = t * c;
+            for row in a.iter_mut().chain(vectors.iter_mut()) {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+            let (x, y) = (a[p], a[q]);
+            a[p] = from_fn(|k| c * x[k] - s * y[k]);
+            a[q] = from_fn(|k| s * x[k] + c * y[k]);
+        }
+    }
+
+    let mut eigen: [(T, [T; 4]); 4] = from_fn(|i| (a[i][i], vectors.map(|row| row[i])));
+    eigen.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    eigen
+}
+
+fn dot<T: Float, const N: usize>(a: &[T; N], b: &[T; N]) -> T {
+    (0..N).fold(T::zero(), |sum, i| sum + a[i] * b[i])
+}
+
+/// Motor, and optionally scale, that best maps the `source` points onto the `target` points
+///
+/// Each pair's squared distance is scaled by its weight, all pairs weigh the same if `weights`
+/// is `None`. Fails with [`RegistrationError::Degenerate`] if the points are collinear, since the
+/// rotation around their line is then free.
+pub fn align_3d<T>(
+    source: &[d3::Point<T>],
+    target: &[d3::Point<T>],
+    weights: Option<&[T]>,
+    scaling: Scaling,
+) -> Result<Alignment<Motor<T>, T>, RegistrationError>
+where
+    T: Float,
+    T: Epsilon,
+    T: ConstZero,
+    T: ConstOne,
+{
+    let source: Vec<[T; 3]> = source.iter().map(|p| [p.0.x, p.0.y, p.0.z]).collect();
+    let target: Vec<[T; 3]> = target.iter().map(|p| [p.0.x, p.0.y, p.0.z]).collect();
+    let centered = Centered::new(&source, &target, weights)?;
+
+    let mut s = [[T::zero(); 3]; 3];
+    for ((a, b), weight) in centered.pairs.iter().zip(&centered.weights) {
+        for j in 0..3 {
+            for k in 0..3 {
+                s[j][k] = s[j][k] + *weight * a[j] * b[k];
+            }
+        }
+    }
+    // the rotor `[w, x, y, z]` that maximizes the correlation `Σ weight * b · rotate(a)` is the
+    // eigenvector of the largest eigenvalue of this matrix, and that eigenvalue is the correlation
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+    let n = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, syy - sxx - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, szz - sxx - syy],
+    ];
+    let [.., (second, _), (correlation, rotor)] = rotor_eigen(n);
+    if centered.spread.is_near_zero() || ((correlation - second) / centered.spread).is_near_zero() {
+        return Err(RegistrationError::Degenerate);
+    }
+    let scale = centered.scale(scaling, correlation)?;
+
+    let [cx, cy, cz] = centered.source_centroid.map(|c| c * scale);
+    let rotated = Motor::from_parts(rotor, [T::zero(); 3])
+        .transform_point(&d3::Point(d3::Vector::new(cx, cy, cz)))
+        .0;
+    let [tx, ty, tz] = centered.target_centroid;
+    let motor = Motor::from_parts(rotor, [tx - rotated.x, ty - rotated.y, tz - rotated.z]);
+
+    let rms_error = centered.rms_error(&source, &target, |[x, y, z]| {
+        let p = motor.transform_point(&d3::Point(d3::Vector::new(x * scale, y * scale, z * scale)));
+        [p.0.x, p.0.y, p.0.z]
+    });
+    Ok(Alignment {
+        motion: motor,
+        scale,
+        rms_error,
+    })
+}
+
+/// Motion, and optionally scale, that best maps the `source` points onto the `target` points
+///
+/// Each pair's squared distance is scaled by its weight, all pairs weigh the same if `weights`
+/// is `None`. Fails with [`RegistrationError::Degenerate`] if the rotation is undetermined, like
+/// when all the points coincide.
+pub fn align_2d<T>(
+    source: &[projective2d::Point<T>],
+    target: &[projective2d::Point<T>],
+    weights: Option<&[T]>,
+    scaling: Scaling,
+) -> Result<Alignment<Motion<T>, T>, RegistrationError>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let source: Vec<[T; 2]> = source.iter().map(|p| [p.0.x, p.0.y]).collect();
+    let target: Vec<[T; 2]> = target.iter().map(|p| [p.0.x, p.0.y]).collect();
+    let centered = Centered::new(&source, &target, weights)?;
+
+    // the rotation by `θ` correlates the sets by `cos θ * dots + sin θ * wedges`
+    let (mut dots, mut wedges) = (T::zero(), T::zero());
+    for ((a, b), weight) in centered.pairs.iter().zip(&centered.weights) {
+        dots = dots + *weight * (a[0] * b[0] + a[1] * b[1]);
+        wedges = wedges + *weight * (a[0] * b[1] - a[1] * b[0]);
+    }
+    let correlation = dots.hypot(wedges);
+    if centered.spread.is_near_zero() || (correlation / centered.spread).is_near_zero() {
+        return Err(RegistrationError::Degenerate);
+    }
+    let scale = centered.scale(scaling, correlation)?;
+
+    let angle = wedges.atan2(dots);
+    let [cx, cy] = centered.source_centroid.map(|c| c * scale);
+    let rotated = Motion::new(angle, geometry2d::Vector::new(T::zero(), T::zero()))
+        .transform_point(&projective2d::Point(geometry2d::Vector::new(cx, cy)))
+        .0;
+    let [tx, ty] = centered.target_centroid;
+    let motion = Motion::new(
+        angle,
+        geometry2d::Vector::new(tx - rotated.x, ty - rotated.y),
+    );
+
+    let rms_error = centered.rms_error(&source, &target, |[x, y]| {
+        let p = motion.transform_point(&projective2d::Point(geometry2d::Vector::new(
+            x * scale,
+            y * scale,
+        )));
+        [p.0.x, p.0.y]
+    });
+    Ok(Alignment {
+        motion,
+        scale,
+        rms_error,
+    })
+}
@@ -1,6 +1,6 @@
 use std::{
     marker::PhantomData,
-    ops::{Add, Mul},
+    ops::{Add, Mul, Neg, Sub},
 };
 
 use num::{
@@ -14,6 +14,17 @@ impl<const D: u32, T, M> Scalar<D, T, M> {
     pub fn new(v: T) -> Self {
         Scalar(v, PhantomData)
     }
+
+    /// Reinterprets the same value under the metric `N`
+    pub fn cast_metric<N>(self) -> Scalar<D, T, N> {
+        Scalar(self.0, PhantomData)
+    }
+}
+
+impl<const D: u32, T, M> From<T> for Scalar<D, T, M> {
+    fn from(value: T) -> Self {
+        Scalar(value, PhantomData)
+    }
 }
 
 impl<const D: u32, T, M> Zero for Scalar<D, T, M>
@@ -48,7 +59,6 @@ where
 impl<const D: u32, T, M> ConstOne for Scalar<D, T, M>
 where
     T: ConstOne,
-    T: PartialEq,
 {
     const ONE: Self = Scalar(T::ONE, PhantomData);
 }
@@ -64,6 +74,28 @@ where
     }
 }
 
+impl<const D: u32, T, M> Sub<Scalar<D, T, M>> for Scalar<D, T, M>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Scalar<D, T, M>;
+
+    fn sub(self, rhs: Scalar<D, T, M>) -> Self::Output {
+        Scalar::<D, T, M>(self.0 - rhs.0, PhantomData)
+    }
+}
+
+impl<const D: u32, T, M> Neg for Scalar<D, T, M>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Scalar<D, T, M>;
+
+    fn neg(self) -> Self::Output {
+        Scalar::<D, T, M>(-self.0, PhantomData)
+    }
+}
+
 impl<const D: u32, T, M> Mul<Scalar<D, T, M>> for Scalar<D, T, M>
 where
     T: Mul<Output = T>,
@@ -145,3 +177,8 @@ where
         self.clone()
     }
 }
+
+crate::assign_ops!(Scalar<D, T, M>, const D: u32, T, M);
+
+#[cfg(feature = "operators")]
+crate::operators!(Scalar<D, T, M>, const D: u32, T, M);
@@ -0,0 +1,92 @@
+//! Subspace algebra on orthonormal frames of coefficients, shared by the [`crate::SubspaceJoin`] and
+//! [`crate::SubspaceMeet`] of every geometry.
+
+use num::Float;
+
+use crate::Epsilon;
+
+fn dot<T, const N: usize>(a: &[T; N], b: &[T; N]) -> T
+where
+    T: Float,
+{
+    a.iter().zip(b).fold(T::zero(), |sum, (a, b)| sum + *a * *b)
+}
+
+/// Extends the orthonormal `basis` with the directions of `vectors` it doesn't span yet, which are
+/// also returned.
+///
+/// Each vector is normalized first, so a direction is new when its rejection from the basis is not
+/// [`Epsilon`] small relative to the vector, whatever its length. Zero vectors add nothing.
+fn extend<T, const N: usize>(basis: &mut Vec<[T; N]>, vectors: &[[T; N]]) -> Vec<[T; N]>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let mut added = Vec::new();
+    for vector in vectors {
+        let length = dot(vector, vector).sqrt();
+        if length.is_zero() {
+            continue;
+        }
+        let mut rejection = vector.map(|v| v / length);
+        for b in basis.iter() {
+            let projection = dot(&rejection, b);
+            for (r, b) in rejection.iter_mut().zip(b) {
+                *r = *r - projection * *b;
+            }
+        }
+        let length = dot(&rejection, &rejection).sqrt();
+        if !length.is_near_zero() {
+            let unit = rejection.map(|r| r / length);
+            basis.push(unit);
+            added.push(unit);
+        }
+    }
+    added
+}
+
+/// Orthonormal basis of the subspace spanned by `a` and `b`, starting with the one of `a`
+pub(crate) fn join<T, const N: usize>(a: &[[T; N]], b: &[[T; N]]) -> Vec<[T; N]>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let mut basis = Vec::new();
+    extend(&mut basis, a);
+    extend(&mut basis, b);
+    basis
+}
+
+/// Orthonormal basis of the directions of `within` orthogonal to its subspace `of`
+fn complement<T, const N: usize>(within: &[[T; N]], of: &[[T; N]]) -> Vec<[T; N]>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let mut basis = Vec::new();
+    extend(&mut basis, of);
+    extend(&mut basis, within)
+}
+
+/// Orthonormal basis of the intersection of the subspaces spanned by `a` and `b`.
+///
+/// The intersection is the complement, inside the join, of the span of both complements. When one
+/// subspace contains the other the smaller one is returned as is, keeping its orientation.
+pub(crate) fn meet<T, const N: usize>(a: &[[T; N]], b: &[[T; N]]) -> Vec<[T; N]>
+where
+    T: Float,
+    T: Epsilon,
+{
+    let a = join(a, &[]);
+    let b = join(b, &[]);
+    let whole = join(&a, &b);
+    let outside = join(&complement(&whole, &a), &complement(&whole, &b));
+    let intersection = complement(&whole, &outside);
+    if intersection.len() == a.len() {
+        a
+    } else if intersection.len() == b.len() {
+        b
+    } else {
+        intersection
+    }
+}
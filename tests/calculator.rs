@@ -0,0 +1,214 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Runs the calculator binary in batch mode with `script` on stdin, returning its output lines
+fn run(arguments: &[&str], script: &str) -> Vec<String> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_gual"))
+        .args(arguments)
+        .arg("--batch")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("the calculator runs");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn euclidean_3d_products() {
+    let output = run(
+        &["--dim", "3"],
+        "x ^ y\n\
+         zy\n\
+         (1 + 2x) * (x + y)\n\
+         x & yz\n\
+         2xy - 3 # trailing comment\n\
+         \n\
+         dot(x + 2y, 3x + y)\n",
+    );
+    assert_eq!(
+        output,
+        ["xy", "-yz", "2 + x + y + 2xy", "1", "-3 + 2xy", "5"]
+    );
+}
+
+#[test]
+fn euclidean_3d_complements_and_duals() {
+    let output = run(
+        &["--dim", "3", "--metric", "euclidean"],
+        "complement(x)\n\
+         left_complement(yz)\n\
+         dual(x + yz)\n\
+         contraction(xyz, x)\n\
+         expansion(x, xy)\n",
+    );
+    assert_eq!(output, ["yz", "x", "x + yz", "yz", "-zx"]);
+}
+
+#[test]
+fn variables_and_errors() {
+    let output = run(
+        &["--dim", "2"],
+        "a = x + y\n\
+         a ^ x\n\
+         x = 2\n\
+         foo(x)\n\
+         xy / x\n\
+         xy / (1 + x)\n\
+         angle(x, 3y)\n",
+    );
+    assert_eq!(
+        output,
+        [
+            "a = x + y",
+            "-xy",
+            "error: `x` is a blade and can not be assigned",
+            "error: unknown function `foo`",
+            "error: only division by scalars is supported",
+            "error: only division by scalars is supported",
+            &std::f64::consts::FRAC_PI_2.to_string(),
+        ]
+    );
+}
+
+#[test]
+fn projective_3d_degenerate_metric() {
+    let output = run(&["--dim", "3", "--metric", "projective"], "z * z\nx * z\n");
+    assert_eq!(output, ["0", "-zx"]);
+}
+
+#[test]
+fn projective_4d_join_and_meet() {
+    let output = run(
+        &["--dim", "4", "--metric", "projective"],
+        "p = x + w\n\
+         q = y + w\n\
+         l = join(p, q)\n\
+         join(l, z + w)\n\
+         meet(wyz, wzx)\n\
+         angle(wyz, wzx)\n\
+         distance(2x + 3y + 2w, wyz)\n\
+         w * w\n",
+    );
+    assert_eq!(
+        output,
+        [
+            "p = x + w",
+            "q = y + w",
+            "l = -wx + wy + xy",
+            "wyz + wzx + wxy - zyx",
+            "-wz",
+            &std::f64::consts::FRAC_PI_2.to_string(),
+            "1",
+            "0",
+        ]
+    );
+}
+
+#[test]
+fn unsupported_algebra_is_rejected() {
+    let output = Command::new(env!("CARGO_BIN_EXE_gual"))
+        .args(["--dim", "4", "--metric", "euclidean"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn projective_2d_scales_by_numbers() {
+    let output = run(
+        &["--dim", "2", "--metric", "projective"],
+        "2y\n\
+         3(x + y) - y * 2\n\
+         x * y\n",
+    );
+    assert_eq!(
+        output,
+        [
+            "2y",
+            "3x + y",
+            "error: the geometric product is not available in this algebra, `*` only scales by a \
+             number",
+        ]
+    );
+}
+
+/// Names of the functions listed by `help`
+fn help_functions(arguments: &[&str]) -> Vec<String> {
+    let help = run(arguments, "help\n");
+    let start = help
+        .iter()
+        .position(|line| line.starts_with("functions:"))
+        .expect("the help lists the functions");
+    let end = help
+        .iter()
+        .position(|line| line.starts_with("variables:"))
+        .expect("the help lists the variables");
+    help[start..end]
+        .iter()
+        .flat_map(|line| line.trim_start_matches("functions:").split_whitespace())
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn every_function_in_the_help_runs() {
+    let algebras: [(&[&str], &[&str]); 5] = [
+        (&["--dim", "2"], &["1", "x", "y", "xy"]),
+        (
+            &["--dim", "2", "--metric", "projective"],
+            &["1", "x", "y", "xy"],
+        ),
+        (
+            &["--dim", "3"],
+            &["1", "x", "y", "z", "yz", "zx", "xy", "xyz"],
+        ),
+        (
+            &["--dim", "3", "--metric", "projective"],
+            &["1", "x", "y", "z", "yz", "zx", "xy", "xyz"],
+        ),
+        (
+            &["--dim", "4", "--metric", "projective"],
+            &[
+                "1", "x", "y", "z", "w", "wx", "wy", "wz", "yz", "zx", "xy", "wyz", "wzx", "wxy",
+                "zyx", "xyzw",
+            ],
+        ),
+    ];
+    for (arguments, blades) in algebras {
+        for function in help_functions(arguments) {
+            let mut calls = Vec::new();
+            for a in blades {
+                calls.push(format!("{function}({a})"));
+                for b in blades {
+                    calls.push(format!("{function}({a}, {b})"));
+                }
+            }
+            let output = run(arguments, &(calls.join("\n") + "\n"));
+            assert_eq!(output.len(), calls.len());
+            assert!(
+                output.iter().any(|line| !line.starts_with("error:")),
+                "{function} fails for every blade in {arguments:?}"
+            );
+        }
+        let output = run(arguments, "join(x, y)\n");
+        if !help_functions(arguments).contains(&"join".to_string()) {
+            assert_eq!(
+                output,
+                ["error: `join` is not available in this algebra, see `help`"]
+            );
+        }
+    }
+}
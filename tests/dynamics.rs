@@ -0,0 +1,125 @@
+use gual::{
+    dynamics::{Body, Inertia, gravity, point_force},
+    geometry3d as d3,
+    homogeneous3d::{Motor, Twist, Wrench},
+};
+
+fn vector(x: f64, y: f64, z: f64) -> d3::Vector<f64> {
+    d3::Vector::new(x, y, z)
+}
+
+fn assert_vector(v: d3::Vector<f64>, expected: d3::Vector<f64>, tolerance: f64) {
+    let d = ((v.x - expected.x).powi(2) + (v.y - expected.y).powi(2) + (v.z - expected.z).powi(2))
+        .sqrt();
+    assert!(d < tolerance, "expected {expected:?}, got {v:?}");
+}
+
+fn free(_: &Body<f64>) -> Wrench<f64> {
+    Wrench::new(&vector(0.0, 0.0, 0.0), &vector(0.0, 0.0, 0.0))
+}
+
+fn top() -> (Inertia<f64>, Body<f64>) {
+    let inertia = Inertia::new(2.0, &vector(1.0, 2.0, 3.0));
+    let placement = Twist::new(&vector(0.3, 0.6, -0.6), &vector(1.0, -2.0, 0.5)).exp();
+    let spinning = Twist::new(&vector(1.0, 0.3, 4.0), &vector(0.5, -0.2, 0.1));
+    (inertia, Body::new(placement, spinning))
+}
+
+fn assert_conserved(start: &Body<f64>, end: &Body<f64>, inertia: &Inertia<f64>, tolerance: f64) {
+    let (e0, e1) = (start.kinetic_energy(inertia), end.kinetic_energy(inertia));
+    assert!(
+        (e0 - e1).abs() < tolerance * e0,
+        "energy went from {e0} to {e1}"
+    );
+    let (m0, m1) = (start.momentum(inertia), end.momentum(inertia));
+    assert_vector(m1.force(), m0.force(), tolerance);
+    assert_vector(m1.torque(), m0.torque(), tolerance);
+}
+
+#[test]
+fn free_spinning_top_rk4() {
+    let (inertia, start) = top();
+    let mut body = start;
+    for _ in 0..2000 {
+        body = body.step_rk4(&inertia, 0.001, free);
+    }
+    assert_conserved(&start, &body, &inertia, 1e-8);
+    // the body has actually moved and turned
+    assert!(body.motor != start.motor);
+    assert!(body.velocity != start.velocity);
+}
+
+#[test]
+fn free_spinning_top_symplectic() {
+    let (inertia, start) = top();
+    let energy = start.kinetic_energy(&inertia);
+    let mut body = start;
+    let mut errors = [0.0f64; 2];
+    for i in 0..20000 {
+        body = body.step_symplectic(&inertia, 0.001, free);
+        let error = (body.kinetic_energy(&inertia) - energy).abs() / energy;
+        errors[i / 10000] = errors[i / 10000].max(error);
+    }
+    assert_conserved(&start, &body, &inertia, 1e-6);
+    // the energy error oscillates without drifting away
+    assert!(errors[0] < 1e-7, "energy error {errors:?}");
+    assert!(errors[1] < 2.0 * errors[0], "energy drifted: {errors:?}");
+    // the momentum of a free body is kept up to rounding
+    assert_vector(
+        body.momentum(&inertia).torque(),
+        start.momentum(&inertia).torque(),
+        1e-9,
+    );
+}
+
+#[test]
+fn spin_around_a_principal_axis() {
+    let inertia = Inertia::new(1.0, &vector(1.0, 2.0, 3.0));
+    let start = Body::new(
+        Motor::translation(&vector(0.0, 0.0, 0.0)),
+        Twist::new(&vector(0.0, 0.0, 1.0), &vector(0.0, 0.0, 0.0)),
+    );
+    let (mut a, mut b) = (start, start);
+    for _ in 0..100 {
+        a = a.step_rk4(&inertia, 0.01, free);
+        b = b.step_symplectic(&inertia, 0.01, free);
+    }
+    let p = d3::Point(vector(1.0, 0.0, 0.0));
+    let (cos, sin) = (1.0f64.cos(), 1.0f64.sin());
+    assert_vector(a.motor.transform_point(&p).0, vector(cos, sin, 0.0), 1e-9);
+    assert_vector(b.motor.transform_point(&p).0, vector(cos, sin, 0.0), 1e-9);
+    assert_vector(a.velocity.angular(), vector(0.0, 0.0, 1.0), 1e-12);
+}
+
+#[test]
+fn falling_body() {
+    let inertia = Inertia::new(3.0, &vector(1.0, 1.0, 2.0));
+    let g = vector(0.0, 0.0, -9.8);
+    let start = Body::new(
+        Motor::translation(&vector(0.0, 0.0, 10.0)),
+        Twist::new(&vector(0.0, 2.0, 0.0), &vector(1.0, 0.0, 0.0)),
+    );
+    let weight = |body: &Body<f64>| gravity(&body.center(), inertia.mass, &g);
+    let mut body = start;
+    for _ in 0..100 {
+        body = body.step_rk4(&inertia, 0.01, weight);
+    }
+    // the center follows a parabola, whatever the body does around it
+    assert_vector(body.center().0, vector(1.0, 0.0, 10.0 - 4.9), 1e-6);
+    assert_vector(body.world_velocity().angular(), vector(0.0, 2.0, 0.0), 1e-9);
+}
+
+#[test]
+fn point_force_wrench() {
+    let push = point_force(&d3::Point(vector(0.0, 1.0, 0.0)), &vector(2.0, 0.0, 0.0));
+    assert_vector(push.force(), vector(2.0, 0.0, 0.0), 1e-12);
+    assert_vector(push.torque(), vector(0.0, 0.0, -2.0), 1e-12);
+
+    let weight = gravity(
+        &d3::Point(vector(1.0, 0.0, 0.0)),
+        2.0,
+        &vector(0.0, 0.0, -1.0),
+    );
+    assert_vector(weight.force(), vector(0.0, 0.0, -2.0), 1e-12);
+    assert_vector(weight.torque(), vector(0.0, 2.0, 0.0), 1e-12);
+}
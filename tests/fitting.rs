@@ -0,0 +1,254 @@
+use gual::{
+    Distance,
+    fitting::{Model, Ransac},
+    geometry2d, geometry3d as d3,
+    homogeneous3d::{Line, Plane},
+    projective2d::{Conic, DirVector, Point, UnitLine},
+};
+
+fn point(x: f64, y: f64, z: f64) -> d3::Point<f64> {
+    d3::Point(d3::Vector::new(x, y, z))
+}
+
+fn point2(x: f64, y: f64) -> Point<f64> {
+    Point(geometry2d::Vector::new(x, y))
+}
+
+fn assert_near(value: f64, expected: f64) {
+    assert!(
+        (value - expected).abs() < 1e-9,
+        "expected {expected}, got {value}"
+    );
+}
+
+/// Grid on the plane `z = x + 2y + 1`, offset alternately by `±offset` along its normal
+fn tilted_grid(offset: f64) -> Vec<d3::Point<f64>> {
+    let norm = 6.0_f64.sqrt();
+    let mut points = Vec::new();
+    for i in -2..=2 {
+        for j in -2..=2 {
+            let (x, y) = (i as f64, j as f64);
+            let side = if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
+            let (nx, ny, nz) = (1.0 / norm, 2.0 / norm, -1.0 / norm);
+            let d = side * offset;
+            points.push(point(x + d * nx, y + d * ny, x + 2.0 * y + 1.0 + d * nz));
+        }
+    }
+    points
+}
+
+#[test]
+fn plane_fit() {
+    let (plane, rms) = Plane::fit(&tilted_grid(0.0)).unwrap();
+    assert_near(rms, 0.0);
+    for p in tilted_grid(0.0) {
+        assert_near(p.distance(&plane).0, 0.0);
+    }
+    assert_near(
+        point(0.0, 0.0, 0.0).distance(&plane).0,
+        1.0 / 6.0_f64.sqrt(),
+    );
+
+    // 25 points, 13 on one side and 12 on the other, keep the same least-squares plane
+    let (plane, rms) = Plane::fit(&tilted_grid(0.1)).unwrap();
+    assert!((rms - 0.1).abs() < 0.01);
+    assert!(point(0.0, 0.0, 1.0).distance(&plane).0 < 0.01);
+
+    assert!(Plane::fit(&[point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0)]).is_none());
+    let collinear = [0.0, 1.0, 2.0, 3.0].map(|t| point(t, 2.0 * t, -t));
+    assert!(Plane::fit(&collinear).is_none());
+}
+
+#[test]
+fn line_fit() {
+    let on_line = [-1.0, 0.0, 2.0, 5.0].map(|t| point(1.0 + t, 2.0, 3.0 - t));
+    let (line, rms) = Line::fit(&on_line).unwrap();
+    assert_near(rms, 0.0);
+    for p in on_line {
+        assert_near(p.distance(&line).0, 0.0);
+    }
+    assert_near(point(1.0, 0.0, 3.0).distance(&line).0, 2.0);
+
+    // points at distance 1 on both sides of the x axis
+    let around = [
+        point(0.0, 1.0, 0.0),
+        point(0.0, -1.0, 0.0),
+        point(3.0, 0.0, 1.0),
+        point(3.0, 0.0, -1.0),
+    ];
+    let (line, rms) = Line::fit(&around).unwrap();
+    assert_near(rms, 1.0);
+    assert_near(point(-7.0, 0.0, 0.0).distance(&line).0, 0.0);
+
+    assert!(Line::fit(&[point(1.0, 1.0, 1.0)]).is_none());
+    assert!(Line::fit(&[point(1.0, 1.0, 1.0); 4]).is_none());
+}
+
+#[test]
+fn projective2d_line_fit() {
+    let on_line = [-2.0, 0.0, 1.0, 4.0].map(|t| point2(3.0 * t, 1.0 - 4.0 * t));
+    let (line, rms) = UnitLine::fit(&on_line).unwrap();
+    assert_near(rms, 0.0);
+    for p in on_line {
+        assert_near(p.distance(&line), 0.0);
+    }
+    assert_near(point2(4.0, 4.0).distance(&line), 5.0);
+
+    let around = [
+        point2(0.0, 1.0),
+        point2(0.0, -1.0),
+        point2(4.0, 1.0),
+        point2(4.0, -1.0),
+    ];
+    let (line, rms) = UnitLine::fit(&around).unwrap();
+    assert_near(rms, 1.0);
+    assert_near(line.distance(&point2(-5.0, 0.0)), 0.0);
+
+    assert!(UnitLine::fit(&[point2(1.0, 2.0); 3]).is_none());
+}
+
+#[test]
+fn circle_fit() {
+    let center = point2(2.0, -1.0);
+    let on_circle =
+        [0.0, 1.0, 2.5, 4.0, 5.5].map(|t: f64| point2(2.0 + 3.0 * t.cos(), -1.0 + 3.0 * t.sin()));
+    let (circle, rms) = Conic::fit_circle(&on_circle).unwrap();
+    assert_near(rms, 0.0);
+    for p in &on_circle {
+        assert!(circle.contains(p));
+    }
+    let expected = Conic::ellipse(
+        &center,
+        &DirVector::new(3.0, 0.0),
+        &DirVector::new(0.0, 3.0),
+    )
+    .unwrap();
+    for (row, expected) in circle.matrix().iter().zip(expected.matrix()) {
+        for (value, expected) in row.iter().zip(expected) {
+            assert_near(*value, expected);
+        }
+    }
+
+    // eight points alternately 0.1 inside and outside of the circle
+    let around: Vec<_> = (0..8)
+        .map(|i| {
+            let t = i as f64 * std::f64::consts::FRAC_PI_4;
+            let r = if i % 2 == 0 { 3.1 } else { 2.9 };
+            point2(2.0 + r * t.cos(), -1.0 + r * t.sin())
+        })
+        .collect();
+    let (circle, rms) = Conic::fit_circle(&around).unwrap();
+    assert!((rms - 0.1).abs() < 1e-3);
+    // the noise is symmetric, so the center stays and the radius squared is the mean of 2.9² and 3.1²
+    let radius = 9.01_f64.sqrt();
+    let u = DirVector::new(radius, 0.0);
+    let v = DirVector::new(0.0, radius);
+    let expected = Conic::ellipse(&center, &u, &v).unwrap();
+    for (row, expected) in circle.matrix().iter().zip(expected.matrix()) {
+        for (value, expected) in row.iter().zip(expected) {
+            assert_near(*value, expected);
+        }
+    }
+    assert!(!circle.contains(&center));
+
+    assert!(Conic::fit_circle(&on_circle[..2]).is_none());
+    let collinear = [0.0, 1.0, 3.0].map(|t| point2(t, 2.0 * t));
+    assert!(Conic::fit_circle(&collinear).is_none());
+}
+
+#[test]
+fn small_point_clouds_fit() {
+    // a 2 cm patch of the tilted grid, near the origin and 100 m away from it
+    for offset in [0.0, 100.0] {
+        let patch: Vec<_> = tilted_grid(0.0)
+            .into_iter()
+            .map(|p| point(0.005 * p.0.x + offset, 0.005 * p.0.y, 0.005 * p.0.z))
+            .collect();
+        let (plane, rms) = Plane::fit(&patch).unwrap();
+        assert_near(rms, 0.0);
+        for p in &patch {
+            assert_near(p.distance(&plane).0, 0.0);
+        }
+
+        let segment = [0.0, 0.005, 0.01, 0.02].map(|t| point(offset + t, 2.0, 3.0 - t));
+        let (line, rms) = Line::fit(&segment).unwrap();
+        assert_near(rms, 0.0);
+        assert_near(point(offset, 0.0, 3.0).distance(&line).0, 2.0);
+
+        let segment = [0.0, 0.005, 0.01, 0.02].map(|t| point2(offset + 0.6 * t, -0.8 * t));
+        let (line, rms) = UnitLine::fit(&segment).unwrap();
+        assert_near(rms, 0.0);
+        assert_near(point2(offset + 0.8, 0.6).distance(&line), 1.0);
+
+        let arc =
+            [0.0, 1.0, 2.5, 4.0].map(|t: f64| point2(offset + 0.01 * t.cos(), 0.01 * t.sin()));
+        let (_, rms) = Conic::fit_circle(&arc).unwrap();
+        assert_near(rms, 0.0);
+    }
+
+    // the same points repeated far away still coincide, and tiny collinear ones stay collinear
+    assert!(Line::fit(&[point(1000.0, 1000.0, 1000.0); 4]).is_none());
+    assert!(UnitLine::fit(&[point2(1000.0, -1000.0); 3]).is_none());
+    let collinear = [0.0, 0.01, 0.02].map(|t| point(t, 2.0 * t, -t));
+    assert!(Plane::fit(&collinear).is_none());
+}
+
+#[test]
+fn ransac_rejects_outliers() {
+    let mut points = tilted_grid(0.0);
+    let outliers = [
+        point(0.0, 0.0, 10.0),
+        point(5.0, -3.0, 4.0),
+        point(-4.0, 4.0, -6.0),
+        point(1.0, 1.0, 1.0),
+        point(2.0, -2.0, 8.0),
+    ];
+    points.extend(outliers);
+
+    let ransac = Ransac::default();
+    let fit = ransac
+        .fit(&points, |plane: &Plane<f64>, p| {
+            p.distance(plane).0.abs() < 0.01
+        })
+        .unwrap();
+    assert_eq!(fit.inliers, (0..25).collect::<Vec<_>>());
+    assert_near(fit.rms_error, 0.0);
+    assert_near(point(0.0, 0.0, 1.0).distance(&fit.model).0, 0.0);
+    assert_eq!(
+        Some(fit),
+        ransac.fit(&points, |plane: &Plane<f64>, p| p.distance(plane).0.abs()
+            < 0.01)
+    );
+
+    // the least-squares fit is pulled away by the outliers
+    let (_, rms) = Plane::fit(&points).unwrap();
+    assert!(rms > 0.1);
+
+    let mut points: Vec<_> = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]
+        .map(|t| point(t, t, 0.0))
+        .into();
+    points.extend([point(0.0, 5.0, 1.0), point(3.0, -1.0, 2.0)]);
+    let fit = ransac
+        .fit(&points, |line: &Line<f64>, p| p.distance(line).0 < 0.01)
+        .unwrap();
+    assert_eq!(fit.inliers, (0..6).collect::<Vec<_>>());
+
+    let mut points: Vec<_> = [0.0, 1.0, 2.0, 3.0, 4.0].map(|t| point2(t, 2.0)).into();
+    points.extend([point2(1.0, 7.0), point2(3.0, -4.0)]);
+    let fit = ransac
+        .fit(&points, |line: &UnitLine<f64>, p| p.distance(line) < 0.01)
+        .unwrap();
+    assert_eq!(fit.inliers, (0..5).collect::<Vec<_>>());
+    assert_near(point2(10.0, 3.0).distance(&fit.model), 1.0);
+}
+
+#[test]
+fn ransac_needs_a_sample() {
+    let points = [point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0)];
+    assert_eq!(<Plane<f64> as Model<d3::Point<f64>>>::SAMPLE_SIZE, 3);
+    assert!(
+        Ransac::default()
+            .fit(&points, |_: &Plane<f64>, _| true)
+            .is_none()
+    );
+}
@@ -0,0 +1,18 @@
+use gual::{
+    Scalar,
+    geometry2d::{Bivector, Evenvector, Multivector, Vector},
+};
+
+#[test]
+fn scalar_minus_mixed_grades() {
+    let s = Scalar::<2, f64>::new(1.0);
+    let v = Vector::<f64>::new(2.0, 3.0);
+    let b = Bivector::<f64>::new(4.0);
+
+    let even: Evenvector<f64> = s - (s + b);
+    assert_eq!(even, Evenvector { s: 0.0, b: -b });
+
+    let multi: Multivector<f64> = s - (v + b);
+    assert_eq!(multi, s + -v + -b);
+    assert_eq!(v - s, -(s - v));
+}
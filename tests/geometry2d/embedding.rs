@@ -0,0 +1,48 @@
+use gual::{
+    Embed, Euclidean, Project, Projective, WedgeProduct,
+    geometry2d::{Bivector, Multivector, Vector},
+    geometry3d as d3,
+};
+use num::traits::ConstZero;
+
+use super::{BivectorIt, VectorIt};
+
+#[test]
+fn embedding_commutes_with_wedge() {
+    for v1 in VectorIt::new(10) {
+        let e1: d3::Vector<i32> = v1.embed();
+        for v2 in VectorIt::new(10) {
+            let e2: d3::Vector<i32> = v2.embed();
+            let b: d3::Bivector<i32> = v1.wedge(&v2).embed();
+            assert_eq!(b, e1.wedge(&e2));
+        }
+        for b in BivectorIt::new(10) {
+            let eb: d3::Bivector<i32> = b.embed();
+            // the plane has no room for a trivector
+            assert_eq!(e1.wedge(&eb), d3::Trivector::ZERO);
+        }
+    }
+}
+
+#[test]
+fn projection_undoes_the_embedding() {
+    for v in VectorIt::new(10) {
+        for b in BivectorIt::new(10) {
+            let m: Multivector<i32> = v + b;
+            let e: d3::Multivector<i32> = m.embed();
+            assert_eq!(e.project(), Some(m));
+        }
+    }
+    let out_of_plane = d3::Vector::<i32>::new(1, 2, 3);
+    assert_eq!(Project::<Vector<i32>>::project(&out_of_plane), None);
+    let out_of_plane = d3::Bivector::<i32>::new(1, 0, 3);
+    assert_eq!(Project::<Bivector<i32>>::project(&out_of_plane), None);
+}
+
+#[test]
+fn metric_cast_keeps_the_coefficients() {
+    let v = Vector::<i32, Euclidean>::new(1, 2);
+    let p: Vector<i32, Projective> = v.cast_metric();
+    assert_eq!(p, Vector::new(1, 2));
+    assert_eq!(p.cast_metric::<Euclidean>(), v);
+}
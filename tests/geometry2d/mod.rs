@@ -1,10 +1,12 @@
 use gual::geometry2d::{Bivector, Vector};
 
+mod add;
 mod antiwedge;
 mod complement;
 mod contraction;
 mod dot;
 mod dual;
+mod embedding;
 mod expansion;
 mod metric;
 mod norm;
@@ -0,0 +1,75 @@
+use gual::{
+    Projective, Scalar,
+    geometry3d::{Bivector, Evenvector, Multivector, Trivector, Vector},
+};
+
+#[test]
+fn mixed_grades_add_to_the_smallest_containing_type() {
+    let v = Vector::<f64>::new(1.0, 2.0, 3.0);
+    let b = Bivector::<f64>::new(4.0, 5.0, 6.0);
+    let t = Trivector::<f64>::new(7.0);
+    let s = Scalar::<3, f64>::new(8.0);
+
+    let vb: Multivector<f64> = v + b;
+    assert_eq!(
+        vb,
+        Multivector {
+            s: 0.0,
+            v,
+            b,
+            t: Trivector::new(0.0)
+        }
+    );
+    let vt: Multivector<f64> = t + v;
+    assert_eq!(vt, v + t);
+    let sb: Evenvector<f64> = s + b;
+    assert_eq!(sb, Evenvector { s: 8.0, b });
+    assert_eq!(s + b, b + s);
+
+    let all = s + v + b + t;
+    assert_eq!(all, Multivector { s: 8.0, v, b, t });
+    assert_eq!(all + t, t + all);
+}
+
+#[test]
+fn subtraction_is_the_addition_of_the_negation() {
+    let v = Vector::<f64, Projective>::new(1.0, 2.0, 3.0);
+    let b = Bivector::<f64, Projective>::new(4.0, 5.0, 6.0);
+    let s = Scalar::<3, f64, Projective>::new(8.0);
+    let even = s + b;
+
+    assert_eq!(s - even, s + -even);
+    assert_eq!(s - (v + b), s + -v + -b);
+    assert_eq!(even - v, even + -v);
+    assert_eq!(v - s, -(s - v));
+}
+
+#[test]
+fn compound_assignments_and_sums() {
+    let vectors = [
+        Vector::<f64>::new(1.0, 0.0, 0.0),
+        Vector::new(0.0, 2.0, 0.0),
+        Vector::new(0.0, 0.0, 3.0),
+    ];
+    let total: Vector<f64> = vectors.iter().sum();
+    assert_eq!(total, Vector::new(1.0, 2.0, 3.0));
+    assert_eq!(vectors.into_iter().sum::<Vector<f64>>(), total);
+
+    let mut m = Multivector::<f64>::XY;
+    m += Vector::X;
+    m -= Trivector::XYZ;
+    m *= 2.0;
+    assert_eq!(
+        m,
+        Multivector {
+            s: 0.0,
+            v: Vector::new(2.0, 0.0, 0.0),
+            b: Bivector::new(0.0, 0.0, 2.0),
+            t: Trivector::new(-2.0),
+        }
+    );
+
+    let mut s = Scalar::<3, f64>::new(1.0);
+    s += Scalar::new(2.0);
+    assert_eq!(s, Scalar::new(3.0));
+}
@@ -1,6 +1,8 @@
 use gual::{
     Angle, Epsilon, Euclidean, Projective,
     canonical::{Angle as CanonicalAngle, SymetricAngle},
+    geometry3d::{self, UnitBivector, UnitVector, Vector},
+    geometry4d,
 };
 
 use crate::geometry3d::{BivectorIt, ToF32, VectorIt};
@@ -65,13 +67,13 @@ fn euclidean_angle_bivector_vector() {
             let b = b.to_f32();
 
             let v = a.geometric_cosine(&b);
-            let r = a.canonical_geometric_cosine(&a);
+            let r = b.canonical_geometric_cosine(&a);
 
             assert!((v.0 - r.0).is_near_zero());
             assert!((v.1.xyz - r.1.xyz).is_near_zero());
 
             let v = a.cosine(&b);
-            let r = a.canonical_cosine(&a);
+            let r = b.canonical_cosine(&a);
 
             match (v, r) {
                 (Some(v), Some(r)) => assert!((v - r).is_near_zero()),
@@ -134,3 +136,42 @@ fn projective_angle_bivector_bivector() {
         }
     }
 }
+
+#[test]
+fn unit_angles_use_the_crate_scalar() {
+    let x = UnitVector::<f64>::X;
+    let y = UnitVector::try_from(Vector::new(1.0, 1.0, 0.0)).unwrap();
+
+    let cosine: Option<gual::Scalar<3, f64>> = x.cosine(&y);
+    assert!((cosine.unwrap().0 - 0.5f64.sqrt()).is_near_zero());
+    assert_eq!(
+        UnitBivector::<f64>::XY.cosine(&x),
+        x.cosine(&UnitBivector::XY)
+    );
+
+    let s: geometry3d::Scalar<f64> = 2.0.into();
+    assert_eq!(s, gual::Scalar::<3, f64>::new(2.0));
+    let s: geometry4d::Scalar<f64> = gual::Scalar::from(2.0);
+    assert_eq!(s.0, 2.0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn legacy_scalars_convert_to_the_crate_scalar() {
+    use gual::geometry3d::legacy::Scalar as Scalar3;
+    use gual::geometry4d::legacy::Scalar as Scalar4;
+
+    let s = Scalar3(2.0) * Scalar3(3.0) - Scalar3(1.0);
+    let new: geometry3d::Scalar<f64> = s.into();
+    assert_eq!(new, geometry3d::Scalar::new(5.0));
+    assert_eq!(Scalar3::from(new), Scalar3(5.0));
+
+    let cosine = UnitVector::<f64>::X
+        .cosine(&UnitVector::X)
+        .map(Scalar3::from);
+    assert_eq!(cosine, Some(Scalar3(1.0)));
+
+    let s: geometry4d::Scalar<f64> = (-Scalar4(2.0) + Scalar4(4.0)).into();
+    assert_eq!(s.0, 2.0);
+    assert_eq!(Scalar4::from(s), Scalar4(2.0));
+}
@@ -0,0 +1,72 @@
+use gual::{
+    Dot, WedgeProduct,
+    geometry3d::{Bivector, Trivector, Vector},
+};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn bivector_factors_wedge_back_to_it() {
+    let bivectors = [
+        Bivector::<f64>::new(1.0, 2.0, 3.0),
+        Bivector::new(0.0, 0.0, -2.0),
+        Bivector::new(5.0, -1.0, 0.0),
+        Vector::new(1.0, 1.0, 0.0).wedge(&Vector::new(0.0, 2.0, 1.0)),
+    ];
+    for b in bivectors {
+        assert!(b.is_blade());
+        let [u, v] = b.factorize();
+        let w = u.wedge(&v);
+        assert!(close(w.yz, b.yz) && close(w.zx, b.zx) && close(w.xy, b.xy));
+        assert!(close(u.dot(&v), 0.0));
+
+        let [e1, e2] = b.orthonormal_frame().unwrap();
+        assert!(close(e1.dot(&e1), 1.0) && close(e2.dot(&e2), 1.0) && close(e1.dot(&e2), 0.0));
+        let unit = e1.wedge(&e2);
+        let length = b.dot(&b).sqrt();
+        assert!(close(unit.yz * length, b.yz));
+        assert!(close(unit.zx * length, b.zx));
+        assert!(close(unit.xy * length, b.xy));
+    }
+    assert_eq!(
+        Bivector::<f64>::new(0.0, 0.0, 0.0).orthonormal_frame(),
+        None
+    );
+}
+
+#[test]
+fn bivector_factorization_is_scale_invariant() {
+    for scale in [0.01, 1e-4, 1e6] {
+        let b = Bivector::<f64>::new(1.0, -2.0, 0.5) * scale;
+        let [u, v] = b.factorize();
+        let w = u.wedge(&v);
+        assert!(close(w.yz / scale, b.yz / scale));
+        assert!(close(w.zx / scale, b.zx / scale));
+        assert!(close(w.xy / scale, b.xy / scale));
+        assert!(b.orthonormal_frame().is_some());
+    }
+    let [u, v] = Bivector::<f64>::new(0.0, 0.0, 0.0).factorize();
+    assert_eq!(
+        (u, v),
+        (Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0))
+    );
+}
+
+#[test]
+fn small_trivectors_keep_their_frame() {
+    let [e1, e2, e3] = Trivector::<f64>::new(1e-4).orthonormal_frame().unwrap();
+    assert_eq!(e1.wedge(&e2).wedge(&e3), Trivector::new(1.0));
+}
+
+#[test]
+fn trivector_factors_wedge_back_to_it() {
+    for t in [Trivector::<f64>::new(3.0), Trivector::new(-0.5)] {
+        let [a, b, c] = t.factorize();
+        assert_eq!(a.wedge(&b).wedge(&c), t);
+        let [e1, e2, e3] = t.orthonormal_frame().unwrap();
+        assert_eq!(e1.wedge(&e2).wedge(&e3), Trivector::new(t.xyz.signum()));
+    }
+    assert_eq!(Trivector::<f64>::new(0.0).orthonormal_frame(), None);
+}
@@ -0,0 +1,123 @@
+use gual::{
+    AnticommutatorProduct, CommutatorProduct, Euclidean, GeometricProduct, Projective,
+    geometry3d::{Bivector, Evenvector, Multivector, Trivector, Vector},
+};
+use num::traits::ConstZero;
+
+use crate::geometry3d::{BivectorIt, VectorIt};
+
+#[test]
+fn euclidean_bivector_bivector() {
+    for a in BivectorIt::<Euclidean>::new(4) {
+        for b in BivectorIt::<Euclidean>::new(4) {
+            let (ab, ba) = (a.geometric_product(&b), b.geometric_product(&a));
+            assert_eq!(ab.b - ba.b, a.commutator_product(&b) * 2);
+            assert_eq!(ab.s + ba.s, a.anticommutator_product(&b) * 2);
+            assert_eq!(ab.s - ba.s, 0);
+            assert_eq!(ab.b + ba.b, Bivector::ZERO);
+        }
+    }
+}
+
+#[test]
+fn projective_bivector_bivector() {
+    for a in BivectorIt::<Projective>::new(4) {
+        for b in BivectorIt::<Projective>::new(4) {
+            let (ab, ba) = (a.geometric_product(&b), b.geometric_product(&a));
+            assert_eq!(ab.b - ba.b, a.commutator_product(&b) * 2);
+            assert_eq!(ab.s + ba.s, a.anticommutator_product(&b) * 2);
+            assert_eq!(ab.s - ba.s, 0);
+            assert_eq!(ab.b + ba.b, Bivector::ZERO);
+        }
+    }
+}
+
+#[test]
+fn euclidean_bivector_vector() {
+    for b in BivectorIt::<Euclidean>::new(4) {
+        for v in VectorIt::<Euclidean>::new(4) {
+            let (bv, vb) = (b.geometric_product(&v), v.geometric_product(&b));
+            assert_eq!(
+                bv - vb,
+                Multivector {
+                    v: b.commutator_product(&v) * 2,
+                    ..Multivector::ZERO
+                }
+            );
+            assert_eq!(
+                bv + vb,
+                Multivector {
+                    t: b.anticommutator_product(&v) * 2,
+                    ..Multivector::ZERO
+                }
+            );
+            assert_eq!(v.commutator_product(&b), -b.commutator_product(&v));
+            assert_eq!(v.anticommutator_product(&b), b.anticommutator_product(&v));
+        }
+    }
+}
+
+#[test]
+fn projective_bivector_vector() {
+    for b in BivectorIt::<Projective>::new(4) {
+        for v in VectorIt::<Projective>::new(4) {
+            let (bv, vb) = (b.geometric_product(&v), v.geometric_product(&b));
+            assert_eq!(
+                bv - vb,
+                Multivector {
+                    v: b.commutator_product(&v) * 2,
+                    ..Multivector::ZERO
+                }
+            );
+            assert_eq!(
+                bv + vb,
+                Multivector {
+                    t: b.anticommutator_product(&v) * 2,
+                    ..Multivector::ZERO
+                }
+            );
+            assert_eq!(v.commutator_product(&b), -b.commutator_product(&v));
+            assert_eq!(v.anticommutator_product(&b), b.anticommutator_product(&v));
+        }
+    }
+}
+
+#[test]
+fn evenvector_evenvector() {
+    let even = |s: i32, b: Bivector<i32>| Evenvector { s, b };
+    for (i, a) in BivectorIt::<Euclidean>::new(3).enumerate() {
+        for (j, b) in BivectorIt::<Euclidean>::new(3).enumerate() {
+            let (a, b) = (even(i as i32 % 5 - 2, a), even(j as i32 % 3 - 1, b));
+            let (ma, mb) = (a + Multivector::ZERO, b + Multivector::ZERO);
+            let (ab, ba) = (ma.geometric_product(&mb), mb.geometric_product(&ma));
+            assert_eq!(ab - ba, a.commutator_product(&b) * 2 + Multivector::ZERO);
+            let anticommutator = a.anticommutator_product(&b);
+            assert_eq!(
+                ab + ba,
+                Multivector {
+                    s: anticommutator.s * 2,
+                    b: anticommutator.b * 2,
+                    ..Multivector::ZERO
+                }
+            );
+        }
+    }
+
+    let a = Evenvector::<i32, Projective> {
+        s: 2,
+        b: Bivector::new(1, -2, 3),
+    };
+    let b = Evenvector::<i32, Projective> {
+        s: -1,
+        b: Bivector::new(0, 4, -1),
+    };
+    assert_eq!(a.commutator_product(&b), a.b.commutator_product(&b.b));
+    assert_eq!(
+        a.anticommutator_product(&b),
+        Evenvector {
+            s: -2 + 3,
+            b: Bivector::new(-1, 8 + 2, -2 - 3),
+        }
+    );
+    let _: Trivector<i32> = Vector::X.anticommutator_product(&Bivector::<i32>::YZ);
+}
@@ -0,0 +1,60 @@
+use gual::{
+    Dot, Embed, Euclidean, Project, Projective, Scalar, WedgeProduct,
+    geometry3d::{Bivector, Multivector, Trivector, Vector},
+    geometry4d as d4,
+};
+
+use crate::geometry3d::{BivectorIt, VectorIt};
+
+#[test]
+fn embedding_in_4d_commutes_with_wedge() {
+    for v1 in VectorIt::<Euclidean>::new(5) {
+        let e1: d4::Vector<i32> = v1.embed();
+        for v2 in VectorIt::<Euclidean>::new(5) {
+            let e2: d4::Vector<i32> = v2.embed();
+            let b: d4::Bivector<i32> = v1.wedge(&v2).embed();
+            assert_eq!(b, e1.wedge(&e2));
+        }
+        for b in BivectorIt::<Euclidean>::new(5) {
+            let eb: d4::Bivector<i32> = b.embed();
+            let t: d4::Trivector<i32> = v1.wedge(&b).embed();
+            assert_eq!(t, e1.wedge(&eb));
+            assert_eq!(t, eb.wedge(&e1));
+        }
+    }
+}
+
+#[test]
+fn projection_from_4d_undoes_the_embedding() {
+    for v in VectorIt::<Euclidean>::new(5) {
+        for b in BivectorIt::<Euclidean>::new(5) {
+            let m = Scalar::<3, i32>::new(3) + v + b + Trivector::new(-2);
+            let e: d4::Multivector<i32> = m.embed();
+            assert_eq!(e.project(), Some(m));
+        }
+    }
+    let point = d4::Vector {
+        x: 1,
+        y: 2,
+        z: 3,
+        w: 1,
+    };
+    assert_eq!(Project::<Vector<i32>>::project(&point), None);
+}
+
+#[test]
+fn metric_cast_reinterprets_the_dot_product() {
+    let v = Vector::<f64, Euclidean>::new(1.0, 2.0, 3.0);
+    let p: Vector<f64, Projective> = v.cast_metric();
+    assert_eq!(v.dot(&v), 14.0);
+    assert_eq!(p.dot(&p), 5.0);
+    assert_eq!(p.cast_metric::<Euclidean>(), v);
+
+    let m: Multivector<f64> = v + Bivector::new(4.0, 5.0, 6.0);
+    let cast: Multivector<f64, Projective> = m.cast_metric();
+    assert_eq!(cast.cast_metric::<Euclidean>(), m);
+    assert_eq!(
+        Scalar::<3, f64>::new(2.0).cast_metric::<Projective>(),
+        Scalar::new(2.0)
+    );
+}
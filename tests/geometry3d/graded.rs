@@ -0,0 +1,70 @@
+use gual::{
+    Graded, Scalar,
+    geometry3d::{BasisBlade, Bivector, Multivector, Trivector, Vector},
+};
+use num::traits::ConstZero;
+
+#[test]
+fn grade_projection_keeps_only_one_grade() {
+    let v = Vector::<f64>::new(1.0, 2.0, 3.0);
+    let b = Bivector::<f64>::new(4.0, 5.0, 6.0);
+    let t = Trivector::<f64>::new(7.0);
+    let m = Scalar::<3, f64>::new(8.0) + v + b + t;
+
+    let zero = Multivector::<f64>::ZERO;
+
+    assert_eq!(m.grade(0), Multivector { s: 8.0, ..zero });
+    assert_eq!(m.grade(1), Multivector { v, ..zero });
+    assert_eq!(m.grade(2), Multivector { b, ..zero });
+    assert_eq!(m.grade(3), Multivector { t, ..zero });
+    assert_eq!(m.grade(4), zero);
+    assert_eq!(v.grade(1), v);
+    assert_eq!(v.grade(2), Vector::ZERO);
+}
+
+#[test]
+fn grades_lists_the_non_zero_grades() {
+    let v = Vector::<f64>::new(1.0, 0.0, 0.0);
+    let t = Trivector::<f64>::new(7.0);
+    let m: Multivector<f64> = v + t;
+
+    assert_eq!(m.grades(), vec![1, 3]);
+    assert!(!m.is_homogeneous());
+    assert_eq!(m.grade(3).grades(), vec![3]);
+    assert!(m.grade(3).is_homogeneous());
+    assert_eq!(Multivector::<f64>::ZERO.grades(), Vec::<u32>::new());
+    assert!(Multivector::<f64>::ZERO.is_homogeneous());
+}
+
+#[test]
+fn components_and_indexing_follow_the_basis_blades() {
+    let b = Bivector::<f64>::new(4.0, 5.0, 6.0);
+    assert_eq!(
+        b.components(),
+        vec![
+            (BasisBlade::YZ, 4.0),
+            (BasisBlade::ZX, 5.0),
+            (BasisBlade::XY, 6.0)
+        ]
+    );
+
+    let mut m = b + Multivector::<f64>::ZERO;
+    assert_eq!(m.components().len(), BasisBlade::ALL.len());
+    for (blade, coefficient) in m.components() {
+        assert_eq!(m[blade], coefficient);
+    }
+    m[BasisBlade::XYZ] = 2.0;
+    assert_eq!(m.t, Trivector::new(2.0));
+    assert!(BasisBlade::ALL.iter().all(|blade| {
+        m.components()
+            .iter()
+            .any(|(component, _)| component == blade)
+    }));
+}
+
+#[test]
+#[should_panic]
+fn indexing_a_missing_blade_panics() {
+    let v = Vector::<f64>::new(1.0, 2.0, 3.0);
+    let _ = v[BasisBlade::XY];
+}
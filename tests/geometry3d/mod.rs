@@ -2,15 +2,21 @@ use std::marker::PhantomData;
 
 use gual::geometry3d::*;
 
+mod add;
 mod angle;
 mod antiwedge;
+mod blade;
+mod commutator;
 mod complement;
 mod contraction;
 mod dot;
 mod dual;
+mod embedding;
 mod expansion;
+mod graded;
 mod metric;
 mod norm;
+mod subspace;
 mod wedge;
 
 struct ScalarIt {
@@ -0,0 +1,104 @@
+use gual::{
+    Graded, SubspaceJoin, SubspaceMeet, WedgeProduct,
+    geometry3d::{Bivector, Trivector, Vector},
+};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn join_of_overlapping_blades() {
+    let a = Vector::<f64>::new(1.0, 2.0, 0.0);
+    let twice_a = Vector::new(2.0, 4.0, 0.0);
+    let b = Vector::new(0.0, 1.0, 1.0);
+
+    // the wedge of parallel vectors is zero, but they still span a line
+    let line = a.subspace_join(&twice_a);
+    assert_eq!(line.grades(), vec![1]);
+    assert!(close(line.v.x * 2.0, line.v.y) && close(line.v.z, 0.0));
+    assert!(close(line.v.x * 5.0f64.sqrt(), 1.0));
+
+    let plane = a.subspace_join(&b);
+    assert_eq!(plane.grades(), vec![2]);
+    let wedge = a.wedge(&b);
+    let length = (wedge.yz * wedge.yz + wedge.zx * wedge.zx + wedge.xy * wedge.xy).sqrt();
+    assert!(close(plane.b.yz * length, wedge.yz));
+    assert!(close(plane.b.zx * length, wedge.zx));
+    assert!(close(plane.b.xy * length, wedge.xy));
+
+    // a vector already in the plane doesn't grow it
+    assert_eq!(wedge.subspace_join(&twice_a).grades(), vec![2]);
+    let space = wedge.subspace_join(&Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(space.grades(), vec![3]);
+    assert!(close(space.t.xyz, 1.0));
+}
+
+#[test]
+fn meet_of_overlapping_blades() {
+    let a = Vector::<f64>::new(1.0, 2.0, 0.0);
+    let b = Vector::new(0.0, 1.0, 1.0);
+
+    let origin = a.subspace_meet(&b);
+    assert_eq!(origin.grades(), vec![0]);
+    assert_eq!(origin.s, 1.0);
+
+    let common = a.subspace_meet(&Vector::new(-3.0, -6.0, 0.0));
+    assert_eq!(common.grades(), vec![1]);
+
+    // a line inside a plane meets it in the line itself
+    let plane = a.wedge(&b);
+    let line = plane.subspace_meet(&Vector::new(1.0, 3.0, 1.0));
+    assert_eq!(line.grades(), vec![1]);
+    assert!(close(line.v.x * 11.0f64.sqrt(), 1.0));
+
+    // two different planes meet in a line that lies in both
+    let other = Bivector::<f64>::new(0.0, 0.0, 1.0);
+    let line = plane.subspace_meet(&other);
+    assert_eq!(line.grades(), vec![1]);
+    assert!(line.v.wedge(&plane).xyz.abs() < 1e-9);
+    assert!(line.v.wedge(&other).xyz.abs() < 1e-9);
+
+    // the whole space meets a plane in the plane
+    assert_eq!(
+        Trivector::new(2.0).subspace_meet(&other).b,
+        Bivector::new(0.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+fn short_vectors_keep_their_direction() {
+    let short = Vector::<f64>::new(0.0005, 0.0, 0.0);
+    let y = Vector::new(0.0, 1.0, 0.0);
+    let plane = short.subspace_join(&y);
+    assert_eq!(plane.grades(), vec![2]);
+    assert!(close(plane.b.xy, 1.0));
+    assert_eq!(short.subspace_meet(&y).s, 1.0);
+    assert_eq!(
+        short.subspace_meet(&Vector::new(3.0, 0.0, 0.0)).grades(),
+        vec![1]
+    );
+
+    // tilts are measured against the length of the vectors, not in absolute terms
+    let tilted = Vector::new(1e-3, 1e-7, 0.0);
+    assert_eq!(short.subspace_join(&tilted).grades(), vec![1]);
+    let tilted = Vector::new(1e-3, 1e-5, 0.0);
+    assert_eq!(short.subspace_join(&tilted).grades(), vec![2]);
+}
+
+#[test]
+fn zero_blades_span_no_subspace() {
+    let zero = Vector::<f64>::new(0.0, 0.0, 0.0);
+    let y = Vector::new(0.0, 1.0, 0.0);
+    assert!(zero.subspace_join(&y).grades().is_empty());
+    assert!(zero.subspace_meet(&y).grades().is_empty());
+    assert!(y.subspace_meet(&zero).grades().is_empty());
+    let plane = Bivector::<f64>::new(0.0, 0.0, 0.0);
+    assert!(
+        plane
+            .subspace_join(&Trivector::new(1.0))
+            .grades()
+            .is_empty()
+    );
+    assert!(Trivector::new(0.0).subspace_meet(&y).grades().is_empty());
+}
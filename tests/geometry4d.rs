@@ -1,5 +1,8 @@
-use gual::{Antiscalar, KVector, WedgeProduct, geometry4d::*};
-use num::traits::ConstOne;
+use gual::{
+    AnticommutatorProduct, Antiscalar, CommutatorProduct, GeometricAntiproduct, GeometricProduct,
+    Graded, KVector, SubspaceJoin, SubspaceMeet, WedgeProduct, geometry4d::*,
+};
+use num::traits::{ConstOne, ConstZero};
 
 struct ScalarIt {
     s: i32,
@@ -77,7 +80,7 @@ impl Iterator for ScalarIt {
         if self.s < self.max {
             let s = self.s;
             self.s += 1;
-            Some(Scalar(s))
+            Some(Scalar::new(s))
         } else {
             None
         }
@@ -413,3 +416,314 @@ fn wedge_vector_trivector() {
 //         }
 //     }
 // }
+
+#[test]
+fn mixed_grades_add_and_subtract() {
+    let v = Vector {
+        x: 1,
+        y: 2,
+        z: 3,
+        w: 4,
+    };
+    let t = Trivector {
+        wyz: 5,
+        wzx: 6,
+        wxy: 7,
+        zyx: 8,
+    };
+    let even = Evenvector {
+        s: Scalar::new(9),
+        b: Bivector::ZERO,
+        a: Quadvector { xyzw: 10 },
+    };
+
+    let sum = v + even + t;
+    assert_eq!(
+        sum,
+        Multivector {
+            s: Scalar::new(9),
+            v,
+            b: Bivector::ZERO,
+            t,
+            a: Quadvector { xyzw: 10 },
+        }
+    );
+    assert_eq!(even + v, v + even);
+    assert_eq!(t - even, t + -even);
+    assert_eq!(Scalar::new(9) - v, -(v - Scalar::new(9)));
+    assert_eq!(sum - sum, Multivector::ZERO);
+
+    let mut total: Multivector<i32> = [v, v].into_iter().sum::<Vector<i32>>() + t;
+    total -= t;
+    total *= Scalar::new(3);
+    assert_eq!(
+        total.v,
+        Vector {
+            x: 6,
+            y: 12,
+            z: 18,
+            w: 24
+        }
+    );
+}
+
+#[test]
+fn graded_multivector_components() {
+    let mut m = Multivector::<i32>::ZERO;
+    for (coefficient, blade) in BasisBlade::ALL.into_iter().enumerate() {
+        m[blade] = coefficient as i32;
+    }
+    assert_eq!(m.s, Scalar::new(0));
+    assert_eq!(
+        m.v,
+        Vector {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4
+        }
+    );
+    assert_eq!(m.a, Quadvector { xyzw: 15 });
+    assert_eq!(m.grades(), vec![1, 2, 3, 4]);
+    assert_eq!(m.grade(3).t, m.t);
+    assert_eq!(m.grade(3).grades(), vec![3]);
+    assert_eq!(
+        m.components()
+            .into_iter()
+            .map(|(blade, coefficient)| blade.grade() * 100 + coefficient as u32)
+            .collect::<Vec<_>>(),
+        vec![
+            0, 101, 102, 103, 104, 205, 206, 207, 208, 209, 210, 311, 312, 313, 314, 415
+        ]
+    );
+    assert!(m.t.is_homogeneous());
+}
+
+fn close_bivectors(a: &Bivector<f64>, b: &Bivector<f64>) -> bool {
+    [
+        a.wx - b.wx,
+        a.wy - b.wy,
+        a.wz - b.wz,
+        a.yz - b.yz,
+        a.zx - b.zx,
+        a.xy - b.xy,
+    ]
+    .iter()
+    .all(|d| d.abs() < 1e-9)
+}
+
+#[test]
+fn bivector_factorization() {
+    let p = Vector {
+        x: 1.0,
+        y: 2.0,
+        z: -1.0,
+        w: 1.0,
+    };
+    let q = Vector {
+        x: 0.0,
+        y: 1.0,
+        z: 3.0,
+        w: 1.0,
+    };
+    let line: Bivector<f64> = p.wedge(&q);
+    assert!(line.is_blade());
+    let [a, b] = line.factorize().unwrap();
+    assert!(close_bivectors(&a.wedge(&b), &line));
+
+    let [e1, e2] = line.orthonormal_frame().unwrap();
+    let dot = |u: &Vector<f64>, v: &Vector<f64>| u.x * v.x + u.y * v.y + u.z * v.z + u.w * v.w;
+    assert!((dot(&e1, &e1) - 1.0).abs() < 1e-9);
+    assert!((dot(&e2, &e2) - 1.0).abs() < 1e-9);
+    assert!(dot(&e1, &e2).abs() < 1e-9);
+
+    let screw = line + Bivector::<f64>::XY + Bivector::WX;
+    assert!(!screw.is_blade());
+    assert_eq!(screw.factorize(), None);
+}
+
+#[test]
+fn bivector_factorization_is_scale_invariant() {
+    let line = Bivector::<f64> {
+        wx: 1.0,
+        wy: 2.0,
+        wz: 0.0,
+        yz: 0.0,
+        zx: 0.0,
+        xy: -0.5,
+    };
+    for scale in [0.01, 1.0, 1e4] {
+        let (scale, unscale) = (Scalar::new(scale), Scalar::new(1.0 / scale));
+        for blade in [scale * Bivector::XY, scale * line] {
+            assert!(blade.is_blade());
+            let [a, b] = blade.factorize().unwrap();
+            assert!(close_bivectors(
+                &(unscale * a.wedge(&b)),
+                &(unscale * blade)
+            ));
+            assert!(blade.orthonormal_frame().is_some());
+        }
+        // `B ^ B` is below the absolute tolerance at the smallest scale, but this is never a blade
+        let sum = Scalar::new(0.03) * scale * (Bivector::WX + Bivector::YZ);
+        assert!(!sum.is_blade());
+        assert_eq!(sum.factorize(), None);
+    }
+    assert!(Bivector::<f64>::ZERO.is_blade());
+    assert_eq!(
+        Bivector::<f64>::ZERO.factorize(),
+        Some([Vector::ZERO, Vector::ZERO])
+    );
+    assert_eq!(Bivector::<f64>::ZERO.orthonormal_frame(), None);
+}
+
+#[test]
+fn bivector_invariant_decomposition() {
+    let screw = Bivector::<f64> {
+        wx: 1.0,
+        wy: -2.0,
+        wz: 0.5,
+        yz: 3.0,
+        zx: 1.0,
+        xy: 2.0,
+    };
+    assert!(!screw.is_blade());
+    let (line, ideal) = screw.invariant_decomposition();
+    assert!(close_bivectors(&(line + ideal), &screw));
+    assert!(line.is_blade());
+    assert!(ideal.is_blade());
+    // they commute under the antiproduct, the one motors are built with
+    let (lhs, rhs) = (
+        line.geometric_antiproduct(&ideal),
+        ideal.geometric_antiproduct(&line),
+    );
+    assert!(close_bivectors(&lhs.b, &rhs.b));
+    assert!((lhs.s.0 - rhs.s.0).abs() < 1e-9);
+    assert!((lhs.a.xyzw - rhs.a.xyzw).abs() < 1e-9);
+
+    assert_eq!((ideal.wx, ideal.wy, ideal.wz), (0.0, 0.0, 0.0));
+    assert!((screw.pitch().unwrap() - ideal.yz / screw.wx).abs() < 1e-9);
+
+    let ideal_only = Bivector::<f64>::YZ + Bivector::XY;
+    assert_eq!(ideal_only.pitch(), None);
+    assert_eq!(
+        ideal_only.invariant_decomposition(),
+        (Bivector::ZERO, ideal_only)
+    );
+}
+
+#[test]
+fn trivector_factorization() {
+    let plane = Trivector::<f64> {
+        wyz: 1.0,
+        wzx: -2.0,
+        wxy: 0.5,
+        zyx: 3.0,
+    };
+    let [a, b, c] = plane.factorize();
+    let t = a.wedge(&b.wedge(&c));
+    for (x, y) in [
+        (t.wyz, plane.wyz),
+        (t.wzx, plane.wzx),
+        (t.wxy, plane.wxy),
+        (t.zyx, plane.zyx),
+    ] {
+        assert!((x - y).abs() < 1e-9);
+    }
+    let [e1, e2, e3] = plane.orthonormal_frame().unwrap();
+    let unit = e1.wedge(&e2.wedge(&e3));
+    let length = plane.wyz.hypot(plane.wzx).hypot(plane.wxy).hypot(plane.zyx);
+    assert!((unit.zyx * length - plane.zyx).abs() < 1e-9);
+    assert_eq!(Trivector::<f64>::ZERO.orthonormal_frame(), None);
+}
+
+#[test]
+fn subspace_join_and_meet() {
+    let planes_xy = Vector::<f64>::X.wedge(&Vector::Y);
+    let planes_yw = Vector::<f64>::Y.wedge(&Vector::W);
+
+    // they share the y direction, so they span a 3D subspace and meet in a line
+    let join = planes_xy.subspace_join(&planes_yw);
+    assert_eq!(join.grades(), vec![3]);
+    assert!(join.t.wedge(&Vector::Z).xyzw.abs() > 0.5);
+    let meet = planes_xy.subspace_meet(&planes_yw);
+    assert_eq!(meet.grades(), vec![1]);
+    assert!((meet.v.y.abs() - 1.0).abs() < 1e-9);
+
+    // a plane inside a 3D subspace
+    let space = Vector::<f64>::X.wedge(&planes_yw);
+    assert_eq!(planes_xy.subspace_join(&space).t, space);
+    assert_eq!(space.subspace_meet(&planes_xy).b, planes_xy);
+
+    // two 3D subspaces meet in a plane
+    let other = Vector::<f64>::Z.wedge(&planes_xy);
+    assert_eq!(space.subspace_meet(&other).grades(), vec![2]);
+    // the join keeps the orientation of the first blade: x ^ y ^ w ^ z
+    assert!((space.subspace_join(&other).a.xyzw + 1.0).abs() < 1e-9);
+    assert_eq!(
+        Vector::<f64>::X.subspace_meet(&Vector::Y).s,
+        Scalar::new(1.0)
+    );
+}
+
+#[test]
+fn subspace_of_short_and_zero_blades() {
+    let short = Scalar::new(5e-4) * Vector::<f64>::W;
+    assert_eq!(short.subspace_join(&Vector::X).grades(), vec![2]);
+    assert_eq!(short.subspace_meet(&Vector::X).s, Scalar::new(1.0));
+
+    // a zero blade spans no subspace, so it joins and meets to zero
+    let planes_xy = Vector::<f64>::X.wedge(&Vector::Y);
+    assert!(Vector::ZERO.subspace_join(&planes_xy).grades().is_empty());
+    assert!(planes_xy.subspace_meet(&Vector::ZERO).grades().is_empty());
+    assert!(
+        Quadvector::ZERO
+            .subspace_meet(&planes_xy)
+            .grades()
+            .is_empty()
+    );
+    assert!(
+        Bivector::ZERO
+            .subspace_join(&Vector::<f64>::X)
+            .grades()
+            .is_empty()
+    );
+}
+
+#[test]
+fn commutator_and_anticommutator() {
+    let two = Scalar::new(2);
+    for a in BivectorIt::new(2) {
+        for b in BivectorIt::new(2) {
+            let (ab, ba) = (a.geometric_product(&b), b.geometric_product(&a));
+            assert_eq!(ab - ba, (a.commutator_product(&b) * two) + Evenvector::ZERO);
+            assert_eq!(ab + ba, a.anticommutator_product(&b) * two);
+        }
+        for v in VectorIt::new(3) {
+            let (av, va) = (a.geometric_product(&v), v.geometric_product(&a));
+            assert_eq!(
+                av - va,
+                (a.commutator_product(&v) * two) + Multivector::ZERO
+            );
+            assert_eq!(
+                av + va,
+                (a.anticommutator_product(&v) * two) + Multivector::ZERO
+            );
+            assert_eq!(v.commutator_product(&a), -a.commutator_product(&v));
+            assert_eq!(v.anticommutator_product(&a), a.anticommutator_product(&v));
+        }
+    }
+
+    let a = Evenvector {
+        s: Scalar::new(2),
+        b: Bivector::WX + Bivector::YZ,
+        a: Quadvector { xyzw: 3 },
+    };
+    let b = Evenvector {
+        s: Scalar::new(-1),
+        b: Bivector::ZX + Bivector::WY + Bivector::XY,
+        a: Quadvector { xyzw: 1 },
+    };
+    let (ab, ba) = (a.geometric_product(&b), b.geometric_product(&a));
+    assert_eq!(ab - ba, (a.commutator_product(&b) * two) + Evenvector::ZERO);
+    assert_eq!(ab + ba, a.anticommutator_product(&b) * two);
+}
@@ -0,0 +1,1005 @@
+use gual::{
+    Angle, AntiwedgeProduct, Distance, GeometricAntiproduct, Join, Meet, Unitizable, WedgeProduct,
+    canonical::KVectorAntiwedge,
+    geometry3d as d3, geometry4d as d4,
+    homogeneous3d::{
+        HomogeneusLine, HomogeneusPlane, HomogeneusPoint, LeastSquaresError, Line, LineIncidence,
+        Motor, NormalizedLine, NormalizedPlane, NormalizedPoint, Plane, Twist, Wrench,
+    },
+};
+use num::traits::ConstZero;
+use std::f64::consts::FRAC_PI_2;
+
+fn points() -> Vec<HomogeneusPoint<f64>> {
+    let mut points = Vec::new();
+    for x in -1..=1 {
+        for y in -1..=1 {
+            for z in [-2, 0, 1] {
+                for w in [0, 1, 2] {
+                    points.push(HomogeneusPoint {
+                        x: x as f64,
+                        y: y as f64,
+                        z: z as f64,
+                        w: w as f64,
+                    });
+                }
+            }
+        }
+    }
+    points
+}
+
+fn planes() -> Vec<HomogeneusPlane<f64>> {
+    points()
+        .into_iter()
+        .map(|p| HomogeneusPlane {
+            wyz: p.x,
+            wzx: p.y,
+            wxy: p.z,
+            zyx: p.w,
+        })
+        .collect()
+}
+
+#[test]
+fn three_point_join_is_the_triple_wedge() {
+    let points = points();
+    for a in points.iter().step_by(5) {
+        for b in points.iter().step_by(7) {
+            for c in points.iter().step_by(3) {
+                let plane = HomogeneusPoint::join(*a, *b, *c);
+                assert_eq!(plane, a.wedge(b).wedge(c));
+                assert_eq!(plane, a.wedge(&b.wedge(c)));
+            }
+        }
+    }
+}
+
+#[test]
+fn three_plane_meet_is_the_triple_antiwedge() {
+    let planes = planes();
+    for a in planes.iter().step_by(5) {
+        for b in planes.iter().step_by(7) {
+            for c in planes.iter().step_by(3) {
+                let point = HomogeneusPlane::meet(*a, *b, *c);
+                assert_eq!(
+                    point,
+                    a.canonical_kvector_antiwedge(b)
+                        .canonical_kvector_antiwedge(c)
+                );
+                assert_eq!(point, a.antiwedge(b).antiwedge(c));
+            }
+        }
+    }
+}
+
+fn point(x: f64, y: f64, z: f64) -> HomogeneusPoint<f64> {
+    HomogeneusPoint { x, y, z, w: 1.0 }
+}
+
+#[test]
+fn triangle_plane_contains_its_vertices() {
+    let a = point(1.0, 0.0, 0.0);
+    let b = point(0.0, 2.0, 0.0);
+    let c = point(0.0, 0.0, 3.0);
+    let plane = HomogeneusPoint::try_join(a, b, c).unwrap();
+    for p in [a, b, c] {
+        assert_eq!(p.antiwedge(&plane).0, 0.0);
+    }
+
+    // the planes x = 1, y = 2 and z = 3 meet at (1, 2, 3)
+    let x = HomogeneusPlane {
+        wyz: 1.0,
+        wzx: 0.0,
+        wxy: 0.0,
+        zyx: -1.0,
+    };
+    let y = HomogeneusPlane {
+        wyz: 0.0,
+        wzx: 1.0,
+        wxy: 0.0,
+        zyx: -2.0,
+    };
+    let z = HomogeneusPlane {
+        wyz: 0.0,
+        wzx: 0.0,
+        wxy: 1.0,
+        zyx: -3.0,
+    };
+    let p = HomogeneusPlane::try_meet(x, y, z).unwrap();
+    assert_eq!((p.x / p.w, p.y / p.w, p.z / p.w), (1.0, 2.0, 3.0));
+}
+
+#[test]
+fn degenerate_join_and_meet() {
+    let a = point(0.0, 0.0, 0.0);
+    let b = point(1.0, 1.0, 1.0);
+    let c = point(2.0, 2.0, 2.0);
+    assert_eq!(HomogeneusPoint::try_join(a, b, c), None);
+    assert_eq!(HomogeneusPoint::try_join(a, a, b), None);
+
+    // planes x = 0, y = 0 and x + y = 0 share the z axis
+    let x = HomogeneusPlane {
+        wyz: 1.0,
+        wzx: 0.0,
+        wxy: 0.0,
+        zyx: 0.0,
+    };
+    let y = HomogeneusPlane {
+        wyz: 0.0,
+        wzx: 1.0,
+        wxy: 0.0,
+        zyx: 0.0,
+    };
+    assert_eq!(HomogeneusPlane::try_meet(x, y, x + y), None);
+    // parallel planes share a line at infinity
+    let x1 = HomogeneusPlane { zyx: -1.0, ..x };
+    let x2 = HomogeneusPlane { zyx: -2.0, ..x };
+    assert_eq!(HomogeneusPlane::try_meet(x, x1, x2), None);
+    assert!(HomogeneusPlane::try_meet(x, x1, y).is_some());
+}
+
+#[test]
+fn join_and_meet_do_not_depend_on_scale() {
+    let scale = |p: HomogeneusPoint<f64>, s: f64| HomogeneusPoint {
+        x: p.x * s,
+        y: p.y * s,
+        z: p.z * s,
+        w: p.w * s,
+    };
+    // a triangle 2 cm wide, and the same triangle far from the origin
+    for offset in [0.0, 1000.0] {
+        let a = point(offset, 0.0, 0.0);
+        let b = point(offset + 0.02, 0.0, 0.0);
+        let c = point(offset, 0.02, 0.0);
+        assert!(HomogeneusPoint::try_join(a, b, c).is_some());
+        // the weights of the points don't matter either
+        assert!(HomogeneusPoint::try_join(scale(a, 1e-6), scale(b, 1e6), c).is_some());
+        let d = point(offset + 0.01, 0.0, 0.0);
+        assert_eq!(
+            HomogeneusPoint::try_join(scale(a, 1e6), b, scale(d, 1e-6)),
+            None
+        );
+    }
+    // a point at infinity spans a plane with two points along another direction
+    let x = HomogeneusPoint {
+        x: 1e-6,
+        y: 0.0,
+        z: 0.0,
+        w: 0.0,
+    };
+    assert!(HomogeneusPoint::try_join(point(0.0, 0.0, 0.0), point(0.0, 0.02, 0.0), x).is_some());
+    assert_eq!(
+        HomogeneusPoint::try_join(point(0.0, 0.0, 0.0), point(0.02, 0.0, 0.0), x),
+        None
+    );
+
+    // planes 2 cm apart, scaled down, still meet at a point
+    let plane = |wyz: f64, wzx: f64, wxy: f64, zyx: f64| HomogeneusPlane { wyz, wzx, wxy, zyx };
+    let x = plane(1e-4, 0.0, 0.0, -0.02e-4);
+    let y = plane(0.0, 1e-4, 0.0, 0.0);
+    let z = plane(0.0, 0.0, 1e-4, 0.0);
+    let p = HomogeneusPlane::try_meet(x, y, z).unwrap();
+    assert!((p.x / p.w - 0.02).abs() < 1e-12);
+    // the z axis is on all three planes, however large they are
+    let x = plane(1e4, 0.0, 0.0, 0.0);
+    let y = plane(0.0, 1e-4, 0.0, 0.0);
+    assert_eq!(
+        HomogeneusPlane::try_meet(x, y, plane(1.0, 1.0, 0.0, 0.0)),
+        None
+    );
+}
+
+fn line(p: HomogeneusPoint<f64>, q: HomogeneusPoint<f64>) -> HomogeneusLine<f64> {
+    p.wedge(&q)
+}
+
+/// Checks that the plane is z = 0, up to its scale
+fn assert_is_xy_plane(plane: HomogeneusPlane<f64>) {
+    assert_eq!((plane.wyz, plane.wzx, plane.zyx), (0.0, 0.0, 0.0));
+    assert_ne!(plane.wxy, 0.0);
+}
+
+#[test]
+fn line_incidence() {
+    let origin = point(0.0, 0.0, 0.0);
+    let x_axis = line(origin, point(1.0, 0.0, 0.0));
+    let y_axis = line(origin, point(0.0, 1.0, 0.0));
+
+    match x_axis.meet(&y_axis) {
+        LineIncidence::Intersecting(p) => {
+            assert_eq!((p.x / p.w, p.y / p.w, p.z / p.w), (0.0, 0.0, 0.0))
+        }
+        other => panic!("expected an intersection, got {other:?}"),
+    }
+    assert_eq!(x_axis.join(&y_axis), x_axis.meet(&y_axis));
+
+    let shifted = line(point(0.0, 1.0, 0.0), point(3.0, 1.0, 0.0));
+    match x_axis.meet(&shifted) {
+        LineIncidence::Parallel(plane) => assert_is_xy_plane(plane),
+        other => panic!("expected parallel lines, got {other:?}"),
+    }
+    match shifted.join(&x_axis) {
+        LineIncidence::Parallel(plane) => assert_is_xy_plane(plane),
+        other => panic!("expected parallel lines, got {other:?}"),
+    }
+
+    let skew = line(point(0.0, 0.0, 1.0), point(0.0, 1.0, 1.0));
+    assert_eq!(x_axis.meet(&skew), LineIncidence::Skew);
+    assert_eq!(x_axis.join(&skew), LineIncidence::Skew);
+
+    let same = line(point(2.0, 0.0, 0.0), point(-1.0, 0.0, 0.0));
+    assert_eq!(x_axis.meet(&same), LineIncidence::Coincident);
+    assert_eq!(x_axis.join(&same), LineIncidence::Coincident);
+
+    let zero = HomogeneusLine::ZERO;
+    assert_eq!(x_axis.meet(&zero), LineIncidence::Degenerate);
+    assert_eq!(zero.join(&zero), LineIncidence::Degenerate);
+}
+
+#[test]
+fn line_incidence_does_not_depend_on_scale() {
+    let a = line(point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0));
+    let b = line(point(0.0, 0.0, 0.1), point(0.0, 1.0, 0.1));
+    let small = d4::Scalar::new(0.0001);
+    let big = d4::Scalar::new(10000.0);
+    assert_eq!(a.meet(&b), LineIncidence::Skew);
+    assert_eq!((a * small).meet(&(b * small)), LineIncidence::Skew);
+    assert_eq!((a * big).meet(&(b * big)), LineIncidence::Skew);
+    assert_eq!((a * small).meet(&(a * big)), LineIncidence::Coincident);
+}
+
+#[test]
+fn line_incidence_does_not_depend_on_position() {
+    // skew lines 5 apart, 100 away from the origin
+    let a = line(point(100.0, 0.0, 0.0), point(100.0, 1.0, 0.0));
+    let b = line(point(0.0, 100.0, 5.0), point(1.0, 100.0, 5.0));
+    assert_eq!(a.meet(&b), LineIncidence::Skew);
+    assert_eq!(a.join(&b), LineIncidence::Skew);
+
+    // lines crossing far from the origin
+    let x_axis = line(point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0));
+    let c = line(point(2000.0, 0.0, 0.0), point(2000.0, 1.0, 0.0));
+    match x_axis.meet(&c) {
+        LineIncidence::Intersecting(p) => assert!((p.x / p.w - 2000.0).abs() < 1e-9),
+        other => panic!("expected an intersection, got {other:?}"),
+    }
+
+    // parallel lines 2 cm apart, and the same line written with other points
+    let d = line(point(1000.0, 0.02, 0.0), point(1003.0, 0.02, 0.0));
+    assert!(matches!(x_axis.meet(&d), LineIncidence::Parallel(_)));
+    let e = line(point(1000.0, 0.0, 0.0), point(-5.0, 0.0, 0.0));
+    assert_eq!(x_axis.meet(&e), LineIncidence::Coincident);
+}
+
+#[test]
+fn normalized_incidence_matches_homogeneous() {
+    let origin = point(0.0, 0.0, 0.0);
+    let a = line(origin, point(1.0, 2.0, 0.0));
+    let b = line(point(1.0, 1.0, 0.0), point(2.0, 3.0, 0.0));
+    let horizon = line(
+        HomogeneusPoint::from(d3::Vector::new(1.0, 0.0, 0.0)),
+        HomogeneusPoint::from(d3::Vector::new(0.0, 1.0, 0.0)),
+    );
+    let (Some(NormalizedLine::Line(na)), Some(NormalizedLine::Line(nb))) =
+        (a.unitize(), b.unitize())
+    else {
+        panic!("finite lines unitize to lines");
+    };
+    let Some(NormalizedLine::HorizonLine(nh)) = horizon.unitize() else {
+        panic!("lines at infinity unitize to horizon lines");
+    };
+
+    let homogeneous = |l: NormalizedLine<f64>| match l {
+        NormalizedLine::Line(l) => HomogeneusLine::from(l),
+        NormalizedLine::HorizonLine(l) => HomogeneusLine::from(l),
+    };
+    let (ha, hb, hh) = (
+        homogeneous(NormalizedLine::Line(na)),
+        homogeneous(NormalizedLine::Line(nb)),
+        homogeneous(NormalizedLine::HorizonLine(nh)),
+    );
+    assert_eq!(na.meet(&nb), ha.meet(&hb));
+    assert_eq!(na.join(&nb), ha.join(&hb));
+    assert_eq!(na.meet(&nh), ha.meet(&hh));
+    assert_eq!(nh.join(&na), hh.join(&ha));
+    match na.meet(&nh) {
+        LineIncidence::Parallel(plane) => assert_is_xy_plane(plane),
+        other => panic!("expected parallel lines, got {other:?}"),
+    }
+
+    let x = HomogeneusPlane {
+        wyz: 1.0,
+        wzx: 0.0,
+        wxy: 0.0,
+        zyx: -1.0,
+    };
+    let y = HomogeneusPlane {
+        wyz: 0.0,
+        wzx: 2.0,
+        wxy: 0.0,
+        zyx: 0.0,
+    };
+    let (Some(NormalizedPlane::Plane(nx)), Some(NormalizedPlane::Plane(ny))) =
+        (x.unitize(), y.unitize())
+    else {
+        panic!("finite planes unitize to planes");
+    };
+    let z_line = nx.meet(&ny);
+    assert_eq!(
+        z_line,
+        HomogeneusPlane::from(nx).meet(&HomogeneusPlane::from(ny))
+    );
+    assert_eq!(nx.meet(&na), HomogeneusPlane::from(nx).meet(&ha));
+    assert_eq!(nh.meet(&ny), hh.meet(&HomogeneusPlane::from(ny)));
+}
+
+fn plane(a: f64, b: f64, c: f64, d: f64) -> HomogeneusPlane<f64> {
+    HomogeneusPlane {
+        wyz: a,
+        wzx: b,
+        wxy: c,
+        zyx: d,
+    }
+}
+
+fn direction(x: f64, y: f64, z: f64) -> HomogeneusPoint<f64> {
+    HomogeneusPoint { x, y, z, w: 0.0 }
+}
+
+fn assert_near(value: f64, expected: f64) {
+    assert!(
+        (value - expected).abs() < 1e-9,
+        "expected {expected}, got {value}"
+    );
+}
+
+#[test]
+fn distance_between_finite_primitives() {
+    let origin = point(0.0, 0.0, 0.0);
+    let x_axis = line(origin, point(1.0, 0.0, 0.0));
+    let half = d4::Scalar::new(0.5);
+
+    assert_near(origin.distance(&point(1.0, 2.0, 2.0)).0, 3.0);
+    assert_near(
+        (origin * d4::Scalar::new(2.0))
+            .distance(&point(1.0, 2.0, 2.0))
+            .0,
+        3.0,
+    );
+    assert_near(point(3.0, 0.0, 4.0).distance(&x_axis).0, 4.0);
+    assert_near(x_axis.distance(&point(3.0, 0.0, 4.0)).0, 4.0);
+    assert_near(
+        point(1.0, 2.0, 3.0).distance(&plane(0.0, 0.0, 2.0, -2.0)).0,
+        2.0,
+    );
+
+    // skew, intersecting and parallel lines, with opposite directions and scales
+    let skew = line(point(0.0, 0.0, 1.0), point(0.0, 1.0, 1.0));
+    assert_near(x_axis.distance(&skew).0, 1.0);
+    assert_near(x_axis.distance(&line(origin, point(0.0, 1.0, 0.0))).0, 0.0);
+    let parallel = line(point(5.0, 3.0, 4.0), point(-1.0, 3.0, 4.0));
+    assert_near(x_axis.distance(&parallel).0, 5.0);
+    assert_near((parallel * half).distance(&x_axis).0, 5.0);
+    assert_near(x_axis.distance(&x_axis).0, 0.0);
+
+    // lines and planes
+    let floor = plane(0.0, 0.0, 1.0, 2.0);
+    assert_near(x_axis.distance(&floor).0, 2.0);
+    assert_near(floor.distance(&(parallel * half)).0, 6.0);
+    assert_near(skew.distance(&plane(0.0, 1.0, 1.0, 0.0)).0, 0.0);
+
+    // planes
+    assert_near(floor.distance(&plane(0.0, 0.0, -3.0, 3.0)).0, 3.0);
+    assert_near(floor.distance(&(floor * d4::Scalar::new(4.0))).0, 0.0);
+    assert_near(floor.distance(&plane(1.0, 0.0, 1.0, 0.0)).0, 0.0);
+
+    // the side of a plane and the orientation of skew lines give the sign
+    assert_near(
+        point(1.0, 2.0, -3.0)
+            .distance(&plane(0.0, 0.0, 2.0, -2.0))
+            .0,
+        -4.0,
+    );
+    assert_near(floor.distance(&plane(0.0, 0.0, 1.0, 5.0)).0, -3.0);
+    assert_near(x_axis.distance(&-skew).0, -1.0);
+    assert_near(
+        line(point(0.0, 0.0, -5.0), point(1.0, 0.0, -5.0))
+            .distance(&floor)
+            .0,
+        -3.0,
+    );
+}
+
+#[test]
+fn nearly_parallel_primitives_keep_their_separation() {
+    let x_axis = line(point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0));
+    let floor = plane(0.0, 0.0, 1.0, 2.0);
+    let large = d4::Scalar::new(1000.0);
+    let small = d4::Scalar::new(0.001);
+
+    // rounding leaves a tiny slope between primitives that are meant to be parallel
+    let parallel = line(point(5.0, 3.0, 4.0), point(-1.0, 3.0, 4.0 + 1e-9));
+    assert_near(x_axis.distance(&parallel).0, 5.0);
+    assert_near((x_axis * large).distance(&(parallel * small)).0, 5.0);
+
+    let tilted_floor = plane(1e-9, 0.0, 1.0, 2.0);
+    assert_near(x_axis.distance(&tilted_floor).0, 2.0);
+    assert_near((x_axis * small).distance(&(tilted_floor * large)).0, 2.0);
+
+    let tilted_ceiling = plane(1e-9, 0.0, -3.0, 3.0);
+    assert_near(floor.distance(&tilted_ceiling).0, 3.0);
+    assert_near((floor * large).distance(&(tilted_ceiling * small)).0, 3.0);
+
+    // a slope above the tolerance still means that they intersect
+    assert_near(x_axis.distance(&plane(0.1, 0.0, 1.0, 2.0)).0, 0.0);
+    assert_near(floor.distance(&plane(0.1, 0.0, -3.0, 3.0)).0, 0.0);
+}
+
+#[test]
+fn distance_at_infinity() {
+    let origin = point(0.0, 0.0, 0.0);
+    let x_axis = line(origin, point(1.0, 0.0, 0.0));
+    let x = direction(1.0, 0.0, 0.0);
+    let y = direction(0.0, 1.0, 0.0);
+    let horizon_xy = line(x, y);
+    let horizon = plane(0.0, 0.0, 0.0, 1.0);
+
+    let (bulk, weight) = origin.geometric_distance(&x);
+    assert!(bulk.0 > 0.0);
+    assert_eq!(weight.xyzw, 0.0);
+    assert_eq!(origin.distance(&x).0, f64::INFINITY);
+
+    // points at infinity are only at distance zero from what goes through them
+    assert_eq!(x.distance(&(x * d4::Scalar::new(-2.0))).0, 0.0);
+    assert_eq!(x.distance(&y).0, f64::INFINITY);
+    assert_eq!(x.distance(&x_axis).0, 0.0);
+    assert_eq!(y.distance(&x_axis).0, f64::INFINITY);
+    assert_eq!(x.distance(&horizon_xy).0, 0.0);
+    assert_eq!(
+        direction(0.0, 0.0, 1.0).distance(&horizon_xy).0,
+        f64::INFINITY
+    );
+    assert_eq!(x.distance(&plane(0.0, 0.0, 1.0, 1.0)).0, 0.0);
+    assert_eq!(x.distance(&plane(1.0, 0.0, 0.0, 1.0)).0, f64::INFINITY);
+    assert_eq!(x.distance(&horizon).0, 0.0);
+
+    // finite primitives meet the horizon, except points
+    assert_eq!(origin.distance(&horizon).0, f64::INFINITY);
+    assert_eq!(origin.distance(&horizon_xy).0, f64::INFINITY);
+    assert_eq!(x_axis.distance(&horizon).0, 0.0);
+    assert_eq!(x_axis.distance(&horizon_xy).0, 0.0);
+    // skew lines keep their relative orientation as the sign
+    assert_eq!(
+        line(origin, point(0.0, 0.0, 1.0)).distance(&horizon_xy).0,
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        horizon_xy.distance(&line(y, direction(0.0, 0.0, 1.0))).0,
+        0.0
+    );
+    assert_eq!(horizon_xy.distance(&plane(1.0, 0.0, 0.0, 5.0)).0, 0.0);
+    assert_eq!(horizon.distance(&plane(1.0, 0.0, 0.0, 5.0)).0, 0.0);
+    assert_eq!(horizon.distance(&horizon).0, 0.0);
+}
+
+#[test]
+fn geometric_distance_is_never_undefined() {
+    let points = points();
+    let lines: Vec<_> = points
+        .iter()
+        .step_by(4)
+        .flat_map(|p| points.iter().step_by(5).map(move |q| p.wedge(q)))
+        .filter(|l| *l != HomogeneusLine::ZERO)
+        .collect();
+    let points: Vec<_> = points
+        .into_iter()
+        .filter(|p| *p != HomogeneusPoint::ZERO)
+        .collect();
+    let planes: Vec<_> = planes()
+        .into_iter()
+        .filter(|p| *p != HomogeneusPlane::ZERO)
+        .collect();
+
+    let defined = |(bulk, weight): (d4::Scalar<f64>, d4::Quadvector<f64>)| {
+        !bulk.0.is_nan() && !weight.xyzw.is_nan() && (bulk.0 != 0.0 || weight.xyzw != 0.0)
+    };
+    for p in &points {
+        for q in &points {
+            assert!(defined(p.geometric_distance(q)), "{p:?} {q:?}");
+        }
+        for l in &lines {
+            assert!(defined(p.geometric_distance(l)), "{p:?} {l:?}");
+        }
+        for e in &planes {
+            assert!(defined(p.geometric_distance(e)), "{p:?} {e:?}");
+        }
+    }
+    for l in &lines {
+        for m in &lines {
+            assert!(defined(l.geometric_distance(m)), "{l:?} {m:?}");
+        }
+        for e in &planes {
+            assert!(defined(l.geometric_distance(e)), "{l:?} {e:?}");
+        }
+    }
+    for e in &planes {
+        for f in &planes {
+            assert!(defined(e.geometric_distance(f)), "{e:?} {f:?}");
+        }
+    }
+}
+
+#[test]
+fn normalized_distance_matches_homogeneous() {
+    let origin = point(0.0, 0.0, 0.0);
+    let Some(NormalizedPoint::Point(p)) = point(1.0, 2.0, 2.0).unitize() else {
+        panic!("finite points unitize to points");
+    };
+    let Some(NormalizedPoint::DirVector(x)) = direction(3.0, 0.0, 0.0).unitize() else {
+        panic!("points at infinity unitize to directions");
+    };
+    let Some(NormalizedLine::Line(axis)) = line(origin, point(0.0, 2.0, 0.0)).unitize() else {
+        panic!("finite lines unitize to lines");
+    };
+    let Some(NormalizedLine::HorizonLine(horizon_xy)) =
+        line(direction(1.0, 0.0, 0.0), direction(0.0, 1.0, 0.0)).unitize()
+    else {
+        panic!("lines at infinity unitize to horizon lines");
+    };
+    let Some(NormalizedPlane::Plane(floor)) = plane(0.0, 0.0, 2.0, 2.0).unitize() else {
+        panic!("finite planes unitize to planes");
+    };
+    let horizon = NormalizedPlane::<f64>::Horizon;
+
+    assert_near(p.distance(&origin).0, 3.0);
+    assert_eq!(p.distance(&NormalizedPoint::DirVector(x)).0, f64::INFINITY);
+    assert_near(NormalizedPoint::Point(p).distance(&axis).0, 5.0_f64.sqrt());
+    assert_near(p.distance(&floor).0, 3.0);
+    assert_near(axis.distance(&floor).0, 1.0);
+    assert_near(floor.distance(&axis).0, 1.0);
+    assert_eq!(
+        NormalizedPoint::DirVector(x).distance(&axis).0,
+        f64::INFINITY
+    );
+    assert_eq!(NormalizedPoint::DirVector(x).distance(&horizon_xy).0, 0.0);
+    assert_eq!(horizon_xy.distance(&floor).0, 0.0);
+    assert_eq!(horizon.distance(&floor).0, 0.0);
+    assert_eq!(
+        horizon.distance(&NormalizedPoint::Point(p)).0,
+        f64::INFINITY
+    );
+    assert_eq!(
+        NormalizedLine::HorizonLine(horizon_xy).distance(&horizon).0,
+        0.0
+    );
+    assert_eq!(
+        p.geometric_distance(&floor),
+        HomogeneusPoint::from(p).geometric_distance(&HomogeneusPlane::from(floor))
+    );
+}
+
+#[test]
+fn angle_between_primitives() {
+    let origin = point(0.0, 0.0, 0.0);
+    let x_axis = line(origin, point(1.0, 0.0, 0.0));
+    let z_axis = line(origin, point(0.0, 0.0, 3.0));
+    let diagonal = line(point(0.0, 0.0, 1.0), point(1.0, 1.0, 1.0));
+    let floor = plane(0.0, 0.0, 1.0, 2.0);
+    let wall = plane(1.0, 0.0, 0.0, 0.0);
+    let horizon_xy = line(direction(1.0, 0.0, 0.0), direction(0.0, 1.0, 0.0));
+    let horizon = plane(0.0, 0.0, 0.0, 1.0);
+    let x = direction(2.0, 0.0, 0.0);
+
+    assert_near(x_axis.cosine(&z_axis).unwrap().0, 0.0);
+    assert_near(x_axis.cosine(&diagonal).unwrap().0, 0.5_f64.sqrt());
+    assert_near(floor.cosine(&wall).unwrap().0, 0.0);
+    assert_near(floor.cosine(&x_axis).unwrap().0, 1.0);
+    assert_near(z_axis.cosine(&floor).unwrap().0, 0.0);
+
+    // directions behave as lines and horizon lines as the planes they bound
+    assert_near(x.cosine(&x_axis).unwrap().0, 1.0);
+    assert_near(x.cosine(&diagonal).unwrap().0, 0.5_f64.sqrt());
+    assert_near(x.cosine(&wall).unwrap().0, 0.0);
+    assert_near(horizon_xy.cosine(&floor).unwrap().0, 1.0);
+    assert_near(horizon_xy.cosine(&wall).unwrap().0, 0.0);
+    assert_near(z_axis.cosine(&horizon_xy).unwrap().0, 0.0);
+
+    // finite points and the horizon have no attitude, their angles are undefined
+    for cosine in [
+        origin.cosine(&x_axis),
+        origin.cosine(&point(1.0, 2.0, 3.0)),
+        origin.cosine(&x),
+        wall.cosine(&point(1.0, 2.0, 3.0)),
+        horizon.cosine(&x_axis),
+        horizon.cosine(&horizon),
+    ] {
+        assert_eq!(cosine, None);
+    }
+    assert_eq!(x_axis.cosine(&HomogeneusLine::ZERO), None);
+}
+
+#[test]
+fn normalized_angle_matches_homogeneous() {
+    let origin = point(0.0, 0.0, 0.0);
+    let a = line(origin, point(1.0, 2.0, 0.0));
+    let b = plane(0.0, 3.0, 4.0, 1.0);
+    let Some(NormalizedLine::Line(na)) = a.unitize() else {
+        panic!("finite lines unitize to lines");
+    };
+    let Some(NormalizedPlane::Plane(nb)) = b.unitize() else {
+        panic!("finite planes unitize to planes");
+    };
+    assert_near(na.cosine(&nb).unwrap().0, a.cosine(&b).unwrap().0);
+    assert_near(nb.cosine(&na).unwrap().0, b.cosine(&a).unwrap().0);
+    assert_near(
+        NormalizedLine::Line(na)
+            .cosine(&NormalizedPlane::Plane(nb))
+            .unwrap()
+            .0,
+        a.cosine(&b).unwrap().0,
+    );
+    assert_eq!(NormalizedPlane::<f64>::Horizon.cosine(&na), None);
+}
+
+fn unit_line(p: HomogeneusPoint<f64>, q: HomogeneusPoint<f64>) -> Line<f64> {
+    match line(p, q).unitize() {
+        Some(NormalizedLine::Line(line)) => line,
+        other => panic!("expected a finite line, got {other:?}"),
+    }
+}
+
+fn assert_point(point: d3::Point<f64>, x: f64, y: f64, z: f64) {
+    assert_near(point.0.x, x);
+    assert_near(point.0.y, y);
+    assert_near(point.0.z, z);
+}
+
+#[test]
+fn closest_points_between_lines() {
+    let x_axis = unit_line(point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0));
+    let skew = unit_line(point(2.0, 0.0, 3.0), point(2.0, 1.0, 3.0));
+
+    let (on_x, on_skew) = Line::closest_points(&x_axis, &skew);
+    assert_point(on_x, 2.0, 0.0, 0.0);
+    assert_point(on_skew, 2.0, 0.0, 3.0);
+    let normal = Line::common_normal(&x_axis, &skew).unwrap();
+    assert_near(normal.distance(&on_x).0, 0.0);
+    assert_near(normal.distance(&on_skew).0, 0.0);
+    assert_near(normal.cosine(&x_axis).unwrap().0, 0.0);
+    assert_near(normal.cosine(&skew).unwrap().0, 0.0);
+
+    // intersecting lines meet at their closest points
+    let crossing = unit_line(point(1.0, -1.0, 0.0), point(3.0, 1.0, 0.0));
+    let (on_x, on_crossing) = Line::closest_points(&x_axis, &crossing);
+    assert_point(on_x, 2.0, 0.0, 0.0);
+    assert_point(on_crossing, 2.0, 0.0, 0.0);
+    let normal = Line::common_normal(&x_axis, &crossing).unwrap();
+    assert_near(normal.distance(&on_x).0, 0.0);
+    assert_near(normal.cosine(&x_axis).unwrap().0, 0.0);
+
+    // parallel lines have no unique common normal
+    let parallel = unit_line(point(5.0, 3.0, 4.0), point(-1.0, 3.0, 4.0));
+    assert_eq!(Line::common_normal(&x_axis, &parallel), None);
+    let (on_x, on_parallel) = Line::closest_points(&x_axis, &parallel);
+    assert_point(on_x, 0.0, 0.0, 0.0);
+    assert_point(on_parallel, 0.0, 3.0, 4.0);
+}
+
+#[test]
+fn foot_points() {
+    let x_axis = unit_line(point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0));
+    let target = d3::Point(d3::Vector::new(4.0, 2.0, -1.0));
+    assert_point(x_axis.foot_of(&target), 4.0, 0.0, 0.0);
+
+    let Some(NormalizedPlane::Plane(floor)) = plane(0.0, 0.0, 1.0, 2.0).unitize() else {
+        panic!("finite planes unitize to planes");
+    };
+    assert_point(floor.foot_of_line(&x_axis), 0.0, 0.0, -2.0);
+    let slanted = unit_line(point(1.0, 1.0, 0.0), point(1.0, 1.0, 1.0));
+    assert_point(floor.foot_of_line(&slanted), 1.0, 1.0, -2.0);
+}
+
+fn unit_plane(a: f64, b: f64, c: f64, d: f64) -> Plane<f64> {
+    match plane(a, b, c, d).unitize() {
+        Some(NormalizedPlane::Plane(plane)) => plane,
+        other => panic!("expected a finite plane, got {other:?}"),
+    }
+}
+
+#[test]
+fn least_squares_point_of_lines() {
+    let target = point(1.0, 2.0, 3.0);
+    let lines = [
+        unit_line(target, point(2.0, 2.0, 3.0)),
+        unit_line(point(1.0, 0.0, 3.0), target),
+        unit_line(target, point(4.0, 5.0, 6.0)),
+    ];
+    let (NormalizedPoint::Point(p), residual) = Line::nearest_point_to_all(&lines).unwrap() else {
+        panic!("the lines meet at a finite point");
+    };
+    assert_point(p, 1.0, 2.0, 3.0);
+    assert_near(residual, 0.0);
+
+    // two skew lines: the midpoint of their closest points, and a weight pulls it to a line
+    let x_axis = unit_line(point(0.0, 0.0, 0.0), point(1.0, 0.0, 0.0));
+    let skew = unit_line(point(0.0, 0.0, 2.0), point(0.0, 1.0, 2.0));
+    let (NormalizedPoint::Point(p), residual) =
+        Line::nearest_point_to_all(&[x_axis, skew]).unwrap()
+    else {
+        panic!("skew lines have a finite nearest point");
+    };
+    assert_point(p, 0.0, 0.0, 1.0);
+    assert_near(residual, 1.0);
+    let (NormalizedPoint::Point(p), _) =
+        Line::weighted_nearest_point_to_all(&[x_axis, skew], &[3.0, 1.0]).unwrap()
+    else {
+        panic!("skew lines have a finite nearest point");
+    };
+    assert_point(p, 0.0, 0.0, 0.5);
+
+    // rays from a stereo pair with a 10 cm baseline meeting 5 m away
+    let target = point(0.3, -0.2, 5.0);
+    let rays = [
+        unit_line(point(-0.05, 0.0, 0.0), target),
+        unit_line(point(0.05, 0.0, 0.0), target),
+    ];
+    let (NormalizedPoint::Point(p), residual) = Line::nearest_point_to_all(&rays).unwrap() else {
+        panic!("the rays meet at a finite point");
+    };
+    assert_point(p, 0.3, -0.2, 5.0);
+    assert_near(residual, 0.0);
+
+    let parallel = unit_line(point(0.0, 1.0, 0.0), point(1.0, 1.0, 0.0));
+    assert_eq!(
+        Line::nearest_point_to_all(&[x_axis, parallel]),
+        Err(LeastSquaresError::RankDeficient { rank: 2 })
+    );
+    assert_eq!(
+        Line::<f64>::nearest_point_to_all(&[]),
+        Err(LeastSquaresError::RankDeficient { rank: 0 })
+    );
+    assert_eq!(
+        Line::weighted_nearest_point_to_all(&[x_axis, skew], &[1.0]),
+        Err(LeastSquaresError::InvalidWeights)
+    );
+    assert_eq!(
+        Line::weighted_nearest_point_to_all(&[x_axis, skew], &[1.0, -1.0]),
+        Err(LeastSquaresError::InvalidWeights)
+    );
+}
+
+#[test]
+fn least_squares_meet_of_planes() {
+    let planes = [
+        unit_plane(1.0, 0.0, 0.0, -1.0),
+        unit_plane(0.0, 2.0, 0.0, -4.0),
+        unit_plane(0.0, 0.0, 1.0, -3.0),
+        unit_plane(1.0, 1.0, 1.0, -6.0),
+    ];
+    let (NormalizedPoint::Point(p), residual) = Plane::least_squares_meet(&planes).unwrap() else {
+        panic!("the planes meet at a finite point");
+    };
+    assert_point(p, 1.0, 2.0, 3.0);
+    assert_near(residual, 0.0);
+
+    // x = 0 and x = 2 pull the point to x = 1, each one at distance 1
+    let planes = [
+        unit_plane(1.0, 0.0, 0.0, 0.0),
+        unit_plane(1.0, 0.0, 0.0, -2.0),
+        unit_plane(0.0, 1.0, 0.0, 0.0),
+        unit_plane(0.0, 0.0, 1.0, 0.0),
+    ];
+    let (NormalizedPoint::Point(p), residual) =
+        Plane::weighted_least_squares_meet(&planes, &[1.0, 1.0, 1.0, 1.0]).unwrap()
+    else {
+        panic!("the planes meet at a finite point");
+    };
+    assert_point(p, 1.0, 0.0, 0.0);
+    assert_near(residual, 0.5_f64.sqrt());
+
+    assert_eq!(
+        Plane::least_squares_meet(&planes[..2]),
+        Err(LeastSquaresError::RankDeficient { rank: 1 })
+    );
+    assert_eq!(
+        Plane::least_squares_meet(&planes[..3]),
+        Err(LeastSquaresError::RankDeficient { rank: 2 })
+    );
+}
+
+#[test]
+fn primitives_add_as_homogeneous_kvectors() {
+    let origin = point(0.0, 0.0, 0.0);
+    let a = line(origin, point(1.0, 0.0, 0.0));
+    let b = line(point(0.0, 1.0, 1.0), point(0.0, 2.0, 1.0));
+    let horizon = line(
+        HomogeneusPoint::from(d3::Vector::new(1.0, 0.0, 0.0)),
+        HomogeneusPoint::from(d3::Vector::new(0.0, 1.0, 0.0)),
+    );
+    let (Some(NormalizedLine::Line(na)), Some(NormalizedLine::Line(nb))) =
+        (a.unitize(), b.unitize())
+    else {
+        panic!("finite lines unitize to lines");
+    };
+    let Some(NormalizedLine::HorizonLine(nh)) = horizon.unitize() else {
+        panic!("lines at infinity unitize to horizon lines");
+    };
+
+    let (ha, hb, hh) = (
+        HomogeneusLine::from(na),
+        HomogeneusLine::from(nb),
+        HomogeneusLine::from(nh),
+    );
+    assert_eq!(na + nb, ha + hb);
+    assert_eq!(na - nh, ha - hh);
+    assert_eq!(-na + na, HomogeneusLine::ZERO);
+
+    let Some(NormalizedPlane::Plane(floor)) = origin
+        .wedge(&point(1.0, 0.0, 0.0))
+        .wedge(&point(0.0, 1.0, 0.0))
+        .unitize()
+    else {
+        panic!("finite planes unitize to planes");
+    };
+    let floor_plane = HomogeneusPlane::from(floor);
+    assert_eq!(floor + floor, floor_plane + floor_plane);
+    assert_eq!(na + floor, ha + floor_plane);
+    assert_eq!(floor - -floor, floor_plane + floor_plane);
+}
+
+fn assert_same_line(a: NormalizedLine<f64>, b: Line<f64>) {
+    let (NormalizedLine::Line(a), b) = (a, HomogeneusLine::from(b)) else {
+        panic!("expected a finite line, got {a:?}");
+    };
+    let a = HomogeneusLine::from(a);
+    for (x, y) in [
+        (a.wx, b.wx),
+        (a.wy, b.wy),
+        (a.wz, b.wz),
+        (a.yz, b.yz),
+        (a.zx, b.zx),
+        (a.xy, b.xy),
+    ] {
+        assert_near(x, y);
+    }
+}
+
+#[test]
+fn motor_sandwich() {
+    let axis = d3::UnitVector::try_from(d3::Vector::new(1.0, 2.0, -2.0)).unwrap();
+    let rotation = Motor::rotation(&axis, 0.7);
+    let translation = Motor::translation(&d3::Vector::new(3.0, -1.0, 4.0));
+    let motor = translation.geometric_antiproduct(&rotation);
+    let (p, q) = (point(1.0, 2.0, 3.0), point(-2.0, 0.5, 1.0));
+    let moved = |p: HomogeneusPoint<f64>| {
+        let p = d3::Point(d3::Vector::new(p.x, p.y, p.z));
+        translation.transform_point(&rotation.transform_point(&p))
+    };
+
+    // the composed motor applies the rotation first
+    for r in [p, q] {
+        let m = moved(r);
+        assert_point(
+            motor.transform_point(&d3::Point(d3::Vector::new(r.x, r.y, r.z))),
+            m.0.x,
+            m.0.y,
+            m.0.z,
+        );
+    }
+    // lines move with their points
+    let (mp, mq) = (moved(p).0, moved(q).0);
+    assert_same_line(
+        motor.transform_line(&line(p, q)).unitize().unwrap(),
+        unit_line(point(mp.x, mp.y, mp.z), point(mq.x, mq.y, mq.z)),
+    );
+    // the antireverse undoes the motor
+    let back = motor.antireverse();
+    assert_point(
+        back.transform_point(&motor.transform_point(&d3::Point(d3::Vector::new(1.0, 2.0, 3.0)))),
+        1.0,
+        2.0,
+        3.0,
+    );
+    // unitizing a scaled motor gives back the same motion
+    let scaled = (motor * 3.0).unitize().unwrap();
+    let p = d3::Point(d3::Vector::new(1.0, 2.0, 3.0));
+    let m = motor.transform_point(&p).0;
+    assert_point(scaled.transform_point(&p), m.x, m.y, m.z);
+    assert!(Motor::from(d4::Evenvector::<f64>::ZERO).unitize().is_none());
+}
+
+#[test]
+fn screw_pitch_and_axis() {
+    let axis = unit_line(point(1.0, 2.0, 0.0), point(1.0, 2.0, 1.0));
+    let twist = Twist::from_line(&axis, 0.5) * 3.0;
+    assert_near(twist.pitch().unwrap(), 0.5);
+    assert_same_line(twist.axis().unwrap(), axis);
+    // the point at the origin turns around the axis and slides along it
+    let linear = twist.linear();
+    assert_point(d3::Point(linear), 6.0, -3.0, 1.5);
+
+    let wrench = Wrench::from_line(&axis, -2.0);
+    assert_near(wrench.pitch().unwrap(), -2.0);
+    assert_same_line(wrench.axis().unwrap(), axis);
+
+    let slide = Twist::new(&d3::Vector::ZERO, &d3::Vector::new(0.0, 0.0, 2.0));
+    assert_eq!(slide.pitch(), None);
+    assert!(matches!(slide.axis(), Some(NormalizedLine::HorizonLine(_))));
+    let zero = Wrench::<f64>::new(&d3::Vector::ZERO, &d3::Vector::ZERO);
+    assert_eq!(zero.axis(), None);
+}
+
+#[test]
+fn screw_reciprocal_product_is_power() {
+    let spin = Twist::new(&d3::Vector::new(0.0, 0.0, 1.0), &d3::Vector::ZERO);
+    // the point (0, 1, 0) moves with velocity (-1, 0, 0), against a unit force along x
+    let push = unit_line(point(0.0, 1.0, 0.0), point(1.0, 1.0, 0.0));
+    let wrench = Wrench::from_line(&push, 0.0);
+    assert_near(wrench.torque().z, -1.0);
+    assert_near(spin.reciprocal_product(&wrench), -1.0);
+    assert_near(wrench.reciprocal_product(&spin), -1.0);
+
+    // a force along the rotation axis does no work
+    let axial = Wrench::new(&d3::Vector::new(0.0, 0.0, 4.0), &d3::Vector::ZERO);
+    assert_near(spin.reciprocal_product(&axial), 0.0);
+    // a pure torque does work against a rotation around the same direction
+    let torque = Wrench::new(&d3::Vector::ZERO, &d3::Vector::new(0.0, 0.0, 3.0));
+    assert_near(spin.reciprocal_product(&torque), 3.0);
+}
+
+#[test]
+fn screw_transform_by_motor() {
+    let spin = Twist::new(&d3::Vector::new(0.0, 0.0, 1.0), &d3::Vector::ZERO);
+    let wrench = Wrench::new(
+        &d3::Vector::new(1.0, -1.0, 2.0),
+        &d3::Vector::new(0.5, 0.0, 1.0),
+    );
+    let axis = d3::UnitVector::try_from(d3::Vector::new(1.0, 2.0, -2.0)).unwrap();
+    let moved = spin.transform(&Motor::translation(&d3::Vector::new(1.0, 2.0, 0.0)));
+    assert_same_line(
+        moved.axis().unwrap(),
+        unit_line(point(1.0, 2.0, 0.0), point(1.0, 2.0, 1.0)),
+    );
+
+    let motor = Motor::rotation(&axis, 0.7);
+    let moved = Motor::translation(&d3::Vector::new(3.0, -1.0, 4.0));
+    for m in [motor, moved] {
+        let (t, w) = (spin.transform(&m), wrench.transform(&m));
+        assert_near(t.reciprocal_product(&w), spin.reciprocal_product(&wrench));
+        assert_near(w.pitch().unwrap(), wrench.pitch().unwrap());
+    }
+}
+
+#[test]
+fn twist_exponential() {
+    let spin = Twist::new(&d3::Vector::new(0.0, 0.0, FRAC_PI_2), &d3::Vector::ZERO);
+    let quarter = Motor::rotation(&d3::UnitVector::Z, FRAC_PI_2);
+    let p = d3::Point(d3::Vector::new(1.0, 0.0, 5.0));
+    let q = spin.exp().transform_point(&p).0;
+    let r = quarter.transform_point(&p).0;
+    assert_point(d3::Point(q), r.x, r.y, r.z);
+    assert_point(d3::Point(q), 0.0, 1.0, 5.0);
+
+    let slide = Twist::new(&d3::Vector::ZERO, &d3::Vector::new(1.0, -2.0, 3.0));
+    assert_point(slide.exp().transform_point(&p), 2.0, -2.0, 8.0);
+
+    // a quarter turn around a vertical axis through (1, 0, 0), rising 0.5 per radian
+    let axis = unit_line(point(1.0, 0.0, 0.0), point(1.0, 0.0, 1.0));
+    let screw = Twist::from_line(&axis, 0.5) * FRAC_PI_2;
+    let p = d3::Point(d3::Vector::new(2.0, 0.0, 0.0));
+    assert_point(
+        screw.exp().transform_point(&p),
+        1.0,
+        1.0,
+        0.25 * std::f64::consts::PI,
+    );
+    // points on the axis only slide along it
+    let on_axis = d3::Point(d3::Vector::new(1.0, 0.0, -1.0));
+    assert_point(
+        screw.exp().transform_point(&on_axis),
+        1.0,
+        0.0,
+        -1.0 + 0.25 * std::f64::consts::PI,
+    );
+}
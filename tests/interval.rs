@@ -0,0 +1,192 @@
+use gual::{
+    Distance, Epsilon, Meet, OrthogonalProjection, Unitizable, UnitizeError, WedgeProduct,
+    ZeroTest,
+    homogeneous3d::{
+        HomogeneusLine, HomogeneusPlane, HomogeneusPoint, NormalizedPlane, NormalizedPoint,
+    },
+    interval::Interval,
+};
+use num::Float;
+use std::cmp::Ordering;
+
+type I = Interval<f64>;
+
+fn point(x: f64, y: f64, z: f64, w: f64) -> HomogeneusPoint<I> {
+    HomogeneusPoint {
+        x: I::point(x),
+        y: I::point(y),
+        z: I::point(z),
+        w: I::point(w),
+    }
+}
+
+fn midpoint(p: &HomogeneusPoint<I>) -> HomogeneusPoint<f64> {
+    HomogeneusPoint {
+        x: p.x.midpoint(),
+        y: p.y.midpoint(),
+        z: p.z.midpoint(),
+        w: p.w.midpoint(),
+    }
+}
+
+#[test]
+fn interval_arithmetic_encloses_exact_result() {
+    let tenth = I::enclosing(0.1);
+    let mut sum = I::point(0.0);
+    for _ in 0..10 {
+        sum = sum + tenth;
+    }
+    assert!(sum.contains(1.0));
+
+    let root = I::point(2.0).sqrt();
+    assert!((root * root).contains(2.0));
+
+    let third = I::point(1.0) / I::point(3.0);
+    assert!((third * I::point(3.0)).contains(1.0));
+    assert!(third.lo() < third.hi());
+
+    let x = I::new(-1.0, 2.0);
+    assert!((x * x).contains(4.0));
+    assert!((x * x).contains(-2.0));
+    assert!(x.powi(2).contains(0.0));
+    assert!(!x.powi(2).contains(-0.5));
+    assert!((I::point(1.0) / x).contains(f64::INFINITY));
+}
+
+#[test]
+fn interval_elementary_functions() {
+    let pi = I::point(-1.0).acos();
+    assert!(pi.contains(std::f64::consts::PI));
+    assert!(pi.sin().contains(0.0));
+    assert!(pi.cos().contains(-1.0));
+
+    let c = I::new(-0.5, 0.5).cos();
+    assert!(c.contains(1.0));
+    assert!(c.lo() > 0.8);
+
+    assert!(I::new(1.0, 2.0).tan().is_infinite());
+    assert!(I::point(1.0).exp().ln().contains(1.0));
+}
+
+#[test]
+fn interval_zero_test_is_three_valued() {
+    let eps = f64::eps();
+    assert_eq!(I::point(0.0).zero_test(), ZeroTest::Zero);
+    assert_eq!(I::new(-eps / 2.0, eps / 2.0).zero_test(), ZeroTest::Zero);
+    assert_eq!(I::new(1.0, 2.0).zero_test(), ZeroTest::NonZero);
+    assert_eq!(I::new(-2.0, -1.0).zero_test(), ZeroTest::NonZero);
+    assert_eq!(I::new(-1.0, 1.0).zero_test(), ZeroTest::Unknown);
+    assert_eq!(I::new(eps / 2.0, 1.0).zero_test(), ZeroTest::Unknown);
+
+    assert!(I::new(-1.0, 1.0).is_near_zero());
+    assert!(!I::new(1.0, 2.0).is_near_zero());
+
+    assert_eq!(0.0_f64.zero_test(), ZeroTest::Zero);
+    assert_eq!(1.0_f64.zero_test(), ZeroTest::NonZero);
+}
+
+#[test]
+fn interval_ordering_is_certain() {
+    assert!(I::new(0.0, 1.0) < I::new(2.0, 3.0));
+    assert!(I::new(2.0, 3.0) > I::new(0.0, 1.0));
+    assert_eq!(I::new(0.0, 2.0).partial_cmp(&I::new(1.0, 3.0)), None);
+    // equality is certain too, and agrees with the ordering
+    assert!(I::new(0.0, 2.0) != I::new(0.0, 2.0));
+    assert_eq!(I::new(0.0, 2.0).partial_cmp(&I::new(0.0, 2.0)), None);
+    assert!(I::point(1.0) == I::point(1.0));
+    assert_eq!(
+        I::point(1.0).partial_cmp(&I::point(1.0)),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn interval_sqrt_of_negative() {
+    let root = I::new(-2.0, -1.0).sqrt();
+    assert!(root.lo().is_nan() && root.hi().is_nan());
+    let root = I::new(-1.0, 4.0).sqrt();
+    assert!(root.contains(0.0) && root.contains(2.0));
+}
+
+#[test]
+fn interval_distance() {
+    let a = point(1.0, 2.0, 2.0, 1.0);
+    let b = point(0.0, 0.0, 0.0, 1.0);
+    let (distance, weight) = a.geometric_distance(&b);
+    assert!(distance.0.contains(3.0));
+    assert!((distance.0 * distance.0).contains(9.0));
+    assert!(weight.xyzw.contains(1.0));
+}
+
+#[test]
+fn interval_meet_and_projection() {
+    let p = point(0.1, 0.2, 0.3, 1.0);
+    let q = point(-0.7, 1.3, 2.9, 1.0);
+    let r = point(5.0, -0.3, 0.1, 1.0);
+    let plane: HomogeneusPlane<I> = p.wedge(&q).wedge(&r);
+    let line: HomogeneusLine<I> = point(1.0, 1.0, 1.0, 1.0).wedge(&point(-3.0, 0.5, 7.0, 1.0));
+
+    let exact_plane: HomogeneusPlane<f64> = midpoint(&p).wedge(&midpoint(&q)).wedge(&midpoint(&r));
+    let exact_line: HomogeneusLine<f64> = HomogeneusPoint {
+        x: 1.0,
+        y: 1.0,
+        z: 1.0,
+        w: 1.0,
+    }
+    .wedge(&HomogeneusPoint {
+        x: -3.0,
+        y: 0.5,
+        z: 7.0,
+        w: 1.0,
+    });
+
+    let meet = plane.meet(&line);
+    let exact_meet = exact_plane.meet(&exact_line);
+    assert!(meet.x.contains(exact_meet.x));
+    assert!(meet.y.contains(exact_meet.y));
+    assert!(meet.z.contains(exact_meet.z));
+    assert!(meet.w.contains(exact_meet.w));
+
+    let s = point(3.0, 4.0, -5.0, 1.0);
+    let projection = s.orthogonal_projection(&plane);
+    let exact_projection = midpoint(&s).orthogonal_projection(&exact_plane);
+    assert!(projection.x.contains(exact_projection.x));
+    assert!(projection.y.contains(exact_projection.y));
+    assert!(projection.z.contains(exact_projection.z));
+    assert!(projection.w.contains(exact_projection.w));
+}
+
+#[test]
+fn interval_unitize_reports_ambiguity() {
+    let mut p = point(1.0, 0.0, 0.0, 0.0);
+    assert!(matches!(p.try_unitize(), Ok(NormalizedPoint::DirVector(_))));
+
+    p.w = I::point(2.0);
+    assert!(matches!(p.try_unitize(), Ok(NormalizedPoint::Point(_))));
+
+    p.w = I::new(-0.5, 0.5);
+    assert_eq!(p.try_unitize(), Err(UnitizeError::Ambiguous));
+    assert_eq!(p.unitize(), None);
+
+    let p = point(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(p.try_unitize(), Err(UnitizeError::Degenerate));
+
+    let horizon = HomogeneusPlane {
+        wyz: I::point(0.0),
+        wzx: I::point(0.0),
+        wxy: I::point(0.0),
+        zyx: I::point(1.0),
+    };
+    assert_eq!(horizon.try_unitize(), Ok(NormalizedPlane::Horizon));
+    let zero = HomogeneusPlane {
+        zyx: I::point(0.0),
+        ..horizon
+    };
+    assert_eq!(zero.try_unitize(), Err(UnitizeError::Degenerate));
+
+    let uncertain = HomogeneusPlane {
+        wyz: I::new(-0.5, 0.5),
+        ..horizon
+    };
+    assert_eq!(uncertain.try_unitize(), Err(UnitizeError::Ambiguous));
+}
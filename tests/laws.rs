@@ -0,0 +1,439 @@
+use gual::{
+    AntiwedgeProduct, Complement, Distance, Dot, GeometricAntiproduct, GeometricProduct, Join,
+    KVector, Meet, Projective, Transform, Unitizable, WedgeProduct, geometry2d as d2,
+    geometry3d as d3, geometry4d as d4,
+    homogeneous3d::{
+        HomogeneusLine, HomogeneusPlane, HomogeneusPoint, Line, Motor, NormalizedLine, Plane,
+        Twist, Wrench,
+    },
+    projective2d::{Conic, ConicIntersection, Homography, MappedPoint, Point},
+};
+
+type Vector3 = d3::Vector<f64, Projective>;
+type Line2 = d3::Bivector<f64, Projective>;
+use proptest::prelude::*;
+
+/// Relative comparison, `scale` is the magnitude of the terms that were added to get the values
+fn close(a: f64, b: f64, scale: f64) -> bool {
+    (a - b).abs() <= 1e-9 * (1.0 + scale)
+}
+
+fn close_all(a: &[f64], b: &[f64], scale: f64) -> bool {
+    a.iter().zip(b).all(|(a, b)| close(*a, *b, scale))
+}
+
+fn magnitude(a: &[f64]) -> f64 {
+    a.iter().map(|a| a.abs()).sum()
+}
+
+fn components2(m: &d2::Multivector<f64>) -> [f64; 4] {
+    [m.s, m.v.x, m.v.y, m.b.xy]
+}
+
+fn components3(m: &d3::Multivector<f64>) -> [f64; 8] {
+    [m.s, m.v.x, m.v.y, m.v.z, m.b.yz, m.b.zx, m.b.xy, m.t.xyz]
+}
+
+fn even4(e: &d4::Evenvector<f64>) -> [f64; 8] {
+    [
+        e.s.0, e.b.wx, e.b.wy, e.b.wz, e.b.yz, e.b.zx, e.b.xy, e.a.xyzw,
+    ]
+}
+
+fn point3(p: &d3::Point<f64>) -> [f64; 3] {
+    [p.0.x, p.0.y, p.0.z]
+}
+
+fn vector3(v: &Vector3) -> [f64; 3] {
+    [v.x, v.y, v.z]
+}
+
+fn bivector3(b: &Line2) -> [f64; 3] {
+    [b.yz, b.zx, b.xy]
+}
+
+fn screw(weight: &d3::Vector<f64>, bulk: &d3::Vector<f64>) -> [f64; 6] {
+    [weight.x, weight.y, weight.z, bulk.x, bulk.y, bulk.z]
+}
+
+fn twist(t: &Twist<f64>) -> [f64; 6] {
+    screw(&t.angular(), &t.linear())
+}
+
+fn wrench(w: &Wrench<f64>) -> [f64; 6] {
+    screw(&w.force(), &w.torque())
+}
+
+fn vector4(v: &d4::Vector<f64>) -> [f64; 4] {
+    [v.x, v.y, v.z, v.w]
+}
+
+fn bivector4(b: &d4::Bivector<f64>) -> [f64; 6] {
+    [b.wx, b.wy, b.wz, b.yz, b.zx, b.xy]
+}
+
+fn trivector4(t: &d4::Trivector<f64>) -> [f64; 4] {
+    [t.wyz, t.wzx, t.wxy, t.zyx]
+}
+
+/// Whether `a` and `b` are as far from each other in both orders, the signed distance from `b` to
+/// `a` being `sign` times the one from `a` to `b`
+fn symmetric<A, B>(a: &A, b: &B, sign: f64, scale: f64) -> bool
+where
+    A: Distance<B, Scalar = d4::Scalar<f64>, Antiscalar = d4::Quadvector<f64>>,
+    B: Distance<A, Scalar = d4::Scalar<f64>, Antiscalar = d4::Quadvector<f64>>,
+{
+    let (a_bulk, a_weight) = a.geometric_distance(b);
+    let (b_bulk, b_weight) = b.geometric_distance(a);
+    close(a_bulk.0, sign * b_bulk.0, scale) && close(a_weight.xyzw, b_weight.xyzw, scale)
+}
+
+/// Sign relating the signed distances between two planes in both orders: each plane is on the side
+/// of the other its normal points away from if their normals agree, and on the same side if they
+/// are opposite
+fn side_flip(e: &HomogeneusPlane<f64>, f: &HomogeneusPlane<f64>) -> f64 {
+    -(e.wyz * f.wyz + e.wzx * f.wzx + e.wxy * f.wxy).signum()
+}
+
+proptest! {
+    #[test]
+    fn geometric_product_is_associative_2d(
+        a in any::<d2::Multivector<f64>>(),
+        b in any::<d2::Multivector<f64>>(),
+        c in any::<d2::Multivector<f64>>(),
+    ) {
+        let left = a.geometric_product(&b).geometric_product(&c);
+        let right = a.geometric_product(&b.geometric_product(&c));
+        let scale = magnitude(&components2(&a)) * magnitude(&components2(&b)) * magnitude(&components2(&c));
+        prop_assert!(close_all(&components2(&left), &components2(&right), scale));
+    }
+
+    #[test]
+    fn geometric_product_is_associative_3d(
+        a in any::<d3::Multivector<f64>>(),
+        b in any::<d3::Multivector<f64>>(),
+        c in any::<d3::Multivector<f64>>(),
+    ) {
+        let left = a.geometric_product(&b).geometric_product(&c);
+        let right = a.geometric_product(&b.geometric_product(&c));
+        let scale = magnitude(&components3(&a)) * magnitude(&components3(&b)) * magnitude(&components3(&c));
+        prop_assert!(close_all(&components3(&left), &components3(&right), scale));
+    }
+
+    #[test]
+    fn geometric_product_is_associative_4d(
+        a in any::<d4::Evenvector<f64>>(),
+        b in any::<d4::Evenvector<f64>>(),
+        c in any::<d4::Evenvector<f64>>(),
+    ) {
+        let left = a.geometric_product(&b).geometric_product(&c);
+        let right = a.geometric_product(&b.geometric_product(&c));
+        let scale = magnitude(&even4(&a)) * magnitude(&even4(&b)) * magnitude(&even4(&c));
+        prop_assert!(close_all(&even4(&left), &even4(&right), scale));
+    }
+
+    #[test]
+    fn antiwedge_is_dual_of_wedge_2d(
+        a in any::<d2::Multivector<f64>>(),
+        b in any::<d2::Multivector<f64>>(),
+    ) {
+        let antiwedge = a.antiwedge(&b);
+        let right = a.right_complement().wedge(&b.right_complement()).left_complement();
+        let left = a.left_complement().wedge(&b.left_complement()).right_complement();
+        let scale = magnitude(&components2(&a)) * magnitude(&components2(&b));
+        prop_assert!(close_all(&components2(&antiwedge), &components2(&right), scale));
+        prop_assert!(close_all(&components2(&antiwedge), &components2(&left), scale));
+    }
+
+    #[test]
+    fn antiwedge_is_dual_of_wedge_3d(
+        a in any::<d3::Multivector<f64>>(),
+        b in any::<d3::Multivector<f64>>(),
+    ) {
+        let antiwedge = a.antiwedge(&b);
+        let right = a.right_complement().wedge(&b.right_complement()).left_complement();
+        let left = a.left_complement().wedge(&b.left_complement()).right_complement();
+        let scale = magnitude(&components3(&a)) * magnitude(&components3(&b));
+        prop_assert!(close_all(&components3(&antiwedge), &components3(&right), scale));
+        prop_assert!(close_all(&components3(&antiwedge), &components3(&left), scale));
+    }
+
+    #[test]
+    fn antiwedge_is_dual_of_wedge_4d(
+        a in any::<d4::Bivector<f64>>(),
+        b in any::<d4::Trivector<f64>>(),
+    ) {
+        let antiwedge = a.antiwedge(&b);
+        let right = KVector::left_complement(&KVector::right_complement(&a).wedge(&KVector::right_complement(&b)));
+        let scale = magnitude(&bivector4(&a)) * magnitude(&trivector4(&b));
+        prop_assert!(close_all(&vector4(&antiwedge), &vector4(&right), scale));
+
+        let antiwedge = b.antiwedge(&a);
+        let right = KVector::left_complement(&KVector::right_complement(&b).wedge(&KVector::right_complement(&a)));
+        prop_assert!(close_all(&vector4(&antiwedge), &vector4(&right), scale));
+    }
+
+    #[test]
+    fn join_contains_its_points(
+        p in any::<HomogeneusPoint<f64>>(),
+        q in any::<HomogeneusPoint<f64>>(),
+        r in any::<HomogeneusPoint<f64>>(),
+    ) {
+        let line: HomogeneusLine<f64> = p.join(&q);
+        let scale = magnitude(&vector4(&p)) * magnitude(&bivector4(&line));
+        prop_assert!(close_all(&trivector4(&p.wedge(&line)), &[0.0; 4], scale));
+        let scale = magnitude(&vector4(&q)) * magnitude(&bivector4(&line));
+        prop_assert!(close_all(&trivector4(&q.wedge(&line)), &[0.0; 4], scale));
+
+        let plane: HomogeneusPlane<f64> = line.join(&r);
+        let scale = magnitude(&bivector4(&line)) * magnitude(&vector4(&r));
+        let incidence = |s: &HomogeneusPoint<f64>| s.wedge(&plane).xyzw;
+        prop_assert!(close(incidence(&p), 0.0, scale * magnitude(&vector4(&p))));
+        prop_assert!(close(incidence(&q), 0.0, scale * magnitude(&vector4(&q))));
+        prop_assert!(close(incidence(&r), 0.0, scale * magnitude(&vector4(&r))));
+    }
+
+    #[test]
+    fn meet_is_contained_in_both(
+        plane in any::<HomogeneusPlane<f64>>(),
+        line in any::<Line<f64>>(),
+    ) {
+        let line: HomogeneusLine<f64> = line.into();
+        let point: HomogeneusPoint<f64> = plane.meet(&line);
+        let scale = magnitude(&trivector4(&plane)) * magnitude(&bivector4(&line));
+        let on_plane = point.wedge(&plane).xyzw;
+        prop_assert!(close(on_plane, 0.0, scale * magnitude(&trivector4(&plane))));
+
+        let point: HomogeneusPoint<f64> = line.meet(&plane);
+        let on_plane = point.wedge(&plane).xyzw;
+        prop_assert!(close(on_plane, 0.0, scale * magnitude(&trivector4(&plane))));
+        let on_line = point.wedge(&line);
+        prop_assert!(close_all(&trivector4(&on_line), &[0.0; 4], scale * magnitude(&bivector4(&line))));
+    }
+
+    #[test]
+    fn unit_strategies_are_unitized(line in any::<Line<f64>>(), plane in any::<Plane<f64>>()) {
+        let line: HomogeneusLine<f64> = line.into();
+        let weight = line.wx * line.wx + line.wy * line.wy + line.wz * line.wz;
+        prop_assert!(close(weight, 1.0, 1.0));
+        prop_assert!(close(line.wx * line.yz + line.wy * line.zx + line.wz * line.xy, 0.0, magnitude(&bivector4(&line))));
+
+        let plane: HomogeneusPlane<f64> = plane.into();
+        let weight = plane.wyz * plane.wyz + plane.wzx * plane.wzx + plane.wxy * plane.wxy;
+        prop_assert!(close(weight, 1.0, 1.0));
+    }
+
+    #[test]
+    fn distance_is_symmetric(
+        p in any::<HomogeneusPoint<f64>>(),
+        q in any::<HomogeneusPoint<f64>>(),
+        l in any::<HomogeneusLine<f64>>(),
+        m in any::<HomogeneusLine<f64>>(),
+        e in any::<HomogeneusPlane<f64>>(),
+        f in any::<HomogeneusPlane<f64>>(),
+    ) {
+        let (p_scale, q_scale) = (magnitude(&vector4(&p)), magnitude(&vector4(&q)));
+        let (l_scale, m_scale) = (magnitude(&bivector4(&l)), magnitude(&bivector4(&m)));
+        let (e_scale, f_scale) = (magnitude(&trivector4(&e)), magnitude(&trivector4(&f)));
+        prop_assert!(symmetric(&p, &q, 1.0, p_scale * q_scale));
+        prop_assert!(symmetric(&p, &l, 1.0, p_scale * l_scale));
+        prop_assert!(symmetric(&p, &e, 1.0, p_scale * e_scale));
+        prop_assert!(symmetric(&l, &m, 1.0, l_scale * m_scale));
+        prop_assert!(symmetric(&l, &e, 1.0, l_scale * e_scale));
+        prop_assert!(symmetric(&e, &f, side_flip(&e, &f), e_scale * f_scale));
+    }
+
+    #[test]
+    fn distance_between_parallels_is_symmetric(
+        line in any::<Line<f64>>(),
+        plane in any::<Plane<f64>>(),
+        offset in any::<d3::Vector<f64>>(),
+        k in -10.0..10.0f64,
+    ) {
+        // the same direction with the moment of a line through another point
+        let l: HomogeneusLine<f64> = line.into();
+        let m = d4::Bivector {
+            wx: k * l.wx,
+            wy: k * l.wy,
+            wz: k * l.wz,
+            yz: k * (l.yz + offset.y * l.wz - offset.z * l.wy),
+            zx: k * (l.zx + offset.z * l.wx - offset.x * l.wz),
+            xy: k * (l.xy + offset.x * l.wy - offset.y * l.wx),
+        };
+        let scale = magnitude(&bivector4(&l)) * magnitude(&bivector4(&m));
+        prop_assert!(symmetric(&l, &m, 1.0, scale));
+
+        let e: HomogeneusPlane<f64> = plane.into();
+        let f = d4::Trivector {
+            wyz: k * e.wyz,
+            wzx: k * e.wzx,
+            wxy: k * e.wxy,
+            zyx: k * (e.zyx + offset.x),
+        };
+        let scale = magnitude(&trivector4(&e)) * magnitude(&trivector4(&f));
+        prop_assert!(symmetric(&e, &f, side_flip(&e, &f), scale));
+        prop_assert!(symmetric(&l, &e, 1.0, magnitude(&bivector4(&l)) * magnitude(&trivector4(&e))));
+    }
+
+    #[test]
+    fn motor_strategy_is_unitized(motor in any::<Motor<f64>>()) {
+        let even = d4::Evenvector::from(motor);
+        let unitized = d4::Evenvector::from(motor.unitize().unwrap());
+        prop_assert!(close_all(&even4(&even), &even4(&unitized), magnitude(&even4(&even))));
+    }
+
+    #[test]
+    fn motors_preserve_distances(
+        motor in any::<Motor<f64>>(),
+        p in any::<d3::Point<f64>>(),
+        q in any::<d3::Point<f64>>(),
+    ) {
+        let (moved_p, moved_q) = (motor.transform_point(&p), motor.transform_point(&q));
+        let scale = magnitude(&point3(&moved_p)) + magnitude(&point3(&moved_q))
+            + magnitude(&point3(&p)) + magnitude(&point3(&q));
+        let length = |a: &d3::Point<f64>, b: &d3::Point<f64>| {
+            let d = a.0 - b.0;
+            (d.x * d.x + d.y * d.y + d.z * d.z).sqrt()
+        };
+        prop_assert!(close(length(&moved_p, &moved_q), length(&p, &q), scale));
+    }
+
+    #[test]
+    fn motor_composition_applies_rhs_first(
+        a in any::<Motor<f64>>(),
+        b in any::<Motor<f64>>(),
+        p in any::<d3::Point<f64>>(),
+    ) {
+        let composed = a.geometric_antiproduct(&b).transform_point(&p);
+        let in_turn = a.transform_point(&b.transform_point(&p));
+        let scale = magnitude(&point3(&in_turn)) + magnitude(&point3(&b.transform_point(&p)));
+        prop_assert!(close_all(&point3(&composed), &point3(&in_turn), scale));
+    }
+
+    #[test]
+    fn antireverse_undoes_the_motor(motor in any::<Motor<f64>>(), p in any::<d3::Point<f64>>()) {
+        let moved = motor.transform_point(&p);
+        let back = motor.antireverse().transform_point(&moved);
+        let scale = magnitude(&point3(&p)) + magnitude(&point3(&moved));
+        prop_assert!(close_all(&point3(&back), &point3(&p), scale));
+    }
+
+    #[test]
+    fn axis_and_pitch_rebuild_the_twist(t in any::<Twist<f64>>()) {
+        match (t.pitch(), t.axis()) {
+            (Some(pitch), Some(NormalizedLine::Line(line))) => {
+                let speed = t.angular().dot(&t.angular()).sqrt();
+                let rebuilt = Twist::from_line(&line, pitch) * speed;
+                prop_assert!(close_all(&twist(&rebuilt), &twist(&t), magnitude(&twist(&t))));
+            }
+            (None, Some(NormalizedLine::HorizonLine(_))) => {}
+            other => prop_assert!(false, "pitch and axis disagree: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn screw_power_is_invariant_under_motors(
+        t in any::<Twist<f64>>(),
+        w in any::<Wrench<f64>>(),
+        motor in any::<Motor<f64>>(),
+    ) {
+        let (moved_t, moved_w) = (t.transform(&motor), w.transform(&motor));
+        let scale = magnitude(&twist(&moved_t)) * magnitude(&wrench(&moved_w))
+            + magnitude(&twist(&t)) * magnitude(&wrench(&w));
+        prop_assert!(close(moved_t.reciprocal_product(&moved_w), t.reciprocal_product(&w), scale));
+        prop_assert!(close(w.reciprocal_product(&t), t.reciprocal_product(&w), scale));
+    }
+
+    #[test]
+    fn twist_motion_keeps_its_twist(t in any::<Twist<f64>>()) {
+        let moved = t.transform(&t.exp());
+        let scale = magnitude(&twist(&t)) + magnitude(&twist(&moved));
+        prop_assert!(close_all(&twist(&moved), &twist(&t), scale));
+    }
+
+    #[test]
+    fn wrench_transform_follows_motor_composition(
+        w in any::<Wrench<f64>>(),
+        a in any::<Motor<f64>>(),
+        b in any::<Motor<f64>>(),
+    ) {
+        let composed = w.transform(&a.geometric_antiproduct(&b));
+        let in_turn = w.transform(&b).transform(&a);
+        let scale = magnitude(&wrench(&in_turn)) + magnitude(&wrench(&w.transform(&b)));
+        prop_assert!(close_all(&wrench(&composed), &wrench(&in_turn), scale));
+    }
+
+    #[test]
+    fn homography_inverse_undoes_it(h in any::<Homography<f64>>(), v in any::<Vector3>()) {
+        // the strategy bounds the entries and the determinant, and with them the condition number
+        let back = h.inverse().unwrap().transform(&h.transform(&v));
+        prop_assert!(close_all(&vector3(&back), &vector3(&v), 1e3 * magnitude(&vector3(&v))));
+    }
+
+    #[test]
+    fn homography_composition_applies_rhs_first(
+        g in any::<Homography<f64>>(),
+        h in any::<Homography<f64>>(),
+        v in any::<Vector3>(),
+    ) {
+        let composed = g.compose(&h).transform(&v);
+        let in_turn = g.transform(&h.transform(&v));
+        let scale = 10.0 * magnitude(&vector3(&h.transform(&v)));
+        prop_assert!(close_all(&vector3(&composed), &vector3(&in_turn), scale));
+    }
+
+    #[test]
+    fn homographies_map_joins_to_joins(
+        h in any::<Homography<f64>>(),
+        p in any::<Vector3>(),
+        q in any::<Vector3>(),
+    ) {
+        let line: Line2 = h.transform(&p.wedge(&q));
+        let join = h.transform(&p).wedge(&h.transform(&q));
+        let scale = magnitude(&vector3(&h.transform(&p))) * magnitude(&vector3(&h.transform(&q)));
+        prop_assert!(close_all(&bivector3(&line), &bivector3(&join), 10.0 * scale));
+    }
+
+    #[test]
+    fn conic_form_is_symmetric(conic in any::<Conic<f64>>(), a in any::<Vector3>(), b in any::<Vector3>()) {
+        let scale = magnitude(&vector3(&a)) * magnitude(&vector3(&b)) * magnitude(&conic.matrix().concat());
+        prop_assert!(close(conic.bilinear_form(&a, &b), conic.bilinear_form(&b, &a), scale));
+    }
+
+    #[test]
+    fn pole_of_the_polar_is_the_point(conic in any::<Conic<f64>>(), p in any::<Point<f64>>()) {
+        let Some(MappedPoint::Point(pole)) = conic.pole(&conic.polar(&p)) else {
+            return Err(TestCaseError::fail("the pole of the polar of a finite point is finite"));
+        };
+        let (pole, p) = ([pole.0.x, pole.0.y], [p.0.x, p.0.y]);
+        prop_assert!(close_all(&pole, &p, 1e3 * magnitude(&p)));
+    }
+
+    #[test]
+    fn conic_meet_is_on_both(conic in any::<Conic<f64>>(), p in any::<Point<f64>>()) {
+        let line = conic.polar(&p);
+        let points = match conic.meet(&line) {
+            ConicIntersection::Secant(a, b) => vec![a, b],
+            ConicIntersection::Tangent(a) => vec![a],
+            _ => vec![],
+        };
+        // the points are on the conic up to the tolerance of the tangency tests of `meet`
+        let m = conic.matrix();
+        let quadratic = (m[0][0] * m[0][0] + 2.0 * m[0][1] * m[0][1] + m[1][1] * m[1][1]).sqrt();
+        for point in points {
+            let v = match point {
+                MappedPoint::Point(q) => {
+                    prop_assert!(conic.contains(&q));
+                    Vector3::new(q.0.x, q.0.y, 1.0)
+                }
+                MappedPoint::AtInfinity(v) => {
+                    let length = v.x * v.x + v.y * v.y;
+                    prop_assert!(conic.bilinear_form(&v, &v).abs() < 1e-3 * quadratic * length);
+                    v
+                }
+            };
+            let scale = magnitude(&vector3(&v)) * magnitude(&bivector3(&line));
+            prop_assert!(close(v.wedge(&line).xyz, 0.0, 1e3 * scale));
+        }
+    }
+}
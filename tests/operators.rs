@@ -0,0 +1,120 @@
+use gual::{
+    AntiwedgeProduct, Dot, Dual, GeometricAntiproduct, GeometricProduct, Scalar, WedgeProduct,
+    geometry2d as d2, geometry3d as d3, geometry4d as d4,
+};
+
+#[test]
+fn operators_match_the_3d_products() {
+    let a = d3::Vector::<f64>::new(1.0, 2.0, 3.0);
+    let b = d3::Vector::<f64>::new(-2.0, 0.5, 1.0);
+    let c = d3::Bivector::<f64>::new(0.5, -1.0, 2.0);
+
+    assert_eq!(a ^ b, a.wedge(&b));
+    assert_eq!(c & (a ^ b), c.antiwedge(&a.wedge(&b)));
+    assert_eq!(a | b, a.dot(&b));
+    assert_eq!(!c, c.dual());
+    assert_eq!(a * c, a.geometric_product(&c));
+    assert_eq!(c * a, c.geometric_product(&a));
+    assert_eq!(a * b * c, a.geometric_product(&b).geometric_product(&c));
+    assert_eq!(
+        a.wedge(&c.antiwedge(&b.right_bulk_dual())),
+        a ^ (c & b.right_bulk_dual())
+    );
+}
+
+#[test]
+#[allow(clippy::op_ref)] // the references are what is under test
+fn operators_work_by_reference() {
+    let a = d3::Vector::<f64>::new(1.0, 2.0, 3.0);
+    let b = d3::Bivector::<f64>::new(0.5, -1.0, 2.0);
+
+    assert_eq!(&a ^ &b, a.wedge(&b));
+    assert_eq!(&b & &a, b.antiwedge(&a));
+    assert_eq!(&a | &a, a.dot(&a));
+    assert_eq!(!&b, b.dual());
+    assert_eq!(&a * &b, a.geometric_product(&b));
+}
+
+#[test]
+fn operators_match_the_2d_and_4d_products() {
+    let a = d2::Vector::<f64>::new(1.0, 2.0);
+    let b = d2::Vector::<f64>::new(3.0, -1.0);
+    assert_eq!(a ^ b, a.wedge(&b));
+    assert_eq!(a * b, a.geometric_product(&b));
+
+    let p = d4::Vector {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 1.0,
+    };
+    let q = d4::Vector {
+        x: -1.0,
+        y: 0.5,
+        z: 2.0,
+        w: 1.0,
+    };
+    let line = p ^ q;
+    assert_eq!(line, p.wedge(&q));
+    assert_eq!(line * p, line.geometric_product(&p));
+    assert_eq!(line % p, line.geometric_antiproduct(&p));
+    assert_eq!(!line, line.dual());
+}
+
+#[test]
+fn scalar_operators() {
+    let s = Scalar::<3, f64>::new(2.0);
+    let v = d3::Vector::<f64>::new(1.0, 2.0, 3.0);
+
+    assert_eq!(s ^ v, s.wedge(&v));
+    assert_eq!(s * v, v * 2.0);
+}
+
+#[test]
+fn every_geometric_product_has_an_operator() {
+    let v = d3::Vector::<f64>::new(1.0, 2.0, 3.0);
+    let b = d3::Bivector::<f64>::new(0.5, -1.0, 2.0);
+    let t = d3::Trivector::<f64>::new(2.0);
+    let e = v * v + b * b;
+    let m = v * b;
+
+    assert_eq!(e * v, e.geometric_product(&v));
+    assert_eq!(e * b, e.geometric_product(&b));
+    assert_eq!(e * t, e.geometric_product(&t));
+    assert_eq!(e * e, e.geometric_product(&e));
+    assert_eq!(e * m, e.geometric_product(&m));
+    assert_eq!(m * v, m.geometric_product(&v));
+    assert_eq!(m * t, m.geometric_product(&t));
+    assert_eq!(m * e, m.geometric_product(&e));
+    assert_eq!(m * m, m.geometric_product(&m));
+
+    let p = d4::Vector {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 1.0,
+    };
+    let q = d4::Vector {
+        x: -1.0,
+        y: 0.5,
+        z: 2.0,
+        w: 1.0,
+    };
+    let line = p ^ q;
+    let a = d4::Quadvector { xyzw: 2.0 };
+    let e = line * line;
+    let m = line * p;
+
+    assert_eq!(e * e, e.geometric_product(&e));
+    assert_eq!(e * p, e.geometric_product(&p));
+    assert_eq!(e * a, e.geometric_product(&a));
+    assert_eq!(e * m, e.geometric_product(&m));
+    assert_eq!(m * p, m.geometric_product(&p));
+    assert_eq!(m * line, m.geometric_product(&line));
+    assert_eq!(m * (line ^ p), m.geometric_product(&(line ^ p)));
+    assert_eq!(m * a, m.geometric_product(&a));
+    assert_eq!(m * e, m.geometric_product(&e));
+    assert_eq!(m * m, m.geometric_product(&m));
+    assert_eq!(a * m, a.geometric_product(&m));
+    assert_eq!(p * m, p.geometric_product(&m));
+}
@@ -0,0 +1,140 @@
+use gual::{
+    AntiwedgeProduct, Transform, WedgeProduct, geometry2d, geometry3d as d3, geometry4d as d4,
+    homogeneous3d::{HomogeneusPlane, HomogeneusPoint},
+    outermorphism::Outermorphism,
+};
+use num::traits::ConstZero;
+
+fn point(x: f64, y: f64, z: f64) -> HomogeneusPoint<f64> {
+    HomogeneusPoint { x, y, z, w: 1.0 }
+}
+
+/// Perspective projection with a shear and a non-uniform scale
+fn perspective() -> Outermorphism<f64, 4> {
+    Outermorphism::from_matrix([
+        [2.0, 0.5, 0.0, 1.0],
+        [0.0, 1.0, -1.0, 0.0],
+        [0.0, 0.0, 3.0, 2.0],
+        [0.0, 0.0, 1.0, 1.0],
+    ])
+}
+
+fn assert_proportional(a: &[f64], b: &[f64], factor: f64) {
+    for (a, b) in a.iter().zip(b) {
+        assert!((a * factor - b).abs() < 1e-9, "{a:?} * {factor} != {b:?}");
+    }
+}
+
+fn plane_coordinates(plane: HomogeneusPlane<f64>) -> [f64; 4] {
+    [plane.wyz, plane.wzx, plane.wxy, plane.zyx]
+}
+
+fn line_coordinates(line: d4::Bivector<f64>) -> [f64; 6] {
+    [line.wx, line.wy, line.wz, line.yz, line.zx, line.xy]
+}
+
+#[test]
+fn identity_keeps_every_basis_element() {
+    let identity = Outermorphism::<f64, 4>::identity();
+    let b = d4::Bivector {
+        wx: 1.0,
+        wy: 2.0,
+        wz: 3.0,
+        yz: 4.0,
+        zx: 5.0,
+        xy: 6.0,
+    };
+    assert_eq!(identity.transform(&b), b);
+    let t = d4::Trivector {
+        wyz: 1.0,
+        wzx: -2.0,
+        wxy: 3.0,
+        zyx: -4.0,
+    };
+    assert_eq!(identity.transform(&t), t);
+    assert_eq!(identity.determinant(), d4::Quadvector { xyzw: 1.0 });
+}
+
+#[test]
+fn determinant_is_the_image_of_the_unit_volume() {
+    let rotate_scale = Outermorphism::<f64, 2>::from_images(
+        &geometry2d::Vector::<f64>::new(0.0, 2.0),
+        &geometry2d::Vector::new(-2.0, 0.0),
+    );
+    assert_eq!(rotate_scale.determinant().xy, 4.0);
+    assert_eq!(
+        rotate_scale.transform(&geometry2d::Vector::<f64>::new(1.0, 1.0)),
+        geometry2d::Vector::new(-2.0, 2.0)
+    );
+
+    let shear =
+        Outermorphism::<f64, 3>::from_matrix([[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 3.0]]);
+    assert_eq!(shear.determinant().xyz, 3.0);
+    let mirror =
+        Outermorphism::<f64, 3>::from_matrix([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(mirror.compose(&shear).determinant().xyz, -3.0);
+
+    assert!((perspective().determinant().xyzw - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn outermorphism_preserves_the_wedge_product() {
+    let shear =
+        Outermorphism::<f64, 3>::from_matrix([[1.0, 2.0, 0.0], [0.0, 1.0, 0.5], [-1.0, 0.0, 3.0]]);
+    let a: d3::Vector<f64> = d3::Vector::new(1.0, -2.0, 0.5);
+    let b = d3::Vector::new(3.0, 1.0, 2.0);
+    let c = d3::Vector::new(0.0, 1.0, -1.0);
+    assert_eq!(
+        shear.transform(&a.wedge(&b)),
+        shear.transform(&a).wedge(&shear.transform(&b))
+    );
+    let volume = a.wedge(&b).wedge(&c);
+    assert!((shear.transform(&volume).xyz - volume.xyz * shear.determinant().xyz).abs() < 1e-12);
+
+    let m = perspective();
+    let p = point(1.0, 2.0, -1.0);
+    let q = point(0.5, 0.0, 3.0);
+    let line = p.wedge(&q);
+    let image = m.transform(&line);
+    let expected = m.transform(&p).wedge(&m.transform(&q));
+    assert_eq!(image, expected);
+}
+
+#[test]
+fn homogeneous_primitives_transform_with_their_points() {
+    let m = perspective();
+    let (a, b, c) = (
+        point(1.0, 0.0, 0.0),
+        point(0.0, 2.0, 1.0),
+        point(-1.0, 1.0, 3.0),
+    );
+    let plane = HomogeneusPoint::join(a, b, c);
+    let moved = HomogeneusPoint::join(m.transform(&a), m.transform(&b), m.transform(&c));
+    assert_proportional(
+        &plane_coordinates(m.transform(&plane)),
+        &plane_coordinates(moved),
+        1.0,
+    );
+
+    // every point on the plane stays on the transformed plane
+    let on_plane = d4::Vector {
+        x: 2.0 * a.x - b.x + 0.5 * c.x,
+        y: 2.0 * a.y - b.y + 0.5 * c.y,
+        z: 2.0 * a.z - b.z + 0.5 * c.z,
+        w: 1.5,
+    };
+    let incidence = m.transform(&on_plane).wedge(&m.transform(&plane));
+    assert!(incidence.xyzw.abs() < 1e-9);
+
+    // the meet of transformed planes is the transformed meet, scaled by the determinant
+    let other = HomogeneusPoint::join(a, b, point(4.0, -1.0, 2.0));
+    let line = plane.antiwedge(&other);
+    let meet = m.transform(&plane).antiwedge(&m.transform(&other));
+    let image = m.transform(&line);
+    assert_proportional(
+        &line_coordinates(image),
+        &line_coordinates(meet),
+        m.determinant().xyzw,
+    );
+    assert_ne!(line, d4::Bivector::ZERO);
+}
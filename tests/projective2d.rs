@@ -0,0 +1,409 @@
+use gual::{
+    AntiwedgeProduct, Projective, Transform, WedgeProduct, geometry3d,
+    projective2d::{
+        Conic, ConicIntersection, ConicKind, DirVector, Homography, Line, MappedPoint, Point,
+    },
+};
+
+type Vector3 = geometry3d::Vector<f64, Projective>;
+
+fn assert_maps_to(mapped: MappedPoint<f64>, x: f64, y: f64) {
+    match mapped {
+        MappedPoint::Point(p) => assert!(
+            (p.0.x - x).abs() < 1e-9 && (p.0.y - y).abs() < 1e-9,
+            "expected ({x}, {y}), got {p:?}"
+        ),
+        MappedPoint::AtInfinity(v) => panic!("expected ({x}, {y}), got {v:?} at infinity"),
+    }
+}
+
+fn square() -> [Point<f64>; 4] {
+    [
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+    ]
+}
+
+fn quad() -> [Point<f64>; 4] {
+    [
+        Point::new(100.0, 120.0),
+        Point::new(640.0, 80.0),
+        Point::new(600.0, 470.0),
+        Point::new(140.0, 400.0),
+    ]
+}
+
+#[test]
+fn homography_from_four_points() {
+    let h = Homography::from_correspondences(&square(), &quad()).unwrap();
+    for (p, q) in square().iter().zip(quad()) {
+        assert_maps_to(h.transform(p), q.0.x, q.0.y);
+    }
+
+    let inverse = h.inverse().unwrap();
+    for (p, q) in square().iter().zip(quad()) {
+        assert_maps_to(inverse.transform(&q), p.0.x, p.0.y);
+    }
+    let center = Point::new(0.25, 0.5);
+    let round_trip = inverse.compose(&h);
+    assert_maps_to(round_trip.transform(&center), 0.25, 0.5);
+
+    // composition applies the right-hand side first
+    let shift = Homography::from_matrix([[1.0, 0.0, 2.0], [0.0, 1.0, -1.0], [0.0, 0.0, 1.0]]);
+    let MappedPoint::Point(moved) = h.transform(&Point::new(2.0, -1.0)) else {
+        panic!("finite point expected");
+    };
+    assert_maps_to(
+        h.compose(&shift).transform(&Point::new(0.0, 0.0)),
+        moved.0.x,
+        moved.0.y,
+    );
+}
+
+#[test]
+fn homography_maps_lines_with_their_points() {
+    let h = Homography::from_correspondences(&square(), &quad()).unwrap();
+    let (a, b) = (Point::new(0.2, 0.3), Point::new(0.9, -0.4));
+    let line = Line::line_from_points(&a, &b);
+    let image = h.transform(&line);
+
+    for p in [a, b, Point::new(0.55, -0.05)] {
+        let mapped = h.transform(&Vector3::from(p));
+        let incidence = mapped.wedge(&image).xyz;
+        assert!(incidence.abs() < 1e-6, "{incidence}");
+    }
+}
+
+#[test]
+fn homography_moves_points_to_and_from_infinity() {
+    // perspective that sends the line y = 1 to infinity
+    let h = Homography::from_matrix([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 1.0]]);
+    match h.transform(&Point::new(3.0, 1.0)) {
+        MappedPoint::AtInfinity(v) => {
+            assert_eq!((v.x, v.y, v.z), (3.0, 1.0, 0.0));
+        }
+        point => panic!("expected a point at infinity, got {point:?}"),
+    }
+    assert_maps_to(h.transform(&Point::new(2.0, 0.5)), 4.0, 1.0);
+
+    // the direction (1, 1) becomes a vanishing point
+    assert_maps_to(h.transform(&DirVector::new(1.0, 1.0)), -1.0, -1.0);
+    // and the horizontal direction stays at infinity
+    assert!(matches!(
+        h.transform(&DirVector::new(1.0, 0.0)),
+        MappedPoint::AtInfinity(_)
+    ));
+}
+
+#[test]
+fn degenerate_homographies() {
+    let mut collinear = square();
+    collinear[2] = Point::new(2.0, 0.0);
+    assert!(Homography::from_correspondences(&collinear, &quad()).is_none());
+    assert!(Homography::from_correspondences(&quad(), &collinear).is_none());
+
+    let singular = Homography::from_matrix([[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert!(singular.inverse().is_none());
+}
+
+fn on_line(t: f64) -> Vector3 {
+    Vector3::new(t, 2.0 * t + 1.0, 1.0)
+}
+
+fn assert_near(value: f64, expected: f64) {
+    assert!(
+        (value - expected).abs() < 1e-9,
+        "expected {expected}, got {value}"
+    );
+}
+
+#[test]
+fn cross_ratio_of_points() {
+    let [a, b, c, d] = [0.0, 1.0, 2.0, 3.0].map(on_line);
+    assert_near(Vector3::cross_ratio(&a, &b, &c, &d).unwrap(), 4.0 / 3.0);
+    // weights don't matter
+    let heavy = Vector3::new(c.x * -3.0, c.y * -3.0, c.z * -3.0);
+    assert_near(Vector3::cross_ratio(&a, &b, &heavy, &d).unwrap(), 4.0 / 3.0);
+
+    let points = [0.0, 1.0, 2.0, 3.0].map(|t| Point::new(t, 2.0 * t + 1.0));
+    let [pa, pb, pc, pd] = points;
+    assert_near(Point::cross_ratio(&pa, &pb, &pc, &pd).unwrap(), 4.0 / 3.0);
+
+    // the cross ratio is a projective invariant
+    let h = Homography::from_matrix([[1.0, 0.2, 0.0], [-0.3, 1.0, 0.5], [0.1, 0.2, 1.0]]);
+    let [ha, hb, hc, hd] = [a, b, c, d].map(|p| h.transform(&p));
+    assert_near(Vector3::cross_ratio(&ha, &hb, &hc, &hd).unwrap(), 4.0 / 3.0);
+
+    // with a point at infinity it is a ratio of lengths
+    let infinity = Vector3::new(1.0, 2.0, 0.0);
+    let [a, b, c] = [0.0, 1.0, 3.0].map(on_line);
+    assert_near(Vector3::cross_ratio(&a, &b, &c, &infinity).unwrap(), 1.5);
+}
+
+#[test]
+fn harmonic_conjugates() {
+    let [a, b, c] = [0.0, 2.0, 1.5].map(on_line);
+    let d = Vector3::harmonic_conjugate(&a, &b, &c).unwrap();
+    assert_near(d.x / d.z, 3.0);
+    assert_near(d.y / d.z, 7.0);
+    assert_near(Vector3::cross_ratio(&a, &b, &c, &d).unwrap(), -1.0);
+
+    let midpoint = on_line(1.0);
+    let d = Vector3::harmonic_conjugate(&a, &b, &midpoint).unwrap();
+    assert!(d.z.abs() < 1e-12);
+    assert_near(d.y / d.x, 2.0);
+
+    let through = |x: f64, y: f64| {
+        Line::line_from_points(&Point::new(1.0, 1.0), &Point::new(1.0 + x, 1.0 + y))
+    };
+    let (la, lb, lc) = (through(1.0, 0.0), through(0.0, 1.0), through(1.0, 1.0));
+    let ld = Line::harmonic_conjugate(&la, &lb, &lc).unwrap();
+    assert_near(Line::cross_ratio(&la, &lb, &lc, &ld).unwrap(), -1.0);
+    // the conjugate of a bisector is the other bisector
+    assert_near(ld.yz / ld.zx, 1.0);
+}
+
+#[test]
+fn cross_ratio_of_lines() {
+    // lines through (1, 1) cut the x axis at t = 0, 1, 2, 3
+    let lines = [0.0, 1.0, 2.0, 3.0]
+        .map(|t| Line::line_from_points(&Point::new(1.0, 1.0), &Point::new(t, 0.0)));
+    let [a, b, c, d] = lines;
+    assert_near(Line::cross_ratio(&a, &b, &c, &d).unwrap(), 4.0 / 3.0);
+
+    // parallel lines meet at infinity
+    let parallel = [0.0, 1.0, 2.0, 3.0]
+        .map(|t| Line::line_from_points(&Point::new(t, 0.0), &Point::new(t + 1.0, 2.0)));
+    let [a, b, c, d] = parallel;
+    assert_near(Line::cross_ratio(&a, &b, &c, &d).unwrap(), 4.0 / 3.0);
+}
+
+#[test]
+fn cross_ratio_on_a_conic() {
+    let on_circle = |angle: f64| Vector3::new(angle.cos(), angle.sin(), 1.0);
+    let [a, b, c, d] = [0.3, 1.2, 2.0, 4.0].map(on_circle);
+    let first = Vector3::cross_ratio_on_conic(&a, &b, &c, &d, &on_circle(5.0)).unwrap();
+    let second = Vector3::cross_ratio_on_conic(&a, &b, &c, &d, &on_circle(-2.5)).unwrap();
+    assert_near(first, second);
+    assert!(Vector3::cross_ratio_on_conic(&a, &b, &c, &d, &a).is_none());
+}
+
+#[test]
+fn degenerate_cross_ratios() {
+    let [a, b, c] = [0.0, 1.0, 2.0].map(on_line);
+    let off = Vector3::new(0.0, 0.0, 1.0);
+    assert!(Vector3::cross_ratio(&a, &b, &c, &Vector3::new(5.0, 0.0, 1.0)).is_none());
+    assert!(Vector3::cross_ratio(&a, &b, &c, &c).is_none());
+    assert!(Vector3::cross_ratio(&a, &a, &b, &c).is_none());
+    assert!(Vector3::harmonic_conjugate(&a, &b, &b).is_none());
+    assert!(Vector3::harmonic_conjugate(&a, &b, &off).is_none());
+
+    let x = Line::line_from_points(&Point::new(0.0, 0.0), &Point::new(1.0, 0.0));
+    let y = Line::line_from_points(&Point::new(0.0, 0.0), &Point::new(0.0, 1.0));
+    let diagonal = Line::line_from_points(&Point::new(0.0, 0.0), &Point::new(1.0, 1.0));
+    let elsewhere = Line::line_from_points(&Point::new(0.0, 3.0), &Point::new(1.0, 0.0));
+    assert!(Line::cross_ratio(&x, &y, &diagonal, &elsewhere).is_none());
+    assert!(Line::cross_ratio(&x, &y, &diagonal, &x).is_none());
+}
+
+fn ellipse() -> Conic<f64> {
+    // centred at (1, 2), semi-axes 3 along (1, 1) and 2 along (-1, 1)
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    Conic::ellipse(
+        &Point::new(1.0, 2.0),
+        &DirVector::new(3.0 * s, 3.0 * s),
+        &DirVector::new(-2.0 * s, 2.0 * s),
+    )
+    .unwrap()
+}
+
+fn on_ellipse(t: f64) -> Point<f64> {
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let (u, v) = (3.0 * t.cos(), 2.0 * t.sin());
+    Point::new(1.0 + s * (u - v), 2.0 + s * (u + v))
+}
+
+fn finite(point: MappedPoint<f64>) -> Point<f64> {
+    match point {
+        MappedPoint::Point(p) => p,
+        MappedPoint::AtInfinity(v) => panic!("{v:?} is at infinity"),
+    }
+}
+
+#[test]
+fn conic_construction() {
+    let conic = ellipse();
+    assert_eq!(conic.kind(), ConicKind::Ellipse);
+    for t in [0.0, 0.5, 2.0, 4.0] {
+        assert!(conic.contains(&on_ellipse(t)));
+    }
+    assert!(!conic.contains(&Point::new(1.0, 2.0)));
+    let p = on_ellipse(1.0);
+    let outward = Point::new(1.0 + 1.01 * (p.0.x - 1.0), 2.0 + 1.01 * (p.0.y - 2.0));
+    assert!(!conic.contains(&outward));
+
+    // five of its points give back the same conic up to scale
+    let points = [0.1, 1.0, 2.5, 3.7, 5.0].map(on_ellipse);
+    let fitted = Conic::from_points(&points).unwrap();
+    let (m, n) = (conic.matrix(), fitted.matrix());
+    let ratio = n[2][2] / m[2][2];
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((n[i][j] - ratio * m[i][j]).abs() < 1e-9 * ratio.abs());
+        }
+    }
+    for t in [0.3, 6.0] {
+        assert!(fitted.contains(&on_ellipse(t)));
+    }
+
+    // the same holds at pixel scale
+    let pixel = |t: f64| {
+        let p = on_ellipse(t);
+        Point::new(640.0 + 100.0 * p.0.x, 480.0 + 100.0 * p.0.y)
+    };
+    let fitted = Conic::from_points(&[0.1, 1.0, 2.5, 3.7, 5.0].map(pixel)).unwrap();
+    assert_eq!(fitted.kind(), ConicKind::Ellipse);
+    assert!(fitted.contains(&pixel(6.0)));
+    assert!(!fitted.contains(&Point::new(740.0, 680.0)));
+}
+
+#[test]
+fn conic_kinds() {
+    let origin = Point::new(0.0, 0.0);
+    let hyperbola = Conic::hyperbola(&origin, &DirVector::X, &DirVector::new(0.0, 2.0)).unwrap();
+    assert_eq!(hyperbola.kind(), ConicKind::Hyperbola);
+    assert!(hyperbola.contains(&Point::new(-1.0, 0.0)));
+    assert!(hyperbola.contains(&Point::new(2f64.cosh(), 2.0 * 2f64.sinh())));
+
+    // y = x² - 1
+    let parabola = Conic::from_matrix([[1.0, 0.0, 0.0], [0.0, 0.0, -0.5], [0.0, -0.5, -1.0]]);
+    assert_eq!(parabola.kind(), ConicKind::Parabola);
+    assert!(parabola.contains(&Point::new(3.0, 8.0)));
+
+    let empty = Conic::from_matrix([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(empty.kind(), ConicKind::Empty);
+
+    // crossing lines x y = 0, parallel lines x² = 1 and the double line x² = 0
+    for rows in [
+        [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+        [[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, -1.0]],
+        [[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]],
+    ] {
+        let conic = Conic::from_matrix(rows);
+        assert_eq!(conic.kind(), ConicKind::Degenerate);
+        assert!(conic.pole(&Line::new(1.0, 0.0, 0.0)).is_none());
+    }
+
+    // three collinear points make a pair of lines, four don't define a conic
+    let points = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (0.0, 1.0), (1.0, 2.0)];
+    let pair = Conic::from_points(&points.map(|(x, y)| Point::new(x, y))).unwrap();
+    assert_eq!(pair.kind(), ConicKind::Degenerate);
+    let points = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (1.0, 2.0)];
+    assert!(Conic::from_points(&points.map(|(x, y)| Point::new(x, y))).is_none());
+    let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 0.0), (3.0, 1.0), (1.0, 2.0)];
+    assert!(Conic::from_points(&points.map(|(x, y)| Point::new(x, y))).is_none());
+}
+
+#[test]
+fn conic_poles_and_polars() {
+    let conic = ellipse();
+    // the polar of a point of the conic is its tangent
+    let p = on_ellipse(0.7);
+    let tangent = conic.polar(&p);
+    match conic.meet(&tangent) {
+        ConicIntersection::Tangent(q) => {
+            let q = finite(q);
+            assert!((q.0.x - p.0.x).abs() < 1e-6 && (q.0.y - p.0.y).abs() < 1e-6);
+        }
+        other => panic!("{other:?}"),
+    }
+
+    let outside = Point::new(6.0, -1.0);
+    let pole = finite(conic.pole(&conic.polar(&outside)).unwrap());
+    assert!((pole.0.x - 6.0).abs() < 1e-9 && (pole.0.y + 1.0).abs() < 1e-9);
+
+    // lines through the centre are polars of points at infinity
+    let diameter = Line::line_from_points(&Point::new(1.0, 2.0), &Point::new(3.0, 0.0));
+    assert!(matches!(
+        conic.pole(&diameter),
+        Some(MappedPoint::AtInfinity(_))
+    ));
+    // and the centre is the pole of the line at infinity
+    let centre = finite(conic.pole(&Line::new(0.0, 0.0, 1.0)).unwrap());
+    assert!((centre.0.x - 1.0).abs() < 1e-9 && (centre.0.y - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn conic_tangents_and_intersections() {
+    let conic = ellipse();
+    let outside = Point::new(6.0, -1.0);
+    let tangents = conic.tangents_from(&outside).unwrap();
+    for tangent in tangents {
+        assert!(matches!(
+            conic.meet(&tangent),
+            ConicIntersection::Tangent(_)
+        ));
+        let p = Vector3::from(&outside);
+        assert!(p.antiwedge(&tangent).abs() < 1e-9);
+    }
+    assert!(conic.tangents_from(&Point::new(1.0, 2.0)).is_none());
+    assert!(conic.tangents_from(&on_ellipse(1.0)).is_none());
+
+    // a chord through two points of the conic, and a line that misses it
+    let (a, b) = (on_ellipse(0.4), on_ellipse(2.0));
+    match conic.meet(&Line::line_from_points(&a, &b)) {
+        ConicIntersection::Secant(p, q) => {
+            let (p, q) = (finite(p), finite(q));
+            let near = |p: Point<f64>, q: Point<f64>| {
+                (p.0.x - q.0.x).abs() < 1e-9 && (p.0.y - q.0.y).abs() < 1e-9
+            };
+            assert!((near(p, a) && near(q, b)) || (near(p, b) && near(q, a)));
+        }
+        other => panic!("{other:?}"),
+    }
+    let far = Line::line_from_points(&Point::new(10.0, 0.0), &Point::new(10.0, 1.0));
+    assert_eq!(conic.meet(&far), ConicIntersection::Disjoint);
+
+    // lines of a hyperbola's asymptotic directions meet it at infinity
+    let hyperbola = Conic::hyperbola(&Point::new(0.0, 0.0), &DirVector::X, &DirVector::Y).unwrap();
+    let asymptote = Line::line_from_points(&Point::new(0.0, 0.0), &Point::new(1.0, 1.0));
+    assert!(matches!(
+        hyperbola.meet(&asymptote),
+        ConicIntersection::Tangent(MappedPoint::AtInfinity(_))
+    ));
+    let parallel = Line::line_from_points(&Point::new(1.0, 0.0), &Point::new(2.0, 1.0));
+    match hyperbola.meet(&parallel) {
+        ConicIntersection::Secant(p, MappedPoint::AtInfinity(_)) => {
+            let p = finite(p);
+            assert!((p.0.x - 1.0).abs() < 1e-9 && p.0.y.abs() < 1e-9);
+        }
+        other => panic!("{other:?}"),
+    }
+
+    // a line that is nearly asymptotic still crosses the hyperbola at two far points
+    let thin = Conic::hyperbola(
+        &Point::new(0.0, 0.0),
+        &DirVector::X,
+        &DirVector::new(0.0, 30.0),
+    )
+    .unwrap();
+    let steep = Line::line_from_points(&Point::new(0.0, 0.0), &Point::new(1.0, 29.0));
+    match thin.meet(&steep) {
+        ConicIntersection::Secant(p, q) => {
+            let x = (900.0_f64 / 59.0).sqrt();
+            for p in [finite(p), finite(q)] {
+                assert!((p.0.x.abs() - x).abs() < 1e-9 && (p.0.y - 29.0 * p.0.x).abs() < 1e-9);
+            }
+        }
+        other => panic!("{other:?}"),
+    }
+
+    // the x axis is part of x y = 0
+    let pair = Conic::from_matrix([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+    assert_eq!(
+        pair.meet(&Line::new(0.0, 1.0, 0.0)),
+        ConicIntersection::Contained
+    );
+}
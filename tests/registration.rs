@@ -0,0 +1,177 @@
+use std::f64::consts::FRAC_PI_2;
+
+use gual::{
+    geometry2d, geometry3d as d3, geometry4d as d4,
+    homogeneous3d::Motor,
+    projective2d::{Motion, Point},
+    registration::{RegistrationError, Scaling, align_2d, align_3d},
+};
+
+fn point(x: f64, y: f64, z: f64) -> d3::Point<f64> {
+    d3::Point(d3::Vector::new(x, y, z))
+}
+
+fn point2(x: f64, y: f64) -> Point<f64> {
+    Point(geometry2d::Vector::new(x, y))
+}
+
+fn assert_point(p: d3::Point<f64>, q: d3::Point<f64>) {
+    let d = (p.0.x - q.0.x).abs() + (p.0.y - q.0.y).abs() + (p.0.z - q.0.z).abs();
+    assert!(d < 1e-9, "expected {q:?}, got {p:?}");
+}
+
+fn assert_point2(p: Point<f64>, q: Point<f64>) {
+    let d = (p.0.x - q.0.x).abs() + (p.0.y - q.0.y).abs();
+    assert!(d < 1e-9, "expected {q:?}, got {p:?}");
+}
+
+fn cloud() -> Vec<d3::Point<f64>> {
+    vec![
+        point(0.0, 0.0, 0.0),
+        point(1.0, 0.0, 0.0),
+        point(0.0, 2.0, 0.0),
+        point(0.0, 0.0, 3.0),
+        point(-1.0, 1.5, 0.5),
+        point(2.0, -1.0, 1.0),
+    ]
+}
+
+fn rotation() -> Motor<f64> {
+    let axis = d3::UnitVector::try_from(d3::Vector::new(1.0, 2.0, -2.0)).unwrap();
+    Motor::rotation(&axis, 2.0)
+}
+
+/// Rotation followed by a translation
+fn moved(p: &d3::Point<f64>) -> d3::Point<f64> {
+    let translation = Motor::translation(&d3::Vector::new(3.0, -1.0, 4.0));
+    translation.transform_point(&rotation().transform_point(p))
+}
+
+#[test]
+fn motor_moves_points() {
+    let z = d3::UnitVector::Z;
+    let quarter = Motor::rotation(&z, FRAC_PI_2);
+    assert_point(
+        quarter.transform_point(&point(1.0, 0.0, 5.0)),
+        point(0.0, 1.0, 5.0),
+    );
+
+    let shift = Motor::translation(&d3::Vector::new(1.0, -2.0, 3.0));
+    assert_point(
+        shift.transform_point(&point(1.0, 1.0, 1.0)),
+        point(2.0, -1.0, 4.0),
+    );
+}
+
+#[test]
+fn rigid_registration_3d() {
+    let target: Vec<_> = cloud().iter().map(moved).collect();
+    let alignment = align_3d(&cloud(), &target, None, Scaling::Rigid).unwrap();
+    assert_eq!(alignment.scale, 1.0);
+    let motor = d4::Evenvector::from(alignment.motion);
+    let rotation = d4::Evenvector::from(rotation());
+    let sign = motor.a.xyzw.signum() * rotation.a.xyzw.signum();
+    assert!((motor.a.xyzw - sign * rotation.a.xyzw).abs() < 1e-9);
+    assert!((motor.b.wx - sign * rotation.b.wx).abs() < 1e-9);
+    assert!((motor.b.wy - sign * rotation.b.wy).abs() < 1e-9);
+    assert!((motor.b.wz - sign * rotation.b.wz).abs() < 1e-9);
+    assert!(alignment.rms_error < 1e-9);
+    for (p, q) in cloud().iter().zip(&target) {
+        assert_point(alignment.motion.transform_point(p), *q);
+    }
+
+    let scaled: Vec<_> = cloud()
+        .iter()
+        .map(|p| moved(&point(2.5 * p.0.x, 2.5 * p.0.y, 2.5 * p.0.z)))
+        .collect();
+    let alignment = align_3d(&cloud(), &scaled, None, Scaling::Uniform).unwrap();
+    assert!((alignment.scale - 2.5).abs() < 1e-9);
+    assert!(alignment.rms_error < 1e-9);
+
+    // without scaling the fit can't be exact
+    let alignment = align_3d(&cloud(), &scaled, None, Scaling::Rigid).unwrap();
+    assert!(alignment.rms_error > 0.1);
+}
+
+#[test]
+fn weighted_registration_3d() {
+    let mut target: Vec<_> = cloud().iter().map(moved).collect();
+    target[4] = point(10.0, 10.0, 10.0);
+
+    let unweighted = align_3d(&cloud(), &target, None, Scaling::Rigid).unwrap();
+    assert!(unweighted.rms_error > 1.0);
+
+    let weights = [1.0, 2.0, 1.0, 0.5, 0.0, 1.0];
+    let weighted = align_3d(&cloud(), &target, Some(&weights), Scaling::Rigid).unwrap();
+    assert!(weighted.rms_error < 1e-9);
+    assert_point(weighted.motion.transform_point(&cloud()[1]), target[1]);
+}
+
+#[test]
+fn registration_errors() {
+    let cloud = cloud();
+    assert_eq!(
+        align_3d(&cloud, &cloud[1..], None, Scaling::Rigid),
+        Err(RegistrationError::MismatchedPoints)
+    );
+    assert_eq!(
+        align_3d(&cloud, &cloud, Some(&[1.0; 5]), Scaling::Rigid),
+        Err(RegistrationError::InvalidWeights)
+    );
+    assert_eq!(
+        align_3d(
+            &cloud,
+            &cloud,
+            Some(&[1.0, 1.0, -1.0, 1.0, 1.0, 1.0]),
+            Scaling::Rigid
+        ),
+        Err(RegistrationError::InvalidWeights)
+    );
+    assert_eq!(
+        align_3d(&cloud, &cloud, Some(&[0.0; 6]), Scaling::Rigid),
+        Err(RegistrationError::Degenerate)
+    );
+
+    let collinear = [0.0, 1.0, 3.0].map(|t| point(t, 2.0 * t, 1.0));
+    assert_eq!(
+        align_3d(&collinear, &collinear, None, Scaling::Rigid),
+        Err(RegistrationError::Degenerate)
+    );
+
+    let coincident = [point2(1.0, 1.0); 3];
+    assert_eq!(
+        align_2d(&coincident, &coincident, None, Scaling::Uniform),
+        Err(RegistrationError::Degenerate)
+    );
+}
+
+#[test]
+fn registration_2d() {
+    let source = [
+        point2(0.0, 0.0),
+        point2(2.0, 0.0),
+        point2(0.0, 1.0),
+        point2(-1.0, 3.0),
+    ];
+    let motion = Motion::new(2.5, geometry2d::Vector::new(-1.0, 4.0));
+    let target = source.map(|p| motion.transform_point(&p));
+
+    let alignment = align_2d(&source, &target, None, Scaling::Rigid).unwrap();
+    assert!((alignment.motion.angle() - 2.5).abs() < 1e-9);
+    assert!(alignment.rms_error < 1e-9);
+    for (p, q) in source.iter().zip(&target) {
+        assert_point2(alignment.motion.transform_point(p), *q);
+    }
+
+    let scaled = source.map(|p| motion.transform_point(&point2(0.5 * p.0.x, 0.5 * p.0.y)));
+    let alignment = align_2d(&source, &scaled, None, Scaling::Uniform).unwrap();
+    assert!((alignment.scale - 0.5).abs() < 1e-9);
+    assert!(alignment.rms_error < 1e-9);
+
+    // collinear points still fix a rotation in the plane
+    let collinear = [point2(0.0, 0.0), point2(1.0, 1.0), point2(2.0, 2.0)];
+    let target = collinear.map(|p| motion.transform_point(&p));
+    let weights = [1.0, 0.0, 3.0];
+    let alignment = align_2d(&collinear, &target, Some(&weights), Scaling::Rigid).unwrap();
+    assert!((alignment.motion.angle() - 2.5).abs() < 1e-9);
+}